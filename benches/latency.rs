@@ -0,0 +1,50 @@
+//! instruction round trip and serialization benchmarks against the fake psu
+//!
+//! `cargo bench --features bench`, an optional argument sets the number of iterations
+
+use inovo_rs::bench::{self, FakePsu};
+use inovo_rs::geometry::Transform;
+use inovo_rs::iva::{GetTarget, Instruction, RobotCommand};
+use inovo_rs::robot::{CommandSequence, MotionParam};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let iterations = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(10_000);
+
+    let instructions = [
+        Instruction::exec(RobotCommand::sleep(0.0)),
+        Instruction::exec(RobotCommand::linear(Transform::new(
+            100.0, 200.0, 300.0, 180.0, 0.0, 90.0,
+        ))),
+        Instruction::exec(RobotCommand::set_parameter(
+            MotionParam::new().set_speed(50.0).set_accel(50.0),
+        )),
+        Instruction::Get(GetTarget::Transform),
+        Instruction::Get(GetTarget::JointCoord),
+    ];
+
+    println!("serialization");
+    for inst in instructions.iter() {
+        println!("{}", bench::serialization(inst, iterations)?);
+        println!("{}", bench::serialization_compact(inst, iterations)?);
+    }
+
+    let psu = FakePsu::new()?;
+    let mut bot = psu.connect()?;
+    println!("round trip");
+    for inst in instructions {
+        println!("{}", bench::round_trip(&mut bot, inst, iterations)?);
+    }
+
+    let sequence = (0..10).fold(CommandSequence::new(), |sequence, i| {
+        sequence.then_linear_relative(Transform::from_z(i as f64))
+    });
+    let report = bench::measure("sequence/10", iterations / 10, || {
+        inovo_rs::robot::IvaRobot::sequence(&mut bot, sequence.clone()).map(|_| ())
+    })?;
+    println!("{}", report);
+
+    Ok(())
+}
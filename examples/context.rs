@@ -10,10 +10,10 @@ impl ContextMachine {
         self.logger.info("doing stuff");
     }
 
-    pub fn with_context_1(&mut self) -> ContextGuard<Self, Context1> {
+    pub fn with_context_1(&mut self) -> ContextGuard<'_, Self, Context1> {
         ContextGuard::new(self, Context1)
     }
-    pub fn with_context_2(&mut self) -> ContextGuard<Self, Context2> {
+    pub fn with_context_2(&mut self) -> ContextGuard<'_, Self, Context2> {
         ContextGuard::new(self, Context2)
     }
 
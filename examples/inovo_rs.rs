@@ -5,7 +5,7 @@ use inovo_rs::robot::*;
 
 fn main() -> Result<(), RobotError> {
     // create a new default logger
-    let mut logger = Logger::default_target("Robot Example");
+    let logger = Logger::default_target("Robot Example");
 
     logger.info("Creating new robot.");
 
@@ -53,7 +53,7 @@ fn main() -> Result<(), RobotError> {
     let tx = home_transform.clone().then_x(100.0);
     let ty = home_transform.clone().then_y(100.0);
     let j1 = home_joint_coord.clone().then_j1(90.0);
-    let j2 = j1.clone() + JointCoord::from([10.0, 10.0, 10.0, 10.0, 10.0, 10.0]);
+    let j2 = &j1 + JointCoord::from([10.0, 10.0, 10.0, 10.0, 10.0, 10.0]);
 
     // Robot Command
     //
@@ -101,7 +101,7 @@ fn main() -> Result<(), RobotError> {
     // after the guard is drop
     bot.with_linear(tx.clone())?;
     {
-        let guard = bot.with_linear_relative(vz.clone())?;
+        let _guard = bot.with_linear_relative(vz.clone())?;
         // do some other stuff
     } // the robot motion will automatically reverse here
       //
@@ -115,7 +115,7 @@ fn main() -> Result<(), RobotError> {
     {
         let mut guard_1 = bot.with_joint(ty.clone())?;
         let mut guard_2 = guard_1.with_joint_relative(rz.clone())?;
-        let mut guard_3 = guard_2.with_joint(j1.clone())?;
+        let guard_3 = guard_2.with_joint(j1.clone())?;
         // do some other stuff
         //
         // you can early drop the guard and its motion will be reverse
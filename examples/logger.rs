@@ -12,7 +12,8 @@ impl target::LoggingTarget for OwOTarget {
         self.log_level
     }
     fn log_message(&mut self, msg: &String, log_level: LogLevel) {
-        let msg = msg.replace(&['r', 'l'], "w").replace(&['R', 'L'], "W");
+        let msg = Template::default().render("owo", log_level, msg);
+        let msg = msg.replace(['r', 'l'], "w").replace(['R', 'L'], "W");
         let prefix = match log_level {
             LogLevel::Trace => "OwO",
             LogLevel::Debug => "*blushes*",
@@ -28,7 +29,7 @@ fn main() {
     // initalize a logger with default target:
     // - Console target with Info level
     // - Rolling file target with Debug level
-    let mut logger = Logger::default_target("Logger");
+    let logger = Logger::default_target("Logger");
 
     // This message should be neither print to console nor log to file
     logger.trace("This is an example of a logger logging a message with level trace");
@@ -45,7 +46,7 @@ fn main() {
     let owo_target = OwOTarget {
         log_level: LogLevel::Trace,
     };
-    let mut my_logger = Logger::empty().push(Box::new(owo_target));
+    let my_logger = Logger::empty().push(Box::new(owo_target));
 
     my_logger.trace("This is an example of a logger logging a message with level trace");
     my_logger.debug("This is an example of a logger logging a message with level debug");
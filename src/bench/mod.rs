@@ -0,0 +1,132 @@
+//! Module for benchmarking the iva transport
+//!
+//! [`round_trip`] measures the instruction round trip time against a [`FakePsu`],
+//! [`serialization`] and [`serialization_compact`] measure the serialization cost of an instruction,
+//! so transport changes can be compared with numbers. `cargo bench --features bench` runs them all.
+//!
+//! Enabled with the `bench` feature.
+//!
+//! ## Example
+//! ```
+//! use inovo_rs::bench::{self, FakePsu};
+//! use inovo_rs::iva::{Instruction, RobotCommand};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let psu = FakePsu::new()?;
+//! let mut bot = psu.connect()?;
+//!
+//! let sleep = Instruction::exec(RobotCommand::sleep(0.0));
+//! println!("{}", bench::round_trip(&mut bot, sleep.clone(), 100)?);
+//! println!("{}", bench::serialization(&sleep, 100)?);
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::fake_psu::FakePsu;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::iva::Instruction;
+use crate::robot::{IvaRobot, Robot, RobotError};
+
+/// number of untimed iterations before measuring
+pub const WARMUP: usize = 10;
+
+/// The timing of a benchmark
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    pub name: String,
+    pub iterations: usize,
+    pub mean: Duration,
+    pub min: Duration,
+    pub p50: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl BenchReport {
+    /// create a report from the timing of each iteration
+    pub fn new(name: impl Into<String>, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let percentile = |p: f64| {
+            let i = ((samples.len() as f64 * p).ceil() as usize).saturating_sub(1);
+            samples.get(i).copied().unwrap_or_default()
+        };
+        let total: Duration = samples.iter().sum();
+        Self {
+            name: name.into(),
+            iterations: samples.len(),
+            mean: total / samples.len().max(1) as u32,
+            min: samples.first().copied().unwrap_or_default(),
+            p50: percentile(0.50),
+            p99: percentile(0.99),
+            max: samples.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+impl std::fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:<32} n={:<8} mean={:>10.2?} min={:>10.2?} p50={:>10.2?} p99={:>10.2?} max={:>10.2?}",
+            self.name, self.iterations, self.mean, self.min, self.p50, self.p99, self.max
+        )
+    }
+}
+
+/// time a closure for a number of iterations, after [`WARMUP`] untimed ones
+pub fn measure<E>(
+    name: impl Into<String>,
+    iterations: usize,
+    mut f: impl FnMut() -> Result<(), E>,
+) -> Result<BenchReport, E> {
+    for _ in 0..WARMUP {
+        f()?;
+    }
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+    Ok(BenchReport::new(name, samples))
+}
+
+/// measure the round trip time of an instruction, from writing it to reading the response
+pub fn round_trip(
+    bot: &mut Robot,
+    inst: Instruction,
+    iterations: usize,
+) -> Result<BenchReport, RobotError> {
+    let name = format!("round_trip/{}", inst.op_code());
+    measure(name, iterations, || {
+        black_box(bot.instruction(inst.clone())?);
+        Ok(())
+    })
+}
+
+/// measure the cost of serializing an instruction, as sent by [`Robot`]
+pub fn serialization(
+    inst: &Instruction,
+    iterations: usize,
+) -> Result<BenchReport, serde_json::Error> {
+    let name = format!("serialization/{}", inst.op_code());
+    measure(name, iterations, || {
+        black_box(inst.clone().to_json()?);
+        Ok(())
+    })
+}
+
+/// measure the cost of serializing an instruction as compact json, for comparison with [`serialization`]
+pub fn serialization_compact(
+    inst: &Instruction,
+    iterations: usize,
+) -> Result<BenchReport, serde_json::Error> {
+    let name = format!("serialization_compact/{}", inst.op_code());
+    measure(name, iterations, || {
+        black_box(serde_json::to_string(inst)?);
+        Ok(())
+    })
+}
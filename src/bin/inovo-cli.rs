@@ -0,0 +1,32 @@
+use inovo_rs::cli::*;
+use inovo_rs::robot::Robot;
+
+fn main() {
+    let options = match CliOptions::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+    if options.command == CliCommand::Help {
+        println!("{}", USAGE);
+        return;
+    }
+    if options.no_color {
+        // read by the console target of the robot logger
+        std::env::set_var("NO_COLOR", "1");
+    }
+
+    let result = Robot::defaut_logger(options.port, &options.host)
+        .map_err(CliError::from)
+        .and_then(|mut bot| options.command.run(&mut bot));
+    match result {
+        Ok(output) if output.is_empty() => {}
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
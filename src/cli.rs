@@ -0,0 +1,297 @@
+//! Command line companion, running one-off commands on the robot.
+//!
+//! the `inovo-cli` binary is built with the `cli` feature,
+//! the parsing lives here so other tools can reuse it.
+//! ```
+//! use inovo_rs::cli::*;
+//! use inovo_rs::geometry::Transform;
+//!
+//! let options = CliOptions::parse(["--host", "psu002", "linear", "100", "0", "200", "0", "180", "0"]).unwrap();
+//! assert_eq!(options.host, "psu002");
+//! assert_eq!(
+//!     options.command,
+//!     CliCommand::Linear(Transform::new(100.0, 0.0, 200.0, 0.0, 180.0, 0.0))
+//! );
+//! ```
+
+use std::path::PathBuf;
+
+use crate::geometry::{JointCoord, Transform};
+use crate::iva::{GripperCommand, IOTarget, VacuumCommand};
+use crate::robot::{CommandSequence, IvaRobot, MotionParam, Robot, RobotError};
+
+/// the environment variable of the default host
+pub const HOST_VAR: &str = "INOVO_HOST";
+
+/// the default port the robot connects back to
+pub const DEFAULT_PORT: u16 = 50003;
+
+/// the usage of the command line
+pub const USAGE: &str = "\
+usage: inovo-cli [--host <host>] [--port <port>] [--no-color] <command>
+
+options:
+    --host <host>    the host of the robot, default to $INOVO_HOST
+    --port <port>    the port the robot connects back to, default to 50003
+    --no-color       log without color, as with $NO_COLOR
+
+commands:
+    linear <x> <y> <z> <rx> <ry> <rz>             linear motion, in mm and degree
+    linear-relative <x> <y> <z> <rx> <ry> <rz>    relative linear motion
+    joint <j1> <j2> <j3> <j4> <j5> <j6>           joint motion, in degree
+    joint-relative <x> <y> <z> <rx> <ry> <rz>     relative joint motion
+    speed <percent>                               set the speed of the motion
+    sleep <second>                                sleep on the robot
+    io set <port> <on|off> [--wrist]              set a digital output
+    io get <port> [--wrist]                       get a digital input
+    get <transform|joint>                         get the current pose
+    get data <key>                                get a value from the data dictionary
+    gripper <activate|get>                        activate or get the width of the gripper
+    gripper set <label>                           set the gripper to a label
+    gripper width <mm> <force>                    set the gripper width
+    vacuum <on|off|get>                           control the vacuum
+    run-seq <file.json>                           run a command sequence from a json file
+    shell [waypoints.json]                        interactive shell, with the shell feature
+    help                                          print this message";
+
+/// A command of the command line
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliCommand {
+    Linear(Transform),
+    LinearRelative(Transform),
+    Joint(JointCoord),
+    JointRelative(Transform),
+    Speed(f64),
+    Sleep(f64),
+    IoSet {
+        target: IOTarget,
+        port: u16,
+        state: bool,
+    },
+    IoGet {
+        target: IOTarget,
+        port: u16,
+    },
+    GetTransform,
+    GetJoint,
+    GetData(String),
+    Gripper(GripperCommand),
+    Vacuum(VacuumCommand),
+    RunSequence(PathBuf),
+    #[cfg(feature = "shell")]
+    Shell(PathBuf),
+    Help,
+}
+
+/// The options of the command line
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliOptions {
+    pub host: String,
+    pub port: u16,
+    /// log without color, see [`ColorMode`](crate::logger::target::ColorMode)
+    pub no_color: bool,
+    pub command: CliCommand,
+}
+
+/// Error of the command line
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error("{0}\n\n{USAGE}")]
+    Usage(String),
+    #[error(transparent)]
+    Robot(#[from] RobotError),
+    #[error("failed to read sequence: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid sequence: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+fn usage(msg: impl Into<String>) -> CliError {
+    CliError::Usage(msg.into())
+}
+
+impl CliOptions {
+    /// parse the arguments, without the program name
+    pub fn parse<I, S>(args: I) -> Result<Self, CliError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut host = std::env::var(HOST_VAR).ok();
+        let mut port = DEFAULT_PORT;
+        let mut wrist = false;
+        let mut no_color = false;
+        let mut words = vec![];
+
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--host" => host = Some(args.next().ok_or_else(|| usage("missing host"))?),
+                "--port" => {
+                    let value = args.next().ok_or_else(|| usage("missing port"))?;
+                    port = value
+                        .parse()
+                        .map_err(|_| usage(format!("invalid port: {}", value)))?;
+                }
+                "--wrist" => wrist = true,
+                "--no-color" => no_color = true,
+                "-h" | "--help" => words.push("help".to_string()),
+                _ => words.push(arg),
+            }
+        }
+
+        let command = CliCommand::parse(&words, wrist)?;
+        let host = match (&command, host) {
+            (CliCommand::Help, host) => host.unwrap_or_default(),
+            (_, Some(host)) => host,
+            (_, None) => return Err(usage(format!("missing host, use --host or ${}", HOST_VAR))),
+        };
+        Ok(CliOptions {
+            host,
+            port,
+            no_color,
+            command,
+        })
+    }
+}
+
+/// parse every word into a number
+fn numbers<const N: usize>(words: &[String]) -> Result<[f64; N], CliError> {
+    if words.len() != N {
+        return Err(usage(format!(
+            "expected {} numbers, got {}",
+            N,
+            words.len()
+        )));
+    }
+    let mut values = [0.0; N];
+    for (value, word) in values.iter_mut().zip(words) {
+        *value = word
+            .parse()
+            .map_err(|_| usage(format!("invalid number: {}", word)))?;
+    }
+    Ok(values)
+}
+
+fn port(word: Option<&String>) -> Result<u16, CliError> {
+    let word = word.ok_or_else(|| usage("missing io port"))?;
+    word.parse()
+        .map_err(|_| usage(format!("invalid io port: {}", word)))
+}
+
+impl CliCommand {
+    /// parse the command from the words, without options
+    pub fn parse(words: &[String], wrist: bool) -> Result<Self, CliError> {
+        let (command, args) = words
+            .split_first()
+            .ok_or_else(|| usage("missing command"))?;
+        let args_str = args.iter().map(String::as_str).collect::<Vec<_>>();
+        let io_target = if wrist {
+            IOTarget::Wrist
+        } else {
+            IOTarget::Beckhoff
+        };
+
+        let command = match (command.as_str(), args_str.as_slice()) {
+            ("linear", _) => CliCommand::Linear(Transform::from_array(numbers(args)?)),
+            ("linear-relative", _) => {
+                CliCommand::LinearRelative(Transform::from_array(numbers(args)?))
+            }
+            ("joint", _) => CliCommand::Joint(JointCoord::from(numbers::<6>(args)?)),
+            ("joint-relative", _) => {
+                CliCommand::JointRelative(Transform::from_array(numbers(args)?))
+            }
+            ("speed", _) => CliCommand::Speed(numbers::<1>(args)?[0]),
+            ("sleep", _) => CliCommand::Sleep(numbers::<1>(args)?[0]),
+            ("io", ["set", _, state]) => CliCommand::IoSet {
+                target: io_target,
+                port: port(args.get(1))?,
+                state: match *state {
+                    "on" | "true" | "1" => true,
+                    "off" | "false" | "0" => false,
+                    _ => return Err(usage(format!("invalid io state: {}", state))),
+                },
+            },
+            ("io", ["get", _]) => CliCommand::IoGet {
+                target: io_target,
+                port: port(args.get(1))?,
+            },
+            ("get", ["transform"]) => CliCommand::GetTransform,
+            ("get", ["joint"]) => CliCommand::GetJoint,
+            ("get", ["data", key]) => CliCommand::GetData(key.to_string()),
+            ("gripper", ["activate"]) => CliCommand::Gripper(GripperCommand::Activate),
+            ("gripper", ["get"]) => CliCommand::Gripper(GripperCommand::Get),
+            ("gripper", ["set", label]) => CliCommand::Gripper(GripperCommand::Set {
+                label: label.to_string(),
+            }),
+            ("gripper", ["width", ..]) => {
+                let [mm, force] = numbers(&args[1..])?;
+                CliCommand::Gripper(GripperCommand::SetWidth { mm, force })
+            }
+            ("vacuum", ["on"]) => CliCommand::Vacuum(VacuumCommand::On),
+            ("vacuum", ["off"]) => CliCommand::Vacuum(VacuumCommand::Off),
+            ("vacuum", ["get"]) => CliCommand::Vacuum(VacuumCommand::Get),
+            ("run-seq", [file]) => CliCommand::RunSequence(PathBuf::from(file)),
+            #[cfg(feature = "shell")]
+            ("shell", []) => CliCommand::Shell(PathBuf::from("waypoints.json")),
+            #[cfg(feature = "shell")]
+            ("shell", [file]) => CliCommand::Shell(PathBuf::from(file)),
+            ("help", _) => CliCommand::Help,
+            _ => return Err(usage(format!("invalid command: {}", words.join(" ")))),
+        };
+        Ok(command)
+    }
+
+    /// run the command on the robot, returning the output to print
+    pub fn run(&self, bot: &mut Robot) -> Result<String, CliError> {
+        let ok = || "OK".to_string();
+        let output = match self {
+            CliCommand::Linear(target) => bot.linear(target.clone()).map(|_| ok())?,
+            CliCommand::LinearRelative(target) => {
+                bot.linear_relative(target.clone()).map(|_| ok())?
+            }
+            CliCommand::Joint(target) => bot.joint(target.clone()).map(|_| ok())?,
+            CliCommand::JointRelative(target) => {
+                bot.joint_relative(target.clone()).map(|_| ok())?
+            }
+            CliCommand::Speed(percent) => bot
+                .set_param(MotionParam::new().set_speed(*percent))
+                .map(|_| ok())?,
+            CliCommand::Sleep(second) => bot.sleep(*second).map(|_| ok())?,
+            CliCommand::IoSet {
+                target,
+                port,
+                state,
+            } => bot.io_set(target.clone(), *port, *state).map(|_| ok())?,
+            CliCommand::IoGet { target, port } => bot.io_get(target.clone(), *port)?.to_string(),
+            CliCommand::GetTransform => bot.get_current_transform()?.to_string(),
+            CliCommand::GetJoint => bot.get_current_joint()?.to_string(),
+            CliCommand::GetData(key) => bot.get_data::<String>(key)?,
+            CliCommand::Gripper(GripperCommand::Activate) => {
+                bot.gripper_activate().map(|_| ok())?
+            }
+            CliCommand::Gripper(GripperCommand::Get) => bot.gripper_get()?.to_string(),
+            CliCommand::Gripper(GripperCommand::Set { label }) => {
+                bot.gripper_set(label).map(|_| ok())?
+            }
+            CliCommand::Gripper(GripperCommand::SetWidth { mm, force }) => {
+                bot.gripper_set_width(*mm, *force).map(|_| ok())?
+            }
+            CliCommand::Vacuum(VacuumCommand::On) => bot.vacuum_on().map(|_| ok())?,
+            CliCommand::Vacuum(VacuumCommand::Off) => bot.vacuum_off().map(|_| ok())?,
+            CliCommand::Vacuum(VacuumCommand::Get) => bot.vacuum_pressure()?.to_string(),
+            CliCommand::RunSequence(path) => {
+                let sequence: CommandSequence =
+                    serde_json::from_str(&std::fs::read_to_string(path)?)?;
+                bot.sequence(sequence).map(|_| ok())?
+            }
+            #[cfg(feature = "shell")]
+            CliCommand::Shell(path) => {
+                crate::shell::Shell::new(path)?.run(bot)?;
+                String::new()
+            }
+            CliCommand::Help => USAGE.to_string(),
+        };
+        Ok(output)
+    }
+}
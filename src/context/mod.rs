@@ -14,10 +14,10 @@
 //!         self.logger.info("doing stuff");
 //!     }
 //!
-//!     pub fn with_context_1(&mut self) -> ContextGuard<Self, Context1> {
+//!     pub fn with_context_1(&mut self) -> ContextGuard<'_, Self, Context1> {
 //!         ContextGuard::new(self, Context1)
 //!     }
-//!     pub fn with_context_2(&mut self) -> ContextGuard<Self, Context2> {
+//!     pub fn with_context_2(&mut self) -> ContextGuard<'_, Self, Context2> {
 //!         ContextGuard::new(self, Context2)
 //!     }
 //!
@@ -133,10 +133,27 @@
 //!         // drop(guard_2);
 //!         // guard_1.doing_stuff();
 //!     }
+//!
+//!     // Transactional usage
+//!     //
+//!     // to only clean up on the error path,
+//!     // commit the guard when the operation succeed
+//!     let guard = context_machine.with_context_1();
+//!     let succeed = true;
+//!     if succeed {
+//!         // the context is kept, `clean_up_1` is not called
+//!         guard.commit();
+//!     }
 //! }
 //! ```
+//!
+//! ## Fallible exit
+//! the exit function of [`Context`] cannot report failure, as it run on drop, possibly during unwinding.
+//! contexts implementing [`TryContext`] can be exited with [`ContextGuard::finish`], returning the error instead,
+//! or run a closure in with [`ContextGuard::scope`].
 
 use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 /// The trait for context
 ///
@@ -148,28 +165,76 @@ pub trait Context<T: ?Sized> {
     fn context_drop(&mut self, machine: &mut T);
 }
 
+/// The trait for context which exit can fail
+///
+/// see [`ContextGuard::finish`]
+pub trait TryContext<T: ?Sized>: Context<T> {
+    /// the error of exiting the context
+    type Error;
+    /// function execute when exit context with [`ContextGuard::finish`]
+    fn try_context_drop(&mut self, machine: &mut T) -> Result<(), Self::Error>;
+}
+
 /// The RAII guard of context
 ///
 /// when it is first create, it will execute the enter function of the context
 ///
-/// and when it is drop, it wll execute the exit function of the context
+/// and when it is drop, it wll execute the exit function of the context,
+/// unless it is consumed by [`ContextGuard::commit`]
 ///
 /// see module document for more
 pub struct ContextGuard<'a, T: ?Sized, C: Context<T>> {
     guard: &'a mut T,
-    context: C,
+    /// the context, taken on drop or commit
+    context: Option<C>,
 }
 
 impl<'a, T: ?Sized, C: Context<T>> ContextGuard<'a, T, C> {
     pub fn new(guard: &'a mut T, mut context: C) -> Self {
         context.context_enter(guard);
-        Self { guard, context }
+        Self {
+            guard,
+            context: Some(context),
+        }
+    }
+
+    /// consume the guard without executing the exit function of the context,
+    /// keeping the context entered
+    ///
+    /// return the context, so it can be exited manually later
+    pub fn commit(mut self) -> C {
+        self.context.take().unwrap()
+    }
+
+    /// consume the guard and exit the context, returning the error if exiting failed
+    /// instead of ignoring it on drop
+    pub fn finish(mut self) -> Result<(), C::Error>
+    where
+        C: TryContext<T>,
+    {
+        let mut context = self.context.take().unwrap();
+        context.try_context_drop(self.guard)
+    }
+
+    /// run a closure within the context, then exit the context with [`ContextGuard::finish`]
+    ///
+    /// if the closure fail, its error is returned and the context is exited on drop.
+    pub fn scope<R, E>(self, f: impl FnOnce(&mut T) -> Result<R, E>) -> Result<R, E>
+    where
+        C: TryContext<T>,
+        E: From<C::Error>,
+    {
+        let r = f(self.guard)?;
+        self.finish()?;
+        Ok(r)
     }
 }
 
 impl<'a, T: ?Sized, C: Context<T>> Drop for ContextGuard<'a, T, C> {
     fn drop(&mut self) {
-        self.context.context_drop(&mut self.guard)
+        if let Some(mut context) = self.context.take() {
+            context.context_drop(self.guard)
+        }
     }
 }
 
@@ -185,3 +250,111 @@ impl<'a, T: ?Sized, C: Context<T>> DerefMut for ContextGuard<'a, T, C> {
         self.guard
     }
 }
+
+/// The owned RAII guard of context, over a shared machine
+///
+/// unlike [`ContextGuard`], it does not borrow the machine,
+/// so it can be stored in a struct alongside the machine, e.g. in long-lived application state.
+///
+/// the machine is locked when entering and exiting the context, and by [`OwnedContextGuard::lock`].
+///
+/// # Example
+/// ```no_run
+/// use inovo_rs::context::*;
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::robot::*;
+/// use std::sync::{Arc, Mutex};
+///
+/// struct Cell {
+///     robot: Arc<Mutex<Robot>>,
+///     above_part: Option<OwnedContextGuard<Robot, IvaContext>>,
+/// }
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let robot = Arc::new(Mutex::new(Robot::defaut_logger(50003, "psu002")?));
+/// let mut cell = Cell {
+///     robot: robot.clone(),
+///     above_part: None,
+/// };
+///
+/// // the robot stay above the part until the guard is dropped
+/// cell.above_part = Some(OwnedContextGuard::enter(robot, |bot| {
+///     bot.with_linear_relative(Transform::from_z(100.0))
+/// })?);
+///
+/// cell.robot.lock().unwrap().sleep(1.0)?;
+///
+/// // move back
+/// cell.above_part = None;
+/// # Ok(())
+/// # }
+/// ```
+pub struct OwnedContextGuard<T: ?Sized, C: Context<T>> {
+    machine: Arc<Mutex<T>>,
+    /// the context, taken on drop or commit
+    context: Option<C>,
+}
+
+impl<T: ?Sized, C: Context<T>> OwnedContextGuard<T, C> {
+    /// enter a context on a shared machine
+    pub fn new(machine: Arc<Mutex<T>>, mut context: C) -> Self {
+        context.context_enter(&mut lock(&machine));
+        Self {
+            machine,
+            context: Some(context),
+        }
+    }
+
+    /// enter a context with a function returning a borrowing [`ContextGuard`],
+    /// and take over the context
+    pub fn enter<E, F>(machine: Arc<Mutex<T>>, enter: F) -> Result<Self, E>
+    where
+        F: for<'a> FnOnce(&'a mut T) -> Result<ContextGuard<'a, T, C>, E>,
+    {
+        let context = enter(&mut lock(&machine))?.commit();
+        Ok(Self {
+            machine,
+            context: Some(context),
+        })
+    }
+
+    /// lock the machine
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        lock(&self.machine)
+    }
+
+    /// get the shared machine
+    pub fn machine(&self) -> &Arc<Mutex<T>> {
+        &self.machine
+    }
+
+    /// consume the guard without executing the exit function of the context,
+    /// see [`ContextGuard::commit`]
+    pub fn commit(mut self) -> C {
+        self.context.take().unwrap()
+    }
+
+    /// consume the guard and exit the context, returning the error if exiting failed,
+    /// see [`ContextGuard::finish`]
+    pub fn finish(mut self) -> Result<(), C::Error>
+    where
+        C: TryContext<T>,
+    {
+        let mut context = self.context.take().unwrap();
+        let result = context.try_context_drop(&mut self.lock());
+        result
+    }
+}
+
+impl<T: ?Sized, C: Context<T>> Drop for OwnedContextGuard<T, C> {
+    fn drop(&mut self) {
+        if let Some(mut context) = self.context.take() {
+            context.context_drop(&mut lock(&self.machine))
+        }
+    }
+}
+
+/// lock a machine, a panic while holding the lock does not prevent exiting contexts
+fn lock<T: ?Sized>(machine: &Mutex<T>) -> MutexGuard<'_, T> {
+    machine.lock().unwrap_or_else(|e| e.into_inner())
+}
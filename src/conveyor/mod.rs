@@ -0,0 +1,150 @@
+//! Module for tracking parts on a moving conveyor
+//!
+//! A [`Conveyor`] reads its position from a [`ConveyorSource`], an encoder or a ROS topic,
+//! and shifts the targets latched at a known position along its direction of travel,
+//! so a part located by a sensor can be picked while moving.
+//!
+//! ## Example
+//! ```no_run
+//! use inovo_rs::conveyor::*;
+//! use inovo_rs::geometry::*;
+//! use inovo_rs::robot::*;
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), RobotError> {
+//! let handle = RobotHandle::new(Robot::defaut_logger(50003, "psu002")?);
+//!
+//! // the robot program counts the encoder in its data dictionary, 0.1 mm per count
+//! let encoder = RobotDataEncoder::new(handle.clone(), "conveyor_counts", 0.1);
+//! let mut conveyor = Conveyor::new(encoder, [1.0, 0.0, 0.0])?;
+//!
+//! // a part located by the camera, latched when the picture is taken
+//! let part = conveyor.latch(Transform::from_vector([300.0, -200.0, 50.0]))?;
+//!
+//! // aim where the part will be once the motion is done
+//! let target = conveyor.predict(&part, Duration::from_millis(400))?;
+//! handle.with(|bot| bot.linear(target.then_z(30.0)).map(|_| ()))?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod source;
+
+pub use source::*;
+
+use std::time::{Duration, Instant};
+
+use crate::context::Context;
+use crate::geometry::Transform;
+use crate::robot::{IvaContext, IvaRobot, RobotError};
+
+/// A target latched at a conveyor position, see [`Conveyor::latch`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackedTarget {
+    /// the target when latched, in the robot base frame
+    pub target: Transform,
+    /// the conveyor position when latched, in mm
+    pub latch_mm: f64,
+}
+
+/// A conveyor, moving the tracked targets along its direction of travel
+pub struct Conveyor<S: ConveyorSource> {
+    source: S,
+    direction: [f64; 3],
+    last: Option<(f64, Instant)>,
+    speed_mm_s: f64,
+}
+
+impl<S: ConveyorSource> Conveyor<S> {
+    /// create a new conveyor, with the direction of travel in the robot base frame
+    pub fn new(source: S, direction: [f64; 3]) -> Result<Self, RobotError> {
+        let norm = direction.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if !(norm.is_finite() && norm > 0.0) {
+            return Err(RobotError::InvalidArgument(format!(
+                "invalid conveyor direction: {:?}",
+                direction
+            )));
+        }
+        Ok(Self {
+            source,
+            direction: direction.map(|v| v / norm),
+            last: None,
+            speed_mm_s: 0.0,
+        })
+    }
+
+    /// get the unit direction of travel
+    pub fn direction(&self) -> [f64; 3] {
+        self.direction
+    }
+    /// get the speed estimated from the last two readings, in mm/s
+    pub fn speed_mm_s(&self) -> f64 {
+        self.speed_mm_s
+    }
+    /// get the source of the position
+    pub fn source(&mut self) -> &mut S {
+        &mut self.source
+    }
+
+    /// read the current position, updating the speed estimate
+    pub fn position_mm(&mut self) -> Result<f64, RobotError> {
+        let position = self.source.read_mm()?;
+        let now = Instant::now();
+        if let Some((last_position, last_time)) = self.last {
+            let elapsed = now.duration_since(last_time).as_secs_f64();
+            if elapsed > 0.0 {
+                self.speed_mm_s = (position - last_position) / elapsed;
+            }
+        }
+        self.last = Some((position, now));
+        Ok(position)
+    }
+
+    /// latch a target at the current position
+    pub fn latch(&mut self, target: Transform) -> Result<TrackedTarget, RobotError> {
+        Ok(TrackedTarget {
+            target,
+            latch_mm: self.position_mm()?,
+        })
+    }
+    /// get the distance a tracked target travelled since latched, in mm
+    pub fn travelled_mm(&mut self, tracked: &TrackedTarget) -> Result<f64, RobotError> {
+        Ok(self.position_mm()? - tracked.latch_mm)
+    }
+
+    /// get the current position of a tracked target
+    pub fn shift(&mut self, tracked: &TrackedTarget) -> Result<Transform, RobotError> {
+        let travelled = self.travelled_mm(tracked)?;
+        Ok(self.shifted(tracked, travelled))
+    }
+    /// get the position of a tracked target after a lead time, at the estimated speed
+    pub fn predict(
+        &mut self,
+        tracked: &TrackedTarget,
+        lead: Duration,
+    ) -> Result<Transform, RobotError> {
+        let travelled = self.travelled_mm(tracked)? + self.speed_mm_s * lead.as_secs_f64();
+        Ok(self.shifted(tracked, travelled))
+    }
+
+    /// move the robot linearly to the predicted position of a tracked target
+    pub fn linear_to<'a, R: IvaRobot>(
+        &mut self,
+        bot: &'a mut R,
+        tracked: &TrackedTarget,
+        lead: Duration,
+    ) -> Result<&'a mut R, RobotError>
+    where
+        IvaContext: Context<R>,
+    {
+        let target = self.predict(tracked, lead)?;
+        bot.linear(target)
+    }
+
+    fn shifted(&self, tracked: &TrackedTarget, travelled_mm: f64) -> Transform {
+        tracked
+            .target
+            .clone()
+            .then_vector(self.direction.map(|v| v * travelled_mm))
+    }
+}
@@ -0,0 +1,135 @@
+use crate::robot::{IvaRobot, RobotError, RobotHandle};
+use crate::ros_bridge::{RosBridge, RosBridgeError};
+
+/// A source of the position of a conveyor, in mm along its direction of travel
+///
+/// any `FnMut() -> Result<f64, RobotError>` is a source, e.g. for a custom encoder.
+pub trait ConveyorSource {
+    /// read the current position, in mm
+    fn read_mm(&mut self) -> Result<f64, RobotError>;
+}
+
+impl<F> ConveyorSource for F
+where
+    F: FnMut() -> Result<f64, RobotError>,
+{
+    fn read_mm(&mut self) -> Result<f64, RobotError> {
+        self()
+    }
+}
+
+/// An encoder counted by the robot program, read from the data dictionary
+pub struct RobotDataEncoder {
+    handle: RobotHandle,
+    key: String,
+    mm_per_count: f64,
+}
+
+impl RobotDataEncoder {
+    /// read the encoder counts stored under `key`
+    pub fn new(handle: RobotHandle, key: impl Into<String>, mm_per_count: f64) -> Self {
+        Self {
+            handle,
+            key: key.into(),
+            mm_per_count,
+        }
+    }
+}
+
+impl ConveyorSource for RobotDataEncoder {
+    fn read_mm(&mut self) -> Result<f64, RobotError> {
+        let counts: f64 = self.handle.with(|bot| bot.get_data(&self.key))?;
+        Ok(counts * self.mm_per_count)
+    }
+}
+
+/// A position published on a ROS topic, read through the ros bridge
+pub struct RosTopicSource {
+    ros_bridge: RosBridge,
+    topic: String,
+    msg_type: String,
+    pointer: String,
+    mm_per_unit: f64,
+}
+
+impl RosTopicSource {
+    /// read the field at the json `pointer` of the messages, e.g. `/data` of a `std_msgs/Float64`
+    pub fn new(
+        ros_bridge: RosBridge,
+        topic: impl Into<String>,
+        msg_type: impl Into<String>,
+        pointer: impl Into<String>,
+    ) -> Self {
+        Self {
+            ros_bridge,
+            topic: topic.into(),
+            msg_type: msg_type.into(),
+            pointer: pointer.into(),
+            mm_per_unit: 1.0,
+        }
+    }
+    /// set the scale of the published value, e.g. 1000 for a position in m
+    pub fn set_mm_per_unit(mut self, mm_per_unit: f64) -> Self {
+        self.mm_per_unit = mm_per_unit;
+        self
+    }
+}
+
+impl ConveyorSource for RosTopicSource {
+    fn read_mm(&mut self) -> Result<f64, RobotError> {
+        let msg = self.ros_bridge.read_topic(&self.topic, &self.msg_type)?;
+        let value = msg
+            .pointer(&self.pointer)
+            .and_then(|v| v.as_f64())
+            .ok_or(RosBridgeError::UnexpectedValue)?;
+        Ok(value * self.mm_per_unit)
+    }
+}
+
+/// A 32 bit encoder counter in two holding registers of a Modbus device, high word first
+///
+/// the counter may wrap around, the position keeps counting.
+#[cfg(feature = "modbus")]
+pub struct ModbusEncoder {
+    client: crate::modbus::ModbusClient,
+    address: u16,
+    mm_per_count: f64,
+    last: Option<u32>,
+    counts: i64,
+}
+
+#[cfg(feature = "modbus")]
+impl ModbusEncoder {
+    /// read the counter at `address` and `address + 1`
+    pub fn new(client: crate::modbus::ModbusClient, address: u16, mm_per_count: f64) -> Self {
+        Self {
+            client,
+            address,
+            mm_per_count,
+            last: None,
+            counts: 0,
+        }
+    }
+}
+
+#[cfg(feature = "modbus")]
+impl ConveyorSource for ModbusEncoder {
+    fn read_mm(&mut self) -> Result<f64, RobotError> {
+        let registers = self.client.read_holding_registers(self.address, 2)?;
+        let raw = match registers[..] {
+            [high, low] => (high as u32) << 16 | low as u32,
+            _ => {
+                return Err(crate::modbus::ModbusError::UnexpectedResponse(format!(
+                    "{} registers",
+                    registers.len()
+                ))
+                .into())
+            }
+        };
+        match self.last.replace(raw) {
+            Some(last) => self.counts += raw.wrapping_sub(last) as i32 as i64,
+            None => self.counts = raw as i32 as i64,
+        }
+        Ok(self.counts as f64 * self.mm_per_count)
+    }
+}
@@ -0,0 +1,196 @@
+//! Module for an in process fake of the psu
+//!
+//! the [`FakePsu`] answers the iva instructions over tcp, so code driving a [`Robot`]
+//! can be exercised and benchmarked without an arm, see [`bench`](crate::bench) with the `bench` feature.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::iva::{GetTarget, GripperCommand, IOCommand, Instruction, VacuumCommand};
+use crate::logger::Logger;
+use crate::robot::Robot;
+use crate::socket::Stream;
+
+/// the answer of the fake psu to a json instruction, `None` to drop the connection
+type Responder = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// An in process fake of the psu, answering the iva instructions over tcp
+///
+/// every connection is served by its own thread, instructions are framed as json,
+/// pretty or compact, and answered by the responder.
+/// # Example
+/// ```
+/// use inovo_rs::fake_psu::FakePsu;
+/// use inovo_rs::robot::*;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let psu = FakePsu::new()?;
+/// let mut bot = psu.connect()?;
+/// bot.sleep(1.0)?;
+/// assert_eq!(psu.instructions().len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FakePsu {
+    addr: SocketAddr,
+    instructions: Arc<Mutex<Vec<Instruction>>>,
+    responder: Responder,
+}
+
+impl FakePsu {
+    /// start a fake psu, answering with [`FakePsu::default_response`]
+    pub fn new() -> Result<Self, io::Error> {
+        Self::with_responder(Self::default_response)
+    }
+    /// start a fake psu, answering with a custom responder
+    pub fn with_responder(
+        responder: impl Fn(&Instruction) -> String + Send + Sync + 'static,
+    ) -> Result<Self, io::Error> {
+        Self::with_dropping_responder(move |inst| Some(responder(inst)))
+    }
+    /// start a fake psu, dropping the connection when the responder answers `None`
+    pub fn with_dropping_responder(
+        responder: impl Fn(&Instruction) -> Option<String> + Send + Sync + 'static,
+    ) -> Result<Self, io::Error> {
+        let instructions = Arc::new(Mutex::new(vec![]));
+        let records = instructions.clone();
+        let responder: Responder =
+            Arc::new(
+                move |json: &str| match serde_json::from_str::<Instruction>(json) {
+                    Ok(inst) => {
+                        let response = responder(&inst);
+                        records.lock().unwrap().push(inst);
+                        response
+                    }
+                    Err(e) => Some(format!("invalid instruction: {}", e)),
+                },
+            );
+        Self::listen(instructions, responder)
+    }
+    /// start a fake psu answering the raw json, without recording the instructions
+    pub fn with_json_responder(
+        responder: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> Result<Self, io::Error> {
+        Self::listen(Arc::new(Mutex::new(vec![])), Arc::new(responder))
+    }
+    /// listen for robots, serving every connection on its own thread
+    fn listen(
+        instructions: Arc<Mutex<Vec<Instruction>>>,
+        responder: Responder,
+    ) -> Result<Self, io::Error> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let serving = responder.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let responder = serving.clone();
+                thread::spawn(move || serve(stream, responder));
+            }
+        });
+        Ok(Self {
+            addr,
+            instructions,
+            responder,
+        })
+    }
+
+    /// connect to a robot listening, as the iva sequence does when started, serving in the background
+    pub fn dial(&self, addr: SocketAddr) -> Result<(), io::Error> {
+        let stream = TcpStream::connect(addr)?;
+        let responder = self.responder.clone();
+        thread::spawn(move || serve(stream, responder));
+        Ok(())
+    }
+
+    /// the address the fake psu is listening on
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+    /// connect a new [`Robot`] to the fake psu, with an empty logger
+    pub fn connect(&self) -> Result<Robot, io::Error> {
+        let logger = Logger::empty();
+        let stream = Stream::new(TcpStream::connect(self.addr)?, logger.clone())?;
+        Ok(Robot::new(stream, logger))
+    }
+    /// get a copy of the instructions received, across all connections
+    pub fn instructions(&self) -> Vec<Instruction> {
+        self.instructions.lock().unwrap().clone()
+    }
+
+    /// the default response, `"OK"` for commands, and a plausible value for queries
+    pub fn default_response(inst: &Instruction) -> String {
+        if let Instruction::Get(GetTarget::DataBatch { keys }) = inst {
+            let values = keys.iter().map(|key| (key.clone(), "0".into()));
+            return serde_json::Value::Object(values.collect()).to_string();
+        }
+        if let Instruction::Get(GetTarget::Snapshot) = inst {
+            let transform = Self::default_response(&Instruction::get(GetTarget::Transform));
+            let joints = Self::default_response(&Instruction::get(GetTarget::JointCoord));
+            return serde_json::json!({
+                "transform": transform,
+                "joint_coord": joints,
+                "beckhoff": 0,
+                "wrist": 0,
+                "gripper": "0",
+            })
+            .to_string();
+        }
+        match inst {
+            Instruction::Get(GetTarget::Transform) => "{rx: 0, ry: 0, rz: 0, x: 0, y: 0, z: 0}",
+            Instruction::Get(
+                GetTarget::JointCoord | GetTarget::JointTorques | GetTarget::Wrench,
+            ) => "[0, 0, 0, 0, 0, 0]",
+            Instruction::Get(GetTarget::TcpVelocity) => "[0, 0]",
+            Instruction::Get(GetTarget::Queue | GetTarget::BeckhoffModules) => "[]",
+            Instruction::Get(GetTarget::Info) => {
+                r#"{"model": "fake", "serial_number": "0", "software_version": "0", "tool": "None"}"#
+            }
+            Instruction::Get(GetTarget::ContextDepth | GetTarget::Data { .. }) => "0",
+            Instruction::IO {
+                io_command: IOCommand::Get,
+                ..
+            } => "False",
+            Instruction::IO {
+                io_command: IOCommand::GetBank { .. },
+                ..
+            }
+            | Instruction::Gripper(GripperCommand::Get)
+            | Instruction::Vacuum(VacuumCommand::Get) => "0",
+            Instruction::Custom(_) => "",
+            _ => "OK",
+        }
+        .to_string()
+    }
+}
+
+/// answer the instructions of a connection until it is closed
+fn serve(stream: TcpStream, responder: Responder) {
+    let Ok(mut writer) = stream.try_clone() else {
+        return;
+    };
+    let mut buffer = String::new();
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            return;
+        };
+        buffer.push_str(&line);
+        buffer.push('\n');
+        // the instruction spans multiple lines
+        if matches!(serde_json::from_str::<serde_json::Value>(&buffer), Err(e) if e.is_eof()) {
+            continue;
+        }
+        let Some(response) = responder(&std::mem::take(&mut buffer)) else {
+            let _ = writer.shutdown(std::net::Shutdown::Both);
+            return;
+        };
+        if writer
+            .write_all(format!("{}\n", response).as_bytes())
+            .is_err()
+        {
+            return;
+        }
+    }
+}
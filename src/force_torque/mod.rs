@@ -0,0 +1,217 @@
+//! Module for reading wrist force/torque sensors
+//!
+//! A [`ForceTorqueSensor`] returns a [`Wrench`], either read through the robot with
+//! [`IvaRobot::get_wrench`], or directly from the sensor with the [`NetFtSensor`] driver,
+//! speaking the ATI Net F/T raw data transfer protocol over udp.
+//!
+//! Enabled with the `force_torque` feature.
+//!
+//! ## Example
+//! ```no_run
+//! use inovo_rs::force_torque::*;
+//!
+//! let mut sensor = NetFtSensor::connect("192.168.1.60:49152", None).unwrap();
+//! sensor.tare().unwrap();
+//!
+//! let wrench = sensor.read_wrench().unwrap();
+//! if wrench.force_norm() > 20.0 {
+//!     println!("contact: {}", wrench);
+//! }
+//! ```
+
+use std::io;
+use std::net::{ToSocketAddrs, UdpSocket};
+use std::time::Duration;
+
+use crate::geometry::Wrench;
+use crate::logger::{Logable, Logger};
+use crate::robot::{IvaRobot, Robot, RobotError};
+
+/// the header of every raw data transfer request
+const RDT_HEADER: u16 = 0x1234;
+/// the command to stream real time samples, a single one when the count is 1
+const RDT_START_STREAMING: u16 = 0x0002;
+/// the command to stop streaming
+const RDT_STOP_STREAMING: u16 = 0x0000;
+/// the size of a raw data transfer record
+const RDT_RECORD_SIZE: usize = 36;
+/// the default udp port of the sensor
+pub const NET_FT_PORT: u16 = 49152;
+
+/// A sensor measuring the force and torque at the wrist
+pub trait ForceTorqueSensor {
+    /// read the current wrench
+    fn read_wrench(&mut self) -> Result<Wrench, RobotError>;
+}
+
+/// a sensor attached to the robot, read through iva
+impl ForceTorqueSensor for Robot {
+    fn read_wrench(&mut self) -> Result<Wrench, RobotError> {
+        self.get_wrench()
+    }
+}
+
+/// A raw data transfer record of a Net F/T sensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetFtRecord {
+    /// the sequence number of the record
+    pub rdt_sequence: u32,
+    /// the internal sample number of the sensor
+    pub ft_sequence: u32,
+    /// the system status code, zero when healthy
+    pub status: u32,
+    /// `[fx, fy, fz, tx, ty, tz]` in counts
+    pub counts: [i32; 6],
+}
+
+impl NetFtRecord {
+    /// parse a record from a udp datagram
+    pub fn parse(buf: &[u8]) -> Result<Self, ForceTorqueError> {
+        if buf.len() != RDT_RECORD_SIZE {
+            return Err(ForceTorqueError::UnexpectedResponse(format!(
+                "record of {} bytes, expected {}",
+                buf.len(),
+                RDT_RECORD_SIZE
+            )));
+        }
+        let word = |i: usize| [buf[i * 4], buf[i * 4 + 1], buf[i * 4 + 2], buf[i * 4 + 3]];
+        Ok(Self {
+            rdt_sequence: u32::from_be_bytes(word(0)),
+            ft_sequence: u32::from_be_bytes(word(1)),
+            status: u32::from_be_bytes(word(2)),
+            counts: std::array::from_fn(|i| i32::from_be_bytes(word(i + 3))),
+        })
+    }
+}
+
+/// A driver of an ATI Net F/T sensor, polling single samples over udp
+///
+/// the counts are converted with the counts per force and torque of the calibration,
+/// 1000000 by default, see the sensor web page for the values of the calibration in use.
+pub struct NetFtSensor {
+    /// the logger of the sensor
+    logger: Logger,
+    /// the udp socket connected to the sensor
+    socket: UdpSocket,
+    /// counts per N
+    counts_per_force: f64,
+    /// counts per Nm
+    counts_per_torque: f64,
+    /// the wrench subtracted from every reading, see [`NetFtSensor::tare`]
+    bias: Wrench,
+}
+
+impl Logable for NetFtSensor {
+    fn get_logger(&mut self) -> &mut Logger {
+        &mut self.logger
+    }
+}
+
+impl NetFtSensor {
+    /// connect to a sensor, with a read timeout of 100ms
+    ///
+    /// the logger default to the one registered with the address, see [`Logger::get_or_create`]
+    pub fn connect(addr: impl ToSocketAddrs, logger: Option<Logger>) -> Result<Self, io::Error> {
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+        socket.connect(addr)?;
+        socket.set_read_timeout(Some(Duration::from_millis(100)))?;
+
+        let peer = socket.peer_addr()?;
+        let logger = logger.unwrap_or_else(|| {
+            let name = format!("Net FT {}", peer).replace(":", "-");
+            Logger::get_or_create(name)
+        });
+        logger.info(format!("connected to Net F/T {}", peer));
+
+        Ok(Self {
+            logger,
+            socket,
+            counts_per_force: 1_000_000.0,
+            counts_per_torque: 1_000_000.0,
+            bias: Wrench::zeros(),
+        })
+    }
+
+    /// set the counts per N and counts per Nm of the calibration
+    pub fn set_counts(&mut self, counts_per_force: f64, counts_per_torque: f64) -> &mut Self {
+        self.counts_per_force = counts_per_force;
+        self.counts_per_torque = counts_per_torque;
+        self
+    }
+    /// set the read timeout of a sample
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<&mut Self, io::Error> {
+        self.socket.set_read_timeout(Some(timeout))?;
+        Ok(self)
+    }
+    /// get the bias subtracted from every reading
+    pub fn bias(&self) -> Wrench {
+        self.bias
+    }
+    /// set the bias subtracted from every reading
+    pub fn set_bias(&mut self, bias: Wrench) -> &mut Self {
+        self.bias = bias;
+        self
+    }
+    /// zero the sensor, by taking the current reading as the bias
+    pub fn tare(&mut self) -> Result<&mut Self, ForceTorqueError> {
+        self.bias = self.read_unbiased()?;
+        self.info(format!("tare: {}", self.bias));
+        Ok(self)
+    }
+
+    /// request and read a single raw record
+    pub fn read_record(&mut self) -> Result<NetFtRecord, ForceTorqueError> {
+        self.send_command(RDT_START_STREAMING, 1)?;
+        let mut buf = [0; 64];
+        let size = self.socket.recv(&mut buf)?;
+        let record = NetFtRecord::parse(&buf[..size])?;
+        if record.status != 0 {
+            return Err(ForceTorqueError::Status(record.status));
+        }
+        Ok(record)
+    }
+    /// stop a streaming started by another client of the sensor
+    pub fn stop_streaming(&mut self) -> Result<&mut Self, ForceTorqueError> {
+        self.send_command(RDT_STOP_STREAMING, 0)?;
+        Ok(self)
+    }
+
+    /// read the wrench without the bias
+    fn read_unbiased(&mut self) -> Result<Wrench, ForceTorqueError> {
+        let counts = self.read_record()?.counts;
+        Ok(Wrench::from_array(std::array::from_fn(|i| {
+            let per_unit = if i < 3 {
+                self.counts_per_force
+            } else {
+                self.counts_per_torque
+            };
+            counts[i] as f64 / per_unit
+        })))
+    }
+
+    fn send_command(&mut self, command: u16, sample_count: u32) -> Result<(), io::Error> {
+        let mut request = [0; 8];
+        request[..2].copy_from_slice(&RDT_HEADER.to_be_bytes());
+        request[2..4].copy_from_slice(&command.to_be_bytes());
+        request[4..].copy_from_slice(&sample_count.to_be_bytes());
+        self.socket.send(&request)?;
+        Ok(())
+    }
+}
+
+impl ForceTorqueSensor for NetFtSensor {
+    fn read_wrench(&mut self) -> Result<Wrench, RobotError> {
+        Ok(self.read_unbiased()? - self.bias)
+    }
+}
+
+/// Force/torque sensor related error
+#[derive(Debug, thiserror::Error)]
+pub enum ForceTorqueError {
+    #[error(transparent)]
+    SocketError(#[from] io::Error),
+    #[error("Sensor Status: {0:#010X}")]
+    Status(u32),
+    #[error("Unexpected Response: {0}")]
+    UnexpectedResponse(String),
+}
@@ -0,0 +1,86 @@
+use crate::geometry::Transform;
+
+/// A chain of named [`Transform`], each relative to the previous link
+///
+/// the composed transform is cached, and recomputed when a link is updated.
+/// ```
+/// use inovo_rs::geometry::*;
+///
+/// let mut chain = TransformChain::new()
+///     .then_link("fixture", Transform::from_x(500.0))
+///     .then_link("part", Transform::from_z(20.0).set_rz(90.0))
+///     .then_link("grasp", Transform::from_x(10.0));
+/// assert_eq!(chain.resolve().to_string(), "x=500 y=10 z=20 | rx=0 ry=0 rz=90");
+///
+/// // the part is located by vision
+/// chain.set_link("part", Transform::from_z(20.0));
+/// assert_eq!(chain.resolve().to_string(), "x=510 y=0 z=20 | rx=0 ry=0 rz=0");
+/// assert_eq!(chain.resolve_to("fixture").unwrap().to_string(), "x=500 y=0 z=0 | rx=0 ry=0 rz=0");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformChain {
+    links: Vec<(String, Transform)>,
+    composed: Transform,
+}
+
+impl TransformChain {
+    /// create a new empty chain, resolving to the identity
+    pub fn new() -> Self {
+        Self {
+            links: vec![],
+            composed: Transform::identity(),
+        }
+    }
+    /// append a named link to the end of the chain
+    pub fn then_link(mut self, name: impl Into<String>, transform: Transform) -> Self {
+        self.composed = &self.composed * &transform;
+        self.links.push((name.into(), transform));
+        self
+    }
+    /// replace the transform of a link, returning the previous one, `None` if there is no such link
+    pub fn set_link(&mut self, name: &str, transform: Transform) -> Option<Transform> {
+        let link = self.links.iter_mut().find(|(n, _)| n == name)?;
+        let previous = std::mem::replace(&mut link.1, transform);
+        self.composed = self.compose(self.links.len());
+        Some(previous)
+    }
+    /// get the transform of a link, relative to the previous link
+    pub fn get_link(&self, name: &str) -> Option<&Transform> {
+        self.position(name).map(|i| &self.links[i].1)
+    }
+    /// get the names of the links, in order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.links.iter().map(|(n, _)| n.as_str())
+    }
+    /// get the number of links
+    pub fn len(&self) -> usize {
+        self.links.len()
+    }
+    /// check if the chain has no link
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty()
+    }
+    /// get the composed transform of the whole chain
+    pub fn resolve(&self) -> Transform {
+        self.composed.clone()
+    }
+    /// get the composed transform up to and including a link, `None` if there is no such link
+    pub fn resolve_to(&self, name: &str) -> Option<Transform> {
+        self.position(name).map(|i| self.compose(i + 1))
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.links.iter().position(|(n, _)| n == name)
+    }
+    fn compose(&self, count: usize) -> Transform {
+        self.links[..count]
+            .iter()
+            .fold(Transform::identity(), |composed, (_, t)| composed * t)
+    }
+}
+
+impl Default for TransformChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
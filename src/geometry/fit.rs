@@ -0,0 +1,230 @@
+use nalgebra::{Matrix3, Rotation3, UnitQuaternion};
+
+use crate::geometry::{Rotation, Transform, Vector3};
+
+/// A plane in space, represented by a point on it and its unit normal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    point: Vector3,
+    normal: Vector3,
+}
+
+impl Plane {
+    /// create a plane through a point, with a normal of any length, `None` for a zero normal
+    pub fn new(point: impl Into<Vector3>, normal: impl Into<Vector3>) -> Option<Self> {
+        let normal = normal.into();
+        let norm = normal.norm();
+        if norm < 1e-9 {
+            return None;
+        }
+        Some(Self {
+            point: point.into(),
+            normal: normal * (1.0 / norm),
+        })
+    }
+    /// create the plane of constant x in the base, in mm
+    pub fn yz(x_mm: f64) -> Self {
+        Self {
+            point: Vector3::new(x_mm, 0.0, 0.0),
+            normal: Vector3::new(1.0, 0.0, 0.0),
+        }
+    }
+    /// create the plane of constant y in the base, in mm
+    pub fn xz(y_mm: f64) -> Self {
+        Self {
+            point: Vector3::new(0.0, y_mm, 0.0),
+            normal: Vector3::new(0.0, 1.0, 0.0),
+        }
+    }
+    /// create the plane of constant z in the base, in mm
+    pub fn xy(z_mm: f64) -> Self {
+        Self {
+            point: Vector3::new(0.0, 0.0, z_mm),
+            normal: Vector3::new(0.0, 0.0, 1.0),
+        }
+    }
+
+    /// get the point on the plane, the centroid of the fitted points
+    pub fn get_point(&self) -> Vector3 {
+        self.point
+    }
+    /// get the unit normal of the plane, pointing to positive z of the base for a fitted plane
+    pub fn get_normal(&self) -> Vector3 {
+        self.normal
+    }
+    /// compute the signed distance of a point to the plane, in mm, positive on the normal side
+    pub fn distance(&self, point: impl Into<Vector3>) -> f64 {
+        (point.into() - self.point).dot(&self.normal)
+    }
+    /// get the tilt of the plane relative to the xy plane of the base, in degree
+    pub fn tilt_deg(&self) -> f64 {
+        self.normal.get_z().clamp(-1.0, 1.0).acos().to_degrees()
+    }
+    /// create a work frame on the plane
+    ///
+    /// the origin is the point of the plane, the z axis is the normal,
+    /// and the x axis is the x axis of the base projected onto the plane
+    pub fn work_frame(&self) -> Transform {
+        let z = nalgebra::Vector3::from(self.normal);
+        let projected = |axis: nalgebra::Vector3<f64>| axis - z * axis.dot(&z);
+        let mut x = projected(nalgebra::Vector3::x());
+        if x.norm() < 1e-9 {
+            x = projected(nalgebra::Vector3::y());
+        }
+        let x = x.normalize();
+        let y = z.cross(&x);
+        let rotation = Rotation3::from_matrix_unchecked(Matrix3::from_columns(&[x, y, z]));
+        Transform::from_parts(
+            self.point,
+            Rotation::from_quaternion(UnitQuaternion::from_rotation_matrix(&rotation)),
+        )
+    }
+}
+
+/// fit a plane through points with least squares, in mm
+///
+/// returns `None` if there are less than 3 points, or the points are collinear.
+/// ```
+/// use inovo_rs::geometry::*;
+///
+/// // a fixture tilted 45 degree about the x axis
+/// let probed = [[0.0, 0.0, 0.0], [100.0, 0.0, 0.0], [0.0, 100.0, 100.0], [100.0, 100.0, 100.0]];
+/// let plane = fit_plane(&probed).unwrap();
+/// assert!((plane.tilt_deg() - 45.0).abs() < 1e-9);
+///
+/// let frame = plane.work_frame();
+/// assert_eq!(format!("{:.3}", frame), "x=50.000 y=50.000 z=50.000 | rx=45.000 ry=0.000 rz=0.000");
+/// ```
+pub fn fit_plane<P: Clone + Into<Vector3>>(points: &[P]) -> Option<Plane> {
+    if points.len() < 3 {
+        return None;
+    }
+    let points = points
+        .iter()
+        .map(|p| nalgebra::Vector3::from(p.clone().into()))
+        .collect::<Vec<_>>();
+    let centroid = points.iter().sum::<nalgebra::Vector3<f64>>() / points.len() as f64;
+    let covariance = points.iter().fold(Matrix3::zeros(), |sum, p| {
+        let d = p - centroid;
+        sum + d * d.transpose()
+    });
+
+    let eigen = covariance.symmetric_eigen();
+    let mut order = [0, 1, 2];
+    order.sort_by(|&a, &b| eigen.eigenvalues[a].total_cmp(&eigen.eigenvalues[b]));
+    // the points spread along a line only
+    if eigen.eigenvalues[order[1]] <= 1e-9 * eigen.eigenvalues[order[2]].max(f64::EPSILON) {
+        return None;
+    }
+
+    let mut normal = eigen.eigenvectors.column(order[0]).normalize();
+    if normal.z < 0.0 {
+        normal = -normal;
+    }
+    Some(Plane {
+        point: centroid.into(),
+        normal: normal.into(),
+    })
+}
+
+/// A circle in space, represented by its center, radius and unit normal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    center: Vector3,
+    radius: f64,
+    normal: Vector3,
+}
+
+impl Circle {
+    /// get the center of the circle
+    pub fn get_center(&self) -> Vector3 {
+        self.center
+    }
+    /// get the radius of the circle, in mm
+    pub fn get_radius(&self) -> f64 {
+        self.radius
+    }
+    /// get the unit normal of the circle, the fitted points go counterclockwise about it
+    pub fn get_normal(&self) -> Vector3 {
+        self.normal
+    }
+    /// generate waypoints along the circle, rotating a start pose about the axis of the circle
+    ///
+    /// the sweep is split into `segments` equal steps, the start pose is not included
+    pub fn arc(&self, start: &Transform, sweep_deg: f64, segments: usize) -> Vec<Transform> {
+        let segments = segments.max(1);
+        (1..=segments)
+            .map(|i| {
+                let angle = sweep_deg * i as f64 / segments as f64;
+                let rotation = Transform::rotation_about(
+                    self.center.into_array(),
+                    self.normal.into_array(),
+                    angle,
+                );
+                start.clone().then(rotation)
+            })
+            .collect()
+    }
+    /// compute the counterclockwise angle from one point to another about the center, in degree from 0 to 360
+    pub fn angle_between(&self, from: impl Into<Vector3>, to: impl Into<Vector3>) -> f64 {
+        let u = from.into() - self.center;
+        let v = to.into() - self.center;
+        let angle = u.cross(&v).dot(&self.normal).atan2(u.dot(&v)).to_degrees();
+        if angle < 0.0 {
+            angle + 360.0
+        } else {
+            angle
+        }
+    }
+}
+
+/// fit the circle through three points, in mm
+///
+/// returns `None` if the points are collinear.
+pub fn fit_circle(
+    p1: impl Into<Vector3>,
+    p2: impl Into<Vector3>,
+    p3: impl Into<Vector3>,
+) -> Option<Circle> {
+    let [p1, p2, p3] = [p1.into(), p2.into(), p3.into()].map(nalgebra::Vector3::from);
+    let a = p1 - p3;
+    let b = p2 - p3;
+    let axb = a.cross(&b);
+    if axb.norm() <= 1e-9 * a.norm() * b.norm() {
+        return None;
+    }
+    let center =
+        p3 + (b * a.norm_squared() - a * b.norm_squared()).cross(&axb) / (2.0 * axb.norm_squared());
+    Some(Circle {
+        center: center.into(),
+        radius: (p1 - center).norm(),
+        normal: axb.normalize().into(),
+    })
+}
+
+/// generate arc waypoints from a start pose, through a via pose, to an end pose
+///
+/// the orientation of the start pose rotates with the arc, the end pose is the last waypoint.
+/// returns `None` if the poses are collinear.
+/// ```
+/// use inovo_rs::geometry::*;
+///
+/// let start = Transform::from_vector([100.0, 0.0, 0.0]);
+/// let via = Transform::from_vector([0.0, 100.0, 0.0]);
+/// let end = Transform::from_vector([-100.0, 0.0, 0.0]);
+///
+/// let waypoints = arc_waypoints(&start, &via, &end, 4).unwrap();
+/// assert_eq!(waypoints.len(), 4);
+/// assert_eq!(format!("{:.3}", waypoints[1].vector_only()), "x=0.000 y=100.000 z=0.000 | rx=0.000 ry=0.000 rz=0.000");
+/// assert!(waypoints[3].translation_distance(&end) < 1e-9);
+/// ```
+pub fn arc_waypoints(
+    start: &Transform,
+    via: &Transform,
+    end: &Transform,
+    segments: usize,
+) -> Option<Vec<Transform>> {
+    let circle = fit_circle(start, via, end)?;
+    let sweep = circle.angle_between(start, end);
+    Some(circle.arc(start, sweep, segments))
+}
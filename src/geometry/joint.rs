@@ -1,12 +1,28 @@
-use std::ops::{Add, Neg, Sub};
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Index, IndexMut, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::geometry::{parse_components, write_component, Degrees, ParseCoordError};
 use crate::iva::MotionTarget;
 use crate::robot::FromRobot;
 
 /// A structure representing a 6 joint coordinate, in degree
-#[derive(Debug, Clone, Deserialize, Serialize)]
+///
+/// # Display and parsing
+/// a joint coord is displayed as `j1=0 j2=90 j3=0 j4=0 j5=0 j6=0`,
+/// and parsed back like a [`Transform`](crate::geometry::Transform).
+/// ```
+/// use inovo_rs::geometry::*;
+///
+/// let j = JointCoord::from_j2(90.0);
+/// assert_eq!(j.to_string(), "j1=0 j2=90 j3=0 j4=0 j5=0 j6=0");
+///
+/// let parsed: JointCoord = "j2=90".parse().unwrap();
+/// assert_eq!(parsed.to_string(), j.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
 pub struct JointCoord {
     j1: f64,
     j2: f64,
@@ -40,84 +56,110 @@ impl JointCoord {
         }
     }
 
+    /// create a new joint coord from an array in radian, like the robot and ros joint states
+    ///
+    /// single joints can be set in radian with [`Radians`](crate::geometry::Radians)
+    /// ```
+    /// use inovo_rs::geometry::*;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let j = JointCoord::from_radians([0.0, FRAC_PI_2, 0.0, 0.0, 0.0, 0.0]).set_j6(Radians(FRAC_PI_2));
+    /// assert_eq!(j.to_string(), "j1=0 j2=90 j3=0 j4=0 j5=0 j6=90");
+    /// assert_eq!(j.to_radians()[5], FRAC_PI_2);
+    /// ```
+    pub fn from_radians(radians: [f64; 6]) -> Self {
+        Self::identity().set_radians(radians)
+    }
+    /// set all joints from an array in radian
+    pub fn set_radians(mut self, radians: [f64; 6]) -> Self {
+        for (i, radian) in radians.into_iter().enumerate() {
+            self[i] = crate::geometry::rad_to_deg(radian);
+        }
+        self
+    }
+    /// get the joints as an array in radian
+    pub fn to_radians(&self) -> [f64; 6] {
+        self.clone().into_array().map(crate::geometry::deg_to_rad)
+    }
+
     /// create a new joint coord from joint 1
-    pub fn from_j1(degree: f64) -> Self {
+    pub fn from_j1(degree: impl Into<Degrees>) -> Self {
         JointCoord::identity().set_j1(degree)
     }
     /// create a new joint coord from joint 2
-    pub fn from_j2(degree: f64) -> Self {
+    pub fn from_j2(degree: impl Into<Degrees>) -> Self {
         JointCoord::identity().set_j2(degree)
     }
     /// create a new joint coord from joint 3
-    pub fn from_j3(degree: f64) -> Self {
+    pub fn from_j3(degree: impl Into<Degrees>) -> Self {
         JointCoord::identity().set_j3(degree)
     }
     /// create a new joint coord from joint 4
-    pub fn from_j4(degree: f64) -> Self {
+    pub fn from_j4(degree: impl Into<Degrees>) -> Self {
         JointCoord::identity().set_j4(degree)
     }
     /// create a new joint coord from joint 5
-    pub fn from_j5(degree: f64) -> Self {
+    pub fn from_j5(degree: impl Into<Degrees>) -> Self {
         JointCoord::identity().set_j5(degree)
     }
     /// create a new joint coord from joint 6
-    pub fn from_j6(degree: f64) -> Self {
+    pub fn from_j6(degree: impl Into<Degrees>) -> Self {
         JointCoord::identity().set_j6(degree)
     }
 
     /// set the joint 1 of the joint coord
-    pub fn set_j1(mut self, degree: f64) -> Self {
-        self.j1 = degree;
+    pub fn set_j1(mut self, degree: impl Into<Degrees>) -> Self {
+        self.j1 = degree.into().0;
         self
     }
     /// set the joint 2 of the joint coord
-    pub fn set_j2(mut self, degree: f64) -> Self {
-        self.j2 = degree;
+    pub fn set_j2(mut self, degree: impl Into<Degrees>) -> Self {
+        self.j2 = degree.into().0;
         self
     }
     /// set the joint 3 of the joint coord
-    pub fn set_j3(mut self, degree: f64) -> Self {
-        self.j3 = degree;
+    pub fn set_j3(mut self, degree: impl Into<Degrees>) -> Self {
+        self.j3 = degree.into().0;
         self
     }
     /// set the joint 4 of the joint coord
-    pub fn set_j4(mut self, degree: f64) -> Self {
-        self.j4 = degree;
+    pub fn set_j4(mut self, degree: impl Into<Degrees>) -> Self {
+        self.j4 = degree.into().0;
         self
     }
     /// set the joint 5 of the joint coord
-    pub fn set_j5(mut self, degree: f64) -> Self {
-        self.j5 = degree;
+    pub fn set_j5(mut self, degree: impl Into<Degrees>) -> Self {
+        self.j5 = degree.into().0;
         self
     }
     /// set the joint 6 of the joint coord
-    pub fn set_j6(mut self, degree: f64) -> Self {
-        self.j6 = degree;
+    pub fn set_j6(mut self, degree: impl Into<Degrees>) -> Self {
+        self.j6 = degree.into().0;
         self
     }
 
     /// append rotation on joint 1
-    pub fn then_j1(self, degree: f64) -> Self {
+    pub fn then_j1(self, degree: impl Into<Degrees>) -> Self {
         self + JointCoord::from_j1(degree)
     }
     /// append rotation on joint 2
-    pub fn then_j2(self, degree: f64) -> Self {
+    pub fn then_j2(self, degree: impl Into<Degrees>) -> Self {
         self + JointCoord::from_j2(degree)
     }
     /// append rotation on joint 3
-    pub fn then_j3(self, degree: f64) -> Self {
+    pub fn then_j3(self, degree: impl Into<Degrees>) -> Self {
         self + JointCoord::from_j3(degree)
     }
     /// append rotation on joint 4
-    pub fn then_j4(self, degree: f64) -> Self {
+    pub fn then_j4(self, degree: impl Into<Degrees>) -> Self {
         self + JointCoord::from_j4(degree)
     }
     /// append rotation on joint 5
-    pub fn then_j5(self, degree: f64) -> Self {
+    pub fn then_j5(self, degree: impl Into<Degrees>) -> Self {
         self + JointCoord::from_j5(degree)
     }
     /// append rotation on joint 6
-    pub fn then_j6(self, degree: f64) -> Self {
+    pub fn then_j6(self, degree: impl Into<Degrees>) -> Self {
         self + JointCoord::from_j6(degree)
     }
 
@@ -126,13 +168,27 @@ impl JointCoord {
     }
 
     pub fn scale(&self, factor: f64) -> JointCoord {
-        self.clone().into_array().map(|v| v * factor).into()
+        self.map(|v| v * factor)
+    }
+
+    /// create a new joint coord by applying a function to every joint
+    pub fn map(&self, f: impl FnMut(f64) -> f64) -> JointCoord {
+        self.clone().into_array().map(f).into()
+    }
+    /// iterate over the joints, from joint 1 to joint 6
+    pub fn iter(&self) -> std::array::IntoIter<f64, 6> {
+        self.clone().into_iter()
     }
 
     /// interpolate two joint coord with a parameter t, scale from 0 to 1
     pub fn interpolate(&self, other: &Self, t: f64) -> Self {
         self.scale(1.0 - t) + other.scale(t)
     }
+
+    /// compute the largest absolute difference of any joint between two joint coord, in degree
+    pub fn max_joint_delta(&self, other: &Self) -> f64 {
+        (self - other).iter().fold(0.0, |max, d| max.max(d.abs()))
+    }
 }
 
 impl From<[f64; 6]> for JointCoord {
@@ -147,38 +203,99 @@ impl From<&[f64; 6]> for JointCoord {
     }
 }
 
-impl Into<[f64; 6]> for JointCoord {
-    fn into(self) -> [f64; 6] {
-        [self.j1, self.j2, self.j3, self.j4, self.j5, self.j6]
+impl From<JointCoord> for [f64; 6] {
+    fn from(value: JointCoord) -> Self {
+        [value.j1, value.j2, value.j3, value.j4, value.j5, value.j6]
+    }
+}
+
+/// index the joints from 0, joint 1 is index 0
+///
+/// panics if the index is not less than 6
+impl Index<usize> for JointCoord {
+    type Output = f64;
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.j1,
+            1 => &self.j2,
+            2 => &self.j3,
+            3 => &self.j4,
+            4 => &self.j5,
+            5 => &self.j6,
+            _ => panic!("joint index out of range: {} >= 6", index),
+        }
+    }
+}
+
+impl IndexMut<usize> for JointCoord {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.j1,
+            1 => &mut self.j2,
+            2 => &mut self.j3,
+            3 => &mut self.j4,
+            4 => &mut self.j5,
+            5 => &mut self.j6,
+            _ => panic!("joint index out of range: {} >= 6", index),
+        }
+    }
+}
+
+impl IntoIterator for JointCoord {
+    type Item = f64;
+    type IntoIter = std::array::IntoIter<f64, 6>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_array().into_iter()
+    }
+}
+
+impl IntoIterator for &JointCoord {
+    type Item = f64;
+    type IntoIter = std::array::IntoIter<f64, 6>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
 impl Neg for JointCoord {
     type Output = Self;
     fn neg(self) -> Self::Output {
-        self.into_array().map(|v| v.neg()).into()
+        self.map(|v| v.neg())
     }
 }
 
 impl Add for JointCoord {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
-        let mut arr = self.into_array();
-        let other_arr = rhs.into_array();
+        let mut sum = self;
         for i in 0..6 {
-            arr[i] += other_arr[i];
+            sum[i] += rhs[i];
         }
-        arr.into()
+        sum
     }
 }
 
 impl Sub for JointCoord {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
-        self + rhs.neg()
+        let mut difference = self;
+        for i in 0..6 {
+            difference[i] -= rhs[i];
+        }
+        difference
     }
 }
 
+impl Neg for &JointCoord {
+    type Output = JointCoord;
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+forward_ref_op!(JointCoord, Add, add, AddAssign, add_assign);
+forward_ref_op!(JointCoord, Sub, sub, SubAssign, sub_assign);
+
 impl From<String> for JointCoord {
     fn from(value: String) -> JointCoord {
         value
@@ -189,7 +306,7 @@ impl From<String> for JointCoord {
             .replace(&['[', ']', ' '][..], "")
             .split(",")
             .filter_map(|s| s.parse::<f64>().ok())
-            .map(|f| crate::geometry::rad_to_deg(f))
+            .map(crate::geometry::rad_to_deg)
             .collect::<Vec<_>>()
             .into()
     }
@@ -198,7 +315,7 @@ impl From<String> for JointCoord {
 impl From<Vec<f64>> for JointCoord {
     fn from(value: Vec<f64>) -> JointCoord {
         [
-            value.get(0).cloned().unwrap_or_default(),
+            value.first().cloned().unwrap_or_default(),
             value.get(1).cloned().unwrap_or_default(),
             value.get(2).cloned().unwrap_or_default(),
             value.get(3).cloned().unwrap_or_default(),
@@ -209,9 +326,29 @@ impl From<Vec<f64>> for JointCoord {
     }
 }
 
-impl Into<MotionTarget> for JointCoord {
-    fn into(self) -> MotionTarget {
-        MotionTarget::JointCoord(self)
+impl Display for JointCoord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keys = ["j1", "j2", "j3", "j4", "j5", "j6"];
+        for (i, (key, value)) in keys.into_iter().zip(self).enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write_component(f, key, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for JointCoord {
+    type Err = ParseCoordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_components(s, ["j1", "j2", "j3", "j4", "j5", "j6"]).map(JointCoord::from)
+    }
+}
+
+impl From<JointCoord> for MotionTarget {
+    fn from(value: JointCoord) -> Self {
+        MotionTarget::JointCoord(value)
     }
 }
 
@@ -0,0 +1,96 @@
+//! Serialize geometry in metre and radian, the unit of the robot.
+//!
+//! the api serializes [`Transform`] and [`JointCoord`] in mm and degree,
+//! use this module with `#[serde(with = "inovo_rs::geometry::m_rad")]` to talk in the unit of the robot,
+//! a [`Transform`] keeps the fields `x`, `y`, `z`, `rx`, `ry`, `rz`,
+//! and a [`JointCoord`] becomes an array of 6 joints, like the robot and ros joint states.
+//! ```
+//! use inovo_rs::geometry::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Message {
+//!     #[serde(with = "m_rad")]
+//!     pose: Transform,
+//!     #[serde(with = "m_rad")]
+//!     joints: JointCoord,
+//! }
+//!
+//! let message = Message {
+//!     pose: Transform::from_x(100.0),
+//!     joints: JointCoord::from_j1(180.0),
+//! };
+//! let json = serde_json::to_string(&message).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"pose":{"x":0.1,"y":0.0,"z":0.0,"rx":0.0,"ry":0.0,"rz":0.0},"joints":[3.141592653589793,0.0,0.0,0.0,0.0,0.0]}"#
+//! );
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::geometry::{JointCoord, Transform};
+
+/// A geometry type with a representation in metre and radian
+pub trait RobotUnits: Sized {
+    /// the serialized representation
+    type Repr: Serialize + for<'de> Deserialize<'de>;
+    /// convert into the representation in metre and radian
+    fn to_robot_units(&self) -> Self::Repr;
+    /// convert from the representation in metre and radian
+    fn from_robot_units(repr: Self::Repr) -> Self;
+}
+
+/// a transform in metre and radian, with the field names of the robot
+#[derive(Serialize, Deserialize)]
+pub struct TransformRepr {
+    x: f64,
+    y: f64,
+    z: f64,
+    rx: f64,
+    ry: f64,
+    rz: f64,
+}
+
+impl RobotUnits for Transform {
+    type Repr = TransformRepr;
+    fn to_robot_units(&self) -> Self::Repr {
+        let [x, y, z, rx, ry, rz] = self.to_m_rad();
+        TransformRepr {
+            x,
+            y,
+            z,
+            rx,
+            ry,
+            rz,
+        }
+    }
+    fn from_robot_units(r: Self::Repr) -> Self {
+        Transform::from_m_rad([r.x, r.y, r.z, r.rx, r.ry, r.rz])
+    }
+}
+
+impl RobotUnits for JointCoord {
+    type Repr = [f64; 6];
+    fn to_robot_units(&self) -> Self::Repr {
+        self.to_radians()
+    }
+    fn from_robot_units(repr: Self::Repr) -> Self {
+        JointCoord::from_radians(repr)
+    }
+}
+
+/// serialize a value in metre and radian
+pub fn serialize<T: RobotUnits, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.to_robot_units().serialize(serializer)
+}
+
+/// deserialize a value in metre and radian
+pub fn deserialize<'de, T: RobotUnits, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::Repr::deserialize(deserializer).map(T::from_robot_units)
+}
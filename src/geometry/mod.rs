@@ -1,12 +1,122 @@
 //! Data Structure representing spatial coordinate and robot pose.
+//!
+//! the api is in mm and degree, while the robot talks in metre and radian.
+//! the single component methods, like [`Transform::set_x`] and [`JointCoord::set_j1`],
+//! take a bare `f64` in the api unit, or a unit wrapper which is converted.
+//! ```
+//! use inovo_rs::geometry::*;
+//!
+//! let a = Transform::from_z(100.0).then_rx(90.0);
+//! let b = Transform::from_z(Meters(0.1)).then_rx(Radians(std::f64::consts::FRAC_PI_2));
+//! assert_eq!(a.to_string(), b.to_string());
+//! ```
 
+/// implement a binary operator and its assign operator for references, forwarding to the owned implementation
+macro_rules! forward_ref_op {
+    ($t:ty, $imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident) => {
+        impl $imp<&$t> for $t {
+            type Output = $t;
+            fn $method(self, rhs: &$t) -> $t {
+                $imp::$method(self, rhs.clone())
+            }
+        }
+        impl $imp<$t> for &$t {
+            type Output = $t;
+            fn $method(self, rhs: $t) -> $t {
+                $imp::$method(self.clone(), rhs)
+            }
+        }
+        impl $imp<&$t> for &$t {
+            type Output = $t;
+            fn $method(self, rhs: &$t) -> $t {
+                $imp::$method(self.clone(), rhs.clone())
+            }
+        }
+        impl $assign_imp for $t {
+            fn $assign_method(&mut self, rhs: $t) {
+                *self = $imp::$method(&*self, rhs);
+            }
+        }
+        impl $assign_imp<&$t> for $t {
+            fn $assign_method(&mut self, rhs: &$t) {
+                *self = $imp::$method(&*self, rhs);
+            }
+        }
+    };
+}
+
+mod chain;
+mod fit;
 mod joint;
+pub mod m_rad;
+mod rotation;
+#[cfg(feature = "rand")]
+mod sample;
 mod transform;
+mod units;
+mod vector;
+mod wrench;
 
 use std::f64::consts::PI;
 
+pub use chain::TransformChain;
+pub use fit::*;
 pub use joint::JointCoord;
+pub use rotation::Rotation;
+#[cfg(feature = "rand")]
+pub use sample::*;
 pub use transform::Transform;
+pub use units::*;
+pub use vector::Vector3;
+pub use wrench::Wrench;
+
+/// Error of parsing a [`Transform`] or a [`JointCoord`] from a string
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseCoordError {
+    #[error("unknown component: {0}")]
+    UnknownKey(String),
+    #[error("invalid component: {0}")]
+    InvalidComponent(String),
+}
+
+/// parse `key=value` components separated by whitespace, `,` or `|`,
+/// into the values of the given keys, missing components default to zero
+fn parse_components<const N: usize>(s: &str, keys: [&str; N]) -> Result<[f64; N], ParseCoordError> {
+    let mut values = [0.0; N];
+    for component in s
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '|')
+        .filter(|c| !c.is_empty())
+    {
+        let (key, value) = component
+            .split_once('=')
+            .ok_or_else(|| ParseCoordError::InvalidComponent(component.to_string()))?;
+        let i = keys
+            .iter()
+            .position(|k| k.eq_ignore_ascii_case(key))
+            .ok_or_else(|| ParseCoordError::UnknownKey(key.to_string()))?;
+        values[i] = value
+            .parse()
+            .map_err(|_| ParseCoordError::InvalidComponent(component.to_string()))?;
+    }
+    Ok(values)
+}
+
+/// write a value with the precision of the formatter,
+/// or rounded to 6 decimals without trailing zeros, a rounded negative zero is written as zero
+fn write_component(f: &mut std::fmt::Formatter<'_>, key: &str, value: f64) -> std::fmt::Result {
+    let value = match f.precision() {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{:.6}", value)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string(),
+    };
+    let value = match value.strip_prefix('-') {
+        Some(abs) if abs.chars().all(|c| c == '0' || c == '.') => abs,
+        _ => &value,
+    };
+    write!(f, "{}={}", key, value)
+}
 
 /// convert degree to radian
 pub fn deg_to_rad(deg: f64) -> f64 {
@@ -0,0 +1,80 @@
+use std::f64::consts::PI;
+use std::ops::{Mul, Neg};
+
+use nalgebra::UnitQuaternion;
+
+use crate::geometry::{Transform, Vector3};
+
+/// A structure representing a 3D rotation, without translation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation {
+    quaternion: UnitQuaternion<f64>,
+}
+
+impl Rotation {
+    /// create a new identity rotation
+    pub fn identity() -> Self {
+        Self::from_quaternion(UnitQuaternion::identity())
+    }
+    /// create a new rotation from euler angle, in degree
+    pub fn from_euler(eular_degree: [f64; 3]) -> Self {
+        Transform::from_euler(eular_degree).get_rotation()
+    }
+    /// create a new rotation about an axis, in degree
+    pub fn from_axis_angle(axis: [f64; 3], degree: f64) -> Self {
+        Transform::from_axis_angle(axis, degree).get_rotation()
+    }
+    /// create a new rotation from a quaternion
+    pub fn from_quaternion(quaternion: UnitQuaternion<f64>) -> Self {
+        Self { quaternion }
+    }
+    /// get the euler angle of the rotation, in degree
+    pub fn get_euler(&self) -> [f64; 3] {
+        Transform::from(*self).get_euler()
+    }
+    /// get the rotation in quaternion
+    pub fn get_quaternion(&self) -> UnitQuaternion<f64> {
+        self.quaternion
+    }
+    /// compute the inverse of the rotation
+    pub fn inverse(&self) -> Self {
+        Self::from_quaternion(self.quaternion.inverse())
+    }
+    /// compute the angle between two rotation, in degree from 0 to 180
+    pub fn angle_to(&self, other: &Self) -> f64 {
+        self.quaternion.angle_to(&other.quaternion) / PI * 180.0
+    }
+}
+
+impl From<&Transform> for Rotation {
+    fn from(value: &Transform) -> Self {
+        value.get_rotation()
+    }
+}
+
+impl From<Rotation> for Transform {
+    fn from(value: Rotation) -> Self {
+        Transform::from_quaternion(value.quaternion)
+    }
+}
+
+impl Mul for Rotation {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_quaternion(self.quaternion * rhs.quaternion)
+    }
+}
+
+impl Mul<Vector3> for Rotation {
+    type Output = Vector3;
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        (self.quaternion * nalgebra::Vector3::from(rhs)).into()
+    }
+}
+
+impl Neg for Rotation {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
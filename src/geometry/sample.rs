@@ -0,0 +1,98 @@
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+use crate::geometry::Transform;
+
+/// The bounds of randomly sampled [`Transform`]
+///
+/// the vector is sampled within a box, the orientation within a cone around a nominal orientation,
+/// with an optional spin about the tool z axis.
+/// ```
+/// use inovo_rs::geometry::*;
+///
+/// let bounds = TransformBounds::new([0.0, 0.0, 100.0], [200.0, 200.0, 100.0])
+///     .set_orientation(Transform::from_rx(180.0))
+///     .set_cone(15.0)
+///     .set_spin(180.0);
+///
+/// let pose = sample_uniform(&bounds, &mut rand::thread_rng());
+/// assert!(bounds.contains(&pose));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformBounds {
+    min_mm: [f64; 3],
+    max_mm: [f64; 3],
+    orientation: Transform,
+    cone_deg: f64,
+    spin_deg: f64,
+}
+
+impl TransformBounds {
+    /// create a new bounds from the corners of the box, with the identity orientation
+    pub fn new(min_mm: [f64; 3], max_mm: [f64; 3]) -> Self {
+        Self {
+            min_mm,
+            max_mm,
+            orientation: Transform::identity(),
+            cone_deg: 0.0,
+            spin_deg: 0.0,
+        }
+    }
+    /// set the nominal orientation, the vector part is ignored
+    pub fn set_orientation(mut self, orientation: Transform) -> Self {
+        self.orientation = orientation.eular_only();
+        self
+    }
+    /// set the half angle of the cone the tool z axis is tilted within, in degree
+    pub fn set_cone(mut self, degree: f64) -> Self {
+        self.cone_deg = degree.clamp(0.0, 180.0);
+        self
+    }
+    /// set the range of the spin about the tool z axis, from `-degree` to `degree`
+    pub fn set_spin(mut self, degree: f64) -> Self {
+        self.spin_deg = degree.abs();
+        self
+    }
+
+    /// check if a transform is within the bounds
+    pub fn contains(&self, transform: &Transform) -> bool {
+        let tolerance = 1e-9;
+        let within_box = transform
+            .get_vector()
+            .into_iter()
+            .zip(self.min_mm.into_iter().zip(self.max_mm))
+            .all(|(v, (min, max))| v >= min.min(max) - tolerance && v <= max.max(min) + tolerance);
+
+        // the angle between the tool z axis and the nominal one
+        let z = |t: &Transform| t.get_quaternion() * nalgebra::Vector3::z();
+        let tilt = z(transform).angle(&z(&self.orientation)) / PI * 180.0;
+
+        within_box && tilt <= self.cone_deg + tolerance
+    }
+}
+
+/// sample a random [`Transform`] uniformly within the bounds
+///
+/// the tool z axis is distributed uniformly over the spherical cap of the cone
+pub fn sample_uniform<R: Rng + ?Sized>(bounds: &TransformBounds, rng: &mut R) -> Transform {
+    let mut uniform = |a: f64, b: f64| {
+        let (low, high) = (a.min(b), a.max(b));
+        if low == high {
+            low
+        } else {
+            rng.gen_range(low..=high)
+        }
+    };
+
+    let vector = [0, 1, 2].map(|i| uniform(bounds.min_mm[i], bounds.max_mm[i]));
+
+    let cos_cone = (bounds.cone_deg / 180.0 * PI).cos();
+    let tilt = uniform(cos_cone, 1.0).acos() / PI * 180.0;
+    let azimuth = uniform(0.0, 2.0 * PI);
+    let spin = uniform(-bounds.spin_deg, bounds.spin_deg);
+
+    let tilt = Transform::from_axis_angle([azimuth.cos(), azimuth.sin(), 0.0], tilt);
+    let rotation = bounds.orientation.clone() * tilt * Transform::from_rz(spin);
+    rotation.set_vector(vector)
+}
@@ -1,17 +1,66 @@
-use nalgebra::geometry::{Isometry3, UnitQuaternion};
-use nalgebra::Translation3;
+use nalgebra::geometry::{Isometry3, Rotation3, UnitQuaternion};
+use nalgebra::{Matrix3, Matrix4, Quaternion, Translation3, Unit, Vector4};
 use std::collections::HashMap;
 use std::f64::consts::PI;
-use std::ops::{Div, Mul, Neg};
+use std::fmt::Display;
+use std::ops::{Div, DivAssign, Mul, MulAssign, Neg};
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::geometry::{
+    parse_components, write_component, Degrees, Meters, Mm, ParseCoordError, Plane, Radians,
+    Rotation, Vector3,
+};
 use crate::iva::MotionTarget;
 use crate::robot::FromRobot;
 
 /// A structure representing a 3D Transformation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// # Display and parsing
+/// a transform is displayed as `x=100 y=0 z=50 | rx=0 ry=90 rz=0`, in mm and degree,
+/// the precision of the formatter is applied to every component, 6 decimals by default.
+///
+/// it can be parsed back with [`str::parse`], the components can be in any order,
+/// separated by whitespace, `,` or `|`, and missing components default to zero.
+/// ```
+/// use inovo_rs::geometry::*;
+///
+/// let t = Transform::from_vector([100.0, 0.0, 50.0]).set_ry(90.0);
+/// assert_eq!(t.to_string(), "x=100 y=0 z=50 | rx=0 ry=90 rz=0");
+/// assert_eq!(format!("{:.1}", t), "x=100.0 y=0.0 z=50.0 | rx=0.0 ry=90.0 rz=0.0");
+///
+/// let parsed: Transform = "z=50, x=100, ry=90".parse().unwrap();
+/// assert_eq!(parsed.to_string(), t.to_string());
+/// ```
+///
+/// # Representation
+/// the orientation is stored as a [`UnitQuaternion`], the euler getters and setters convert on demand,
+/// so long `then_*` chains compose rotations without drifting through euler angles.
+/// the serialized form still uses the euler components `x`, `y`, `z`, `rx`, `ry`, `rz`.
+///
+/// the euler angles set are kept, and read back as set, e.g. `rz=270` stays `270` and not `-90`.
+/// once the rotation is composed, they are decomposed from the quaternion,
+/// snapping `ry` to ±90 near the gimbal lock, where `rx` and `rz` are folded into `rz`.
+/// ```
+/// use inovo_rs::geometry::*;
+///
+/// assert_eq!(Transform::from_rz(270.0).get_rz(), 270.0);
+/// let locked = Transform::from_ry(90.0).then_rz(0.0);
+/// assert_eq!(locked.get_ry(), 90.0);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(from = "EulerTransform", into = "EulerTransform")]
 pub struct Transform {
+    vector: [f64; 3],
+    rotation: UnitQuaternion<f64>,
+    /// the euler angles as set, in degree, `None` once the rotation is composed
+    euler: Option<[f64; 3]>,
+}
+
+/// the serialized form of a [`Transform`], in mm and degree
+#[derive(Serialize, Deserialize)]
+struct EulerTransform {
     x: f64,
     y: f64,
     z: f64,
@@ -20,16 +69,34 @@ pub struct Transform {
     rz: f64,
 }
 
+impl From<EulerTransform> for Transform {
+    fn from(t: EulerTransform) -> Self {
+        Transform::new(t.x, t.y, t.z, t.rx, t.ry, t.rz)
+    }
+}
+
+impl From<Transform> for EulerTransform {
+    fn from(t: Transform) -> Self {
+        let [x, y, z] = t.get_vector();
+        let [rx, ry, rz] = t.get_euler();
+        EulerTransform {
+            x,
+            y,
+            z,
+            rx,
+            ry,
+            rz,
+        }
+    }
+}
+
 impl Transform {
     /// create a new transform from vector and euler angle
     pub fn new(x_mm: f64, y_mm: f64, z_mm: f64, rx_deg: f64, ry_deg: f64, rz_deg: f64) -> Self {
         Self {
-            x: x_mm,
-            y: y_mm,
-            z: z_mm,
-            rx: rx_deg,
-            ry: ry_deg,
-            rz: rz_deg,
+            vector: [x_mm, y_mm, z_mm],
+            rotation: euler_to_quaternion([rx_deg, ry_deg, rz_deg]),
+            euler: Some([rx_deg, ry_deg, rz_deg]),
         }
     }
     /// create a new identity transform
@@ -40,9 +107,26 @@ impl Transform {
     pub fn from_array(q: [f64; 6]) -> Self {
         Self::new(q[0], q[1], q[2], q[3], q[4], q[5])
     }
+    /// create a new transform from an array in metre and radian, the unit of the robot
+    pub fn from_m_rad(q: [f64; 6]) -> Self {
+        Self::from_array([
+            Mm::from(Meters(q[0])).0,
+            Mm::from(Meters(q[1])).0,
+            Mm::from(Meters(q[2])).0,
+            Degrees::from(Radians(q[3])).0,
+            Degrees::from(Radians(q[4])).0,
+            Degrees::from(Radians(q[5])).0,
+        ])
+    }
+    /// get the transform as an array in metre and radian, the unit of the robot
+    pub fn to_m_rad(&self) -> [f64; 6] {
+        let [x, y, z] = self.get_vector().map(|v| Meters::from(Mm(v)).0);
+        let [rx, ry, rz] = self.get_euler().map(|v| Radians::from(Degrees(v)).0);
+        [x, y, z, rx, ry, rz]
+    }
     /// create a new transform from vector only
-    pub fn from_vector(vector_mm: [f64; 3]) -> Self {
-        Transform::new(vector_mm[0], vector_mm[1], vector_mm[2], 0.0, 0.0, 0.0)
+    pub fn from_vector(vector_mm: impl Into<Vector3>) -> Self {
+        Self::identity().set_vector(vector_mm)
     }
     /// create a new transform from euler only
     pub fn from_euler(eular_degree: [f64; 3]) -> Self {
@@ -55,106 +139,153 @@ impl Transform {
             eular_degree[2],
         )
     }
+    /// create a new transform from a vector and a rotation
+    pub fn from_parts(vector: Vector3, rotation: Rotation) -> Self {
+        Self::from_vector(vector).set_rotation(rotation)
+    }
+    /// create a new transform from quaternion only
+    pub fn from_quaternion(quaternion: UnitQuaternion<f64>) -> Self {
+        Self::identity().set_quaternion(quaternion)
+    }
+    /// create a new transform rotating about an axis through the origin
+    ///
+    /// the axis does not need to be normalized, a zero axis gives no rotation
+    pub fn from_axis_angle(axis: [f64; 3], degree: f64) -> Self {
+        let rotation = Unit::try_new(nalgebra::Vector3::from(axis), f64::EPSILON)
+            .map(|axis| UnitQuaternion::from_axis_angle(&axis, degree / 180.0 * PI))
+            .unwrap_or_else(UnitQuaternion::identity);
+        Self::from_quaternion(rotation)
+    }
+    /// create a new transform rotating about an axis through a point, in mm
+    /// ```
+    /// use inovo_rs::geometry::*;
+    ///
+    /// // rotate the part 90 degree about the fixture pin
+    /// let pin = [100.0, 0.0, 0.0];
+    /// let part = Transform::from_vector([150.0, 0.0, 20.0]);
+    /// let rotated = part.then(Transform::rotation_about(pin, [0.0, 0.0, 1.0], 90.0));
+    /// assert_eq!(rotated.to_string(), "x=100 y=50 z=20 | rx=0 ry=0 rz=90");
+    /// ```
+    pub fn rotation_about(point_mm: [f64; 3], axis: [f64; 3], degree: f64) -> Self {
+        let point = Self::from_vector(point_mm);
+        point.clone() * Self::from_axis_angle(axis, degree) * point.inverse()
+    }
     /// create a new transform from x component
-    pub fn from_x(mm: f64) -> Self {
+    pub fn from_x(mm: impl Into<Mm>) -> Self {
         Self::identity().set_x(mm)
     }
     /// create a new transform from y component
-    pub fn from_y(mm: f64) -> Self {
+    pub fn from_y(mm: impl Into<Mm>) -> Self {
         Self::identity().set_y(mm)
     }
     /// create a new transform from z component
-    pub fn from_z(mm: f64) -> Self {
+    pub fn from_z(mm: impl Into<Mm>) -> Self {
         Self::identity().set_z(mm)
     }
     /// create a new transform from rx component
-    pub fn from_rx(degree: f64) -> Self {
+    pub fn from_rx(degree: impl Into<Degrees>) -> Self {
         Self::identity().set_rx(degree)
     }
     /// create a new transform from ry component
-    pub fn from_ry(degree: f64) -> Self {
+    pub fn from_ry(degree: impl Into<Degrees>) -> Self {
         Self::identity().set_ry(degree)
     }
     /// create a new transform from rz component
-    pub fn from_rz(degree: f64) -> Self {
+    pub fn from_rz(degree: impl Into<Degrees>) -> Self {
         Self::identity().set_rz(degree)
     }
 
     /// get the vector of the transform
     pub fn get_vector(&self) -> [f64; 3] {
-        [self.x, self.y, self.z]
+        self.vector
     }
     /// get the euler of the transform
     pub fn get_euler(&self) -> [f64; 3] {
-        [self.rx, self.ry, self.rz]
+        self.euler
+            .unwrap_or_else(|| quaternion_to_euler(&self.rotation))
+    }
+    /// get the rotation of the transform
+    pub fn get_rotation(&self) -> Rotation {
+        Rotation::from_quaternion(self.rotation)
+    }
+    /// get the rotation of the transform in quaternion
+    pub fn get_quaternion(&self) -> UnitQuaternion<f64> {
+        self.rotation
     }
     /// get the x component of the transform
     pub fn get_x(&self) -> f64 {
-        self.x
+        self.vector[0]
     }
     /// get the y component of the transform
     pub fn get_y(&self) -> f64 {
-        self.y
+        self.vector[1]
     }
     /// get the z component of the transform
     pub fn get_z(&self) -> f64 {
-        self.z
+        self.vector[2]
     }
     /// get the rx component of the transform
     pub fn get_rx(&self) -> f64 {
-        self.rx
+        self.get_euler()[0]
     }
     /// get the ry component of the transform
     pub fn get_ry(&self) -> f64 {
-        self.ry
+        self.get_euler()[1]
     }
     /// get the rz component of the transform
     pub fn get_rz(&self) -> f64 {
-        self.rz
+        self.get_euler()[2]
     }
     /// set the vector of the transform
-    pub fn set_vector(mut self, vector_mm: [f64; 3]) -> Self {
-        self.x = vector_mm[0];
-        self.y = vector_mm[1];
-        self.z = vector_mm[2];
+    pub fn set_vector(mut self, vector_mm: impl Into<Vector3>) -> Self {
+        self.vector = vector_mm.into().into();
         self
     }
     /// set the euler of the transform
     pub fn set_euler(mut self, eular_degree: [f64; 3]) -> Self {
-        self.rx = eular_degree[0];
-        self.ry = eular_degree[1];
-        self.rz = eular_degree[2];
+        self.rotation = euler_to_quaternion(eular_degree);
+        self.euler = Some(eular_degree);
+        self
+    }
+    /// set the rotation of the transform
+    pub fn set_rotation(self, rotation: Rotation) -> Self {
+        self.set_quaternion(rotation.get_quaternion())
+    }
+    /// set the rotation of the transform in quaternion
+    pub fn set_quaternion(mut self, quaternion: UnitQuaternion<f64>) -> Self {
+        self.rotation = quaternion;
+        self.euler = None;
         self
     }
     /// set the x component of the transform
-    pub fn set_x(mut self, mm: f64) -> Self {
-        self.x = mm;
+    pub fn set_x(mut self, mm: impl Into<Mm>) -> Self {
+        self.vector[0] = mm.into().0;
         self
     }
     /// set the y component of the transform
-    pub fn set_y(mut self, mm: f64) -> Self {
-        self.y = mm;
+    pub fn set_y(mut self, mm: impl Into<Mm>) -> Self {
+        self.vector[1] = mm.into().0;
         self
     }
     /// set the z component of the transform
-    pub fn set_z(mut self, mm: f64) -> Self {
-        self.z = mm;
+    pub fn set_z(mut self, mm: impl Into<Mm>) -> Self {
+        self.vector[2] = mm.into().0;
         self
     }
     /// set the rx component of the transform
-    pub fn set_rx(mut self, degree: f64) -> Self {
-        self.rx = degree;
-        self
+    pub fn set_rx(self, degree: impl Into<Degrees>) -> Self {
+        let [_, ry, rz] = self.get_euler();
+        self.set_euler([degree.into().0, ry, rz])
     }
     /// set the ry component of the transform
-    pub fn set_ry(mut self, degree: f64) -> Self {
-        self.ry = degree;
-        self
+    pub fn set_ry(self, degree: impl Into<Degrees>) -> Self {
+        let [rx, _, rz] = self.get_euler();
+        self.set_euler([rx, degree.into().0, rz])
     }
     /// set the rz component of the transform
-    pub fn set_rz(mut self, degree: f64) -> Self {
-        self.rz = degree;
-        self
+    pub fn set_rz(self, degree: impl Into<Degrees>) -> Self {
+        let [rx, ry, _] = self.get_euler();
+        self.set_euler([rx, ry, degree.into().0])
     }
 
     /// append a new transform to the original transform
@@ -162,38 +293,42 @@ impl Transform {
         transform * self
     }
     /// append x translation to the original transform
-    pub fn then_x(self, mm: f64) -> Self {
+    pub fn then_x(self, mm: impl Into<Mm>) -> Self {
         Self::from_x(mm) * self
     }
     /// append y translation to the original transform
-    pub fn then_y(self, mm: f64) -> Self {
+    pub fn then_y(self, mm: impl Into<Mm>) -> Self {
         Self::from_y(mm) * self
     }
     /// append z translation to the original transform
-    pub fn then_z(self, mm: f64) -> Self {
+    pub fn then_z(self, mm: impl Into<Mm>) -> Self {
         Self::from_z(mm) * self
     }
     /// append vector translation to the original transform
-    pub fn then_vector(self, vector_mm: [f64; 3]) -> Self {
+    pub fn then_vector(self, vector_mm: impl Into<Vector3>) -> Self {
         Self::from_vector(vector_mm) * self
     }
 
     /// append rx rotation to the original transform
-    pub fn then_rx(self, degree: f64) -> Self {
+    pub fn then_rx(self, degree: impl Into<Degrees>) -> Self {
         Self::from_rx(degree) * self
     }
     /// append ry rotation to the original transform
-    pub fn then_ry(self, degree: f64) -> Self {
+    pub fn then_ry(self, degree: impl Into<Degrees>) -> Self {
         Self::from_ry(degree) * self
     }
     /// append rz rotation to the original transform
-    pub fn then_rz(self, degree: f64) -> Self {
+    pub fn then_rz(self, degree: impl Into<Degrees>) -> Self {
         Self::from_rz(degree) * self
     }
     /// append euler rotation to the original transform
     pub fn then_euler(self, eular_degree: [f64; 3]) -> Self {
         Self::from_euler(eular_degree) * self
     }
+    /// append rotation to the original transform
+    pub fn then_rotation(self, rotation: Rotation) -> Self {
+        Transform::from(rotation) * self
+    }
 
     /// create a new transform by extracting the vector part
     pub fn vector_only(&self) -> Self {
@@ -215,33 +350,33 @@ impl Transform {
         self.clone().then_relative_to(self.vector_only(), transform)
     }
     /// append relative x translation to the original transform
-    pub fn then_relative_x(self, mm: f64) -> Self {
+    pub fn then_relative_x(self, mm: impl Into<Mm>) -> Self {
         self.then_relative(Self::from_x(mm))
     }
     /// append relative y translation to the original transform
-    pub fn then_relative_y(self, mm: f64) -> Self {
+    pub fn then_relative_y(self, mm: impl Into<Mm>) -> Self {
         self.then_relative(Self::from_y(mm))
     }
     /// append relative z translation to the original transform
-    pub fn then_relative_z(self, mm: f64) -> Self {
+    pub fn then_relative_z(self, mm: impl Into<Mm>) -> Self {
         self.then_relative(Self::from_z(mm))
     }
 
     /// append relative vector translation to the original transform
-    pub fn then_relative_vector(self, vector_mm: [f64; 3]) -> Self {
+    pub fn then_relative_vector(self, vector_mm: impl Into<Vector3>) -> Self {
         self.then_relative(Self::from_vector(vector_mm))
     }
 
     /// append relative rx rotation to the original transform
-    pub fn then_relative_rx(self, degree: f64) -> Self {
+    pub fn then_relative_rx(self, degree: impl Into<Degrees>) -> Self {
         self.then_relative(Self::from_rx(degree))
     }
     /// append relative ry rotation to the original transform
-    pub fn then_relative_ry(self, degree: f64) -> Self {
+    pub fn then_relative_ry(self, degree: impl Into<Degrees>) -> Self {
         self.then_relative(Self::from_ry(degree))
     }
     /// append relative rz rotation to the original transform
-    pub fn then_relative_rz(self, degree: f64) -> Self {
+    pub fn then_relative_rz(self, degree: impl Into<Degrees>) -> Self {
         self.then_relative(Self::from_rz(degree))
     }
     /// append relative euler rotation to the original transform
@@ -249,43 +384,221 @@ impl Transform {
         self.then_relative(Self::from_euler(eular_degree))
     }
 
-    /// get the euler rotation in radian
-    fn radian_euler(&self) -> [f64; 3] {
-        self.get_euler().map(|p| p / 180.0 * PI)
-    }
     /// get the vector in `Translation3<f64>`
     fn translation(&self) -> Translation3<f64> {
-        Translation3::from(self.get_vector())
-    }
-    /// get the euler in `UnitQuaterion<f64>`
-    fn unit_quaternion(&self) -> UnitQuaternion<f64> {
-        let euler = self.radian_euler();
-        UnitQuaternion::from_euler_angles(euler[0], euler[1], euler[2])
+        Translation3::from(self.vector)
     }
     /// get the transform in `Isometry<f64>`
     fn isometry(&self) -> Isometry3<f64> {
-        let translation = self.translation();
-        let rotation = self.unit_quaternion();
-        Isometry3::from_parts(translation, rotation)
+        Isometry3::from_parts(self.translation(), self.rotation)
     }
     /// compute the inverse of the  transform
     pub fn inverse(&self) -> Self {
         self.isometry().inverse().into()
     }
+    /// mirror the transform across a plane, e.g. the left-hand variant of a right-hand pose
+    ///
+    /// the vector is reflected across the plane, and the rotation is conjugated by the reflection,
+    /// i.e. its axis is reflected and its angle negated, so the frame stays right-handed.
+    /// for a plane of constant y, the x and z axes of the frame are mirrored and its y axis is flipped.
+    /// ```
+    /// use inovo_rs::geometry::*;
+    ///
+    /// let right = Transform::new(400.0, -200.0, 100.0, 180.0, 0.0, 30.0);
+    /// let left = right.mirrored(&Plane::xz(0.0));
+    /// assert!(left.approx_eq(&Transform::new(400.0, 200.0, 100.0, 180.0, 0.0, -30.0), 1e-9, 1e-9));
+    /// assert!(left.mirrored(&Plane::xz(0.0)).approx_eq(&right, 1e-9, 1e-9));
+    /// ```
+    pub fn mirrored(&self, plane: &Plane) -> Self {
+        let normal = nalgebra::Vector3::from(plane.get_normal());
+        let reflection = Matrix3::identity() - 2.0 * normal * normal.transpose();
+        let rotation = reflection * self.rotation.to_rotation_matrix().into_inner() * reflection;
+        let rotation = Rotation3::from_matrix_unchecked(rotation);
+
+        let vector =
+            nalgebra::Vector3::from(self.vector) - 2.0 * plane.distance(self.vector) * normal;
+        Self {
+            vector: vector.into(),
+            rotation: UnitQuaternion::from_rotation_matrix(&rotation),
+            euler: None,
+        }
+    }
     /// interpolate two transform with a parameter t, scale from 0 to 1
     pub fn interpolate(&self, other: &Self, t: f64) -> Option<Self> {
         self.isometry()
             .try_lerp_slerp(&other.isometry(), t, f64::EPSILON)
             .map(|i| i.into())
     }
+
+    /// compute the euclidean distance between the vectors of two transform, in mm
+    pub fn translation_distance(&self, other: &Self) -> f64 {
+        (nalgebra::Vector3::from(self.vector) - nalgebra::Vector3::from(other.vector)).norm()
+    }
+    /// compute the angle of the rotation between two transform, in degree from 0 to 180
+    pub fn rotation_distance_deg(&self, other: &Self) -> f64 {
+        self.rotation.angle_to(&other.rotation) / PI * 180.0
+    }
+
+    /// check if two transform are within a translation tolerance in mm, and a rotation tolerance in degree
+    pub fn approx_eq(&self, other: &Self, tolerance_mm: f64, tolerance_deg: f64) -> bool {
+        self.translation_distance(other) <= tolerance_mm
+            && self.rotation_distance_deg(other) <= tolerance_deg
+    }
+
+    /// compute the mean of multiple transform, `None` if there is none
+    ///
+    /// the vectors are averaged arithmetically, the rotations with the eigenvector method for quaternion,
+    /// which does not depend on the sign of the quaternions.
+    /// ```
+    /// use inovo_rs::geometry::*;
+    ///
+    /// let measurements = [
+    ///     Transform::new(99.0, 0.0, 50.0, 0.0, 0.0, 179.0),
+    ///     Transform::new(101.0, 0.0, 50.0, 0.0, 0.0, -179.0),
+    /// ];
+    /// let mean = Transform::mean(&measurements).unwrap();
+    /// assert_eq!(mean.get_vector(), [100.0, 0.0, 50.0]);
+    /// assert!(mean.rotation_distance_deg(&Transform::from_rz(180.0)) < 1e-9);
+    /// ```
+    pub fn mean(transforms: &[Transform]) -> Option<Self> {
+        if transforms.is_empty() {
+            return None;
+        }
+        let n = transforms.len() as f64;
+        let vector = transforms
+            .iter()
+            .fold(nalgebra::Vector3::zeros(), |sum, t| {
+                sum + nalgebra::Vector3::from(t.vector)
+            })
+            / n;
+        let accumulator = transforms.iter().fold(Matrix4::zeros(), |sum, t| {
+            let q: Vector4<f64> = t.rotation.coords;
+            sum + q * q.transpose()
+        });
+        let eigen = accumulator.symmetric_eigen();
+        let q = eigen
+            .eigenvectors
+            .column(eigen.eigenvalues.imax())
+            .into_owned();
+        Some(Transform {
+            vector: vector.into(),
+            rotation: UnitQuaternion::from_quaternion(Quaternion::from(q)),
+            euler: None,
+        })
+    }
+
+    /// get the transform as a row major 4x4 homogeneous matrix, translation in mm
+    pub fn to_matrix(&self) -> [[f64; 4]; 4] {
+        let m = Matrix4::from(self.clone());
+        [0, 1, 2, 3].map(|r| [0, 1, 2, 3].map(|c| m[(r, c)]))
+    }
+    /// create a new transform from a row major 4x4 homogeneous matrix, translation in mm
+    ///
+    /// the rotation part is orthonormalized, and the last row is ignored
+    /// ```
+    /// use inovo_rs::geometry::*;
+    ///
+    /// let matrix = [
+    ///     [0.0, -1.0, 0.0, 100.0],
+    ///     [1.0, 0.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 1.0, 50.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ];
+    /// let t = Transform::from_matrix(matrix);
+    /// assert_eq!(t.to_string(), "x=100 y=0 z=50 | rx=0 ry=0 rz=90");
+    /// assert_eq!(t.to_matrix()[2][3], 50.0);
+    /// ```
+    pub fn from_matrix(matrix: [[f64; 4]; 4]) -> Self {
+        Matrix4::from_fn(|r, c| matrix[r][c]).into()
+    }
+}
+
+impl From<Transform> for Matrix4<f64> {
+    fn from(value: Transform) -> Self {
+        value.isometry().to_homogeneous()
+    }
+}
+
+impl From<Matrix4<f64>> for Transform {
+    fn from(value: Matrix4<f64>) -> Self {
+        let rotation = Rotation3::from_matrix(&Matrix3::from_fn(|r, c| value[(r, c)]));
+        Transform {
+            vector: [value[(0, 3)], value[(1, 3)], value[(2, 3)]],
+            rotation: UnitQuaternion::from_rotation_matrix(&rotation),
+            euler: None,
+        }
+    }
 }
 
 impl From<Isometry3<f64>> for Transform {
     fn from(value: Isometry3<f64>) -> Self {
-        let vector = value.translation.vector.into();
-        let euler = value.rotation.euler_angles();
-        let euler = [euler.0, euler.1, euler.2].map(|p| p / PI * 180.0);
-        Transform::from_vector(vector).set_euler(euler)
+        Transform {
+            vector: value.translation.vector.into(),
+            rotation: value.rotation,
+            euler: None,
+        }
+    }
+}
+
+/// convert an euler angle in degree to a quaternion
+fn euler_to_quaternion(eular_degree: [f64; 3]) -> UnitQuaternion<f64> {
+    let [rx, ry, rz] = eular_degree.map(|p| p / 180.0 * PI);
+    UnitQuaternion::from_euler_angles(rx, ry, rz)
+}
+
+/// convert a quaternion to an euler angle in degree
+///
+/// near the gimbal lock, `ry` is snapped to ±90 and the rotation about z is read as `rz` alone,
+/// as the decomposition of `rx` and `rz` is unstable there
+fn quaternion_to_euler(quaternion: &UnitQuaternion<f64>) -> [f64; 3] {
+    const GIMBAL_LOCK: f64 = 1e-6;
+    let m = quaternion.to_rotation_matrix().into_inner();
+    let cos_ry = m[(2, 1)].hypot(m[(2, 2)]);
+    let [rx, ry, rz] = if cos_ry < GIMBAL_LOCK {
+        [
+            0.0,
+            PI / 2.0 * -m[(2, 0)].signum(),
+            (-m[(0, 1)]).atan2(m[(1, 1)]),
+        ]
+    } else {
+        [
+            m[(2, 1)].atan2(m[(2, 2)]),
+            (-m[(2, 0)]).atan2(cos_ry),
+            m[(1, 0)].atan2(m[(0, 0)]),
+        ]
+    };
+    // adding zero turns a negative zero into zero
+    [rx, ry, rz].map(|p| p / PI * 180.0 + 0.0)
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+/// two transform are equal if the vectors and the rotations are exactly equal,
+/// a quaternion and its negation are the same rotation, see [`Transform::approx_eq`] for a tolerance
+impl PartialEq for Transform {
+    fn eq(&self, other: &Self) -> bool {
+        self.vector == other.vector
+            && (self.rotation.coords == other.rotation.coords
+                || self.rotation.coords == -other.rotation.coords)
+    }
+}
+
+impl std::fmt::Debug for Transform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [x, y, z] = self.get_vector();
+        let [rx, ry, rz] = self.get_euler();
+        f.debug_struct("Transform")
+            .field("x", &x)
+            .field("y", &y)
+            .field("z", &z)
+            .field("rx", &rx)
+            .field("ry", &ry)
+            .field("rz", &rz)
+            .finish()
     }
 }
 
@@ -296,12 +609,12 @@ impl From<String> for Transform {
             .skip_while(|&c| c != 'r')
             .take_while(|&c| c != '}')
             .collect::<String>()
-            .replace(&['{', '}', ' '], "")
+            .replace(['{', '}', ' '], "")
             .split(",")
             .filter_map(|term| {
                 let t = term.split(':').collect::<Vec<_>>();
 
-                let k = t.get(0)?.to_string();
+                let k = t.first()?.to_string();
 
                 let v = match t.get(1)?.parse::<f64>() {
                     Ok(f) => f,
@@ -323,13 +636,40 @@ impl From<String> for Transform {
 
 impl From<HashMap<String, f64>> for Transform {
     fn from(value: HashMap<String, f64>) -> Transform {
-        Transform::identity()
-            .set_x(value.get("x").cloned().unwrap_or_default())
-            .set_y(value.get("y").cloned().unwrap_or_default())
-            .set_z(value.get("z").cloned().unwrap_or_default())
-            .set_rx(value.get("rx").cloned().unwrap_or_default())
-            .set_ry(value.get("ry").cloned().unwrap_or_default())
-            .set_rz(value.get("rz").cloned().unwrap_or_default())
+        let get = |k: &str| value.get(k).cloned().unwrap_or_default();
+        Transform::new(
+            get("x"),
+            get("y"),
+            get("z"),
+            get("rx"),
+            get("ry"),
+            get("rz"),
+        )
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (key, value)) in ["x", "y", "z", "rx", "ry", "rz"]
+            .into_iter()
+            .zip(self.get_vector().into_iter().chain(self.get_euler()))
+            .enumerate()
+        {
+            match i {
+                0 => {}
+                3 => f.write_str(" | ")?,
+                _ => f.write_str(" ")?,
+            }
+            write_component(f, key, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Transform {
+    type Err = ParseCoordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_components(s, ["x", "y", "z", "rx", "ry", "rz"]).map(Transform::from_array)
     }
 }
 
@@ -342,6 +682,7 @@ impl Mul for Transform {
 
 impl Div for Transform {
     type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, rhs: Self) -> Self::Output {
         rhs.inverse() * self
     }
@@ -354,9 +695,19 @@ impl Neg for Transform {
     }
 }
 
-impl Into<MotionTarget> for Transform {
-    fn into(self) -> MotionTarget {
-        MotionTarget::Transform(self)
+impl Neg for &Transform {
+    type Output = Transform;
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+forward_ref_op!(Transform, Mul, mul, MulAssign, mul_assign);
+forward_ref_op!(Transform, Div, div, DivAssign, div_assign);
+
+impl From<Transform> for MotionTarget {
+    fn from(value: Transform) -> Self {
+        MotionTarget::Transform(value)
     }
 }
 
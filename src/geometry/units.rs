@@ -0,0 +1,78 @@
+use std::f64::consts::PI;
+use std::fmt::Display;
+
+/// A length in millimetre, the length unit of the api
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Mm(pub f64);
+
+/// A length in metre, the length unit of the robot
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Meters(pub f64);
+
+/// An angle in degree, the angle unit of the api
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Degrees(pub f64);
+
+/// An angle in radian, the angle unit of the robot
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Radians(pub f64);
+
+impl From<f64> for Mm {
+    fn from(value: f64) -> Self {
+        Mm(value)
+    }
+}
+
+impl From<Meters> for Mm {
+    fn from(value: Meters) -> Self {
+        Mm(value.0 * 1000.0)
+    }
+}
+
+impl From<Mm> for Meters {
+    fn from(value: Mm) -> Self {
+        Meters(value.0 / 1000.0)
+    }
+}
+
+impl From<f64> for Degrees {
+    fn from(value: f64) -> Self {
+        Degrees(value)
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(value: Radians) -> Self {
+        Degrees(value.0 * 180.0 / PI)
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(value: Degrees) -> Self {
+        Radians(value.0 / 180.0 * PI)
+    }
+}
+
+impl Display for Mm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} mm", self.0)
+    }
+}
+
+impl Display for Meters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} m", self.0)
+    }
+}
+
+impl Display for Degrees {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} deg", self.0)
+    }
+}
+
+impl Display for Radians {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} rad", self.0)
+    }
+}
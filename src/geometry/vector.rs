@@ -0,0 +1,128 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::Transform;
+
+/// A structure representing a 3D vector, in mm
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Vector3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Vector3 {
+    /// create a new vector
+    pub fn new(x_mm: f64, y_mm: f64, z_mm: f64) -> Self {
+        Self {
+            x: x_mm,
+            y: y_mm,
+            z: z_mm,
+        }
+    }
+    /// create a new zero vector
+    pub fn zeros() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+    /// get the x component of the vector
+    pub fn get_x(&self) -> f64 {
+        self.x
+    }
+    /// get the y component of the vector
+    pub fn get_y(&self) -> f64 {
+        self.y
+    }
+    /// get the z component of the vector
+    pub fn get_z(&self) -> f64 {
+        self.z
+    }
+    /// get the vector as an array
+    pub fn into_array(self) -> [f64; 3] {
+        self.into()
+    }
+    /// get the length of the vector, in mm
+    pub fn norm(&self) -> f64 {
+        nalgebra::Vector3::from(*self).norm()
+    }
+    /// compute the dot product of two vector
+    pub fn dot(&self, other: &Self) -> f64 {
+        nalgebra::Vector3::from(*self).dot(&nalgebra::Vector3::from(*other))
+    }
+    /// compute the cross product of two vector
+    pub fn cross(&self, other: &Self) -> Self {
+        nalgebra::Vector3::from(*self)
+            .cross(&nalgebra::Vector3::from(*other))
+            .into()
+    }
+}
+
+impl From<[f64; 3]> for Vector3 {
+    fn from(value: [f64; 3]) -> Self {
+        Vector3::new(value[0], value[1], value[2])
+    }
+}
+
+impl From<Vector3> for [f64; 3] {
+    fn from(value: Vector3) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl From<nalgebra::Vector3<f64>> for Vector3 {
+    fn from(value: nalgebra::Vector3<f64>) -> Self {
+        Vector3::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<Vector3> for nalgebra::Vector3<f64> {
+    fn from(value: Vector3) -> Self {
+        nalgebra::Vector3::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<&Transform> for Vector3 {
+    fn from(value: &Transform) -> Self {
+        value.get_vector().into()
+    }
+}
+
+impl From<Transform> for Vector3 {
+    fn from(value: Transform) -> Self {
+        value.get_vector().into()
+    }
+}
+
+impl From<Vector3> for Transform {
+    fn from(value: Vector3) -> Self {
+        Transform::from_vector(value)
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+impl Mul<f64> for Vector3 {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
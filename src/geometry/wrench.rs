@@ -0,0 +1,117 @@
+use std::ops::{Add, Neg, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::write_component;
+use crate::robot::FromRobot;
+
+/// A structure representing a force and a torque, in N and Nm
+///
+/// # Example
+/// ```
+/// use inovo_rs::geometry::Wrench;
+///
+/// let contact = Wrench::new(0.0, 0.0, -12.0, 0.0, 0.0, 0.0);
+/// let bias = Wrench::new(0.0, 0.0, -2.0, 0.0, 0.0, 0.0);
+/// assert_eq!((contact - bias).force_norm(), 10.0);
+/// assert_eq!(bias.to_string(), "fx=0 fy=0 fz=-2 tx=0 ty=0 tz=0");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Wrench {
+    force: [f64; 3],
+    torque: [f64; 3],
+}
+
+impl Wrench {
+    /// create a new wrench, force in N and torque in Nm
+    pub fn new(fx: f64, fy: f64, fz: f64, tx: f64, ty: f64, tz: f64) -> Self {
+        Self {
+            force: [fx, fy, fz],
+            torque: [tx, ty, tz],
+        }
+    }
+    /// create a zero wrench
+    pub fn zeros() -> Self {
+        Self::default()
+    }
+    /// create a wrench from `[fx, fy, fz, tx, ty, tz]`
+    pub fn from_array(q: [f64; 6]) -> Self {
+        Self::new(q[0], q[1], q[2], q[3], q[4], q[5])
+    }
+    /// get the wrench as `[fx, fy, fz, tx, ty, tz]`
+    pub fn into_array(self) -> [f64; 6] {
+        let [fx, fy, fz] = self.force;
+        let [tx, ty, tz] = self.torque;
+        [fx, fy, fz, tx, ty, tz]
+    }
+    /// get the force, in N
+    pub fn get_force(&self) -> [f64; 3] {
+        self.force
+    }
+    /// get the torque, in Nm
+    pub fn get_torque(&self) -> [f64; 3] {
+        self.torque
+    }
+    /// get the magnitude of the force, in N
+    pub fn force_norm(&self) -> f64 {
+        self.force.iter().map(|f| f * f).sum::<f64>().sqrt()
+    }
+    /// get the magnitude of the torque, in Nm
+    pub fn torque_norm(&self) -> f64 {
+        self.torque.iter().map(|t| t * t).sum::<f64>().sqrt()
+    }
+}
+
+impl From<[f64; 6]> for Wrench {
+    fn from(value: [f64; 6]) -> Self {
+        Self::from_array(value)
+    }
+}
+
+impl From<Wrench> for [f64; 6] {
+    fn from(value: Wrench) -> Self {
+        value.into_array()
+    }
+}
+
+impl Add for Wrench {
+    type Output = Wrench;
+    fn add(self, rhs: Wrench) -> Wrench {
+        let (a, b) = (self.into_array(), rhs.into_array());
+        Wrench::from_array(std::array::from_fn(|i| a[i] + b[i]))
+    }
+}
+
+impl Sub for Wrench {
+    type Output = Wrench;
+    fn sub(self, rhs: Wrench) -> Wrench {
+        self + -rhs
+    }
+}
+
+impl Neg for Wrench {
+    type Output = Wrench;
+    fn neg(self) -> Wrench {
+        Wrench::from_array(self.into_array().map(|v| -v))
+    }
+}
+
+impl std::fmt::Display for Wrench {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keys = ["fx", "fy", "fz", "tx", "ty", "tz"];
+        for (i, (key, value)) in keys.iter().zip(self.into_array()).enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write_component(f, key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// the robot responds `[fx, fy, fz, tx, ty, tz]` in N and Nm
+impl FromRobot for Wrench {
+    fn from_robot(res: String) -> Result<Self, String> {
+        <[f64; 6]>::from_robot(res).map(Wrench::from_array)
+    }
+}
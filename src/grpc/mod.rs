@@ -0,0 +1,403 @@
+//! gRPC server, a typed and language-neutral interface mirroring [`IvaRobot`].
+//!
+//! the interface is published in `proto/inovo.proto` of the crate, with the package `inovo.v1`.
+//! lengths are in mm and angles in degree, like the rust api.
+//!
+//! # Example
+//! ```no_run
+//! use inovo_rs::grpc;
+//! use inovo_rs::robot::*;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let handle = RobotHandle::new(Robot::defaut_logger(50003, "psu002")?);
+//!     grpc::serve("0.0.0.0:50051".parse()?, handle).await?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! a rust client is generated alongside the server:
+//! ```no_run
+//! use inovo_rs::grpc::{proto, IvaRobotClient};
+//!
+//! # async fn client() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut client = IvaRobotClient::connect("http://cell-01:50051").await?;
+//! let pose = client.get_current_transform(proto::Empty {}).await?.into_inner();
+//! client.linear(proto::Transform { z: pose.z + 10.0, ..pose }).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::net::SocketAddr;
+
+use tonic::{Request, Response, Status};
+
+use crate::geometry::{JointCoord, Transform};
+use crate::iva::{IOTarget, MotionMode, MotionTarget, RobotCommand};
+use crate::robot::{CommandSequence, IvaRobot, MotionParam, Robot, RobotError, RobotHandle};
+
+#[allow(clippy::all)]
+pub mod proto;
+
+pub use proto::iva_robot_client::IvaRobotClient;
+pub use proto::iva_robot_server::IvaRobotServer;
+
+impl From<Transform> for proto::Transform {
+    fn from(value: Transform) -> Self {
+        let [x, y, z] = value.get_vector();
+        let [rx, ry, rz] = value.get_euler();
+        Self {
+            x,
+            y,
+            z,
+            rx,
+            ry,
+            rz,
+        }
+    }
+}
+
+impl From<proto::Transform> for Transform {
+    fn from(value: proto::Transform) -> Self {
+        Transform::new(value.x, value.y, value.z, value.rx, value.ry, value.rz)
+    }
+}
+
+impl From<JointCoord> for proto::JointCoord {
+    fn from(value: JointCoord) -> Self {
+        let [j1, j2, j3, j4, j5, j6] = value.into_array();
+        Self {
+            j1,
+            j2,
+            j3,
+            j4,
+            j5,
+            j6,
+        }
+    }
+}
+
+impl From<proto::JointCoord> for JointCoord {
+    fn from(value: proto::JointCoord) -> Self {
+        JointCoord::new(value.j1, value.j2, value.j3, value.j4, value.j5, value.j6)
+    }
+}
+
+impl From<proto::MotionParam> for MotionParam {
+    fn from(value: proto::MotionParam) -> Self {
+        let mut param = MotionParam::new();
+        if let Some(percent) = value.speed {
+            param = param.set_speed(percent);
+        }
+        if let Some(percent) = value.accel {
+            param = param.set_accel(percent);
+        }
+        if let Some(mm) = value.blend_linear {
+            param = param.set_blend_linear(mm);
+        }
+        if let Some(deg) = value.blend_angular {
+            param = param.set_blend_angular(deg);
+        }
+        if let Some(mm) = value.tcp_speed_linear {
+            param = param.set_tcp_speed_linear(mm);
+        }
+        if let Some(deg) = value.tcp_speed_angular {
+            param = param.set_tcp_speed_angular(deg);
+        }
+        param
+    }
+}
+
+impl TryFrom<proto::MotionTarget> for MotionTarget {
+    type Error = Status;
+    fn try_from(value: proto::MotionTarget) -> Result<Self, Self::Error> {
+        match value.target {
+            Some(proto::motion_target::Target::Transform(t)) => {
+                Ok(MotionTarget::Transform(t.into()))
+            }
+            Some(proto::motion_target::Target::JointCoord(j)) => {
+                Ok(MotionTarget::JointCoord(j.into()))
+            }
+            None => Err(Status::invalid_argument("missing motion target")),
+        }
+    }
+}
+
+impl TryFrom<proto::RobotCommand> for RobotCommand {
+    type Error = Status;
+    fn try_from(value: proto::RobotCommand) -> Result<Self, Self::Error> {
+        use proto::robot_command::Command;
+        match value.command {
+            Some(Command::Synchronize(_)) => Ok(RobotCommand::Synchronize),
+            Some(Command::Sleep(second)) => Ok(RobotCommand::sleep(second)),
+            Some(Command::SetParam(param)) => Ok(RobotCommand::SetParameter(param.into())),
+            Some(Command::Motion(motion)) => {
+                let motion_mode = match proto::MotionMode::try_from(motion.mode) {
+                    Ok(proto::MotionMode::Linear) => MotionMode::Linear,
+                    Ok(proto::MotionMode::LinearRelative) => MotionMode::LinearRelative,
+                    Ok(proto::MotionMode::Joint) => MotionMode::Joint,
+                    Ok(proto::MotionMode::JointRelative) => MotionMode::JointRelative,
+                    Err(_) => return Err(Status::invalid_argument("invalid motion mode")),
+                };
+                let target = motion
+                    .target
+                    .ok_or_else(|| Status::invalid_argument("missing motion target"))?
+                    .try_into()?;
+                Ok(RobotCommand::Motion {
+                    motion_mode,
+                    target,
+                })
+            }
+            None => Err(Status::invalid_argument("missing robot command")),
+        }
+    }
+}
+
+fn io_port(target: i32, port: u32) -> Result<(IOTarget, u16), Status> {
+    let target = match proto::IoTarget::try_from(target) {
+        Ok(proto::IoTarget::Beckhoff) => IOTarget::Beckhoff,
+        Ok(proto::IoTarget::Wrist) => IOTarget::Wrist,
+        Err(_) => return Err(Status::invalid_argument("invalid io target")),
+    };
+    let port = u16::try_from(port)
+        .map_err(|_| Status::invalid_argument(format!("invalid io port: {}", port)))?;
+    Ok((target, port))
+}
+
+/// convert a robot error to a status
+pub fn status(error: RobotError) -> Status {
+    match error {
+        RobotError::InvalidArgument(msg) => Status::invalid_argument(msg),
+        RobotError::ResponseError(res) => Status::failed_precondition(res),
+        RobotError::SocketError(e) => Status::unavailable(e.to_string()),
+        RobotError::Cancelled => Status::cancelled("cancelled"),
+        RobotError::SoftLimit(violation) => Status::out_of_range(violation.to_string()),
+        RobotError::Timeout(msg) => Status::deadline_exceeded(msg),
+        e @ RobotError::ContextMismatch { .. } => Status::failed_precondition(e.to_string()),
+        e => Status::internal(e.to_string()),
+    }
+}
+
+/// The gRPC service of a shared robot
+#[derive(Clone)]
+pub struct GrpcService {
+    handle: RobotHandle,
+}
+
+impl GrpcService {
+    /// create a new service
+    pub fn new(handle: RobotHandle) -> Self {
+        Self { handle }
+    }
+    /// get the tonic server of the service, to add to a router
+    pub fn into_server(self) -> IvaRobotServer<Self> {
+        IvaRobotServer::new(self)
+    }
+
+    /// run a blocking robot function on the blocking thread pool
+    async fn blocking<T, F>(&self, f: F) -> Result<Response<T>, Status>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Robot) -> Result<T, RobotError> + Send + 'static,
+    {
+        let handle = self.handle.clone();
+        tokio::task::spawn_blocking(move || handle.with(f))
+            .await
+            .map_err(|e| Status::internal(format!("robot task failed: {}", e)))?
+            .map(Response::new)
+            .map_err(status)
+    }
+    /// run a robot command, responding empty
+    async fn command<F>(&self, f: F) -> Result<Response<proto::Empty>, Status>
+    where
+        F: FnOnce(&mut Robot) -> Result<&mut Robot, RobotError> + Send + 'static,
+    {
+        self.blocking(|bot| f(bot).map(|_| proto::Empty {})).await
+    }
+}
+
+/// serve the robot on an address, until the server fails
+pub async fn serve(addr: SocketAddr, handle: RobotHandle) -> Result<(), tonic::transport::Error> {
+    tonic::transport::Server::builder()
+        .add_service(GrpcService::new(handle).into_server())
+        .serve(addr)
+        .await
+}
+
+#[tonic::async_trait]
+impl proto::iva_robot_server::IvaRobot for GrpcService {
+    async fn execute(
+        &self,
+        request: Request<proto::RobotCommand>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let command = request.into_inner().try_into()?;
+        self.command(|bot| bot.execute(command)).await
+    }
+    async fn sleep(
+        &self,
+        request: Request<proto::SleepRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let second = request.into_inner().second;
+        self.command(move |bot| bot.sleep(second)).await
+    }
+    async fn set_param(
+        &self,
+        request: Request<proto::MotionParam>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let param = request.into_inner().into();
+        self.command(|bot| bot.set_param(param)).await
+    }
+    async fn linear(
+        &self,
+        request: Request<proto::Transform>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let target = request.into_inner().into();
+        self.command(|bot| bot.linear(target)).await
+    }
+    async fn linear_relative(
+        &self,
+        request: Request<proto::Transform>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let target = request.into_inner().into();
+        self.command(|bot| bot.linear_relative(target)).await
+    }
+    async fn joint(
+        &self,
+        request: Request<proto::MotionTarget>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let target = MotionTarget::try_from(request.into_inner())?;
+        self.command(|bot| bot.joint(target)).await
+    }
+    async fn joint_relative(
+        &self,
+        request: Request<proto::Transform>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let target = request.into_inner().into();
+        self.command(|bot| bot.joint_relative(target)).await
+    }
+    async fn enqueue(
+        &self,
+        request: Request<proto::RobotCommand>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let command = request.into_inner().try_into()?;
+        self.command(|bot| bot.enqueue(command)).await
+    }
+    async fn dequeue(&self, _: Request<proto::Empty>) -> Result<Response<proto::Empty>, Status> {
+        self.command(|bot| bot.dequeue()).await
+    }
+    async fn sequence(
+        &self,
+        request: Request<proto::CommandSequence>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let sequence = request
+            .into_inner()
+            .commands
+            .into_iter()
+            .map(RobotCommand::try_from)
+            .collect::<Result<CommandSequence, _>>()?;
+        self.command(|bot| bot.sequence(sequence)).await
+    }
+    async fn pop(&self, _: Request<proto::Empty>) -> Result<Response<proto::Empty>, Status> {
+        self.command(|bot| bot.pop()).await
+    }
+    async fn pop_all(&self, _: Request<proto::Empty>) -> Result<Response<proto::Empty>, Status> {
+        self.command(|bot| bot.pop_all()).await
+    }
+    async fn get_context_depth(
+        &self,
+        _: Request<proto::Empty>,
+    ) -> Result<Response<proto::ContextDepth>, Status> {
+        self.blocking(|bot| {
+            bot.context_depth()
+                .map(|depth| proto::ContextDepth { depth })
+        })
+        .await
+    }
+    async fn get_current_transform(
+        &self,
+        _: Request<proto::Empty>,
+    ) -> Result<Response<proto::Transform>, Status> {
+        self.blocking(|bot| bot.get_current_transform().map(Into::into))
+            .await
+    }
+    async fn get_current_joint(
+        &self,
+        _: Request<proto::Empty>,
+    ) -> Result<Response<proto::JointCoord>, Status> {
+        self.blocking(|bot| bot.get_current_joint().map(Into::into))
+            .await
+    }
+    async fn get_data(
+        &self,
+        request: Request<proto::DataKey>,
+    ) -> Result<Response<proto::DataValue>, Status> {
+        let key = request.into_inner().key;
+        self.blocking(|bot| {
+            bot.get_data::<String>(key)
+                .map(|value| proto::DataValue { value })
+        })
+        .await
+    }
+    async fn io_set(
+        &self,
+        request: Request<proto::IoSetRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let request = request.into_inner();
+        let (target, port) = io_port(request.target, request.port)?;
+        self.command(move |bot| bot.io_set(target, port, request.state))
+            .await
+    }
+    async fn io_get(
+        &self,
+        request: Request<proto::IoPort>,
+    ) -> Result<Response<proto::IoState>, Status> {
+        let request = request.into_inner();
+        let (target, port) = io_port(request.target, request.port)?;
+        self.blocking(move |bot| {
+            bot.io_get(target, port)
+                .map(|state| proto::IoState { state })
+        })
+        .await
+    }
+    async fn gripper_activate(
+        &self,
+        _: Request<proto::Empty>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        self.command(|bot| bot.gripper_activate()).await
+    }
+    async fn gripper_set(
+        &self,
+        request: Request<proto::GripperLabel>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let label = request.into_inner().label;
+        self.command(|bot| bot.gripper_set(label)).await
+    }
+    async fn gripper_set_width(
+        &self,
+        request: Request<proto::GripperWidth>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let proto::GripperWidth { mm, force } = request.into_inner();
+        self.command(move |bot| bot.gripper_set_width(mm, force))
+            .await
+    }
+    async fn gripper_get(
+        &self,
+        _: Request<proto::Empty>,
+    ) -> Result<Response<proto::Value>, Status> {
+        self.blocking(|bot| bot.gripper_get().map(|value| proto::Value { value }))
+            .await
+    }
+    async fn vacuum_on(&self, _: Request<proto::Empty>) -> Result<Response<proto::Empty>, Status> {
+        self.command(|bot| bot.vacuum_on()).await
+    }
+    async fn vacuum_off(&self, _: Request<proto::Empty>) -> Result<Response<proto::Empty>, Status> {
+        self.command(|bot| bot.vacuum_off()).await
+    }
+    async fn vacuum_pressure(
+        &self,
+        _: Request<proto::Empty>,
+    ) -> Result<Response<proto::Value>, Status> {
+        self.blocking(|bot| bot.vacuum_pressure().map(|value| proto::Value { value }))
+            .await
+    }
+}
@@ -0,0 +1,1771 @@
+// The messages and service of `proto/inovo.proto`, package `inovo.v1`.
+//
+// the messages follow the prost-build output, the service is generated by `tonic_build::manual`,
+// keep both in sync with the proto file.
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Empty {}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Transform {
+    #[prost(double, tag = "1")]
+    pub x: f64,
+    #[prost(double, tag = "2")]
+    pub y: f64,
+    #[prost(double, tag = "3")]
+    pub z: f64,
+    #[prost(double, tag = "4")]
+    pub rx: f64,
+    #[prost(double, tag = "5")]
+    pub ry: f64,
+    #[prost(double, tag = "6")]
+    pub rz: f64,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct JointCoord {
+    #[prost(double, tag = "1")]
+    pub j1: f64,
+    #[prost(double, tag = "2")]
+    pub j2: f64,
+    #[prost(double, tag = "3")]
+    pub j3: f64,
+    #[prost(double, tag = "4")]
+    pub j4: f64,
+    #[prost(double, tag = "5")]
+    pub j5: f64,
+    #[prost(double, tag = "6")]
+    pub j6: f64,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MotionTarget {
+    #[prost(oneof = "motion_target::Target", tags = "1, 2")]
+    pub target: ::core::option::Option<motion_target::Target>,
+}
+/// Nested message and enum types in `MotionTarget`.
+pub mod motion_target {
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum Target {
+        #[prost(message, tag = "1")]
+        Transform(super::Transform),
+        #[prost(message, tag = "2")]
+        JointCoord(super::JointCoord),
+    }
+}
+
+/// unset fields are left unchanged on the robot
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MotionParam {
+    #[prost(double, optional, tag = "1")]
+    pub speed: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "2")]
+    pub accel: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "3")]
+    pub blend_linear: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "4")]
+    pub blend_angular: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "5")]
+    pub tcp_speed_linear: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "6")]
+    pub tcp_speed_angular: ::core::option::Option<f64>,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Motion {
+    #[prost(enumeration = "MotionMode", tag = "1")]
+    pub mode: i32,
+    #[prost(message, optional, tag = "2")]
+    pub target: ::core::option::Option<MotionTarget>,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RobotCommand {
+    #[prost(oneof = "robot_command::Command", tags = "1, 2, 3, 4")]
+    pub command: ::core::option::Option<robot_command::Command>,
+}
+/// Nested message and enum types in `RobotCommand`.
+pub mod robot_command {
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum Command {
+        #[prost(message, tag = "1")]
+        Synchronize(super::Empty),
+        #[prost(double, tag = "2")]
+        Sleep(f64),
+        #[prost(message, tag = "3")]
+        SetParam(super::MotionParam),
+        #[prost(message, tag = "4")]
+        Motion(super::Motion),
+    }
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandSequence {
+    #[prost(message, repeated, tag = "1")]
+    pub commands: ::prost::alloc::vec::Vec<RobotCommand>,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SleepRequest {
+    #[prost(double, tag = "1")]
+    pub second: f64,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ContextDepth {
+    #[prost(uint32, tag = "1")]
+    pub depth: u32,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataKey {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataValue {
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct IoPort {
+    #[prost(enumeration = "IoTarget", tag = "1")]
+    pub target: i32,
+    #[prost(uint32, tag = "2")]
+    pub port: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct IoSetRequest {
+    #[prost(enumeration = "IoTarget", tag = "1")]
+    pub target: i32,
+    #[prost(uint32, tag = "2")]
+    pub port: u32,
+    #[prost(bool, tag = "3")]
+    pub state: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct IoState {
+    #[prost(bool, tag = "1")]
+    pub state: bool,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GripperLabel {
+    #[prost(string, tag = "1")]
+    pub label: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GripperWidth {
+    #[prost(double, tag = "1")]
+    pub mm: f64,
+    #[prost(double, tag = "2")]
+    pub force: f64,
+}
+
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Value {
+    #[prost(double, tag = "1")]
+    pub value: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MotionMode {
+    Linear = 0,
+    LinearRelative = 1,
+    Joint = 2,
+    JointRelative = 3,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum IoTarget {
+    Beckhoff = 0,
+    Wrist = 1,
+}
+
+/// Generated client implementations.
+pub mod iva_robot_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value
+    )]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct IvaRobotClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl IvaRobotClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> IvaRobotClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> IvaRobotClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::Body>>>::Error:
+                Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            IvaRobotClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn execute(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RobotCommand>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/Execute");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "Execute"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn sleep(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SleepRequest>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/Sleep");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "Sleep"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_param(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MotionParam>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/SetParam");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "SetParam"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn linear(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Transform>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/Linear");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "Linear"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn linear_relative(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Transform>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/LinearRelative");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "LinearRelative"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn joint(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MotionTarget>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/Joint");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "Joint"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn joint_relative(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Transform>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/JointRelative");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "JointRelative"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn enqueue(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RobotCommand>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/Enqueue");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "Enqueue"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn dequeue(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/Dequeue");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "Dequeue"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn sequence(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CommandSequence>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/Sequence");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "Sequence"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn pop(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/Pop");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "Pop"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn pop_all(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/PopAll");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "PopAll"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_context_depth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::ContextDepth>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/GetContextDepth");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "GetContextDepth"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_current_transform(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Transform>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/GetCurrentTransform");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "GetCurrentTransform"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_current_joint(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::JointCoord>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/GetCurrentJoint");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "GetCurrentJoint"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DataKey>,
+        ) -> std::result::Result<tonic::Response<super::DataValue>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/GetData");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "GetData"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn io_set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IoSetRequest>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/IoSet");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "IoSet"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn io_get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IoPort>,
+        ) -> std::result::Result<tonic::Response<super::IoState>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/IoGet");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "IoGet"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn gripper_activate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/GripperActivate");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "GripperActivate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn gripper_set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GripperLabel>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/GripperSet");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "GripperSet"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn gripper_set_width(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GripperWidth>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/GripperSetWidth");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "GripperSetWidth"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn gripper_get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Value>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/GripperGet");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "GripperGet"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn vacuum_on(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/VacuumOn");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "VacuumOn"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn vacuum_off(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/VacuumOff");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "VacuumOff"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn vacuum_pressure(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Value>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/inovo.v1.IvaRobot/VacuumPressure");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("inovo.v1.IvaRobot", "VacuumPressure"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod iva_robot_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with IvaRobotServer.
+    #[async_trait]
+    pub trait IvaRobot: std::marker::Send + std::marker::Sync + 'static {
+        async fn execute(
+            &self,
+            request: tonic::Request<super::RobotCommand>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn sleep(
+            &self,
+            request: tonic::Request<super::SleepRequest>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn set_param(
+            &self,
+            request: tonic::Request<super::MotionParam>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn linear(
+            &self,
+            request: tonic::Request<super::Transform>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn linear_relative(
+            &self,
+            request: tonic::Request<super::Transform>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn joint(
+            &self,
+            request: tonic::Request<super::MotionTarget>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn joint_relative(
+            &self,
+            request: tonic::Request<super::Transform>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn enqueue(
+            &self,
+            request: tonic::Request<super::RobotCommand>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn dequeue(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn sequence(
+            &self,
+            request: tonic::Request<super::CommandSequence>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn pop(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn pop_all(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn get_context_depth(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::ContextDepth>, tonic::Status>;
+        async fn get_current_transform(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Transform>, tonic::Status>;
+        async fn get_current_joint(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::JointCoord>, tonic::Status>;
+        async fn get_data(
+            &self,
+            request: tonic::Request<super::DataKey>,
+        ) -> std::result::Result<tonic::Response<super::DataValue>, tonic::Status>;
+        async fn io_set(
+            &self,
+            request: tonic::Request<super::IoSetRequest>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn io_get(
+            &self,
+            request: tonic::Request<super::IoPort>,
+        ) -> std::result::Result<tonic::Response<super::IoState>, tonic::Status>;
+        async fn gripper_activate(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn gripper_set(
+            &self,
+            request: tonic::Request<super::GripperLabel>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn gripper_set_width(
+            &self,
+            request: tonic::Request<super::GripperWidth>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn gripper_get(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Value>, tonic::Status>;
+        async fn vacuum_on(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn vacuum_off(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        async fn vacuum_pressure(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<super::Value>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct IvaRobotServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> IvaRobotServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for IvaRobotServer<T>
+    where
+        T: IvaRobot,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/inovo.v1.IvaRobot/Execute" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExecuteSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::RobotCommand> for ExecuteSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RobotCommand>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::execute(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExecuteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/Sleep" => {
+                    #[allow(non_camel_case_types)]
+                    struct SleepSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::SleepRequest> for SleepSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SleepRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as IvaRobot>::sleep(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SleepSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/SetParam" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetParamSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::MotionParam> for SetParamSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MotionParam>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::set_param(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetParamSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/Linear" => {
+                    #[allow(non_camel_case_types)]
+                    struct LinearSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Transform> for LinearSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Transform>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as IvaRobot>::linear(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LinearSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/LinearRelative" => {
+                    #[allow(non_camel_case_types)]
+                    struct LinearRelativeSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Transform> for LinearRelativeSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Transform>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as IvaRobot>::linear_relative(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LinearRelativeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/Joint" => {
+                    #[allow(non_camel_case_types)]
+                    struct JointSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::MotionTarget> for JointSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MotionTarget>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as IvaRobot>::joint(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = JointSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/JointRelative" => {
+                    #[allow(non_camel_case_types)]
+                    struct JointRelativeSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Transform> for JointRelativeSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Transform>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as IvaRobot>::joint_relative(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = JointRelativeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/Enqueue" => {
+                    #[allow(non_camel_case_types)]
+                    struct EnqueueSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::RobotCommand> for EnqueueSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RobotCommand>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::enqueue(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EnqueueSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/Dequeue" => {
+                    #[allow(non_camel_case_types)]
+                    struct DequeueSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for DequeueSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::dequeue(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DequeueSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/Sequence" => {
+                    #[allow(non_camel_case_types)]
+                    struct SequenceSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::CommandSequence> for SequenceSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CommandSequence>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::sequence(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SequenceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/Pop" => {
+                    #[allow(non_camel_case_types)]
+                    struct PopSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for PopSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as IvaRobot>::pop(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PopSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/PopAll" => {
+                    #[allow(non_camel_case_types)]
+                    struct PopAllSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for PopAllSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::pop_all(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PopAllSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/GetContextDepth" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetContextDepthSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for GetContextDepthSvc<T> {
+                        type Response = super::ContextDepth;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as IvaRobot>::get_context_depth(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetContextDepthSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/GetCurrentTransform" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCurrentTransformSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for GetCurrentTransformSvc<T> {
+                        type Response = super::Transform;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as IvaRobot>::get_current_transform(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCurrentTransformSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/GetCurrentJoint" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCurrentJointSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for GetCurrentJointSvc<T> {
+                        type Response = super::JointCoord;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as IvaRobot>::get_current_joint(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCurrentJointSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/GetData" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDataSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::DataKey> for GetDataSvc<T> {
+                        type Response = super::DataValue;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DataKey>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::get_data(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetDataSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/IoSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct IoSetSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::IoSetRequest> for IoSetSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IoSetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as IvaRobot>::io_set(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = IoSetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/IoGet" => {
+                    #[allow(non_camel_case_types)]
+                    struct IoGetSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::IoPort> for IoGetSvc<T> {
+                        type Response = super::IoState;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::IoPort>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { <T as IvaRobot>::io_get(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = IoGetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/GripperActivate" => {
+                    #[allow(non_camel_case_types)]
+                    struct GripperActivateSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for GripperActivateSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as IvaRobot>::gripper_activate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GripperActivateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/GripperSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct GripperSetSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::GripperLabel> for GripperSetSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GripperLabel>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::gripper_set(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GripperSetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/GripperSetWidth" => {
+                    #[allow(non_camel_case_types)]
+                    struct GripperSetWidthSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::GripperWidth> for GripperSetWidthSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GripperWidth>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as IvaRobot>::gripper_set_width(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GripperSetWidthSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/GripperGet" => {
+                    #[allow(non_camel_case_types)]
+                    struct GripperGetSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for GripperGetSvc<T> {
+                        type Response = super::Value;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::gripper_get(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GripperGetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/VacuumOn" => {
+                    #[allow(non_camel_case_types)]
+                    struct VacuumOnSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for VacuumOnSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::vacuum_on(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VacuumOnSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/VacuumOff" => {
+                    #[allow(non_camel_case_types)]
+                    struct VacuumOffSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for VacuumOffSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as IvaRobot>::vacuum_off(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VacuumOffSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/inovo.v1.IvaRobot/VacuumPressure" => {
+                    #[allow(non_camel_case_types)]
+                    struct VacuumPressureSvc<T: IvaRobot>(pub Arc<T>);
+                    impl<T: IvaRobot> tonic::server::UnaryService<super::Empty> for VacuumPressureSvc<T> {
+                        type Response = super::Value;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as IvaRobot>::vacuum_pressure(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VacuumPressureSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    let mut response = http::Response::new(tonic::body::Body::default());
+                    let headers = response.headers_mut();
+                    headers.insert(
+                        tonic::Status::GRPC_STATUS,
+                        (tonic::Code::Unimplemented as i32).into(),
+                    );
+                    headers.insert(
+                        http::header::CONTENT_TYPE,
+                        tonic::metadata::GRPC_CONTENT_TYPE,
+                    );
+                    Ok(response)
+                }),
+            }
+        }
+    }
+    impl<T> Clone for IvaRobotServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "inovo.v1.IvaRobot";
+    impl<T> tonic::server::NamedService for IvaRobotServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -0,0 +1,165 @@
+//! Utilities to inspect the iva protocol, when evolving the robot-side block in lock-step
+//!
+//! ## Example
+//! ```
+//! use inovo_rs::iva::debug::*;
+//! use inovo_rs::iva::*;
+//!
+//! // a line captured from a robot-side block of a newer version
+//! let line = r#"{"op_code": "get", "target": "transform", "frame": "tool"}"#;
+//! let issues = validate(line).unwrap_err();
+//! assert_eq!(issues[0].to_string(), "unknown field /frame");
+//!
+//! assert!(check_roundtrip(&Instruction::get(GetTarget::Transform)).is_ok());
+//! ```
+
+use serde_json::Value;
+
+use crate::iva::Instruction;
+
+/// A difference between two json values, at a json pointer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diff {
+    /// the json pointer of the value, e.g. `/target/x`
+    pub path: String,
+    /// the left value, `None` if missing
+    pub left: Option<Value>,
+    /// the right value, `None` if missing
+    pub right: Option<Value>,
+}
+
+impl std::fmt::Display for Diff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |value: &Option<Value>| match value {
+            Some(value) => value.to_string(),
+            None => "<missing>".to_string(),
+        };
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            show(&self.left),
+            show(&self.right)
+        )
+    }
+}
+
+/// An issue of an instruction json against the schema of [`Instruction`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum Issue {
+    /// the json is not an instruction, e.g. a missing `op_code` tag or a missing field
+    #[error("invalid instruction: {0}")]
+    Invalid(String),
+    /// a field ignored when parsed, at a json pointer
+    #[error("unknown field {0}")]
+    UnknownField(String),
+    /// a value read differently than written
+    #[error("changed {0}")]
+    Changed(Diff),
+}
+
+/// pretty print a json line, e.g. an instruction captured from the wire
+pub fn pretty(json: &str) -> Result<String, serde_json::Error> {
+    let value: Value = serde_json::from_str(json)?;
+    serde_json::to_string_pretty(&value)
+}
+
+/// compare two instructions field by field
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::iva::debug::*;
+/// use inovo_rs::iva::*;
+///
+/// let a = Instruction::exec(RobotCommand::linear(Transform::from_x(100.0)));
+/// let b = Instruction::exec(RobotCommand::joint(Transform::from_x(100.0)));
+/// let diffs = diff(&a, &b);
+/// assert_eq!(diffs.len(), 1);
+/// assert_eq!(diffs[0].to_string(), r#"/motion_mode: "linear" -> "joint""#);
+/// ```
+pub fn diff(left: &Instruction, right: &Instruction) -> Vec<Diff> {
+    let value = |inst| serde_json::to_value(inst).unwrap_or(Value::Null);
+    diff_json(&value(left), &value(right))
+}
+
+/// compare two json values, numbers are compared as float
+pub fn diff_json(left: &Value, right: &Value) -> Vec<Diff> {
+    let mut diffs = vec![];
+    diff_at(String::new(), Some(left), Some(right), &mut diffs);
+    diffs
+}
+
+fn diff_at(path: String, left: Option<&Value>, right: Option<&Value>, diffs: &mut Vec<Diff>) {
+    match (left, right) {
+        (Some(Value::Object(l)), Some(Value::Object(r))) => {
+            let mut keys: Vec<&String> = l.keys().chain(r.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let path = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+                diff_at(path, l.get(key), r.get(key), diffs);
+            }
+        }
+        (Some(Value::Array(l)), Some(Value::Array(r))) => {
+            for i in 0..l.len().max(r.len()) {
+                diff_at(format!("{}/{}", path, i), l.get(i), r.get(i), diffs);
+            }
+        }
+        (Some(Value::Number(l)), Some(Value::Number(r))) if l.as_f64() == r.as_f64() => {}
+        (l, r) if l == r => {}
+        (l, r) => diffs.push(Diff {
+            path,
+            left: l.cloned(),
+            right: r.cloned(),
+        }),
+    }
+}
+
+/// parse an instruction json, reporting the fields the instruction ignores or reads differently
+///
+/// the fields missing but defaulted, e.g. the io actions of a motion, are not an issue.
+/// ```
+/// use inovo_rs::iva::debug::*;
+/// use inovo_rs::iva::*;
+///
+/// let inst = validate(r#"{"op_code": "get", "target": "data", "key": "count"}"#).unwrap();
+/// assert_eq!(inst, Instruction::get(GetTarget::data("count")));
+///
+/// let issues = validate(r#"{"target": "transform"}"#).unwrap_err();
+/// assert!(matches!(issues[0], Issue::Invalid(_)));
+/// ```
+pub fn validate(json: &str) -> Result<Instruction, Vec<Issue>> {
+    let invalid = |e: serde_json::Error| vec![Issue::Invalid(e.to_string())];
+    let sent: Value = serde_json::from_str(json).map_err(invalid)?;
+    let inst: Instruction = serde_json::from_value(sent.clone()).map_err(invalid)?;
+    let read = serde_json::to_value(&inst).map_err(invalid)?;
+
+    let issues: Vec<Issue> = diff_json(&sent, &read)
+        .into_iter()
+        .filter_map(|diff| match (&diff.left, &diff.right) {
+            (Some(_), None) => Some(Issue::UnknownField(diff.path)),
+            (None, Some(_)) => None,
+            _ => Some(Issue::Changed(diff)),
+        })
+        .collect();
+    match issues.is_empty() {
+        true => Ok(inst),
+        false => Err(issues),
+    }
+}
+
+/// check an instruction is read back the same after written as json
+pub fn check_roundtrip(inst: &Instruction) -> Result<(), Vec<Issue>> {
+    let json = inst
+        .clone()
+        .to_json()
+        .map_err(|e| vec![Issue::Invalid(e.to_string())])?;
+    let read = validate(&json)?;
+    if read == *inst {
+        return Ok(());
+    }
+    let issues: Vec<Issue> = diff(inst, &read).into_iter().map(Issue::Changed).collect();
+    match issues.is_empty() {
+        true => Err(vec![Issue::Invalid(format!("read back as {:?}", read))]),
+        false => Err(issues),
+    }
+}
@@ -1,13 +1,15 @@
 //! Module for constructing `IVA` message for communicating with robot
 
+pub mod debug;
+
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 use crate::geometry::{JointCoord, Transform};
-use crate::robot::MotionParam;
+use crate::robot::{MotionParam, RobotError};
 
 /// data structure representing all iva request messages
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "op_code")]
 #[serde(rename_all = "snake_case")]
 pub enum Instruction {
@@ -19,9 +21,16 @@ pub enum Instruction {
     Enqueue(RobotCommand),
     Dequeue {
         enter_context: f64,
+        /// the number of commands to execute, all if `None`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        count: Option<u32>,
     },
     Pop,
+    PopAll,
+    /// drop the commands enqueued, without executing them
+    ClearQueue,
     Gripper(GripperCommand),
+    Vacuum(VacuumCommand),
     #[serde(rename = "io")]
     IO {
         target: IOTarget,
@@ -50,14 +59,33 @@ impl Instruction {
         Instruction::Enqueue(robot_command)
     }
     pub fn dequeue() -> Instruction {
-        Instruction::Dequeue { enter_context: 0.0 }
+        Instruction::Dequeue {
+            enter_context: 0.0,
+            count: None,
+        }
+    }
+    /// execute only the first `count` commands of the queue, the others stay enqueued
+    pub fn dequeue_n(count: u32) -> Instruction {
+        Instruction::Dequeue {
+            enter_context: 0.0,
+            count: Some(count),
+        }
     }
     pub fn dequeue_push() -> Instruction {
-        Instruction::Dequeue { enter_context: 1.0 }
+        Instruction::Dequeue {
+            enter_context: 1.0,
+            count: None,
+        }
     }
     pub fn pop() -> Instruction {
         Instruction::Pop
     }
+    pub fn pop_all() -> Instruction {
+        Instruction::PopAll
+    }
+    pub fn clear_queue() -> Instruction {
+        Instruction::ClearQueue
+    }
 
     pub fn get(get_target: GetTarget) -> Instruction {
         Instruction::Get(get_target)
@@ -67,6 +95,10 @@ impl Instruction {
         Instruction::Gripper(gripper_command)
     }
 
+    pub fn vacuum(vacuum_command: VacuumCommand) -> Instruction {
+        Instruction::Vacuum(vacuum_command)
+    }
+
     pub fn io_set(target: IOTarget, port: u16, state: bool) -> Instruction {
         Instruction::IO {
             target,
@@ -85,17 +117,64 @@ impl Instruction {
         }
     }
 
+    pub fn io_pulse(target: IOTarget, port: u16, second: f64) -> Instruction {
+        Instruction::IO {
+            target,
+            port,
+            io_command: IOCommand::Pulse { second },
+        }
+    }
+
+    pub fn io_get_bank(target: IOTarget, port: u16, count: u16) -> Instruction {
+        Instruction::IO {
+            target,
+            port,
+            io_command: IOCommand::GetBank { count },
+        }
+    }
+
     pub fn custom(custom_command: CustomCommand) -> Instruction {
         Instruction::Custom(custom_command)
     }
 
+    /// get the op code of the instruction, as tagged in the iva message
+    pub fn op_code(&self) -> &'static str {
+        match self {
+            Instruction::Execute { .. } => "execute",
+            Instruction::Enqueue(_) => "enqueue",
+            Instruction::Dequeue { .. } => "dequeue",
+            Instruction::Pop => "pop",
+            Instruction::PopAll => "pop_all",
+            Instruction::ClearQueue => "clear_queue",
+            Instruction::Gripper(_) => "gripper",
+            Instruction::Vacuum(_) => "vacuum",
+            Instruction::IO { .. } => "io",
+            Instruction::Get(_) => "get",
+            Instruction::Custom(_) => "custom",
+        }
+    }
+
+    /// check the instruction only reads the robot, e.g. a get or an io get
+    pub fn is_query(&self) -> bool {
+        matches!(
+            self,
+            Instruction::Get(_)
+                | Instruction::Gripper(GripperCommand::Get)
+                | Instruction::Vacuum(VacuumCommand::Get)
+                | Instruction::IO {
+                    io_command: IOCommand::Get | IOCommand::GetBank { .. },
+                    ..
+                }
+        )
+    }
+
     pub fn to_json(self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(&self)
     }
 }
 
 /// data structure representing all robot command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "action")]
 #[serde(rename_all = "snake_case")]
 pub enum RobotCommand {
@@ -109,6 +188,9 @@ pub enum RobotCommand {
         #[serde(flatten)]
         target: MotionTarget,
     },
+    /// a motion with io actions attached, see [`RobotCommand::with_io`]
+    #[serde(rename = "motion_io")]
+    MotionIO(MotionIO),
 }
 
 impl RobotCommand {
@@ -145,10 +227,66 @@ impl RobotCommand {
             target: target.into(),
         }
     }
+
+    /// attach an io action to a motion, turning it into a [`RobotCommand::MotionIO`]
+    ///
+    /// ## Error
+    /// an [`RobotError::InvalidArgument`] if the command is not a motion
+    pub fn with_io(self, action: IOAction) -> Result<RobotCommand, RobotError> {
+        match self {
+            RobotCommand::Motion {
+                motion_mode,
+                target,
+            } => Ok(RobotCommand::MotionIO(MotionIO {
+                motion_mode,
+                target,
+                io: vec![action],
+            })),
+            RobotCommand::MotionIO(mut motion) => {
+                motion.io.push(action);
+                Ok(RobotCommand::MotionIO(motion))
+            }
+            other => Err(RobotError::InvalidArgument(format!(
+                "io action attached to a non motion command: {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// get the motion mode and the target of a motion, with or without io actions
+    pub fn motion(&self) -> Option<(&MotionMode, &MotionTarget)> {
+        match self {
+            RobotCommand::Motion {
+                motion_mode,
+                target,
+            }
+            | RobotCommand::MotionIO(MotionIO {
+                motion_mode,
+                target,
+                ..
+            }) => Some((motion_mode, target)),
+            _ => None,
+        }
+    }
+    /// get the motion mode and the mutable target of a motion, with or without io actions
+    pub(crate) fn motion_mut(&mut self) -> Option<(&MotionMode, &mut MotionTarget)> {
+        match self {
+            RobotCommand::Motion {
+                motion_mode,
+                target,
+            }
+            | RobotCommand::MotionIO(MotionIO {
+                motion_mode,
+                target,
+                ..
+            }) => Some((motion_mode, target)),
+            _ => None,
+        }
+    }
 }
 
 /// data structure representing robot motion blend mode
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum MotionMode {
     Linear,
@@ -158,7 +296,7 @@ pub enum MotionMode {
 }
 
 /// data structure representing robot motion target
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "target")]
 #[serde(rename_all = "snake_case")]
 pub enum MotionTarget {
@@ -167,17 +305,28 @@ pub enum MotionTarget {
 }
 
 /// data structure representing robot gripper command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "action")]
 #[serde(rename_all = "snake_case")]
 pub enum GripperCommand {
     Activate,
     Get,
     Set { label: String },
+    SetWidth { mm: f64, force: f64 },
+}
+
+/// data structure representing vacuum gripper command
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action")]
+#[serde(rename_all = "snake_case")]
+pub enum VacuumCommand {
+    On,
+    Off,
+    Get,
 }
 
 /// data structure representing psu io target
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum IOTarget {
     Beckhoff,
@@ -185,28 +334,137 @@ pub enum IOTarget {
 }
 
 /// data structure representing io command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "action")]
 #[serde(rename_all = "snake_case")]
 pub enum IOCommand {
     Get,
     Set { state: f64 },
+    GetBank { count: u16 },
+    Pulse { second: f64 },
+}
+
+/// data structure representing a motion with io actions triggered by the robot at its start or end,
+/// see [`RobotCommand::with_io`]
+///
+/// non exhaustive, it is built by attaching io actions to a motion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MotionIO {
+    pub motion_mode: MotionMode,
+    #[serde(flatten)]
+    pub target: MotionTarget,
+    pub io: Vec<IOAction>,
+}
+
+/// data structure representing when an io action is triggered during a motion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum IOTrigger {
+    /// when the motion starts
+    #[default]
+    Start,
+    /// when the motion reaches its target
+    End,
+}
+
+/// data structure representing an io action attached to a motion, see [`RobotCommand::with_io`]
+///
+/// the robot performs the action in sync with the motion, e.g. a glue valve opened
+/// at the start of a dispensing move and closed at its end.
+/// ## Example
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::iva::*;
+/// # use inovo_rs::robot::RobotError;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let dispense = RobotCommand::linear(Transform::from_x(100.0))
+///     .with_io(IOAction::set(IOTarget::Beckhoff, 3, true))?
+///     .with_io(IOAction::set(IOTarget::Beckhoff, 3, false).at_end())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IOAction {
+    pub trigger: IOTrigger,
+    pub target: IOTarget,
+    pub port: u16,
+    #[serde(flatten)]
+    pub io_command: IOCommand,
+}
+
+impl IOAction {
+    /// set a digital output at the start of the motion
+    pub fn set(target: IOTarget, port: u16, state: bool) -> IOAction {
+        IOAction {
+            trigger: IOTrigger::Start,
+            target,
+            port,
+            io_command: IOCommand::Set {
+                state: if state { 1.0 } else { 0.0 },
+            },
+        }
+    }
+    /// pulse a digital output at the start of the motion
+    pub fn pulse(target: IOTarget, port: u16, second: f64) -> IOAction {
+        IOAction {
+            trigger: IOTrigger::Start,
+            target,
+            port,
+            io_command: IOCommand::Pulse { second },
+        }
+    }
+    /// trigger the action at the end of the motion instead
+    pub fn at_end(mut self) -> IOAction {
+        self.trigger = IOTrigger::End;
+        self
+    }
 }
 
 /// data structure representing command to get data from robot
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "target")]
 #[serde(rename_all = "snake_case")]
 pub enum GetTarget {
     Transform,
     JointCoord,
-    Data { key: String },
+    /// the joint torques, in Nm
+    JointTorques,
+    /// the tcp linear and angular speed, in m/s and rad/s
+    TcpVelocity,
+    /// the wrench of the wrist force/torque sensor, in N and Nm
+    Wrench,
+    ContextDepth,
+    Data {
+        key: String,
+    },
+    /// many keys of the data dict in one round trip, responded as a json object of the key to the value
+    DataBatch {
+        keys: Vec<String>,
+    },
+    /// the commands enqueued and not yet dequeued, responded as a json list of the commands
+    Queue,
+    /// the pose, joints, io and gripper sampled at once, responded as a json object,
+    /// see [`RobotSnapshot`](crate::robot::RobotSnapshot)
+    Snapshot,
+    /// the model, serial number, software version and tool of the arm, responded as a json object,
+    /// see [`RobotInfo`](crate::robot::RobotInfo)
+    Info,
+    /// the beckhoff io modules, responded as a json list,
+    /// see [`BeckhoffModules`](crate::robot::BeckhoffModules)
+    BeckhoffModules,
 }
 
 impl GetTarget {
     pub fn data(key: impl Into<String>) -> GetTarget {
         GetTarget::Data { key: key.into() }
     }
+    pub fn data_batch(keys: impl IntoIterator<Item = impl Into<String>>) -> GetTarget {
+        GetTarget::DataBatch {
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
 }
 
 /// data structure representing custom command
@@ -221,7 +479,7 @@ impl GetTarget {
 ///     .add_string("my_string_key", "my_string_value")
 ///     .add_float("my_float_key", 69.420);
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct CustomCommand(BTreeMap<String, CustomArg>);
 
@@ -237,10 +495,60 @@ impl CustomCommand {
         self.0.insert(key.into(), CustomArg::Float(value));
         self
     }
+    /// create a custom command from the fields of a struct
+    ///
+    /// numbers and booleans are sent as float, strings as is, and the other values as json strings.
+    /// a unit or `None` is an empty command, any other value than a struct or a map is an error.
+    /// ```
+    /// use inovo_rs::iva::*;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Dispense {
+    ///     volume_ml: f64,
+    ///     nozzle: String,
+    ///     purge: bool,
+    /// }
+    ///
+    /// let command = CustomCommand::from_serialize(&Dispense {
+    ///     volume_ml: 2.5,
+    ///     nozzle: "wide".to_string(),
+    ///     purge: true,
+    /// })
+    /// .unwrap();
+    /// assert_eq!(
+    ///     command,
+    ///     CustomCommand::new()
+    ///         .add_float("volume_ml", 2.5)
+    ///         .add_string("nozzle", "wide")
+    ///         .add_float("purge", 1.0)
+    /// );
+    /// ```
+    pub fn from_serialize<T: Serialize>(value: &T) -> Result<CustomCommand, serde_json::Error> {
+        use serde_json::Value;
+        let fields = match serde_json::to_value(value)? {
+            Value::Object(fields) => fields,
+            Value::Null => Default::default(),
+            value => {
+                return Err(serde::ser::Error::custom(format!(
+                    "custom command from a non struct: {}",
+                    value
+                )))
+            }
+        };
+        Ok(fields
+            .into_iter()
+            .fold(CustomCommand::new(), |command, (key, value)| match value {
+                Value::String(s) => command.add_string(key, s),
+                Value::Number(n) => command.add_float(key, n.as_f64().unwrap_or(f64::NAN)),
+                Value::Bool(b) => command.add_float(key, if b { 1.0 } else { 0.0 }),
+                value => command.add_string(key, value.to_string()),
+            }))
+    }
 }
 
 /// data structure representing value in custom command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 #[serde(rename_all = "snake_case")]
 pub enum CustomArg {
@@ -11,6 +11,22 @@
 //! cargo add inovo-rs
 //! ```
 //!
+//! ## Features
+//! - `bench`: latency benchmarks of the iva transport against a [`FakePsu`](fake_psu::FakePsu), `cargo bench --features bench`
+//! - `cli`: the `inovo-cli` binary, running one-off commands on the robot
+//! - `force_torque`: a direct udp driver of ATI Net F/T wrist sensors
+//! - `grpc`: a [`tonic`](https://docs.rs/tonic) server mirroring [`IvaRobot`](robot::IvaRobot), see `proto/inovo.proto`
+//! - `log`: bridge the [`Logger`](logger::Logger) with the [`log`](https://docs.rs/log) facade, in both direction
+//! - `metrics`: collect instruction, latency and error metrics, with a Prometheus endpoint
+//! - `modbus`: Modbus TCP client for the other devices in the cell
+//! - `procedures`: download and upload the sequencer procedures through the ROS bridge, with unverified services
+//! - `python`: [`pyo3`](https://pyo3.rs) bindings of the robot and geometry, built with maturin
+//! - `rand`: random pose sampling within bounds
+//! - `rest`: an [`axum`](https://docs.rs/axum) http server driving the robot
+//! - `script`: run [`rhai`](https://rhai.rs) scripts on the robot
+//! - `shell`: an interactive jog and teach shell in `inovo-cli`
+//! - `tracing`: emit [`tracing`](https://docs.rs/tracing) spans for robot, ROS bridge and socket IO
+//!
 //! ## Example
 //! ```no_run
 //! use inovo_rs::geometry::*;
@@ -20,7 +36,7 @@
 //!
 //! fn main() -> Result<(), RobotError> {
 //!     // create a new default logger
-//!     let mut logger = Logger::default_target("Robot Example");
+//!     let logger = Logger::default_target("Robot Example");
 //!
 //!     logger.info("Creating new robot.");
 //!
@@ -68,7 +84,7 @@
 //!     let tx = home_transform.clone().then_x(100.0);
 //!     let ty = home_transform.clone().then_y(100.0);
 //!     let j1 = home_joint_coord.clone().then_j1(90.0);
-//!     let j2 = j1.clone() + JointCoord::from([10.0, 10.0, 10.0, 10.0, 10.0, 10.0]);
+//!     let j2 = &j1 + JointCoord::from([10.0, 10.0, 10.0, 10.0, 10.0, 10.0]);
 //!
 //!     // Robot Command
 //!     //
@@ -203,10 +219,34 @@
 //!     Ok(())
 //! }
 //! ```
+#[cfg(feature = "bench")]
+pub mod bench;
+#[cfg(feature = "cli")]
+pub mod cli;
 pub mod context;
+pub mod conveyor;
+pub mod fake_psu;
+#[cfg(feature = "force_torque")]
+pub mod force_torque;
 pub mod geometry;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod iva;
 pub mod logger;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "modbus")]
+pub mod modbus;
+pub mod pallet;
+pub mod patterns;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "rest")]
+pub mod rest;
 pub mod robot;
 pub mod ros_bridge;
+#[cfg(feature = "script")]
+pub mod script;
+#[cfg(feature = "shell")]
+pub mod shell;
 pub mod socket;
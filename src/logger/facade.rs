@@ -0,0 +1,112 @@
+//! Bridge between [`Logger`] and the [`log`] facade.
+//!
+//! - [`LogTarget`] is a [`LoggingTarget`] forwarding messages to the `log` facade,
+//!   so the crate's logs end up in the host application's logging setup.
+//! - [`LogBridge`] is a [`log::Log`] backed by a [`Logger`],
+//!   so `log` records of the host application end up in the crate's targets.
+//!
+//! Do not use both on the same logger, the messages would be forwarded back and forth forever.
+//!
+//! Enabled with the `log` feature.
+//!
+//! # Example
+//! ```no_run
+//! use inovo_rs::logger::facade::*;
+//! use inovo_rs::logger::*;
+//!
+//! // forward the robot's logs to the application's `log` implementation
+//! let logger = Logger::empty().push(Box::new(LogTarget::new("robot", LogLevel::Debug)));
+//!
+//! // or, make the crate's logger the `log` implementation of the application
+//! LogBridge::init(Logger::default_target("app"), log::LevelFilter::Info).unwrap();
+//! log::info!("hello from the log facade");
+//! ```
+
+use crate::logger::target::LoggingTarget;
+use crate::logger::{LogLevel, Logger};
+
+impl From<log::Level> for LogLevel {
+    fn from(value: log::Level) -> Self {
+        match value {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+/// A logging target forwarding to the [`log`] facade, with the name as the record target
+pub struct LogTarget {
+    name: String,
+    log_level: LogLevel,
+}
+
+impl LogTarget {
+    /// create a log facade target, with a name and a level
+    pub fn new(name: impl Into<String>, log_level: LogLevel) -> Self {
+        Self {
+            name: name.into(),
+            log_level,
+        }
+    }
+}
+
+impl LoggingTarget for LogTarget {
+    fn log_message(&mut self, msg: &String, log_level: LogLevel) {
+        let level = match log_level {
+            LogLevel::Off => return,
+            LogLevel::Error => log::Level::Error,
+            LogLevel::Warn => log::Level::Warn,
+            LogLevel::Info => log::Level::Info,
+            LogLevel::Debug => log::Level::Debug,
+            LogLevel::Trace => log::Level::Trace,
+        };
+        log::log!(target: &self.name, level, "{}", msg);
+    }
+    fn set_level(&mut self, log_level: LogLevel) {
+        self.log_level = log_level;
+    }
+    fn get_level(&self) -> LogLevel {
+        self.log_level
+    }
+}
+
+/// A [`log::Log`] implementation logging every record to a [`Logger`]
+pub struct LogBridge {
+    logger: Logger,
+    max_level: log::LevelFilter,
+}
+
+impl LogBridge {
+    /// create a bridge logging records up to `max_level` to `logger`
+    pub fn new(logger: Logger, max_level: log::LevelFilter) -> Self {
+        Self { logger, max_level }
+    }
+
+    /// install a bridge as the global `log` implementation
+    pub fn init(logger: Logger, max_level: log::LevelFilter) -> Result<(), log::SetLoggerError> {
+        log::set_boxed_logger(Box::new(Self::new(logger, max_level)))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
+
+impl log::Log for LogBridge {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= self.max_level
+    }
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        self.logger.log(
+            format!("[{}] {}", record.target(), record.args()),
+            record.level().into(),
+        );
+    }
+    fn flush(&self) {
+        self.logger.flush();
+    }
+}
@@ -0,0 +1,135 @@
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::logger::LogLevel;
+
+/// The environment variable read by [`EnvFilter::from_env`]
+pub const ENV_FILTER_VAR: &str = "INOVO_LOG";
+
+/// the directive names standing for several logger name prefixes
+const ALIASES: &[(&str, &[&str])] = &[("socket", &["listener", "client"])];
+
+/// Error of parsing a [`LogLevel`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("invalid log level: {0}")]
+pub struct ParseLevelError(String);
+
+impl FromStr for LogLevel {
+    type Err = ParseLevelError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" => Ok(LogLevel::Off),
+            "error" => Ok(LogLevel::Error),
+            "warn" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            "trace" => Ok(LogLevel::Trace),
+            _ => Err(ParseLevelError(s.to_string())),
+        }
+    }
+}
+
+/// A filter of logging level by logger name, in the format of `INOVO_LOG`
+///
+/// the filter is a comma separated list of directives:
+/// - `{level}` : the level of every logger
+/// - `{name}={level}` : the level of loggers with name starting with `name`, case insensitive
+///
+/// the directive with the longest matching name wins.
+///
+/// the loggers created by the crate are named:
+/// - `{host}` : the [`Robot`](crate::robot::Robot)
+/// - `Listener {addr}` and `Client {addr} {addr}` : the [`socket`](crate::socket)
+/// - `wire {addr}` : the raw lines of a robot, see [`Robot::set_wire_trace`](crate::robot::Robot::set_wire_trace)
+/// - `ros {host}` : the [`RosBridge`](crate::ros_bridge::RosBridge)
+/// - `Modbus {addr}` : the `ModbusClient`, with the `modbus` feature
+///
+/// so `ros`, `wire` and `modbus` match their loggers, and `socket` is an alias of `listener` and `client`.
+///
+/// # Example
+/// ```
+/// use inovo_rs::logger::*;
+///
+/// let filter: EnvFilter = "info,socket=trace,ros=warn".parse().unwrap();
+///
+/// assert_eq!(filter.level_for("Listener 10.0.0.2-50003"), Some(LogLevel::Trace));
+/// assert_eq!(filter.level_for("Client 10.0.0.2-50003 10.0.0.9-41022"), Some(LogLevel::Trace));
+/// assert_eq!(filter.level_for("ros psu002"), Some(LogLevel::Warn));
+/// assert_eq!(filter.level_for("psu002"), Some(LogLevel::Info));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnvFilter {
+    /// the level of loggers without a matching name
+    default: Option<LogLevel>,
+    /// the lowercased name prefix and level
+    directives: Vec<(String, LogLevel)>,
+}
+
+impl EnvFilter {
+    /// the filter from the `INOVO_LOG` environment variable, read once per process
+    ///
+    /// invalid directives are reported to stderr and ignored
+    pub fn from_env() -> &'static EnvFilter {
+        static FILTER: OnceLock<EnvFilter> = OnceLock::new();
+        FILTER.get_or_init(|| {
+            let var = std::env::var(ENV_FILTER_VAR).unwrap_or_default();
+            var.parse().unwrap_or_else(|e| {
+                eprintln!("{}: {}", ENV_FILTER_VAR, e);
+                EnvFilter::parse_lossy(&var)
+            })
+        })
+    }
+
+    /// parse a filter, ignoring invalid directives
+    pub fn parse_lossy(s: &str) -> EnvFilter {
+        let mut filter = EnvFilter::default();
+        for directive in s.split(',') {
+            let _ = filter.add_directive(directive);
+        }
+        filter
+    }
+
+    /// add a single `{level}` or `{name}={level}` directive
+    fn add_directive(&mut self, directive: &str) -> Result<(), ParseLevelError> {
+        let directive = directive.trim();
+        if directive.is_empty() {
+            return Ok(());
+        }
+        match directive.split_once('=') {
+            Some((name, level)) => {
+                let level = level.parse()?;
+                let name = name.trim().to_ascii_lowercase();
+                match ALIASES.iter().find(|(alias, _)| *alias == name) {
+                    Some((_, prefixes)) => self
+                        .directives
+                        .extend(prefixes.iter().map(|prefix| (prefix.to_string(), level))),
+                    None => self.directives.push((name, level)),
+                }
+            }
+            None => self.default = Some(directive.parse()?),
+        }
+        Ok(())
+    }
+
+    /// get the level of a logger by name, [`None`] if no directive match
+    pub fn level_for(&self, name: &str) -> Option<LogLevel> {
+        let name = name.to_ascii_lowercase();
+        self.directives
+            .iter()
+            .filter(|(prefix, _)| name.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, level)| *level)
+            .or(self.default)
+    }
+}
+
+impl FromStr for EnvFilter {
+    type Err = ParseLevelError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = EnvFilter::default();
+        for directive in s.split(',') {
+            filter.add_directive(directive)?;
+        }
+        Ok(filter)
+    }
+}
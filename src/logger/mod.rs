@@ -8,7 +8,7 @@
 //!     // initalize a logger with default target:
 //!     // - Console target with Info level
 //!     // - Rolling file target with Debug level
-//!     let mut logger = Logger::default_target("Logger");
+//!     let logger = Logger::default_target("Logger");
 //!
 //!     // This message should be neither print to console nor log to file
 //!     logger.trace("This is an example of a logger logging a message with level trace");
@@ -22,8 +22,25 @@
 //!     logger.error("This is an example of a logger logging a message with level error");
 //! }
 //! ```
+//!
+//! The level of default loggers can be changed without code changes,
+//! with the `INOVO_LOG` environment variable, see [`EnvFilter`].
+//! ```text
+//! INOVO_LOG=info,listener=trace,ros=warn cargo run
+//! ```
 
+#[cfg(feature = "log")]
+pub mod facade;
+mod filter;
 pub mod target;
+mod template;
+
+pub use filter::*;
+pub use template::*;
+
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use target::{ConsoleTarget, LoggingTarget, RollingFileTarget};
 
@@ -39,24 +56,30 @@ pub enum LogLevel {
     Trace = 0,
 }
 
-impl ToString for LogLevel {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
             LogLevel::Off => "Off",
             LogLevel::Error => "Error",
             LogLevel::Warn => "Warn",
             LogLevel::Info => "Info",
             LogLevel::Debug => "Debug",
             LogLevel::Trace => "Trace",
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
+/// The process-wide registry of named loggers
+static REGISTRY: Mutex<BTreeMap<String, Logger>> = Mutex::new(BTreeMap::new());
+
 /// The logger data structure, which contain a [`Vec`] of [`LoggingTarget`].
 ///
 /// Allowing a single logger to perform multiple different logging action in a single call
 ///
+/// The targets are shared behind a [`Mutex`], cloning a logger is cheap
+/// and the clones log to the same targets, from any thread.
+///
 /// # Example
 /// this define a logger with 2 default target
 /// - console logging target, level: `INFO`
@@ -67,7 +90,7 @@ impl ToString for LogLevel {
 ///     // initalize a logger with default target:
 ///     // - Console target with Info level
 ///     // - Rolling file target with Debug level
-///     let mut logger = Logger::default_target("Logger");
+///     let logger = Logger::default_target("Logger");
 ///
 ///     // This message should be neither print to console nor log to file
 ///     logger.trace("This is an example of a logger logging a message with level trace");
@@ -81,15 +104,29 @@ impl ToString for LogLevel {
 ///     logger.error("This is an example of a logger logging a message with level error");
 /// }
 /// ```
+#[derive(Clone)]
 pub struct Logger {
-    /// the logging target of which the logger will log to
-    targets: Vec<Box<dyn LoggingTarget>>,
+    /// the logging target of which the logger will log to, shared between clones
+    targets: Arc<Mutex<Vec<Box<dyn LoggingTarget>>>>,
 }
 
+// clones of a logger are shared between threads, it must stay thread safe without `unsafe impl`
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Logger>();
+};
+
 impl Logger {
     /// create a new logger given a [`Vec`] of [`LoggingTarget`]
     pub fn new(targets: Vec<Box<dyn LoggingTarget>>) -> Logger {
-        Self { targets }
+        Self {
+            targets: Arc::new(Mutex::new(targets)),
+        }
+    }
+
+    /// lock the targets, a panic while logging does not prevent further logging
+    fn targets(&self) -> MutexGuard<'_, Vec<Box<dyn LoggingTarget>>> {
+        self.targets.lock().unwrap_or_else(|e| e.into_inner())
     }
 
     /// create a new logger with no target
@@ -97,9 +134,9 @@ impl Logger {
         Self::new(vec![])
     }
 
-    /// add a new target to logger
-    pub fn push(mut self, target: Box<dyn LoggingTarget>) -> Logger {
-        self.targets.push(target);
+    /// add a new target to logger, shared with all its clones
+    pub fn push(self, target: Box<dyn LoggingTarget>) -> Logger {
+        self.targets().push(target);
         self
     }
 
@@ -119,7 +156,7 @@ impl Logger {
     ///     // initalize a logger with default target:
     ///     // - Console target with Info level
     ///     // - Rolling file target with Debug level
-    ///     let mut logger = Logger::default_target("Logger");
+    ///     let logger = Logger::default_target("Logger");
     ///
     ///     // This message should be neither print to console nor log to file
     ///     logger.trace("This is an example of a logger logging a message with level trace");
@@ -133,30 +170,115 @@ impl Logger {
     ///     logger.error("This is an example of a logger logging a message with level error");
     /// }
     /// ```
+    ///
+    /// if the `INOVO_LOG` environment variable has a directive matching the name,
+    /// both targets take the level of the directive, see [`EnvFilter`].
+    ///
+    /// if the log file cannot be created, e.g. on a read-only filesystem,
+    /// fallback to console only logging with a warning, see [`Logger::default_target_checked`].
     pub fn default_target(name: impl Into<String>) -> Logger {
-        Self::default_target_with_levels(name, LogLevel::Info, LogLevel::Debug)
+        let name = name.into();
+        Self::default_target_with_levels(&name, LogLevel::Info, LogLevel::Debug)
+            .with_env_filter(&name)
+    }
+
+    /// create a new logger with default target [`ConsoleTarget`] and [`RollingFileTarget`],
+    /// like [`Logger::default_target`], but return the error if the log file cannot be created
+    /// instead of falling back to console only logging
+    pub fn default_target_checked(name: impl Into<String>) -> Result<Logger, io::Error> {
+        let name = name.into();
+        Ok(
+            Self::try_default_target_with_levels(&name, LogLevel::Info, LogLevel::Debug)?
+                .with_env_filter(&name),
+        )
     }
 
     /// create a new logger with default targets  [`ConsoleTarget`] and [`RollingFileTarget`]
     /// of specified logging level, with a name
+    ///
+    /// if the log file cannot be created, fallback to console only logging with a warning
     pub fn default_target_with_levels(
         name: impl Into<String>,
         console_log_level: LogLevel,
         file_log_level: LogLevel,
     ) -> Logger {
         let name = name.into();
-        let mut console = ConsoleTarget::default(&name);
-        let mut rolling_file = RollingFileTarget::default(&name);
+        Self::try_default_target_with_levels(&name, console_log_level, file_log_level)
+            .unwrap_or_else(|e| {
+                let logger =
+                    Self::new(vec![Box::new(ConsoleTarget::new(&name, console_log_level))]);
+                logger.warn(format!(
+                    "fail to create log file, logging to console only: {}",
+                    e
+                ));
+                logger
+            })
+    }
+
+    fn try_default_target_with_levels(
+        name: &String,
+        console_log_level: LogLevel,
+        file_log_level: LogLevel,
+    ) -> Result<Logger, io::Error> {
+        let mut console = ConsoleTarget::default(name);
+        let mut rolling_file = RollingFileTarget::default(name)?;
         console.set_level(console_log_level);
         rolling_file.set_level(file_log_level);
-        Self::from_console_file(console, rolling_file)
+        Ok(Self::from_console_file(console, rolling_file))
+    }
+
+    /// set the level of all targets from the `INOVO_LOG` directive matching the name
+    fn with_env_filter(self, name: &str) -> Self {
+        if let Some(log_level) = EnvFilter::from_env().level_for(name) {
+            self.set_level(log_level);
+        }
+        self
+    }
+
+    /// get the logger registered with a name,
+    /// or create and register one with [`Logger::default_target`]
+    ///
+    /// the [`Robot`](crate::robot::Robot), [`Listener`](crate::socket::Listener) and [`Stream`](crate::socket::Stream)
+    /// default to registered loggers, so reconnecting to the same address reuse the same file target
+    /// instead of rotating a new one.
+    ///
+    /// registered loggers live until the end of the process.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::logger::*;
+    ///
+    /// let a = Logger::get_or_create("cell");
+    /// let b = Logger::get_or_create("cell");
+    /// // both log to the same console and file target
+    /// a.info("from a");
+    /// b.info("from b");
+    /// ```
+    pub fn get_or_create(name: impl Into<String>) -> Logger {
+        let name = name.into();
+        let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        registry
+            .entry(name)
+            .or_insert_with_key(|name| Logger::default_target(name))
+            .clone()
+    }
+
+    /// get the logger registered with a name, if any
+    pub fn get(name: &str) -> Option<Logger> {
+        let registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        registry.get(name).cloned()
+    }
+
+    /// register a logger with a name, replacing and returning the previous one
+    ///
+    /// registering before connecting let the crate use your logger wherever it default to [`Logger::get_or_create`]
+    pub fn register(name: impl Into<String>, logger: Logger) -> Option<Logger> {
+        let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        registry.insert(name.into(), logger)
     }
 
     /// create a new logger with targets  [`ConsoleTarget`] and [`RollingFileTarget`]
     pub fn from_console_file(console: ConsoleTarget, rolling_file: RollingFileTarget) -> Self {
-        Self {
-            targets: vec![Box::new(console), Box::new(rolling_file)],
-        }
+        Self::new(vec![Box::new(console), Box::new(rolling_file)])
     }
 
     /// The logging function
@@ -164,31 +286,43 @@ impl Logger {
     /// Log the message with a specified log level,
     ///
     /// It log to all it's owned targets
-    pub fn log(&mut self, msg: impl Into<String>, log_level: LogLevel) {
-        let msg = format!("{:<5} | {}\n", log_level.to_string(), msg.into());
-        self.targets
+    pub fn log(&self, msg: impl Into<String>, log_level: LogLevel) {
+        let msg = msg.into();
+        self.targets()
             .iter_mut()
             .for_each(|target| target.log(&msg, log_level));
     }
 
+    /// set the level of all targets
+    pub fn set_level(&self, log_level: LogLevel) {
+        self.targets()
+            .iter_mut()
+            .for_each(|target| target.set_level(log_level));
+    }
+
+    /// flush all targets
+    pub fn flush(&self) {
+        self.targets().iter_mut().for_each(|target| target.flush());
+    }
+
     /// log a message with level [`LogLevel::Error`]
-    pub fn error(&mut self, msg: impl Into<String>) {
+    pub fn error(&self, msg: impl Into<String>) {
         self.log(msg, LogLevel::Error)
     }
     /// log a message with level [`LogLevel::Warn`]
-    pub fn warn(&mut self, msg: impl Into<String>) {
+    pub fn warn(&self, msg: impl Into<String>) {
         self.log(msg, LogLevel::Warn)
     }
     /// log a message with level [`LogLevel::Info`]
-    pub fn info(&mut self, msg: impl Into<String>) {
+    pub fn info(&self, msg: impl Into<String>) {
         self.log(msg, LogLevel::Info)
     }
     /// log a message with level [`LogLevel::Debug`]
-    pub fn debug(&mut self, msg: impl Into<String>) {
+    pub fn debug(&self, msg: impl Into<String>) {
         self.log(msg, LogLevel::Debug)
     }
     /// log a message with level [`LogLevel::Trace`]
-    pub fn trace(&mut self, msg: impl Into<String>) {
+    pub fn trace(&self, msg: impl Into<String>) {
         self.log(msg, LogLevel::Trace)
     }
 }
@@ -202,7 +336,7 @@ pub trait Logable {
     fn get_logger(&mut self) -> &mut Logger;
 
     fn log(&mut self, msg: impl Into<String>, log_level: LogLevel) {
-        self.get_logger().log(msg, log_level)
+        Logger::log(self.get_logger(), msg, log_level)
     }
 
     /// log a message with level [`LogLevel::Error`]
@@ -237,4 +371,8 @@ impl Logable for &mut Logger {
     }
 }
 
-unsafe impl Send for Logger {}
+impl<L: Logable + ?Sized> Logable for &mut L {
+    fn get_logger(&mut self) -> &mut Logger {
+        (**self).get_logger()
+    }
+}
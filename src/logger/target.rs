@@ -16,6 +16,7 @@
 //!         self.log_level
 //!     }
 //!     fn log_message(&mut self, msg: &String, log_level: LogLevel) {
+//!         let msg = Template::default().render("owo", log_level, msg);
 //!         let msg = msg.replace(&['r', 'l'], "w").replace(&['R', 'L'], "W");
 //!         let prefix = match log_level{
 //!             LogLevel::Trace => "OwO",
@@ -32,7 +33,7 @@
 //!     let owo_target = OwOTarget {
 //!         log_level: LogLevel::Trace,
 //!     };
-//!     let mut my_logger = Logger::empty().push(Box::new(owo_target));
+//!     let my_logger = Logger::empty().push(Box::new(owo_target));
 //!     my_logger.trace("This is an example of a logger logging a message with level trace");
 //!     my_logger.debug("This is an example of a logger logging a message with level debug");
 //!     my_logger.info("This is an example of a logger logging a message with level info");
@@ -49,18 +50,34 @@
 //! *gwomps*        : Ewwow | This is an exampwe of a woggew wogging a message with wevew ewwow
 //! ```
 
+mod background;
+mod capture;
+mod ring_buffer;
+mod syslog;
+mod tcp;
+
+pub use background::*;
+pub use capture::*;
+pub use ring_buffer::*;
+pub use syslog::*;
+pub use tcp::*;
+
+pub use colored::Color;
+
 use std::env;
 use std::fs;
-use std::io::Write;
+use std::io::{self, BufWriter, IsTerminal, Write};
 use std::path;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use chrono;
-use colored::Colorize;
 
-use crate::logger::LogLevel;
+use crate::logger::{LogLevel, Template};
 
 /// A Trait for all logging target, which can set a level and log with a level
+///
+/// targets must be [`Send`], as the [`Logger`](crate::logger::Logger) can be shared across threads
 /// # Custom Logger Example
 /// ```
 /// use inovo_rs::logger::*;
@@ -75,6 +92,7 @@ use crate::logger::LogLevel;
 ///         self.log_level
 ///     }
 ///     fn log_message(&mut self, msg: &String, log_level: LogLevel) {
+///         let msg = Template::default().render("owo", log_level, msg);
 ///         let msg = msg.replace(&['r', 'l'], "w").replace(&['R', 'L'], "W");
 ///         let prefix = match log_level{
 ///             LogLevel::Trace => "OwO",
@@ -91,7 +109,7 @@ use crate::logger::LogLevel;
 ///     let owo_target = OwOTarget {
 ///         log_level: LogLevel::Trace,
 ///     };
-///     let mut my_logger = Logger::empty().push(Box::new(owo_target));
+///     let my_logger = Logger::empty().push(Box::new(owo_target));
 ///     my_logger.trace("This is an example of a logger logging a message with level trace");
 ///     my_logger.debug("This is an example of a logger logging a message with level debug");
 ///     my_logger.info("This is an example of a logger logging a message with level info");
@@ -107,7 +125,7 @@ use crate::logger::LogLevel;
 /// (´,,•ω•,,)♡     : Wawn  | This is an exampwe of a woggew wogging a message with wevew wawn
 /// *gwomps*        : Ewwow | This is an exampwe of a woggew wogging a message with wevew ewwow
 /// ```
-pub trait LoggingTarget {
+pub trait LoggingTarget: Send {
     /// set the level of the target
     fn set_level(&mut self, log_level: LogLevel);
     /// get the level of the target
@@ -116,8 +134,9 @@ pub trait LoggingTarget {
     /// since it was handled.
     ///
     /// ## Parameter
-    /// - `msg: &String`: the string have the log_level embedded in format of `{log_level} | {message}`
+    /// - `msg: &String`: the raw message, the layout with the level is up to the target, see [`Template::default`]
     /// - `log_level: LogLevel`: the filtering is already handled, this log level is for logging flavoring only
+    #[allow(clippy::ptr_arg)]
     fn log_message(&mut self, msg: &String, log_level: LogLevel);
     /// log a message with a level
     fn log(&mut self, msg: &String, log_level: LogLevel) {
@@ -125,6 +144,8 @@ pub trait LoggingTarget {
             self.log_message(msg, log_level)
         }
     }
+    /// flush any buffered message, default to do nothing for unbuffered target
+    fn flush(&mut self) {}
 }
 
 /// The console logging target
@@ -134,12 +155,15 @@ pub trait LoggingTarget {
 /// - `[{name}] {log_level} | {message}`
 ///
 /// ### color
-/// different color for different level:
+/// different color for different level, see [`ConsoleTheme`]:
 /// - [`LogLevel::Error`] : red
 /// - [`LogLevel::Warn`] : yellow
 /// - [`LogLevel::Info`] : green
 /// - other : white
 ///
+/// the messages are colored when the output is a terminal and `NO_COLOR` is not set,
+/// see [`ColorMode::Auto`], which can be overridden with [`ConsoleTarget::set_color_mode`].
+///
 /// ### name tag padding
 /// the bracketed name is padded with the maximum character of name created (min 8).
 /// ```text
@@ -158,9 +182,112 @@ pub trait LoggingTarget {
 /// [THIS IS A       ] Info  | ---- another message
 /// [THIS IS A LOGGER] Info  | ---- another message
 ///  ```
+///
+/// ### template
+/// the format can be replaced with [`ConsoleTarget::set_template`], the color is kept.
+///
+/// ### stderr
+/// the messages are printed to stdout, or to stderr with [`ConsoleTarget::set_stderr`],
+/// e.g. to keep stdout for the output of a tool.
+/// ```
+/// use inovo_rs::logger::target::*;
+/// use inovo_rs::logger::*;
+///
+/// let console = ConsoleTarget::default("robot")
+///     .set_stderr(true)
+///     .set_theme(ConsoleTheme::default().set_color(LogLevel::Info, None));
+/// let logger = Logger::empty().push(Box::new(console));
+/// ```
 pub struct ConsoleTarget {
     name: String,
     log_level: LogLevel,
+    template: Option<Template>,
+    color_mode: ColorMode,
+    theme: ConsoleTheme,
+    stderr: bool,
+}
+
+/// When a [`ConsoleTarget`] colors its messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// colored when the output is a terminal, unless the `NO_COLOR` environment variable is set,
+    /// or forced by a `CLICOLOR_FORCE` other than `0`
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    /// resolve the mode for an output
+    pub fn should_color(&self, is_terminal: bool) -> bool {
+        let set = |key: &str| env::var(key).is_ok_and(|value| !value.is_empty() && value != "0");
+        match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto if set("CLICOLOR_FORCE") => true,
+            ColorMode::Auto => env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()) && is_terminal,
+        }
+    }
+}
+
+/// The colors of the levels of a [`ConsoleTarget`], `None` for no color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsoleTheme {
+    pub error: Option<Color>,
+    pub warn: Option<Color>,
+    pub info: Option<Color>,
+    pub debug: Option<Color>,
+    pub trace: Option<Color>,
+}
+
+impl Default for ConsoleTheme {
+    fn default() -> Self {
+        Self {
+            error: Some(Color::Red),
+            warn: Some(Color::Yellow),
+            info: Some(Color::Green),
+            debug: Some(Color::White),
+            trace: Some(Color::White),
+        }
+    }
+}
+
+impl ConsoleTheme {
+    /// a theme without color for any level
+    pub fn plain() -> Self {
+        Self {
+            error: None,
+            warn: None,
+            info: None,
+            debug: None,
+            trace: None,
+        }
+    }
+    /// set the color of a level
+    pub fn set_color(mut self, log_level: LogLevel, color: impl Into<Option<Color>>) -> Self {
+        let color = color.into();
+        match log_level {
+            LogLevel::Error => self.error = color,
+            LogLevel::Warn => self.warn = color,
+            LogLevel::Info => self.info = color,
+            LogLevel::Debug => self.debug = color,
+            LogLevel::Trace => self.trace = color,
+            LogLevel::Off => {}
+        }
+        self
+    }
+    /// get the color of a level
+    pub fn color(&self, log_level: LogLevel) -> Option<Color> {
+        match log_level {
+            LogLevel::Error => self.error,
+            LogLevel::Warn => self.warn,
+            LogLevel::Info => self.info,
+            LogLevel::Debug => self.debug,
+            LogLevel::Trace => self.trace,
+            LogLevel::Off => None,
+        }
+    }
 }
 
 static PAD: Mutex<usize> = Mutex::new(8);
@@ -173,7 +300,60 @@ impl ConsoleTarget {
         if name.len() > *pad {
             *pad = name.len()
         }
-        Self { name, log_level }
+        Self {
+            name,
+            log_level,
+            template: None,
+            color_mode: ColorMode::Auto,
+            theme: ConsoleTheme::default(),
+            stderr: false,
+        }
+    }
+    /// format the messages with a template instead of `[{name}] {log_level} | {message}`
+    pub fn set_template(mut self, template: Template) -> Self {
+        self.template = Some(template);
+        self
+    }
+    /// set when the messages are colored, default to [`ColorMode::Auto`]
+    pub fn set_color_mode(mut self, color_mode: ColorMode) -> Self {
+        self.color_mode = color_mode;
+        self
+    }
+    /// set the colors of the levels
+    pub fn set_theme(mut self, theme: ConsoleTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+    /// print the messages to stderr instead of stdout
+    pub fn set_stderr(mut self, stderr: bool) -> Self {
+        self.stderr = stderr;
+        self
+    }
+
+    /// render a message as printed, colored by the level if the color mode allows it for the output
+    pub fn render(&self, msg: &str, log_level: LogLevel) -> String {
+        let formated = match &self.template {
+            Some(template) => template.render(&self.name, log_level, msg),
+            None => format!(
+                "[{:<pad$}] {}",
+                self.name,
+                Template::default().render(&self.name, log_level, msg),
+                pad = PAD.lock().unwrap()
+            ),
+        };
+        let is_terminal = match self.stderr {
+            true => io::stderr().is_terminal(),
+            false => io::stdout().is_terminal(),
+        };
+        match self.theme.color(log_level) {
+            // the reset before the newline, for the collectors reading line by line
+            Some(color) if self.color_mode.should_color(is_terminal) => {
+                let line = formated.trim_end_matches('\n');
+                let newline = &formated[line.len()..];
+                format!("\x1b[{}m{}\x1b[0m{}", color.to_fg_str(), line, newline)
+            }
+            _ => formated,
+        }
     }
     /// create a console logging target, with a name and a default level
     pub fn default(name: impl Into<String>) -> Self {
@@ -183,14 +363,11 @@ impl ConsoleTarget {
 
 impl LoggingTarget for ConsoleTarget {
     fn log_message(&mut self, msg: &String, log_level: LogLevel) {
-        let formated = format!("[{:<pad$}] {}", self.name, msg, pad = PAD.lock().unwrap());
-        let colored = match log_level {
-            LogLevel::Error => formated.red(),
-            LogLevel::Warn => formated.yellow(),
-            LogLevel::Info => formated.green(),
-            _ => formated.white(),
-        };
-        print!("{}", colored);
+        let rendered = self.render(msg, log_level);
+        match self.stderr {
+            true => eprint!("{}", rendered),
+            false => print!("{}", rendered),
+        }
     }
     fn set_level(&mut self, log_level: LogLevel) {
         self.log_level = log_level;
@@ -203,25 +380,30 @@ impl LoggingTarget for ConsoleTarget {
 /// get the logging directory, from cargo environment variable `PATH_LOGGING`
 ///
 /// if it is not specified, take the `./logging`
-pub fn get_logging_dir() -> path::PathBuf {
-    let logging_dir = option_env!("PATH_LOGGING")
-        .map(|s| path::PathBuf::from(s))
-        .unwrap_or(env::current_dir().unwrap().join("logging"));
+///
+/// the directory is created if it does not exist
+pub fn get_logging_dir() -> Result<path::PathBuf, io::Error> {
+    let logging_dir = match option_env!("PATH_LOGGING") {
+        Some(dir) => path::PathBuf::from(dir),
+        None => env::current_dir()?.join("logging"),
+    };
 
     if !logging_dir.is_dir() {
-        fs::create_dir(&logging_dir).unwrap();
+        fs::create_dir_all(&logging_dir)?;
     }
 
-    logging_dir
+    Ok(logging_dir)
 }
 
 /// get the directory of a logger, `<logging directory>/<name>/`
-pub fn get_logger_dir(name: &String) -> path::PathBuf {
-    let logger_dir = get_logging_dir().join(name);
+///
+/// the directory is created if it does not exist
+pub fn get_logger_dir(name: &String) -> Result<path::PathBuf, io::Error> {
+    let logger_dir = get_logging_dir()?.join(name);
     if !logger_dir.is_dir() {
-        fs::create_dir(&logger_dir).unwrap();
+        fs::create_dir(&logger_dir)?;
     }
-    logger_dir
+    Ok(logger_dir)
 }
 
 /// The struct for rolling file logging
@@ -238,7 +420,26 @@ pub fn get_logger_dir(name: &String) -> path::PathBuf {
 /// - `pub logger_dir: path::PathBuf`:the logger directory of the logger
 /// - `pub trigger_size: u64`: the size of the file with will trigger rotation
 /// - `pub rolling_number: u8` : the number of total file in rotation,
-/// - `pub file_handle: Option<fs::File>`: the file handle of the current file,
+/// - `pub file_handle: Option<BufWriter<fs::File>>`: the file handle of the current file,
+/// - `pub file_size: u64`: the number of bytes written to the current file,
+/// - `pub flush_interval: Option<Duration>`: the interval of flushing in buffered mode,
+/// - `pub last_flush: Instant`: the time of the last flush,
+/// - `pub template: Option<Template>`: the format of the lines, default to `[{timestamp}] {log_level} | {message}`
+///
+/// # Buffered mode
+/// By default every message is flushed and synced to disk, which is slow when logging every socket message.
+/// With [`RollingFileTarget::buffered`], messages are buffered in memory and flushed at most once per interval,
+/// and on drop.
+///
+/// To also move the writing off the logging thread, wrap it in a [`BackgroundTarget`].
+/// ```no_run
+/// use inovo_rs::logger::target::*;
+/// use inovo_rs::logger::*;
+/// use std::time::Duration;
+///
+/// let file = RollingFileTarget::default("robot").unwrap().buffered(Duration::from_secs(1));
+/// let logger = Logger::empty().push(Box::new(BackgroundTarget::new(Box::new(file))));
+/// ```
 #[derive(Debug)]
 pub struct RollingFileTarget {
     name: String,
@@ -246,49 +447,90 @@ pub struct RollingFileTarget {
     logger_dir: path::PathBuf,
     trigger_size: u64,
     rolling_number: u8,
-    file_handle: Option<fs::File>,
+    file_handle: Option<BufWriter<fs::File>>,
+    file_size: u64,
+    flush_interval: Option<Duration>,
+    last_flush: Instant,
+    template: Option<Template>,
 }
 
 impl RollingFileTarget {
     /// create a default rolling file target with a name
-    pub fn default(name: impl Into<String>) -> RollingFileTarget {
+    pub fn default(name: impl Into<String>) -> Result<RollingFileTarget, io::Error> {
         Self::new(name, 1 << 20, 10, LogLevel::Debug)
     }
     /// create a rolling file target with a name, trigger size, rolling number, and log level
+    ///
+    /// the logger directory is created, the files are rotated and a new `<>.0.log` is created,
+    /// so an unwritable logging directory is reported here instead of on the first message.
     pub fn new(
         name: impl Into<String>,
         trigger_size: u64,
         rolling_number: u8,
         log_level: LogLevel,
-    ) -> RollingFileTarget {
+    ) -> Result<RollingFileTarget, io::Error> {
         let name = name.into();
-        let logger_dir = get_logger_dir(&name);
+        let logger_dir = get_logger_dir(&name)?;
 
-        let rolling_file = Self {
+        let mut rolling_file = Self {
             name,
             log_level,
             logger_dir,
             trigger_size,
             rolling_number,
             file_handle: None,
+            file_size: 0,
+            flush_interval: None,
+            last_flush: Instant::now(),
+            template: None,
         };
-        rolling_file.rotate();
-        rolling_file
+        rolling_file.rotate()?;
+        rolling_file.create()?;
+        Ok(rolling_file)
+    }
+
+    /// buffer the messages in memory, flushing them at most once per `flush_interval`
+    pub fn buffered(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = Some(flush_interval);
+        self
+    }
+
+    /// format the lines with a template
+    pub fn set_template(mut self, template: Template) -> Self {
+        self.template = Some(template);
+        self
     }
 
     /// perform rotation on the files
-    pub fn rotate(&self) {
+    pub fn rotate(&self) -> Result<(), io::Error> {
         for i in (0..self.rolling_number).rev() {
             let pathi = self._file_path(i);
             if !pathi.is_file() {
                 continue;
             }
             if i == self.rolling_number - 1 {
-                fs::remove_file(pathi).unwrap();
+                fs::remove_file(pathi)?;
             } else {
-                fs::rename(pathi, self._file_path(i + 1)).unwrap();
+                fs::rename(pathi, self._file_path(i + 1))?;
             }
         }
+        Ok(())
+    }
+
+    /// create a new `<>.0.log` as the current file, only after a rotation
+    fn create(&mut self) -> Result<&mut BufWriter<fs::File>, io::Error> {
+        let file = fs::File::create(self._file_path(0))?;
+        self.file_size = 0;
+        Ok(self.file_handle.insert(BufWriter::new(file)))
+    }
+    /// reopen `<>.0.log` after a failure, appending to the lines already logged
+    fn reopen(&mut self) -> Result<&mut BufWriter<fs::File>, io::Error> {
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self._file_path(0))?;
+        self.file_size = file.metadata()?.len();
+        Ok(self.file_handle.insert(BufWriter::new(file)))
     }
 
     /// generate the file name of the i-th in rotation
@@ -302,38 +544,53 @@ impl RollingFileTarget {
     ///
     /// before logging, check if the file already excess trigger size,
     /// perform rotation if it is
-    fn _log(&mut self, msg: &String) {
-        let msg = format!(
-            "[{}] {}",
-            chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
-            msg
-        );
-
-        if let Some(f) = &self.file_handle {
-            if f.metadata().unwrap().len() >= self.trigger_size {
-                self.file_handle = None;
-                self.rotate();
-            }
+    fn _log(&mut self, msg: &str, log_level: LogLevel) -> Result<(), io::Error> {
+        let msg = match &self.template {
+            Some(template) => template.render(&self.name, log_level, msg),
+            None => format!(
+                "[{}] {}",
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+                Template::default().render(&self.name, log_level, msg)
+            ),
+        };
+
+        if self.file_handle.is_some() && self.file_size >= self.trigger_size {
+            self._flush()?;
+            self.file_handle = None;
+            self.rotate()?;
+            self.create()?;
         }
 
-        self.file_handle = {
-            let mut file = if let Some(f) = self.file_handle.take() {
-                f
-            } else {
-                fs::File::create(self._file_path(0)).unwrap()
-            };
+        let file = match self.file_handle.as_mut() {
+            Some(f) => f,
+            None => self.reopen()?,
+        };
+        file.write_all(msg.as_bytes())?;
+        self.file_size += msg.len() as u64;
 
-            file.write(msg.as_bytes()).unwrap();
-            file.sync_all().unwrap();
+        match self.flush_interval {
+            Some(interval) if self.last_flush.elapsed() < interval => Ok(()),
+            _ => self._flush(),
+        }
+    }
 
-            Some(file)
-        };
+    /// flush the buffered messages and sync the current file to disk
+    fn _flush(&mut self) -> Result<(), io::Error> {
+        self.last_flush = Instant::now();
+        if let Some(f) = self.file_handle.as_mut() {
+            f.flush()?;
+            f.get_ref().sync_all()?;
+        }
+        Ok(())
     }
 }
 
 impl LoggingTarget for RollingFileTarget {
-    fn log_message(&mut self, msg: &String, _: LogLevel) {
-        self._log(msg)
+    /// messages failed to write are dropped, the current file is reopened on the next message
+    fn log_message(&mut self, msg: &String, log_level: LogLevel) {
+        if self._log(msg, log_level).is_err() {
+            self.file_handle = None;
+        }
     }
     fn set_level(&mut self, log_level: LogLevel) {
         self.log_level = log_level
@@ -341,4 +598,13 @@ impl LoggingTarget for RollingFileTarget {
     fn get_level(&self) -> LogLevel {
         self.log_level
     }
+    fn flush(&mut self) {
+        let _ = self._flush();
+    }
+}
+
+impl Drop for RollingFileTarget {
+    fn drop(&mut self) {
+        let _ = self._flush();
+    }
 }
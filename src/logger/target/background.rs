@@ -0,0 +1,126 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::logger::target::LoggingTarget;
+use crate::logger::LogLevel;
+
+/// The command sent to the background writer
+enum Command {
+    Log(String, LogLevel),
+    Flush(mpsc::Sender<()>),
+}
+
+/// The background logging target
+///
+/// wrap another target and log to it from a background thread,
+/// so logging never block on the disk or network.
+///
+/// ### flushing
+/// the wrapped target is flushed at least once per flush interval,
+/// on [`LoggingTarget::flush`], and when the background target is dropped.
+/// dropping block until every queued message is written.
+///
+/// # Example
+/// ```no_run
+/// use inovo_rs::logger::target::*;
+/// use inovo_rs::logger::*;
+/// use std::time::Duration;
+///
+/// let file = RollingFileTarget::default("robot").unwrap().buffered(Duration::from_secs(1));
+/// let background = BackgroundTarget::new(Box::new(file)).set_flush_interval(Duration::from_secs(1));
+///
+/// let logger = Logger::default_target("robot").push(Box::new(background));
+/// logger.debug("this does not wait for the disk");
+/// ```
+pub struct BackgroundTarget {
+    log_level: LogLevel,
+    target: Option<Box<dyn LoggingTarget>>,
+    flush_interval: Duration,
+    sender: Option<mpsc::Sender<Command>>,
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl BackgroundTarget {
+    /// wrap a target, taking its level as the level of the background target
+    ///
+    /// the background thread is started on the first message
+    pub fn new(target: Box<dyn LoggingTarget>) -> Self {
+        Self {
+            log_level: target.get_level(),
+            target: Some(target),
+            flush_interval: Duration::from_secs(1),
+            sender: None,
+            worker: None,
+        }
+    }
+
+    /// set the maximum interval between flushes of the wrapped target, default to 1 second
+    pub fn set_flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval;
+        self
+    }
+
+    /// get the sender to the background thread, starting it if needed
+    fn sender(&mut self) -> Option<&mpsc::Sender<Command>> {
+        if let Some(mut target) = self.target.take() {
+            let (sender, receiver) = mpsc::channel();
+            let flush_interval = self.flush_interval;
+            self.worker = Some(thread::spawn(move || {
+                let mut last_flush = Instant::now();
+                loop {
+                    let timeout = flush_interval.saturating_sub(last_flush.elapsed());
+                    match receiver.recv_timeout(timeout) {
+                        Ok(Command::Log(msg, log_level)) => target.log_message(&msg, log_level),
+                        Ok(Command::Flush(done)) => {
+                            target.flush();
+                            last_flush = Instant::now();
+                            let _ = done.send(());
+                        }
+                        Err(mpsc::RecvTimeoutError::Timeout) => {}
+                        Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                    }
+                    if last_flush.elapsed() >= flush_interval {
+                        target.flush();
+                        last_flush = Instant::now();
+                    }
+                }
+                target.flush();
+            }));
+            self.sender = Some(sender);
+        }
+        self.sender.as_ref()
+    }
+}
+
+impl LoggingTarget for BackgroundTarget {
+    fn log_message(&mut self, msg: &String, log_level: LogLevel) {
+        if let Some(sender) = self.sender() {
+            let _ = sender.send(Command::Log(msg.clone(), log_level));
+        }
+    }
+    fn set_level(&mut self, log_level: LogLevel) {
+        self.log_level = log_level;
+    }
+    fn get_level(&self) -> LogLevel {
+        self.log_level
+    }
+    /// block until every queued message is written and the wrapped target is flushed
+    fn flush(&mut self) {
+        if let Some(sender) = self.sender() {
+            let (done, wait) = mpsc::channel();
+            if sender.send(Command::Flush(done)).is_ok() {
+                let _ = wait.recv();
+            }
+        }
+    }
+}
+
+impl Drop for BackgroundTarget {
+    fn drop(&mut self) {
+        self.sender = None;
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::logger::target::{LogEntry, LoggingTarget};
+use crate::logger::LogLevel;
+
+/// The capturing logging target, for testing
+///
+/// store every message, with helpers to assert what was logged.
+///
+/// clones share the same messages, keep a clone to assert
+/// after pushing the target into a [`Logger`](crate::logger::Logger).
+///
+/// # Example
+/// ```
+/// use inovo_rs::logger::target::*;
+/// use inovo_rs::logger::*;
+///
+/// let capture = CaptureTarget::new();
+/// let logger = Logger::empty().push(Box::new(capture.clone()));
+///
+/// logger.warn("vacuum pressure low: -12 kPa");
+///
+/// capture.assert_logged(LogLevel::Warn, "pressure low");
+/// capture.assert_not_logged(LogLevel::Error, "vacuum");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CaptureTarget {
+    log_level: LogLevel,
+    entries: Arc<Mutex<Vec<LogEntry>>>,
+}
+
+impl Default for CaptureTarget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CaptureTarget {
+    /// create a capturing target of level [`LogLevel::Trace`]
+    pub fn new() -> Self {
+        Self {
+            log_level: LogLevel::Trace,
+            entries: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    /// get a copy of all captured messages
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.lock().clone()
+    }
+    /// get all captured messages, without the level
+    pub fn messages(&self) -> Vec<String> {
+        self.lock().iter().map(|e| e.message.clone()).collect()
+    }
+    /// remove all captured messages
+    pub fn clear(&self) {
+        self.lock().clear()
+    }
+
+    /// check if a message of a level containing a substring was captured
+    pub fn contains(&self, log_level: LogLevel, substring: &str) -> bool {
+        self.lock()
+            .iter()
+            .any(|e| e.log_level == log_level && e.message.contains(substring))
+    }
+
+    /// assert a message of a level containing a substring was captured
+    ///
+    /// ## Panic
+    /// panic with all captured messages if not
+    #[track_caller]
+    pub fn assert_logged(&self, log_level: LogLevel, substring: &str) {
+        if !self.contains(log_level, substring) {
+            panic!(
+                "expected a {} message containing {:?}, captured:\n{}",
+                log_level,
+                substring,
+                self.dump()
+            );
+        }
+    }
+    /// assert no message of a level containing a substring was captured
+    ///
+    /// ## Panic
+    /// panic with all captured messages if there is
+    #[track_caller]
+    pub fn assert_not_logged(&self, log_level: LogLevel, substring: &str) {
+        if self.contains(log_level, substring) {
+            panic!(
+                "unexpected {} message containing {:?}, captured:\n{}",
+                log_level,
+                substring,
+                self.dump()
+            );
+        }
+    }
+
+    fn dump(&self) -> String {
+        self.lock()
+            .iter()
+            .map(|e| format!("    {:<5} | {}\n", e.log_level.to_string(), e.message))
+            .collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<LogEntry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl LoggingTarget for CaptureTarget {
+    fn log_message(&mut self, msg: &String, log_level: LogLevel) {
+        self.lock().push(LogEntry::from_message(msg, log_level));
+    }
+    fn set_level(&mut self, log_level: LogLevel) {
+        self.log_level = log_level;
+    }
+    fn get_level(&self) -> LogLevel {
+        self.log_level
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Local};
+
+use crate::logger::target::LoggingTarget;
+use crate::logger::LogLevel;
+
+/// A logged message retained in memory
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    /// the local time the message was logged
+    pub timestamp: DateTime<Local>,
+    /// the level of the message
+    pub log_level: LogLevel,
+    /// the message
+    pub message: String,
+}
+
+impl LogEntry {
+    /// create an entry from a message as received by [`LoggingTarget::log_message`]
+    #[allow(clippy::ptr_arg)]
+    pub(crate) fn from_message(msg: &String, log_level: LogLevel) -> Self {
+        Self {
+            timestamp: Local::now(),
+            log_level,
+            message: msg.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}] {:<5} | {}",
+            self.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            self.log_level.to_string(),
+            self.message
+        )
+    }
+}
+
+/// The in-memory ring buffer logging target
+///
+/// retain the last messages up to a capacity, dropping the oldest,
+/// so recent activity can be shown when something goes wrong.
+///
+/// clones share the same buffer, keep a clone to take snapshots
+/// after pushing the target into a [`Logger`](crate::logger::Logger).
+///
+/// # Example
+/// ```
+/// use inovo_rs::logger::target::*;
+/// use inovo_rs::logger::*;
+///
+/// let recent = RingBufferTarget::new(100, LogLevel::Debug);
+/// let logger = Logger::empty().push(Box::new(recent.clone()));
+///
+/// logger.info("picking part");
+/// logger.error("gripper timeout");
+///
+/// for entry in recent.snapshot() {
+///     println!("{}", entry);
+/// }
+/// assert_eq!(recent.snapshot()[1].message, "gripper timeout");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingBufferTarget {
+    log_level: LogLevel,
+    capacity: usize,
+    entries: Arc<Mutex<VecDeque<LogEntry>>>,
+}
+
+impl RingBufferTarget {
+    /// create a ring buffer target retaining the last `capacity` messages
+    pub fn new(capacity: usize, log_level: LogLevel) -> Self {
+        Self {
+            log_level,
+            capacity,
+            entries: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+        }
+    }
+
+    /// get a copy of the retained messages, from the oldest to the newest
+    pub fn snapshot(&self) -> Vec<LogEntry> {
+        self.entries().iter().cloned().collect()
+    }
+    /// get the number of retained messages
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+    /// check if there is no retained message
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+    /// remove all retained messages
+    pub fn clear(&self) {
+        self.entries().clear()
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, VecDeque<LogEntry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl LoggingTarget for RingBufferTarget {
+    fn log_message(&mut self, msg: &String, log_level: LogLevel) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries();
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(LogEntry::from_message(msg, log_level));
+    }
+    fn set_level(&mut self, log_level: LogLevel) {
+        self.log_level = log_level;
+    }
+    fn get_level(&self) -> LogLevel {
+        self.log_level
+    }
+}
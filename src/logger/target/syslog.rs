@@ -0,0 +1,229 @@
+use std::io::{self, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
+
+use chrono::SecondsFormat;
+
+use crate::logger::target::LoggingTarget;
+use crate::logger::{LogLevel, Template};
+
+/// The syslog facility of the messages
+#[repr(u8)]
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub enum SyslogFacility {
+    User = 1,
+    Local0 = 16,
+    Local1 = 17,
+    Local2 = 18,
+    Local3 = 19,
+    Local4 = 20,
+    Local5 = 21,
+    Local6 = 22,
+    Local7 = 23,
+}
+
+/// The transport to the syslog server
+enum SyslogTransport {
+    /// one datagram per message
+    Udp(UdpSocket),
+    /// octet counting framing, reconnected on a later message after a failure
+    Tcp(Option<TcpStream>),
+}
+
+/// The syslog logging target
+///
+/// send every message to a syslog server in [RFC 5424](https://www.rfc-editor.org/rfc/rfc5424) format,
+/// over UDP or TCP.
+///
+/// ### format
+/// - `<{priority}>1 {timestamp} {hostname} {name} {pid} - - {log_level} | {message}`
+///
+/// the name is used as the `APP-NAME`, with spaces replaced by `_`.
+///
+/// ### severity
+/// - [`LogLevel::Error`] : error (3)
+/// - [`LogLevel::Warn`] : warning (4)
+/// - [`LogLevel::Info`] : informational (6)
+/// - other : debug (7)
+///
+/// messages failed to send are dropped. over TCP, a reconnect is attempted on the next message,
+/// at most once per retry interval, the messages are dropped while the server is down.
+///
+/// # Example
+/// ```no_run
+/// use inovo_rs::logger::target::*;
+/// use inovo_rs::logger::*;
+///
+/// let syslog = SyslogTarget::udp("robot cell", "10.0.0.5:514", LogLevel::Info)
+///     .unwrap()
+///     .set_facility(SyslogFacility::Local3);
+///
+/// let logger = Logger::default_target("robot cell").push(Box::new(syslog));
+/// logger.info("cell started");
+/// ```
+pub struct SyslogTarget {
+    name: String,
+    log_level: LogLevel,
+    hostname: String,
+    facility: SyslogFacility,
+    server: SocketAddr,
+    transport: SyslogTransport,
+    retry_interval: Duration,
+    last_attempt: Option<Instant>,
+}
+
+impl SyslogTarget {
+    /// the timeout of connecting and writing to the server over TCP
+    pub const TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// create a syslog target sending to a server over UDP
+    pub fn udp(
+        name: impl Into<String>,
+        server: impl ToSocketAddrs,
+        log_level: LogLevel,
+    ) -> Result<Self, io::Error> {
+        let server = Self::resolve(server)?;
+        let bind_addr: SocketAddr = match server {
+            SocketAddr::V4(_) => ([0, 0, 0, 0], 0).into(),
+            SocketAddr::V6(_) => ([0u16; 8], 0).into(),
+        };
+        let socket = UdpSocket::bind(bind_addr)?;
+        Ok(Self::new(
+            name,
+            server,
+            log_level,
+            SyslogTransport::Udp(socket),
+        ))
+    }
+    /// create a syslog target sending to a server over TCP
+    pub fn tcp(
+        name: impl Into<String>,
+        server: impl ToSocketAddrs,
+        log_level: LogLevel,
+    ) -> Result<Self, io::Error> {
+        let server = Self::resolve(server)?;
+        let stream = Self::connect(server)?;
+        Ok(Self::new(
+            name,
+            server,
+            log_level,
+            SyslogTransport::Tcp(Some(stream)),
+        ))
+    }
+
+    fn new(
+        name: impl Into<String>,
+        server: SocketAddr,
+        log_level: LogLevel,
+        transport: SyslogTransport,
+    ) -> Self {
+        let hostname = local_ip_address::local_ip()
+            .map(|ip| ip.to_string())
+            .unwrap_or_else(|_| "-".to_string());
+        Self {
+            name: name.into(),
+            log_level,
+            hostname,
+            facility: SyslogFacility::User,
+            server,
+            transport,
+            retry_interval: Duration::from_secs(5),
+            last_attempt: None,
+        }
+    }
+
+    fn connect(server: SocketAddr) -> Result<TcpStream, io::Error> {
+        let stream = TcpStream::connect_timeout(&server, Self::TIMEOUT)?;
+        stream.set_write_timeout(Some(Self::TIMEOUT))?;
+        Ok(stream)
+    }
+
+    fn resolve(server: impl ToSocketAddrs) -> Result<SocketAddr, io::Error> {
+        server
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no syslog address"))
+    }
+
+    /// set the facility of the messages, default to [`SyslogFacility::User`]
+    pub fn set_facility(mut self, facility: SyslogFacility) -> Self {
+        self.facility = facility;
+        self
+    }
+    /// set the hostname of the messages, default to the local ip address
+    pub fn set_hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = hostname.into();
+        self
+    }
+    /// set the minimum interval between reconnect attempts over TCP, default to 5 second
+    pub fn set_retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+
+    /// format a message in RFC 5424
+    fn format(&self, msg: &str, log_level: LogLevel) -> String {
+        let severity = match log_level {
+            LogLevel::Error => 3,
+            LogLevel::Warn => 4,
+            LogLevel::Info => 6,
+            _ => 7,
+        };
+        format!(
+            "<{}>1 {} {} {} {} - - {}",
+            self.facility as u8 * 8 + severity,
+            chrono::Local::now().to_rfc3339_opts(SecondsFormat::Millis, false),
+            self.hostname,
+            self.name.replace(' ', "_"),
+            std::process::id(),
+            Template::default()
+                .render(&self.name, log_level, msg)
+                .trim_end()
+        )
+    }
+
+    /// send a formatted message to the server
+    fn send(&mut self, msg: &str) -> Result<(), io::Error> {
+        match &mut self.transport {
+            SyslogTransport::Udp(socket) => {
+                socket.send_to(msg.as_bytes(), self.server)?;
+            }
+            SyslogTransport::Tcp(stream) => {
+                let tcp = match stream {
+                    Some(tcp) => tcp,
+                    None => {
+                        if let Some(last_attempt) = self.last_attempt {
+                            if last_attempt.elapsed() < self.retry_interval {
+                                return Err(io::Error::new(
+                                    io::ErrorKind::NotConnected,
+                                    "waiting to reconnect",
+                                ));
+                            }
+                        }
+                        self.last_attempt = Some(Instant::now());
+                        stream.insert(Self::connect(self.server)?)
+                    }
+                };
+                let framed = format!("{} {}", msg.len(), msg);
+                if let Err(e) = tcp.write_all(framed.as_bytes()) {
+                    *stream = None;
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl LoggingTarget for SyslogTarget {
+    fn log_message(&mut self, msg: &String, log_level: LogLevel) {
+        let formatted = self.format(msg, log_level);
+        let _ = self.send(&formatted);
+    }
+    fn set_level(&mut self, log_level: LogLevel) {
+        self.log_level = log_level;
+    }
+    fn get_level(&self) -> LogLevel {
+        self.log_level
+    }
+}
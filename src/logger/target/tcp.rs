@@ -0,0 +1,167 @@
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use crate::logger::target::LoggingTarget;
+use crate::logger::{LogLevel, Template};
+
+/// The remote tcp logging target
+///
+/// stream log lines to a remote collector over TCP,
+/// for headless controllers where local log files are hard to retrieve.
+///
+/// ### format
+/// log message as a line with format of
+/// - `[{timestamp}] [{name}] {log_level} | {message}`
+///
+/// or a [`Template`] set with [`TcpTarget::set_template`].
+///
+/// ### buffering and reconnect
+/// lines are buffered while the collector is unreachable, up to a capacity,
+/// after which the oldest lines are dropped.
+/// a reconnect is attempted on the next message, at most once per retry interval,
+/// and the buffered lines are sent first once connected.
+///
+/// # Example
+/// ```no_run
+/// use inovo_rs::logger::target::*;
+/// use inovo_rs::logger::*;
+/// use std::time::Duration;
+///
+/// let tcp = TcpTarget::new("cell", "10.0.0.5:5170", LogLevel::Debug)
+///     .set_capacity(10_000)
+///     .set_retry_interval(Duration::from_secs(10));
+///
+/// let logger = Logger::default_target("cell").push(Box::new(tcp));
+/// logger.info("cell started");
+/// ```
+pub struct TcpTarget {
+    name: String,
+    log_level: LogLevel,
+    addr: String,
+    stream: Option<TcpStream>,
+    buffer: VecDeque<String>,
+    capacity: usize,
+    retry_interval: Duration,
+    last_attempt: Option<Instant>,
+    template: Option<Template>,
+}
+
+impl TcpTarget {
+    /// the timeout of connecting and writing to the collector
+    pub const TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// create a tcp target streaming to a collector, with a name and a level
+    ///
+    /// the connection is established on the first message
+    pub fn new(name: impl Into<String>, addr: impl Into<String>, log_level: LogLevel) -> Self {
+        Self {
+            name: name.into(),
+            log_level,
+            addr: addr.into(),
+            stream: None,
+            buffer: VecDeque::new(),
+            capacity: 1024,
+            retry_interval: Duration::from_secs(5),
+            last_attempt: None,
+            template: None,
+        }
+    }
+
+    /// set the maximum number of buffered lines while disconnected, default to 1024
+    pub fn set_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+    /// set the minimum interval between reconnect attempts, default to 5 second
+    pub fn set_retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+    /// format the lines with a template
+    pub fn set_template(mut self, template: Template) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// get the number of lines waiting to be sent
+    pub fn pending(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// try to send all buffered lines, reconnecting if needed
+    pub fn flush(&mut self) -> Result<(), io::Error> {
+        if self.stream.is_none() {
+            if let Some(last_attempt) = self.last_attempt {
+                if last_attempt.elapsed() < self.retry_interval {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotConnected,
+                        "waiting to reconnect",
+                    ));
+                }
+            }
+            self.last_attempt = Some(Instant::now());
+            self.stream = Some(self.connect()?);
+        }
+
+        if let Some(stream) = self.stream.as_mut() {
+            while let Some(line) = self.buffer.front() {
+                if let Err(e) = stream.write_all(line.as_bytes()) {
+                    self.stream = None;
+                    return Err(e);
+                }
+                self.buffer.pop_front();
+            }
+        }
+        Ok(())
+    }
+
+    fn connect(&self) -> Result<TcpStream, io::Error> {
+        let mut last_error = io::Error::new(io::ErrorKind::InvalidInput, "no collector address");
+        for addr in self.addr.to_socket_addrs()? {
+            match TcpStream::connect_timeout(&addr, Self::TIMEOUT) {
+                Ok(stream) => {
+                    stream.set_write_timeout(Some(Self::TIMEOUT))?;
+                    return Ok(stream);
+                }
+                Err(e) => last_error = e,
+            }
+        }
+        Err(last_error)
+    }
+}
+
+impl LoggingTarget for TcpTarget {
+    fn log_message(&mut self, msg: &String, log_level: LogLevel) {
+        if self.buffer.len() >= self.capacity {
+            self.buffer.pop_front();
+        }
+        let line = match &self.template {
+            Some(template) => template.render(&self.name, log_level, msg),
+            None => format!(
+                "[{}] [{}] {}",
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+                self.name,
+                Template::default().render(&self.name, log_level, msg)
+            ),
+        };
+        self.buffer.push_back(line);
+        let _ = self.flush();
+    }
+    fn set_level(&mut self, log_level: LogLevel) {
+        self.log_level = log_level;
+    }
+    fn get_level(&self) -> LogLevel {
+        self.log_level
+    }
+    fn flush(&mut self) {
+        let _ = TcpTarget::flush(self);
+    }
+}
+
+impl Drop for TcpTarget {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
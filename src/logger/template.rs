@@ -0,0 +1,110 @@
+use std::fmt::Write;
+
+use crate::logger::LogLevel;
+
+/// A part of a parsed [`Template`]
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    Timestamp(String),
+    Level,
+    Name,
+    Message,
+}
+
+/// A format template of log lines
+///
+/// ### placeholders
+/// - `{timestamp}` : the local time, as `%Y-%m-%d %H:%M:%S`
+/// - `{timestamp:<format>}` : the local time, in a [`chrono` format](chrono::format::strftime)
+/// - `{level}` : the log level, padded to 5 characters
+/// - `{name}` : the name of the target
+/// - `{message}` : the message
+///
+/// unknown placeholders are kept as is. every rendered line ends with a newline.
+/// the default template is `{level} | {message}`.
+///
+/// # Example
+/// ```
+/// use inovo_rs::logger::*;
+///
+/// let template = Template::new("{level} {name}: {message}");
+/// let line = template.render("robot", LogLevel::Info, "arm homed");
+/// assert_eq!(line, "Info  robot: arm homed\n");
+///
+/// let template = Template::new("{timestamp:%H:%M:%S%.3f} <{level}> {message}");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    /// parse a template
+    pub fn new(template: impl Into<String>) -> Self {
+        let template = template.into();
+        let mut segments = vec![];
+        let mut literal = String::new();
+        let mut rest = template.as_str();
+
+        while let Some(start) = rest.find('{') {
+            literal.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let Some(end) = rest.find('}') else {
+                break;
+            };
+            let segment = match &rest[1..end] {
+                "timestamp" => Some(Segment::Timestamp("%Y-%m-%d %H:%M:%S".to_string())),
+                "level" => Some(Segment::Level),
+                "name" => Some(Segment::Name),
+                "message" => Some(Segment::Message),
+                p => p
+                    .strip_prefix("timestamp:")
+                    .map(|format| Segment::Timestamp(format.to_string())),
+            };
+            match segment {
+                Some(segment) => {
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(segment);
+                }
+                None => literal.push_str(&rest[..=end]),
+            }
+            rest = &rest[end + 1..];
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Self { segments }
+    }
+
+    /// render a line with a name, a level and a message
+    pub fn render(&self, name: &str, log_level: LogLevel, message: &str) -> String {
+        let now = chrono::Local::now();
+        let mut line = String::new();
+        for segment in self.segments.iter() {
+            match segment {
+                Segment::Literal(s) => line.push_str(s),
+                Segment::Timestamp(format) => {
+                    // an invalid format renders nothing instead of panicking
+                    let _ = write!(line, "{}", now.format(format));
+                }
+                Segment::Level => line.push_str(&format!("{:<5}", log_level.to_string())),
+                Segment::Name => line.push_str(name),
+                Segment::Message => line.push_str(message),
+            }
+        }
+        line.push('\n');
+        line
+    }
+}
+
+impl Default for Template {
+    /// the default layout of a line, `{level} | {message}`
+    fn default() -> Self {
+        Self::new("{level} | {message}")
+    }
+}
@@ -0,0 +1,227 @@
+//! Module for collecting operational metrics of the robot
+//!
+//! The [`Metrics`] struct count instructions sent, errors by category,
+//! instruction latency, and reconnects. It can be pulled with [`Metrics::snapshot`],
+//! rendered in the Prometheus text format with [`Metrics::render`],
+//! or exposed as a Prometheus endpoint with [`Metrics::serve`].
+//!
+//! Enabled with the `metrics` feature.
+//!
+//! ## Example
+//! ```no_run
+//! use inovo_rs::metrics::Metrics;
+//! use inovo_rs::robot::*;
+//!
+//! let metrics = Metrics::new();
+//! metrics.serve("0.0.0.0:9184").unwrap();
+//!
+//! let mut bot = Robot::defaut_logger(50003, "psu002").unwrap();
+//! bot.set_metrics(metrics.clone());
+//!
+//! bot.sleep(1.0).unwrap();
+//!
+//! let snapshot = metrics.snapshot();
+//! assert_eq!(snapshot.instructions.get("execute"), Some(&1));
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// upper bounds of the latency histogram buckets, in second
+///
+/// motion instructions block until the motion is done, hence the long tail
+pub const LATENCY_BUCKETS: [f64; 13] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0,
+];
+
+/// A cheaply clonable handle to a set of metrics
+///
+/// all clones share the same counters, so a clone can be handed to a [`Robot`](crate::robot::Robot)
+/// while another one is used for exporting.
+#[derive(Debug, Clone, Default)]
+pub struct Metrics {
+    inner: Arc<Mutex<MetricsSnapshot>>,
+}
+
+/// A point in time copy of all metrics
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSnapshot {
+    /// number of instructions sent, by op code
+    pub instructions: BTreeMap<String, u64>,
+    /// number of errors, by category
+    pub errors: BTreeMap<String, u64>,
+    /// instruction round trip latency, by op code
+    pub latency: BTreeMap<String, Histogram>,
+    /// number of reconnects
+    pub reconnects: u64,
+}
+
+/// A cumulative histogram with bounds of [`LATENCY_BUCKETS`]
+#[derive(Debug, Clone, Default)]
+pub struct Histogram {
+    /// number of observations less than or equal to each bucket bound
+    pub buckets: [u64; LATENCY_BUCKETS.len()],
+    /// sum of all observations, in second
+    pub sum: f64,
+    /// number of observations
+    pub count: u64,
+}
+
+impl Histogram {
+    /// record an observation in second
+    pub fn observe(&mut self, second: f64) {
+        for (bucket, bound) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if second <= bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += second;
+        self.count += 1;
+    }
+}
+
+impl Metrics {
+    /// the timeout of reading a request and writing a response of the endpoint
+    pub const TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// create a new set of metrics with all counters at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// record an instruction sent with its op code, and the round trip latency
+    pub fn record_instruction(&self, op_code: &str, latency: Duration) {
+        let mut inner = self.inner.lock().unwrap();
+        *inner.instructions.entry(op_code.to_string()).or_default() += 1;
+        inner
+            .latency
+            .entry(op_code.to_string())
+            .or_default()
+            .observe(latency.as_secs_f64());
+    }
+    /// record an error with its category
+    pub fn record_error(&self, category: &str) {
+        let mut inner = self.inner.lock().unwrap();
+        *inner.errors.entry(category.to_string()).or_default() += 1;
+    }
+    /// record a reconnect to the robot
+    pub fn record_reconnect(&self) {
+        self.inner.lock().unwrap().reconnects += 1;
+    }
+
+    /// get a copy of all metrics
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        self.inner.lock().unwrap().clone()
+    }
+
+    /// render all metrics in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let snapshot = self.snapshot();
+        let mut out = String::new();
+
+        let _ = writeln!(
+            out,
+            "# HELP inovo_instructions_total Number of IVA instructions sent."
+        );
+        let _ = writeln!(out, "# TYPE inovo_instructions_total counter");
+        for (op_code, count) in snapshot.instructions.iter() {
+            let _ = writeln!(
+                out,
+                "inovo_instructions_total{{op_code=\"{}\"}} {}",
+                op_code, count
+            );
+        }
+
+        let _ = writeln!(out, "# HELP inovo_errors_total Number of errors.");
+        let _ = writeln!(out, "# TYPE inovo_errors_total counter");
+        for (category, count) in snapshot.errors.iter() {
+            let _ = writeln!(
+                out,
+                "inovo_errors_total{{category=\"{}\"}} {}",
+                category, count
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP inovo_instruction_latency_seconds Round trip latency of IVA instructions."
+        );
+        let _ = writeln!(out, "# TYPE inovo_instruction_latency_seconds histogram");
+        for (op_code, histogram) in snapshot.latency.iter() {
+            for (bucket, bound) in histogram.buckets.iter().zip(LATENCY_BUCKETS) {
+                let _ = writeln!(
+                    out,
+                    "inovo_instruction_latency_seconds_bucket{{op_code=\"{}\",le=\"{}\"}} {}",
+                    op_code, bound, bucket
+                );
+            }
+            let _ = writeln!(
+                out,
+                "inovo_instruction_latency_seconds_bucket{{op_code=\"{}\",le=\"+Inf\"}} {}",
+                op_code, histogram.count
+            );
+            let _ = writeln!(
+                out,
+                "inovo_instruction_latency_seconds_sum{{op_code=\"{}\"}} {}",
+                op_code, histogram.sum
+            );
+            let _ = writeln!(
+                out,
+                "inovo_instruction_latency_seconds_count{{op_code=\"{}\"}} {}",
+                op_code, histogram.count
+            );
+        }
+
+        let _ = writeln!(out, "# HELP inovo_reconnects_total Number of reconnects.");
+        let _ = writeln!(out, "# TYPE inovo_reconnects_total counter");
+        let _ = writeln!(out, "inovo_reconnects_total {}", snapshot.reconnects);
+
+        out
+    }
+
+    /// serve the metrics as a Prometheus endpoint at `http://<addr>/metrics`, in a background thread
+    ///
+    /// the requests are served one at a time, a client idle for [`Metrics::TIMEOUT`] is dropped.
+    pub fn serve(&self, addr: impl ToSocketAddrs) -> Result<thread::JoinHandle<()>, io::Error> {
+        let listener = TcpListener::bind(addr)?;
+        let metrics = self.clone();
+        Ok(thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let _ = metrics.respond(stream);
+            }
+        }))
+    }
+
+    /// respond to a single http request
+    fn respond(&self, mut stream: std::net::TcpStream) -> Result<(), io::Error> {
+        stream.set_read_timeout(Some(Self::TIMEOUT))?;
+        stream.set_write_timeout(Some(Self::TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // skip the headers
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 2 {
+            line.clear();
+        }
+
+        let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+        let (status, body) = match path {
+            "/metrics" => ("200 OK", self.render()),
+            _ => ("404 Not Found", String::new()),
+        };
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )?;
+        stream.flush()
+    }
+}
@@ -0,0 +1,381 @@
+//! Module for communicating with Modbus TCP devices
+//!
+//! Most cells mix the arm with feeders, conveyors, or gripper controllers speaking Modbus TCP,
+//! the [`ModbusClient`] allow them to be driven alongside the [`Robot`](crate::robot::Robot),
+//! sharing the same [`Logger`] and error handling.
+//!
+//! Enabled with the `modbus` feature.
+//!
+//! ## Example
+//! ```no_run
+//! use inovo_rs::modbus::*;
+//!
+//! let mut feeder = ModbusClient::connect("192.168.1.50:502", 1, None).unwrap();
+//!
+//! // start the feeder
+//! feeder.write_single_coil(0, true).unwrap();
+//!
+//! // read the part counter
+//! let counter = feeder.read_holding_registers(100, 2).unwrap();
+//! ```
+
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::logger::{Logable, Logger};
+
+/// Modbus function code for reading coils
+const READ_COILS: u8 = 0x01;
+/// Modbus function code for reading discrete inputs
+const READ_DISCRETE_INPUTS: u8 = 0x02;
+/// Modbus function code for reading holding registers
+const READ_HOLDING_REGISTERS: u8 = 0x03;
+/// Modbus function code for reading input registers
+const READ_INPUT_REGISTERS: u8 = 0x04;
+/// Modbus function code for writing a single coil
+const WRITE_SINGLE_COIL: u8 = 0x05;
+/// Modbus function code for writing a single register
+const WRITE_SINGLE_REGISTER: u8 = 0x06;
+/// Modbus function code for writing multiple coils
+const WRITE_MULTIPLE_COILS: u8 = 0x0F;
+/// Modbus function code for writing multiple registers
+const WRITE_MULTIPLE_REGISTERS: u8 = 0x10;
+/// the maximum number of coils or discrete inputs in a read request
+const MAX_READ_BITS: u16 = 2000;
+/// the maximum number of registers in a read request
+const MAX_READ_REGISTERS: u16 = 125;
+/// the maximum number of coils in a write multiple coils request
+const MAX_WRITE_COILS: usize = 1968;
+/// the maximum number of registers in a write multiple registers request
+const MAX_WRITE_REGISTERS: usize = 123;
+
+/// A Modbus TCP client
+///
+/// each request is sent with an incrementing transaction id, and blocks until the response arrive,
+/// or the timeout of the connection elapse.
+/// a late response to an earlier request, e.g. after a timeout, is discarded.
+///
+/// ## Example
+/// ```no_run
+/// use inovo_rs::modbus::*;
+///
+/// let mut client = ModbusClient::connect("192.168.1.50:502", 1, None).unwrap();
+///
+/// let inputs: Vec<bool> = client.read_discrete_inputs(0, 8).unwrap();
+/// client.write_multiple_registers(10, &[1, 2, 3]).unwrap();
+/// ```
+pub struct ModbusClient {
+    /// the logger of the client
+    logger: Logger,
+    /// the tcp connection to the device
+    stream: TcpStream,
+    /// the unit identifier of the device
+    unit_id: u8,
+    /// the transaction identifier of the last request
+    transaction_id: u16,
+}
+
+impl Logable for ModbusClient {
+    fn get_logger(&mut self) -> &mut Logger {
+        &mut self.logger
+    }
+}
+
+impl ModbusClient {
+    /// the default timeout of connecting, reading and writing to the device
+    pub const TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// construct a new [`ModbusClient`] from a connected tcp stream
+    pub fn new(stream: TcpStream, unit_id: u8, logger: Logger) -> Self {
+        Self {
+            logger,
+            stream,
+            unit_id,
+            transaction_id: 0,
+        }
+    }
+
+    /// connect to a Modbus TCP device, with a read and write timeout of [`ModbusClient::TIMEOUT`]
+    ///
+    /// ## Argument
+    /// - `addr` : address of the device, usually on port 502
+    /// - `unit_id` : the unit identifier of the device
+    /// - `logger : Option<Logger>` : a logger for the client, default to one named after the peer address
+    pub fn connect(
+        addr: impl ToSocketAddrs,
+        unit_id: u8,
+        logger: Option<Logger>,
+    ) -> Result<Self, ModbusError> {
+        let mut last_error = io::Error::new(io::ErrorKind::InvalidInput, "no device address");
+        let mut stream = None;
+        for addr in addr.to_socket_addrs()? {
+            match TcpStream::connect_timeout(&addr, Self::TIMEOUT) {
+                Ok(s) => {
+                    stream = Some(s);
+                    break;
+                }
+                Err(e) => last_error = e,
+            }
+        }
+        let stream = stream.ok_or(last_error)?;
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(Some(Self::TIMEOUT))?;
+        stream.set_write_timeout(Some(Self::TIMEOUT))?;
+
+        let logger = match logger {
+            Some(logger) => logger,
+            None => {
+                let peer_addr = stream.peer_addr()?.to_string().replace(':', "-");
+                Logger::get_or_create(format!("Modbus {}", peer_addr))
+            }
+        };
+        logger.info(format!("connected to modbus device, unit {}", unit_id));
+
+        Ok(Self::new(stream, unit_id, logger))
+    }
+
+    /// set the read and write timeout of a request
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<&mut Self, io::Error> {
+        self.stream.set_read_timeout(Some(timeout))?;
+        self.stream.set_write_timeout(Some(timeout))?;
+        Ok(self)
+    }
+
+    /// read `count` coils starting at `address`
+    ///
+    /// ## Error
+    /// [`ModbusError::InvalidArgument`] for no coil or more than 2000 coils, which do not fit in a response
+    pub fn read_coils(&mut self, address: u16, count: u16) -> Result<Vec<bool>, ModbusError> {
+        self.read_bits(READ_COILS, address, count)
+    }
+    /// read `count` discrete inputs starting at `address`, see [`ModbusClient::read_coils`]
+    pub fn read_discrete_inputs(
+        &mut self,
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<bool>, ModbusError> {
+        self.read_bits(READ_DISCRETE_INPUTS, address, count)
+    }
+    /// read `count` holding registers starting at `address`
+    ///
+    /// ## Error
+    /// [`ModbusError::InvalidArgument`] for no register or more than 125 registers, which do not fit in a response
+    pub fn read_holding_registers(
+        &mut self,
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<u16>, ModbusError> {
+        self.read_registers(READ_HOLDING_REGISTERS, address, count)
+    }
+    /// read `count` input registers starting at `address`, see [`ModbusClient::read_holding_registers`]
+    pub fn read_input_registers(
+        &mut self,
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<u16>, ModbusError> {
+        self.read_registers(READ_INPUT_REGISTERS, address, count)
+    }
+
+    /// write a single coil
+    pub fn write_single_coil(&mut self, address: u16, state: bool) -> Result<(), ModbusError> {
+        let value: u16 = if state { 0xFF00 } else { 0x0000 };
+        let mut data = address.to_be_bytes().to_vec();
+        data.extend(value.to_be_bytes());
+        self.request(WRITE_SINGLE_COIL, &data)?;
+        Ok(())
+    }
+    /// write a single holding register
+    pub fn write_single_register(&mut self, address: u16, value: u16) -> Result<(), ModbusError> {
+        let mut data = address.to_be_bytes().to_vec();
+        data.extend(value.to_be_bytes());
+        self.request(WRITE_SINGLE_REGISTER, &data)?;
+        Ok(())
+    }
+    /// write consecutive coils starting at `address`
+    ///
+    /// ## Error
+    /// [`ModbusError::InvalidArgument`] for no state or more than 1968 states, which do not fit in a request
+    pub fn write_multiple_coils(
+        &mut self,
+        address: u16,
+        states: &[bool],
+    ) -> Result<(), ModbusError> {
+        if states.is_empty() || states.len() > MAX_WRITE_COILS {
+            return Err(ModbusError::InvalidArgument(format!(
+                "{} coils, expected 1 to {}",
+                states.len(),
+                MAX_WRITE_COILS
+            )));
+        }
+        let mut packed = vec![0u8; states.len().div_ceil(8)];
+        for (i, _) in states.iter().enumerate().filter(|(_, s)| **s) {
+            packed[i / 8] |= 1 << (i % 8);
+        }
+        let mut data = address.to_be_bytes().to_vec();
+        data.extend((states.len() as u16).to_be_bytes());
+        data.push(packed.len() as u8);
+        data.extend(packed);
+        self.request(WRITE_MULTIPLE_COILS, &data)?;
+        Ok(())
+    }
+    /// write consecutive holding registers starting at `address`
+    ///
+    /// ## Error
+    /// [`ModbusError::InvalidArgument`] for no value or more than 123 values, which do not fit in a request
+    pub fn write_multiple_registers(
+        &mut self,
+        address: u16,
+        values: &[u16],
+    ) -> Result<(), ModbusError> {
+        if values.is_empty() || values.len() > MAX_WRITE_REGISTERS {
+            return Err(ModbusError::InvalidArgument(format!(
+                "{} registers, expected 1 to {}",
+                values.len(),
+                MAX_WRITE_REGISTERS
+            )));
+        }
+        let mut data = address.to_be_bytes().to_vec();
+        data.extend((values.len() as u16).to_be_bytes());
+        data.push((values.len() * 2) as u8);
+        for value in values {
+            data.extend(value.to_be_bytes());
+        }
+        self.request(WRITE_MULTIPLE_REGISTERS, &data)?;
+        Ok(())
+    }
+
+    /// read bits with a function code, unpacking the response into a [`Vec`] of `bool`
+    fn read_bits(
+        &mut self,
+        function: u8,
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<bool>, ModbusError> {
+        check_count(count, MAX_READ_BITS, "bits")?;
+        let mut data = address.to_be_bytes().to_vec();
+        data.extend(count.to_be_bytes());
+        let res = self.request(function, &data)?;
+
+        let bytes = res.get(1..).unwrap_or_default();
+        if bytes.len() * 8 < count as usize {
+            return Err(ModbusError::UnexpectedResponse(format!(
+                "expected {} bits, got {} bytes",
+                count,
+                bytes.len()
+            )));
+        }
+        Ok((0..count as usize)
+            .map(|i| bytes[i / 8] & (1 << (i % 8)) != 0)
+            .collect())
+    }
+
+    /// read registers with a function code, unpacking the response into a [`Vec`] of `u16`
+    fn read_registers(
+        &mut self,
+        function: u8,
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<u16>, ModbusError> {
+        check_count(count, MAX_READ_REGISTERS, "registers")?;
+        let mut data = address.to_be_bytes().to_vec();
+        data.extend(count.to_be_bytes());
+        let res = self.request(function, &data)?;
+
+        let bytes = res.get(1..).unwrap_or_default();
+        if bytes.len() != count as usize * 2 {
+            return Err(ModbusError::UnexpectedResponse(format!(
+                "expected {} registers, got {} bytes",
+                count,
+                bytes.len()
+            )));
+        }
+        Ok(bytes
+            .chunks_exact(2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+            .collect())
+    }
+
+    /// send a request and return the data of the response, without the function code
+    fn request(&mut self, function: u8, data: &[u8]) -> Result<Vec<u8>, ModbusError> {
+        self.transaction_id = self.transaction_id.wrapping_add(1);
+
+        let mut frame = Vec::with_capacity(8 + data.len());
+        frame.extend(self.transaction_id.to_be_bytes());
+        frame.extend(0u16.to_be_bytes());
+        frame.extend((data.len() as u16 + 2).to_be_bytes());
+        frame.push(self.unit_id);
+        frame.push(function);
+        frame.extend(data);
+
+        self.trace(format!(">>> {:02X?}", frame));
+        self.stream.write_all(&frame)?;
+
+        let pdu = loop {
+            let (transaction_id, pdu) = self.read_frame()?;
+            if transaction_id == self.transaction_id {
+                break pdu;
+            }
+            // a late response to an earlier request, the device is still in sync
+            self.warn(format!(
+                "discarding response to transaction {}, waiting for {}",
+                transaction_id, self.transaction_id
+            ));
+        };
+
+        match pdu[0] {
+            f if f == function => Ok(pdu[1..].to_vec()),
+            f if f == function | 0x80 => Err(ModbusError::Exception {
+                function,
+                code: pdu.get(1).cloned().unwrap_or_default(),
+            }),
+            f => Err(ModbusError::UnexpectedResponse(format!(
+                "function code {:#04X} does not match request {:#04X}",
+                f, function
+            ))),
+        }
+    }
+
+    /// read a response frame, returning its transaction id and pdu
+    fn read_frame(&mut self) -> Result<(u16, Vec<u8>), ModbusError> {
+        let mut header = [0u8; 7];
+        self.stream.read_exact(&mut header)?;
+        let transaction_id = u16::from_be_bytes([header[0], header[1]]);
+        let length = u16::from_be_bytes([header[4], header[5]]) as usize;
+        if length < 2 {
+            return Err(ModbusError::UnexpectedResponse(format!(
+                "invalid frame length {}",
+                length
+            )));
+        }
+
+        let mut pdu = vec![0u8; length - 1];
+        self.stream.read_exact(&mut pdu)?;
+        self.trace(format!("<<< {:02X?}{:02X?}", header, pdu));
+        Ok((transaction_id, pdu))
+    }
+}
+
+/// check the count of a read request, from 1 to `max`
+fn check_count(count: u16, max: u16, unit: &str) -> Result<(), ModbusError> {
+    if count == 0 || count > max {
+        return Err(ModbusError::InvalidArgument(format!(
+            "{} {}, expected 1 to {}",
+            count, unit, max
+        )));
+    }
+    Ok(())
+}
+
+/// Modbus related error
+#[derive(Debug, thiserror::Error)]
+pub enum ModbusError {
+    #[error(transparent)]
+    SocketError(#[from] io::Error),
+    #[error("Modbus Exception: function {function:#04X}, code {code}")]
+    Exception { function: u8, code: u8 },
+    #[error("Unexpected Response: {0}")]
+    UnexpectedResponse(String),
+    #[error("Invalid Argument: {0}")]
+    InvalidArgument(String),
+}
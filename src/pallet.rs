@@ -0,0 +1,186 @@
+//! Module for pallets, grids of slots at regular pitches
+//!
+//! A [`Pallet`] is defined either from a frame and pitches, or calibrated from
+//! three taught slots with [`calibrate_from_corners`], which absorbs the skew of the fixture.
+//!
+//! ## Example
+//! ```no_run
+//! use inovo_rs::pallet::*;
+//! use inovo_rs::robot::*;
+//!
+//! # fn main() -> Result<(), RobotError> {
+//! let mut bot = Robot::defaut_logger(50003, "psu002")?;
+//!
+//! // teach the first slot, the last slot of the first row, then the first slot of the last row
+//! let p00 = bot.get_current_transform()?;
+//! let p0n = bot.get_current_transform()?;
+//! let pm0 = bot.get_current_transform()?;
+//!
+//! let pallet = calibrate_from_corners(&p00, &p0n, &pm0, 4, 6)
+//!     .ok_or(RobotError::InvalidArgument("corners are collinear".to_string()))?;
+//! for slot in pallet.slots() {
+//!     bot.linear(slot.clone().then_relative_z(-50.0))?.linear(slot)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use nalgebra::{Matrix3, Rotation3, UnitQuaternion};
+
+use crate::geometry::{Rotation, Transform, Vector3};
+
+/// A grid of `rows` by `cols` slots
+///
+/// the slot of row `i` and column `j` is the first slot translated by `i` row steps and `j` column steps,
+/// keeping the orientation of the first slot.
+#[derive(Debug, Clone)]
+pub struct Pallet {
+    origin: Transform,
+    rows: usize,
+    cols: usize,
+    row_step: Vector3,
+    col_step: Vector3,
+}
+
+impl Pallet {
+    /// create a new pallet with square slots
+    ///
+    /// ## Argument
+    /// - `origin`: the first slot, the columns go along its x axis and the rows along its y axis
+    /// - `rows`, `cols`: number of rows and columns
+    /// - `row_pitch_mm`, `col_pitch_mm`: distance between two rows and two columns
+    pub fn new(
+        origin: Transform,
+        rows: usize,
+        cols: usize,
+        row_pitch_mm: f64,
+        col_pitch_mm: f64,
+    ) -> Self {
+        let rotation = origin.get_rotation();
+        Self {
+            rows,
+            cols,
+            row_step: rotation * Vector3::new(0.0, row_pitch_mm, 0.0),
+            col_step: rotation * Vector3::new(col_pitch_mm, 0.0, 0.0),
+            origin,
+        }
+    }
+
+    /// get the number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    /// get the number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+    /// get the number of slots
+    pub fn len(&self) -> usize {
+        self.rows * self.cols
+    }
+    /// whether the pallet has no slot
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// get the distance between two rows, in mm
+    pub fn row_pitch_mm(&self) -> f64 {
+        self.row_step.norm()
+    }
+    /// get the distance between two columns, in mm
+    pub fn col_pitch_mm(&self) -> f64 {
+        self.col_step.norm()
+    }
+    /// get the deviation of the angle between the rows and the columns from 90 degree
+    pub fn skew_deg(&self) -> f64 {
+        let cos = self.row_step.dot(&self.col_step) / (self.row_pitch_mm() * self.col_pitch_mm());
+        90.0 - cos.clamp(-1.0, 1.0).acos().to_degrees()
+    }
+
+    /// get the frame of the pallet
+    ///
+    /// the origin is the first slot, the x axis goes along the first row,
+    /// and the z axis is normal to the pallet, on the side the columns are counterclockwise to the rows.
+    pub fn frame(&self) -> Transform {
+        let x = nalgebra::Vector3::from(self.col_step).normalize();
+        let z = x.cross(&nalgebra::Vector3::from(self.row_step)).normalize();
+        let y = z.cross(&x);
+        let rotation = Rotation3::from_matrix_unchecked(Matrix3::from_columns(&[x, y, z]));
+        Transform::from_parts(
+            self.origin.get_vector().into(),
+            Rotation::from_quaternion(UnitQuaternion::from_rotation_matrix(&rotation)),
+        )
+    }
+
+    /// get the slot of a row and a column, `None` if out of the pallet
+    pub fn slot(&self, row: usize, col: usize) -> Option<Transform> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        let offset = self.row_step * row as f64 + self.col_step * col as f64;
+        Some(self.origin.clone().then_vector(offset))
+    }
+    /// get the slot of an index, counting row by row
+    pub fn slot_index(&self, index: usize) -> Option<Transform> {
+        match self.cols {
+            0 => None,
+            cols => self.slot(index / cols, index % cols),
+        }
+    }
+    /// iterate over the slots, row by row
+    pub fn slots(&self) -> impl Iterator<Item = Transform> + '_ {
+        (0..self.len()).filter_map(|index| self.slot_index(index))
+    }
+}
+
+/// calibrate a pallet from three taught slots
+///
+/// ## Argument
+/// - `p00`: the first slot, its orientation is kept for all slots
+/// - `p0n`: the last slot of the first row
+/// - `pm0`: the first slot of the last row
+/// - `rows`, `cols`: number of rows and columns
+///
+/// returns `None` if there are less than 2 rows or columns, or the slots are collinear.
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::pallet::*;
+///
+/// // a 3 x 4 pallet of 20 mm pitch, with the rows skewed by 1 degree
+/// let p00 = Transform::from_vector([300.0, 0.0, 10.0]);
+/// let p0n = Transform::from_vector([360.0, 0.0, 10.0]);
+/// let skew = 1f64.to_radians();
+/// let pm0 = Transform::from_vector([300.0 - 40.0 * skew.sin(), 40.0 * skew.cos(), 10.0]);
+///
+/// let pallet = calibrate_from_corners(&p00, &p0n, &pm0, 3, 4).unwrap();
+/// assert!((pallet.col_pitch_mm() - 20.0).abs() < 1e-9);
+/// assert!((pallet.skew_deg() + 1.0).abs() < 1e-9);
+///
+/// let last = pallet.slot(2, 3).unwrap();
+/// assert!((last.get_x() - (360.0 - 40.0 * skew.sin())).abs() < 1e-9);
+/// ```
+pub fn calibrate_from_corners(
+    p00: &Transform,
+    p0n: &Transform,
+    pm0: &Transform,
+    rows: usize,
+    cols: usize,
+) -> Option<Pallet> {
+    if rows < 2 || cols < 2 {
+        return None;
+    }
+    let origin = Vector3::from(p00);
+    let row_step = (Vector3::from(pm0) - origin) * (1.0 / (rows - 1) as f64);
+    let col_step = (Vector3::from(p0n) - origin) * (1.0 / (cols - 1) as f64);
+
+    let normal = col_step.cross(&row_step).norm();
+    if normal <= 1e-9 * row_step.norm() * col_step.norm() {
+        return None;
+    }
+    Some(Pallet {
+        origin: p00.clone(),
+        rows,
+        cols,
+        row_step,
+        col_step,
+    })
+}
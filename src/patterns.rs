@@ -0,0 +1,217 @@
+//! Module for motion patterns superimposed on a path
+//!
+//! A [`Weave`] oscillates the tcp across a base path, for dispensing beads wider than the nozzle
+//! or covering a band in surface finishing, see [`weave`].
+//!
+//! ## Example
+//! ```no_run
+//! use inovo_rs::geometry::*;
+//! use inovo_rs::patterns::*;
+//! use inovo_rs::robot::*;
+//!
+//! # fn main() -> Result<(), RobotError> {
+//! let mut bot = Robot::defaut_logger(50003, "psu002")?;
+//!
+//! let seam = [
+//!     Transform::from_rx(180.0).set_vector([400.0, -100.0, 50.0]),
+//!     Transform::from_rx(180.0).set_vector([400.0, 100.0, 50.0]),
+//! ];
+//! // 3 mm each side, a cycle every 10 mm, across the seam on the surface
+//! let bead = Weave::new(3.0, 0.1, WeavePlane::Tool).set_shape(WeaveShape::Sine);
+//!
+//! bot.set_param(MotionParam::new().set_speed(20.0).set_blend_linear(1.0))?;
+//! bot.sequence(bead.sequence(&seam))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::f64::consts::PI;
+
+use crate::geometry::{Transform, Vector3};
+use crate::robot::CommandSequence;
+
+/// The plane the weave oscillates in, containing the direction of travel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WeavePlane {
+    /// the plane normal to the tool z axis, e.g. across a seam on the surface
+    Tool,
+    /// the plane normal to a vector in the base frame, e.g. `[0.0, 0.0, 1.0]` for horizontal
+    Normal(Vector3),
+}
+
+/// The shape of the weave
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum WeaveShape {
+    /// straight lines between the peaks
+    #[default]
+    ZigZag,
+    /// a sine wave, sampled with [`Weave::set_samples_per_cycle`] points per cycle
+    Sine,
+}
+
+/// A weave superimposed on a path
+///
+/// the oscillation is across the direction of travel in the [`WeavePlane`],
+/// starting to the left of the path when looking along the normal of the plane.
+/// the phase follows the length of the path, so the pattern is the same whatever the speed,
+/// and the orientation is interpolated between the path points.
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::patterns::*;
+///
+/// let path = [Transform::identity(), Transform::from_x(16.0)];
+/// let waypoints = Weave::new(2.0, 0.125, WeavePlane::Normal([0.0, 0.0, 1.0].into())).waypoints(&path);
+///
+/// // the two peaks of each 8 mm cycle
+/// let y: Vec<f64> = waypoints.iter().map(|t| t.get_y()).collect();
+/// assert_eq!(y, [0.0, 2.0, -2.0, 2.0, -2.0, 0.0]);
+/// assert_eq!(waypoints[1].get_x(), 2.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weave {
+    amplitude_mm: f64,
+    frequency: f64,
+    plane: WeavePlane,
+    shape: WeaveShape,
+    samples_per_cycle: usize,
+}
+
+impl Weave {
+    /// create a new zig-zag weave
+    ///
+    /// ## Argument
+    /// - `amplitude_mm`: the distance of the peaks from the path
+    /// - `frequency`: the number of cycles per mm of path
+    /// - `plane`: the plane of the oscillation
+    pub fn new(amplitude_mm: f64, frequency: f64, plane: WeavePlane) -> Self {
+        Self {
+            amplitude_mm,
+            frequency,
+            plane,
+            shape: WeaveShape::ZigZag,
+            samples_per_cycle: 16,
+        }
+    }
+    /// set the shape of the weave
+    pub fn set_shape(mut self, shape: WeaveShape) -> Self {
+        self.shape = shape;
+        self
+    }
+    /// set the number of points per cycle of a sine weave, at least 4
+    pub fn set_samples_per_cycle(mut self, samples: usize) -> Self {
+        self.samples_per_cycle = samples.max(4);
+        self
+    }
+
+    /// compute the waypoints of the weave along a path
+    ///
+    /// the first and last point are on the wave at the ends of the path, and every corner of the path is kept.
+    /// a path of less than 2 points, or a weave of no frequency, is returned unchanged.
+    pub fn waypoints(&self, path: &[Transform]) -> Vec<Transform> {
+        if path.len() < 2 || !(self.frequency.is_finite() && self.frequency > 0.0) {
+            return path.to_vec();
+        }
+        // the length of the path at each point
+        let mut lengths = vec![0.0];
+        for pair in path.windows(2) {
+            let length = lengths[lengths.len() - 1] + pair[0].translation_distance(&pair[1]);
+            lengths.push(length);
+        }
+        let total = lengths[lengths.len() - 1];
+
+        let (first, step) = match self.shape {
+            // the peaks, a quarter cycle in then every half cycle
+            WeaveShape::ZigZag => (0.25 / self.frequency, 0.5 / self.frequency),
+            WeaveShape::Sine => {
+                let step = 1.0 / (self.frequency * self.samples_per_cycle as f64);
+                (step, step)
+            }
+        };
+        let mut samples: Vec<f64> = (0..)
+            .map(|k| first + step * k as f64)
+            .take_while(|s| *s < total)
+            .chain(lengths.iter().copied())
+            .collect();
+        samples.sort_by(f64::total_cmp);
+        samples.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+
+        let mut segment = 0;
+        samples
+            .into_iter()
+            .map(|s| {
+                while segment + 2 < lengths.len() && s > lengths[segment + 1] {
+                    segment += 1;
+                }
+                self.sample(&path[segment], &path[segment + 1], &lengths[segment..], s)
+            })
+            .collect()
+    }
+
+    /// build a sequence of linear motions through the waypoints of the weave, see [`Weave::waypoints`]
+    ///
+    /// the motions run at the current motion param, set a small linear blend to keep the tcp moving.
+    pub fn sequence(&self, path: &[Transform]) -> CommandSequence {
+        self.waypoints(path)
+            .into_iter()
+            .fold(CommandSequence::new(), |seq, waypoint| {
+                seq.then_linear(waypoint)
+            })
+    }
+
+    /// the point of the weave at the length `s` of the path, on the segment from `a` to `b`
+    fn sample(&self, a: &Transform, b: &Transform, lengths: &[f64], s: f64) -> Transform {
+        let length = lengths[1] - lengths[0];
+        let t = match length > 0.0 {
+            true => ((s - lengths[0]) / length).clamp(0.0, 1.0),
+            false => 0.0,
+        };
+        let point = a.interpolate(b, t).unwrap_or_else(|| {
+            let position = Vector3::from(a) + (Vector3::from(b) - Vector3::from(a)) * t;
+            a.clone().set_vector(position)
+        });
+
+        let direction = Vector3::from(b) - Vector3::from(a);
+        let normal = match self.plane {
+            WeavePlane::Tool => point.get_rotation() * Vector3::new(0.0, 0.0, 1.0),
+            WeavePlane::Normal(normal) => normal,
+        };
+        let across = normal.cross(&direction);
+        if across.norm() < 1e-9 {
+            return point;
+        }
+        let offset = self.amplitude_mm * self.wave(s);
+        point.then_vector(across * (offset / across.norm()))
+    }
+
+    /// the value of the wave at the length `s` of the path, from -1 to 1
+    fn wave(&self, s: f64) -> f64 {
+        let phase = (s * self.frequency).rem_euclid(1.0);
+        match self.shape {
+            WeaveShape::ZigZag if phase < 0.25 => 4.0 * phase,
+            WeaveShape::ZigZag if phase < 0.75 => 2.0 - 4.0 * phase,
+            WeaveShape::ZigZag => 4.0 * phase - 4.0,
+            WeaveShape::Sine => (2.0 * PI * phase).sin(),
+        }
+    }
+}
+
+/// superimpose a zig-zag weave onto a path, producing a sequence of linear motions
+///
+/// a shortcut of [`Weave::sequence`], see [`Weave`] for a sine weave.
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::patterns::*;
+///
+/// let path = [Transform::identity(), Transform::from_x(100.0), Transform::from_vector([100.0, 100.0, 0.0])];
+/// let bead = weave(&path, 5.0, 0.05, WeavePlane::Normal([0.0, 0.0, 1.0].into()));
+/// // a peak every 10 mm, and the 3 points of the path
+/// assert_eq!(bead.len(), 20 + 3);
+/// ```
+pub fn weave(
+    path: &[Transform],
+    amplitude_mm: f64,
+    frequency: f64,
+    plane: WeavePlane,
+) -> CommandSequence {
+    Weave::new(amplitude_mm, frequency, plane).sequence(path)
+}
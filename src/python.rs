@@ -0,0 +1,407 @@
+//! Python bindings, scripting the same transport and geometry from Python.
+//!
+//! the `inovo_rs` python module is built with [maturin](https://www.maturin.rs),
+//! using the `pyproject.toml` of the crate:
+//! ```bash
+//! maturin develop --release
+//! ```
+//! ```python
+//! from inovo_rs import Robot, Transform, MotionParam, CommandSequence
+//!
+//! bot = Robot(50003, "psu002")
+//! bot.set_param(MotionParam(speed=50.0))
+//!
+//! home = bot.get_current_transform()
+//! seq = CommandSequence().then_linear(home.then_z(-50.0)).then_sleep(1.0).then_linear(home)
+//! bot.sequence(seq)
+//! ```
+//!
+//! the robot is released from the python global interpreter lock while waiting for the psu,
+//! and errors are raised as `inovo_rs.RobotError`.
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyIndexError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::geometry::{JointCoord, Transform};
+use crate::iva::{IOTarget, MotionTarget};
+use crate::robot::{CommandSequence, IvaRobot, MotionParam, Robot};
+
+create_exception!(
+    inovo_rs,
+    RobotError,
+    PyRuntimeError,
+    "an error of the robot"
+);
+
+impl From<crate::robot::RobotError> for PyErr {
+    fn from(value: crate::robot::RobotError) -> Self {
+        RobotError::new_err(value.to_string())
+    }
+}
+
+/// A transform, in mm and degree
+#[pyclass(name = "Transform", module = "inovo_rs", eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyTransform(pub Transform);
+
+#[pymethods]
+impl PyTransform {
+    #[new]
+    #[pyo3(signature = (x=0.0, y=0.0, z=0.0, rx=0.0, ry=0.0, rz=0.0))]
+    fn new(x: f64, y: f64, z: f64, rx: f64, ry: f64, rz: f64) -> Self {
+        Self(Transform::new(x, y, z, rx, ry, rz))
+    }
+    #[getter]
+    fn x(&self) -> f64 {
+        self.0.get_x()
+    }
+    #[getter]
+    fn y(&self) -> f64 {
+        self.0.get_y()
+    }
+    #[getter]
+    fn z(&self) -> f64 {
+        self.0.get_z()
+    }
+    #[getter]
+    fn rx(&self) -> f64 {
+        self.0.get_rx()
+    }
+    #[getter]
+    fn ry(&self) -> f64 {
+        self.0.get_ry()
+    }
+    #[getter]
+    fn rz(&self) -> f64 {
+        self.0.get_rz()
+    }
+    fn then(&self, other: &Self) -> Self {
+        Self(self.0.clone().then(other.0.clone()))
+    }
+    fn then_relative(&self, other: &Self) -> Self {
+        Self(self.0.clone().then_relative(other.0.clone()))
+    }
+    fn then_x(&self, mm: f64) -> Self {
+        Self(self.0.clone().then_x(mm))
+    }
+    fn then_y(&self, mm: f64) -> Self {
+        Self(self.0.clone().then_y(mm))
+    }
+    fn then_z(&self, mm: f64) -> Self {
+        Self(self.0.clone().then_z(mm))
+    }
+    fn then_rx(&self, degree: f64) -> Self {
+        Self(self.0.clone().then_rx(degree))
+    }
+    fn then_ry(&self, degree: f64) -> Self {
+        Self(self.0.clone().then_ry(degree))
+    }
+    fn then_rz(&self, degree: f64) -> Self {
+        Self(self.0.clone().then_rz(degree))
+    }
+    fn inverse(&self) -> Self {
+        Self(self.0.inverse())
+    }
+    fn approx_eq(&self, other: &Self, tolerance_mm: f64, tolerance_deg: f64) -> bool {
+        self.0.approx_eq(&other.0, tolerance_mm, tolerance_deg)
+    }
+    fn to_list(&self) -> [f64; 6] {
+        let [x, y, z] = self.0.get_vector();
+        let [rx, ry, rz] = self.0.get_euler();
+        [x, y, z, rx, ry, rz]
+    }
+    fn __mul__(&self, other: &Self) -> Self {
+        Self(&self.0 * &other.0)
+    }
+    fn __truediv__(&self, other: &Self) -> Self {
+        Self(&self.0 / &other.0)
+    }
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+    fn __repr__(&self) -> String {
+        format!("Transform({})", self.0)
+    }
+}
+
+/// A joint coordinate, in degree
+#[pyclass(name = "JointCoord", module = "inovo_rs", eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyJointCoord(pub JointCoord);
+
+#[pymethods]
+impl PyJointCoord {
+    #[new]
+    #[pyo3(signature = (j1=0.0, j2=0.0, j3=0.0, j4=0.0, j5=0.0, j6=0.0))]
+    fn new(j1: f64, j2: f64, j3: f64, j4: f64, j5: f64, j6: f64) -> Self {
+        Self(JointCoord::new(j1, j2, j3, j4, j5, j6))
+    }
+    fn to_list(&self) -> [f64; 6] {
+        self.0.clone().into_array()
+    }
+    fn __getitem__(&self, index: usize) -> PyResult<f64> {
+        match index {
+            0..=5 => Ok(self.0[index]),
+            _ => Err(PyIndexError::new_err("joint index out of range")),
+        }
+    }
+    fn __len__(&self) -> usize {
+        6
+    }
+    fn __add__(&self, other: &Self) -> Self {
+        Self(&self.0 + &other.0)
+    }
+    fn __sub__(&self, other: &Self) -> Self {
+        Self(&self.0 - &other.0)
+    }
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+    fn __repr__(&self) -> String {
+        format!("JointCoord({})", self.0)
+    }
+}
+
+/// the target of a joint motion, a transform or a joint coordinate
+#[derive(FromPyObject)]
+enum JointTarget {
+    Transform(PyTransform),
+    JointCoord(PyJointCoord),
+}
+
+impl From<JointTarget> for MotionTarget {
+    fn from(value: JointTarget) -> Self {
+        match value {
+            JointTarget::Transform(t) => t.0.into(),
+            JointTarget::JointCoord(j) => j.0.into(),
+        }
+    }
+}
+
+/// A motion parameter, unset parameters are left unchanged on the robot
+#[pyclass(name = "MotionParam", module = "inovo_rs", eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyMotionParam(pub MotionParam);
+
+#[pymethods]
+impl PyMotionParam {
+    #[new]
+    #[pyo3(signature = (speed=None, accel=None, blend_linear=None, blend_angular=None, tcp_speed_linear=None, tcp_speed_angular=None))]
+    fn new(
+        speed: Option<f64>,
+        accel: Option<f64>,
+        blend_linear: Option<f64>,
+        blend_angular: Option<f64>,
+        tcp_speed_linear: Option<f64>,
+        tcp_speed_angular: Option<f64>,
+    ) -> Self {
+        let mut param = MotionParam::new();
+        if let Some(percent) = speed {
+            param = param.set_speed(percent);
+        }
+        if let Some(percent) = accel {
+            param = param.set_accel(percent);
+        }
+        if let Some(mm) = blend_linear {
+            param = param.set_blend_linear(mm);
+        }
+        if let Some(deg) = blend_angular {
+            param = param.set_blend_angular(deg);
+        }
+        if let Some(mm) = tcp_speed_linear {
+            param = param.set_tcp_speed_linear(mm);
+        }
+        if let Some(deg) = tcp_speed_angular {
+            param = param.set_tcp_speed_angular(deg);
+        }
+        Self(param)
+    }
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// A sequence of robot commands
+#[pyclass(name = "CommandSequence", module = "inovo_rs", eq)]
+#[derive(Clone, PartialEq, Default)]
+pub struct PyCommandSequence(pub CommandSequence);
+
+#[pymethods]
+impl PyCommandSequence {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+    fn then_linear(&self, target: PyTransform) -> Self {
+        Self(self.0.clone().then_linear(target.0))
+    }
+    fn then_linear_relative(&self, target: PyTransform) -> Self {
+        Self(self.0.clone().then_linear_relative(target.0))
+    }
+    fn then_joint(&self, target: JointTarget) -> Self {
+        Self(self.0.clone().then_joint(target))
+    }
+    fn then_joint_relative(&self, target: PyTransform) -> Self {
+        Self(self.0.clone().then_joint_relative(target.0))
+    }
+    fn then_sleep(&self, second: f64) -> Self {
+        Self(self.0.clone().then_sleep(second))
+    }
+    fn then_sync(&self) -> Self {
+        Self(self.0.clone().then_sync())
+    }
+    fn then_set_param(&self, param: PyMotionParam) -> Self {
+        Self(self.0.clone().then_set_param(param.0))
+    }
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.0).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json)
+            .map(Self)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+fn io_target(target: &str) -> PyResult<IOTarget> {
+    match target {
+        "beckhoff" => Ok(IOTarget::Beckhoff),
+        "wrist" => Ok(IOTarget::Wrist),
+        _ => Err(PyValueError::new_err(format!(
+            "invalid io target: {}, expected beckhoff or wrist",
+            target
+        ))),
+    }
+}
+
+/// A inovo robot arm
+#[pyclass(name = "Robot", module = "inovo_rs")]
+pub struct PyRobot(pub Robot);
+
+impl PyRobot {
+    /// run a robot function without the global interpreter lock
+    fn with<T: Send>(
+        &mut self,
+        py: Python<'_>,
+        f: impl FnOnce(&mut Robot) -> Result<T, crate::robot::RobotError> + Send,
+    ) -> PyResult<T> {
+        let robot = &mut self.0;
+        Ok(py.allow_threads(|| f(robot))?)
+    }
+    /// run a robot command without the global interpreter lock
+    fn command(
+        &mut self,
+        py: Python<'_>,
+        f: impl FnOnce(&mut Robot) -> Result<&mut Robot, crate::robot::RobotError> + Send,
+    ) -> PyResult<()> {
+        self.with(py, |bot| f(bot).map(|_| ()))
+    }
+}
+
+#[pymethods]
+impl PyRobot {
+    /// connect to the robot, running the iva sequence on the psu
+    #[new]
+    fn new(py: Python<'_>, port: u16, host: String) -> PyResult<Self> {
+        Ok(Self(py.allow_threads(|| Robot::defaut_logger(port, host))?))
+    }
+    fn sleep(&mut self, py: Python<'_>, second: f64) -> PyResult<()> {
+        self.command(py, |bot| bot.sleep(second))
+    }
+    fn set_param(&mut self, py: Python<'_>, param: PyMotionParam) -> PyResult<()> {
+        self.command(py, |bot| bot.set_param(param.0))
+    }
+    fn linear(&mut self, py: Python<'_>, target: PyTransform) -> PyResult<()> {
+        self.command(py, |bot| bot.linear(target.0))
+    }
+    fn linear_relative(&mut self, py: Python<'_>, target: PyTransform) -> PyResult<()> {
+        self.command(py, |bot| bot.linear_relative(target.0))
+    }
+    fn joint(&mut self, py: Python<'_>, target: JointTarget) -> PyResult<()> {
+        let target = MotionTarget::from(target);
+        self.command(py, |bot| bot.joint(target))
+    }
+    fn joint_relative(&mut self, py: Python<'_>, target: PyTransform) -> PyResult<()> {
+        self.command(py, |bot| bot.joint_relative(target.0))
+    }
+    fn sequence(&mut self, py: Python<'_>, sequence: PyCommandSequence) -> PyResult<()> {
+        self.command(py, |bot| bot.sequence(sequence.0))
+    }
+    fn pop(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.command(py, |bot| bot.pop())
+    }
+    fn pop_all(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.command(py, |bot| bot.pop_all())
+    }
+    fn context_depth(&mut self, py: Python<'_>) -> PyResult<u32> {
+        self.with(py, |bot| bot.context_depth())
+    }
+    fn get_current_transform(&mut self, py: Python<'_>) -> PyResult<PyTransform> {
+        self.with(py, |bot| bot.get_current_transform())
+            .map(PyTransform)
+    }
+    fn get_current_joint(&mut self, py: Python<'_>) -> PyResult<PyJointCoord> {
+        self.with(py, |bot| bot.get_current_joint())
+            .map(PyJointCoord)
+    }
+    fn get_data(&mut self, py: Python<'_>, key: String) -> PyResult<String> {
+        self.with(py, |bot| bot.get_data::<String>(key))
+    }
+    /// set a digital output, the target is `"beckhoff"` or `"wrist"`
+    fn io_set(&mut self, py: Python<'_>, target: &str, port: u16, state: bool) -> PyResult<()> {
+        let target = io_target(target)?;
+        self.command(py, |bot| bot.io_set(target, port, state))
+    }
+    /// get a digital input, the target is `"beckhoff"` or `"wrist"`
+    fn io_get(&mut self, py: Python<'_>, target: &str, port: u16) -> PyResult<bool> {
+        let target = io_target(target)?;
+        self.with(py, |bot| bot.io_get(target, port))
+    }
+    fn gripper_activate(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.command(py, |bot| bot.gripper_activate())
+    }
+    fn gripper_set(&mut self, py: Python<'_>, label: String) -> PyResult<()> {
+        self.command(py, |bot| bot.gripper_set(label))
+    }
+    fn gripper_set_width(&mut self, py: Python<'_>, mm: f64, force: f64) -> PyResult<()> {
+        self.command(py, |bot| bot.gripper_set_width(mm, force))
+    }
+    fn gripper_get(&mut self, py: Python<'_>) -> PyResult<f64> {
+        self.with(py, |bot| bot.gripper_get())
+    }
+    fn vacuum_on(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.command(py, |bot| bot.vacuum_on())
+    }
+    fn vacuum_off(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.command(py, |bot| bot.vacuum_off())
+    }
+    fn vacuum_pressure(&mut self, py: Python<'_>) -> PyResult<f64> {
+        self.with(py, |bot| bot.vacuum_pressure())
+    }
+}
+
+/// the `inovo_rs` python module
+#[pymodule]
+fn inovo_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyTransform>()?;
+    m.add_class::<PyJointCoord>()?;
+    m.add_class::<PyMotionParam>()?;
+    m.add_class::<PyCommandSequence>()?;
+    m.add_class::<PyRobot>()?;
+    m.add("RobotError", m.py().get_type::<RobotError>())?;
+    Ok(())
+}
+
+/// register the `inovo_rs` module to be imported by an embedded interpreter,
+/// call it before the interpreter is initialized
+pub fn append_to_inittab() {
+    pyo3::append_to_inittab!(inovo_rs);
+}
@@ -0,0 +1,247 @@
+//! HTTP server, driving the robot from web HMIs and other languages.
+//!
+//! every endpoint takes and returns json, in the serde format of this crate.
+//! ```text
+//! POST /linear                  Transform           linear motion
+//! POST /linear_relative         Transform           relative linear motion
+//! POST /joint                   MotionTarget        joint motion
+//! POST /joint_relative          Transform           relative joint motion
+//! POST /param                   MotionParam         set the motion param
+//! POST /sequence                CommandSequence     run a command sequence
+//! GET  /transform                                   the current transform
+//! GET  /joint                                       the current joint coord
+//! GET  /io/{target}/{port}                          a digital input, target is beckhoff or wrist
+//! PUT  /io/{target}/{port}      bool                set a digital output
+//! POST /gripper                 GripperCommand      a gripper command, get returns the width
+//! POST /vacuum                  VacuumCommand       a vacuum command, get returns the pressure
+//! GET  /data/{key}                                  a value from the data dictionary
+//! ```
+//! commands respond `204 No Content`, and errors respond `{"error": .., "category": ..}`.
+//!
+//! # Example
+//! ```no_run
+//! use inovo_rs::rest;
+//! use inovo_rs::robot::*;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let handle = RobotHandle::new(Robot::defaut_logger(50003, "psu002")?);
+//!     let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+//!     rest::serve(listener, handle).await?;
+//!     Ok(())
+//! }
+//! ```
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::json;
+
+use crate::geometry::{JointCoord, Transform};
+use crate::iva::{GripperCommand, IOTarget, MotionTarget, VacuumCommand};
+use crate::robot::{CommandSequence, IvaRobot, MotionParam, Robot, RobotError, RobotHandle};
+
+/// Error of the http server
+#[derive(Debug, thiserror::Error)]
+pub enum RestError {
+    #[error(transparent)]
+    Robot(#[from] RobotError),
+    #[error("robot task failed: {0}")]
+    Task(#[from] tokio::task::JoinError),
+}
+
+impl RestError {
+    /// get the status code of the error
+    pub fn status(&self) -> StatusCode {
+        match self {
+            RestError::Robot(RobotError::InvalidArgument(_) | RobotError::SoftLimit(_)) => {
+                StatusCode::BAD_REQUEST
+            }
+            RestError::Robot(RobotError::ResponseError(_)) => StatusCode::CONFLICT,
+            RestError::Robot(_) => StatusCode::BAD_GATEWAY,
+            RestError::Task(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    /// get the category of the error, see [`RobotError::category`]
+    pub fn category(&self) -> &'static str {
+        match self {
+            RestError::Robot(e) => e.category(),
+            RestError::Task(_) => "task",
+        }
+    }
+}
+
+impl IntoResponse for RestError {
+    fn into_response(self) -> Response {
+        let body = json!({
+            "error": self.to_string(),
+            "category": self.category(),
+        });
+        (self.status(), Json(body)).into_response()
+    }
+}
+
+type RestResult<T> = Result<T, RestError>;
+
+/// run a blocking robot function on the blocking thread pool
+async fn blocking<T, F>(handle: RobotHandle, f: F) -> RestResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut Robot) -> Result<T, RobotError> + Send + 'static,
+{
+    Ok(tokio::task::spawn_blocking(move || handle.with(f)).await??)
+}
+
+/// run a robot command, responding no content
+async fn command<F>(handle: RobotHandle, f: F) -> RestResult<StatusCode>
+where
+    F: FnOnce(&mut Robot) -> Result<&mut Robot, RobotError> + Send + 'static,
+{
+    blocking(handle, |bot| f(bot).map(|_| ())).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// create the router of the endpoints
+pub fn router(handle: RobotHandle) -> Router {
+    Router::new()
+        .route("/linear", post(linear))
+        .route("/linear_relative", post(linear_relative))
+        .route("/joint", post(joint).get(get_joint))
+        .route("/joint_relative", post(joint_relative))
+        .route("/param", post(param))
+        .route("/sequence", post(sequence))
+        .route("/transform", get(get_transform))
+        .route("/io/{target}/{port}", get(io_get).put(io_set))
+        .route("/gripper", post(gripper))
+        .route("/vacuum", post(vacuum))
+        .route("/data/{key}", get(data))
+        .with_state(handle)
+}
+
+/// serve the endpoints on a listener, until the server fails
+pub async fn serve(listener: tokio::net::TcpListener, handle: RobotHandle) -> std::io::Result<()> {
+    axum::serve(listener, router(handle)).await
+}
+
+async fn linear(
+    State(handle): State<RobotHandle>,
+    Json(target): Json<Transform>,
+) -> RestResult<StatusCode> {
+    command(handle, |bot| bot.linear(target)).await
+}
+
+async fn linear_relative(
+    State(handle): State<RobotHandle>,
+    Json(target): Json<Transform>,
+) -> RestResult<StatusCode> {
+    command(handle, |bot| bot.linear_relative(target)).await
+}
+
+async fn joint(
+    State(handle): State<RobotHandle>,
+    Json(target): Json<MotionTarget>,
+) -> RestResult<StatusCode> {
+    command(handle, |bot| bot.joint(target)).await
+}
+
+async fn joint_relative(
+    State(handle): State<RobotHandle>,
+    Json(target): Json<Transform>,
+) -> RestResult<StatusCode> {
+    command(handle, |bot| bot.joint_relative(target)).await
+}
+
+async fn param(
+    State(handle): State<RobotHandle>,
+    Json(param): Json<MotionParam>,
+) -> RestResult<StatusCode> {
+    command(handle, |bot| bot.set_param(param)).await
+}
+
+async fn sequence(
+    State(handle): State<RobotHandle>,
+    Json(sequence): Json<CommandSequence>,
+) -> RestResult<StatusCode> {
+    command(handle, |bot| bot.sequence(sequence)).await
+}
+
+async fn get_transform(State(handle): State<RobotHandle>) -> RestResult<Json<Transform>> {
+    blocking(handle, |bot| bot.get_current_transform())
+        .await
+        .map(Json)
+}
+
+async fn get_joint(State(handle): State<RobotHandle>) -> RestResult<Json<JointCoord>> {
+    blocking(handle, |bot| bot.get_current_joint())
+        .await
+        .map(Json)
+}
+
+async fn io_get(
+    State(handle): State<RobotHandle>,
+    Path((target, port)): Path<(IOTarget, u16)>,
+) -> RestResult<Json<bool>> {
+    blocking(handle, move |bot| bot.io_get(target, port))
+        .await
+        .map(Json)
+}
+
+async fn io_set(
+    State(handle): State<RobotHandle>,
+    Path((target, port)): Path<(IOTarget, u16)>,
+    Json(state): Json<bool>,
+) -> RestResult<StatusCode> {
+    command(handle, move |bot| bot.io_set(target, port, state)).await
+}
+
+async fn gripper(
+    State(handle): State<RobotHandle>,
+    Json(gripper_command): Json<GripperCommand>,
+) -> RestResult<Response> {
+    match gripper_command {
+        GripperCommand::Get => {
+            let width = blocking(handle, |bot| bot.gripper_get()).await?;
+            Ok(Json(width).into_response())
+        }
+        GripperCommand::Activate => Ok(command(handle, |bot| bot.gripper_activate())
+            .await?
+            .into_response()),
+        GripperCommand::Set { label } => Ok(command(handle, |bot| bot.gripper_set(label))
+            .await?
+            .into_response()),
+        GripperCommand::SetWidth { mm, force } => {
+            Ok(command(handle, move |bot| bot.gripper_set_width(mm, force))
+                .await?
+                .into_response())
+        }
+    }
+}
+
+async fn vacuum(
+    State(handle): State<RobotHandle>,
+    Json(vacuum_command): Json<VacuumCommand>,
+) -> RestResult<Response> {
+    match vacuum_command {
+        VacuumCommand::Get => {
+            let pressure = blocking(handle, |bot| bot.vacuum_pressure()).await?;
+            Ok(Json(pressure).into_response())
+        }
+        VacuumCommand::On => Ok(command(handle, |bot| bot.vacuum_on())
+            .await?
+            .into_response()),
+        VacuumCommand::Off => Ok(command(handle, |bot| bot.vacuum_off())
+            .await?
+            .into_response()),
+    }
+}
+
+async fn data(
+    State(handle): State<RobotHandle>,
+    Path(key): Path<String>,
+) -> RestResult<Json<String>> {
+    blocking(handle, move |bot| bot.get_data::<String>(key))
+        .await
+        .map(Json)
+}
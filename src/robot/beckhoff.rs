@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::robot::{FromRobot, RobotError};
+
+/// A beckhoff io module, e.g. an `EL1008` of 8 digital inputs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeckhoffModule {
+    pub slot: u16,
+    pub name: String,
+    pub inputs: u16,
+    pub outputs: u16,
+}
+
+/// The beckhoff io modules of the robot in slot order, see [`IvaRobot::beckhoff_modules`](crate::robot::IvaRobot::beckhoff_modules)
+///
+/// the beckhoff ports are numbered across the modules in slot order, the inputs and the outputs separately.
+/// # Example
+/// ```no_run
+/// use inovo_rs::robot::*;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+///
+/// // fail at startup rather than on the first io of a missing module
+/// let modules = bot.beckhoff_modules()?;
+/// modules.validate([0, 1, 8], [0, 3])?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BeckhoffModules {
+    modules: Vec<BeckhoffModule>,
+}
+
+impl BeckhoffModules {
+    /// create from modules, sorted by slot
+    pub fn new(mut modules: Vec<BeckhoffModule>) -> Self {
+        modules.sort_by_key(|module| module.slot);
+        Self { modules }
+    }
+    /// get the modules, in slot order
+    pub fn modules(&self) -> &[BeckhoffModule] {
+        &self.modules
+    }
+    /// get the number of digital inputs of all modules
+    pub fn inputs(&self) -> u16 {
+        self.modules.iter().map(|module| module.inputs).sum()
+    }
+    /// get the number of digital outputs of all modules
+    pub fn outputs(&self) -> u16 {
+        self.modules.iter().map(|module| module.outputs).sum()
+    }
+    /// get the module of an input port
+    pub fn input_module(&self, port: u16) -> Option<&BeckhoffModule> {
+        Self::module_of(&self.modules, port, |module| module.inputs)
+    }
+    /// get the module of an output port
+    pub fn output_module(&self, port: u16) -> Option<&BeckhoffModule> {
+        Self::module_of(&self.modules, port, |module| module.outputs)
+    }
+
+    /// check the ports used by the application exist
+    ///
+    /// ## Error
+    /// an [`RobotError::InvalidArgument`] listing every missing port
+    pub fn validate(
+        &self,
+        inputs: impl IntoIterator<Item = u16>,
+        outputs: impl IntoIterator<Item = u16>,
+    ) -> Result<(), RobotError> {
+        let (input_count, output_count) = (self.inputs(), self.outputs());
+        let mut missing: Vec<String> = inputs
+            .into_iter()
+            .filter(|port| *port >= input_count)
+            .map(|port| format!("input {}", port))
+            .collect();
+        missing.extend(
+            outputs
+                .into_iter()
+                .filter(|port| *port >= output_count)
+                .map(|port| format!("output {}", port)),
+        );
+        match missing.is_empty() {
+            true => Ok(()),
+            false => Err(RobotError::InvalidArgument(format!(
+                "beckhoff {} not available, {} inputs and {} outputs",
+                missing.join(", "),
+                input_count,
+                output_count
+            ))),
+        }
+    }
+
+    fn module_of(
+        modules: &[BeckhoffModule],
+        mut port: u16,
+        count: fn(&BeckhoffModule) -> u16,
+    ) -> Option<&BeckhoffModule> {
+        for module in modules {
+            if port < count(module) {
+                return Some(module);
+            }
+            port -= count(module);
+        }
+        None
+    }
+}
+
+/// a json list of the modules
+impl FromRobot for BeckhoffModules {
+    fn from_robot(res: String) -> Result<Self, String> {
+        let modules = serde_json::from_str(&res).map_err(|e| format!("{}: {}", e, res))?;
+        Ok(Self::new(modules))
+    }
+}
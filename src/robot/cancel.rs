@@ -0,0 +1,61 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::robot::Robot;
+
+/// A flag shared across threads, cancelling the instructions of a robot
+///
+/// once cancelled, every instruction of the robot fails with [`RobotError::Cancelled`](crate::robot::RobotError::Cancelled)
+/// without being sent, until the token is reset.
+/// # Example
+/// ```no_run
+/// use inovo_rs::robot::*;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+///
+/// let token = bot.cancel_token();
+/// std::thread::spawn(move || token.cancel());
+///
+/// match bot.sleep(1.0) {
+///     Err(RobotError::Cancelled) => bot.cancel_token().reset(),
+///     res => drop(res?),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// create a new token, not cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// cancel the instructions
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+    /// whether the token is cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+    /// allow the instructions again
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::SeqCst);
+    }
+}
+
+impl Robot {
+    /// get the cancel token of the robot
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+    /// replace the cancel token, e.g. to cancel several robots with one token
+    pub fn set_cancel_token(&mut self, token: CancelToken) -> &mut Self {
+        self.cancel = token;
+        self
+    }
+}
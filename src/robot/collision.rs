@@ -0,0 +1,301 @@
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::logger::Logable;
+use crate::robot::{CancelToken, IvaRobot, Poller, Robot, RobotError, RobotHandle, StateSource};
+use crate::ros_bridge::{RosBridge, RuntimeState};
+
+/// An anomaly detected by a [`CollisionMonitor`], joints are numbered from 1
+#[derive(Debug, Clone, PartialEq)]
+pub enum Anomaly {
+    /// the torque of a joint exceeded its limit, in Nm
+    TorqueLimit {
+        joint: usize,
+        torque: f64,
+        limit: f64,
+    },
+    /// the torque of a joint changed more than the limit between two samples, in Nm
+    TorqueJump {
+        joint: usize,
+        delta: f64,
+        limit: f64,
+    },
+    /// the runtime left the running state, e.g. on a protective stop
+    RuntimeState {
+        from: RuntimeState,
+        to: RuntimeState,
+    },
+}
+
+impl std::fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Anomaly::TorqueLimit {
+                joint,
+                torque,
+                limit,
+            } => write!(
+                f,
+                "j{} torque {:.2} Nm over limit {:.2} Nm",
+                joint, torque, limit
+            ),
+            Anomaly::TorqueJump {
+                joint,
+                delta,
+                limit,
+            } => write!(
+                f,
+                "j{} torque jump {:.2} Nm over limit {:.2} Nm",
+                joint, delta, limit
+            ),
+            Anomaly::RuntimeState { from, to } => write!(f, "runtime state {:?} -> {:?}", from, to),
+        }
+    }
+}
+
+type AnomalyCallback = Box<dyn FnMut(&Anomaly) + Send>;
+/// the robot a monitor thread queries, and the error that stopped it
+type MonitorState = (StateSource, Result<(), RobotError>);
+
+/// A builder of a background thread, watching the joint torques and the runtime state for collisions and stalls
+///
+/// on an anomaly, the monitor cancels the [`CancelToken`] if any, then calls the callback if any.
+/// a torque limit is reported once until the torque is back under the limit.
+/// # Example
+/// ```no_run
+/// use inovo_rs::robot::*;
+/// use inovo_rs::ros_bridge::RosBridge;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+/// // a second iva connection, for monitoring only
+/// let monitor = Robot::defaut_logger(50004, "psu002")?;
+///
+/// let watch = CollisionMonitor::new()
+///     .set_torque_limits([60.0, 60.0, 40.0, 12.0, 12.0, 12.0])
+///     .set_torque_jump(15.0)
+///     .watch_runtime(RosBridge::new("psu002", 500))
+///     .set_cancel_token(bot.cancel_token())
+///     .on_anomaly(|anomaly| eprintln!("crash response: {}", anomaly))
+///     .spawn(monitor)?;
+///
+/// match bot.sleep(10.0) {
+///     Err(RobotError::Cancelled) => println!("{:?}", watch.anomalies()),
+///     res => drop(res?),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct CollisionMonitor {
+    rate_hz: f64,
+    torque_limits: Option<[f64; 6]>,
+    torque_jump: Option<f64>,
+    ros_bridge: Option<RosBridge>,
+    cancel: Option<CancelToken>,
+    callback: Option<AnomalyCallback>,
+}
+
+impl Default for CollisionMonitor {
+    fn default() -> Self {
+        Self {
+            rate_hz: 50.0,
+            torque_limits: None,
+            torque_jump: None,
+            ros_bridge: None,
+            cancel: None,
+            callback: None,
+        }
+    }
+}
+
+impl CollisionMonitor {
+    /// create a new monitor at 50 Hz, watching nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// set the rate of the queries, in Hz
+    pub fn set_rate_hz(mut self, rate_hz: f64) -> Self {
+        self.rate_hz = rate_hz;
+        self
+    }
+    /// set the limit of the absolute torque of each joint, in Nm
+    pub fn set_torque_limits(mut self, limits: [f64; 6]) -> Self {
+        self.torque_limits = Some(limits);
+        self
+    }
+    /// set the limit of the torque change of any joint between two samples, in Nm
+    pub fn set_torque_jump(mut self, limit: f64) -> Self {
+        self.torque_jump = Some(limit);
+        self
+    }
+    /// watch the runtime state through the ros bridge, reporting any transition out of running but to stop
+    pub fn watch_runtime(mut self, ros_bridge: RosBridge) -> Self {
+        self.ros_bridge = Some(ros_bridge);
+        self
+    }
+    /// set the token cancelled on an anomaly, usually the [`Robot::cancel_token`] of the robot in motion
+    pub fn set_cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+    /// set the callback called on an anomaly, from the monitor thread
+    pub fn on_anomaly(mut self, f: impl FnMut(&Anomaly) + Send + 'static) -> Self {
+        self.callback = Some(Box::new(f));
+        self
+    }
+
+    /// watch a dedicated robot connection
+    pub fn spawn(self, robot: Robot) -> Result<CollisionWatch, RobotError> {
+        self.spawn_source(StateSource::Owned(Box::new(robot)))
+    }
+    /// watch a shared robot, interleaving the queries with the other commands
+    pub fn spawn_shared(self, handle: RobotHandle) -> Result<CollisionWatch, RobotError> {
+        self.spawn_source(StateSource::Shared(handle))
+    }
+
+    fn spawn_source(mut self, mut source: StateSource) -> Result<CollisionWatch, RobotError> {
+        if !(self.rate_hz.is_finite() && self.rate_hz > 0.0) {
+            return Err(RobotError::InvalidArgument(format!(
+                "invalid monitor rate: {} Hz",
+                self.rate_hz
+            )));
+        }
+        let period = Duration::from_secs_f64(1.0 / self.rate_hz);
+        let anomalies = Arc::new(Mutex::new(Vec::new()));
+        let logger = source.with(|bot| bot.get_logger().clone());
+
+        let thread_anomalies = anomalies.clone();
+        let mut detector = Detector::default();
+        let poller = Poller::spawn(period, (source, Ok(())), move |(source, res)| {
+            let found = match self.sample(source, &mut detector) {
+                Ok(found) => found,
+                // the token of a shared robot was cancelled, the monitor has done its job
+                Err(RobotError::Cancelled) => return ControlFlow::Break(()),
+                Err(e) => {
+                    *res = Err(e);
+                    return ControlFlow::Break(());
+                }
+            };
+            for anomaly in found {
+                if let Some(cancel) = &self.cancel {
+                    cancel.cancel();
+                }
+                logger.warn(format!("anomaly: {}", anomaly));
+                if let Some(callback) = &mut self.callback {
+                    callback(&anomaly);
+                }
+                thread_anomalies.lock().unwrap().push(anomaly);
+            }
+            ControlFlow::Continue(())
+        });
+
+        Ok(CollisionWatch { anomalies, poller })
+    }
+
+    fn sample(
+        &mut self,
+        source: &mut StateSource,
+        detector: &mut Detector,
+    ) -> Result<Vec<Anomaly>, RobotError> {
+        let mut found = vec![];
+        if self.torque_limits.is_some() || self.torque_jump.is_some() {
+            let torques = source.with(|bot| bot.get_joint_torques())?;
+            found.extend(detector.torques(torques, self.torque_limits, self.torque_jump));
+        }
+        if let Some(ros_bridge) = &mut self.ros_bridge {
+            let state = ros_bridge.get_runtime_state()?;
+            found.extend(detector.runtime_state(state));
+        }
+        Ok(found)
+    }
+}
+
+/// the edge detection state of a monitor
+#[derive(Default)]
+struct Detector {
+    last_torques: Option<[f64; 6]>,
+    over_limit: [bool; 6],
+    last_state: Option<RuntimeState>,
+}
+
+impl Detector {
+    fn torques(
+        &mut self,
+        torques: [f64; 6],
+        limits: Option<[f64; 6]>,
+        jump: Option<f64>,
+    ) -> Vec<Anomaly> {
+        let mut found = vec![];
+        for (i, &torque) in torques.iter().enumerate() {
+            if let Some(limits) = limits {
+                let over = torque.abs() > limits[i];
+                if over && !self.over_limit[i] {
+                    found.push(Anomaly::TorqueLimit {
+                        joint: i + 1,
+                        torque,
+                        limit: limits[i],
+                    });
+                }
+                self.over_limit[i] = over;
+            }
+            if let (Some(limit), Some(last)) = (jump, self.last_torques) {
+                let delta = torque - last[i];
+                if delta.abs() > limit {
+                    found.push(Anomaly::TorqueJump {
+                        joint: i + 1,
+                        delta,
+                        limit,
+                    });
+                }
+            }
+        }
+        self.last_torques = Some(torques);
+        found
+    }
+
+    fn runtime_state(&mut self, state: RuntimeState) -> Option<Anomaly> {
+        let from = self.last_state.replace(state)?;
+        match (from, state) {
+            (RuntimeState::Running, RuntimeState::Pause | RuntimeState::Disabled) => {
+                Some(Anomaly::RuntimeState { from, to: state })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A running [`CollisionMonitor`], stopping the monitor on drop
+pub struct CollisionWatch {
+    anomalies: Arc<Mutex<Vec<Anomaly>>>,
+    poller: Poller<MonitorState>,
+}
+
+impl CollisionWatch {
+    /// get the anomalies detected so far
+    pub fn anomalies(&self) -> Vec<Anomaly> {
+        self.anomalies.lock().unwrap().clone()
+    }
+    /// whether an anomaly was detected
+    pub fn is_triggered(&self) -> bool {
+        !self.anomalies.lock().unwrap().is_empty()
+    }
+    /// whether the monitor stopped, after an error of the queries
+    pub fn is_finished(&self) -> bool {
+        self.poller.is_finished()
+    }
+
+    /// stop the monitor, returning the anomalies and the dedicated robot connection if any,
+    /// or the error that stopped the monitor
+    pub fn stop(mut self) -> Result<(Vec<Anomaly>, Option<Robot>), RobotError> {
+        let robot = match self.poller.stop() {
+            Some(state) => {
+                let (source, res) = state.unwrap_or_else(|e| std::panic::resume_unwind(e));
+                res?;
+                source.into_robot()
+            }
+            None => None,
+        };
+        Ok((self.anomalies(), robot))
+    }
+}
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::geometry::*;
 use crate::iva::*;
-use crate::robot::MotionParam;
+use crate::robot::{MotionParam, RobotError};
 
 /// A struct to hold a list of robot commands
 /// # Example
@@ -20,7 +20,7 @@ use crate::robot::MotionParam;
 ///     .then_sleep(10.0)
 ///     .then_sync();
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct CommandSequence {
     seq: Vec<RobotCommand>,
 }
@@ -65,6 +65,279 @@ impl CommandSequence {
     pub fn then_set_param(self, param: MotionParam) -> Self {
         self.then(RobotCommand::SetParameter(param))
     }
+
+    /// append a linear motion with its own linear blend, in mm
+    ///
+    /// expands to a set param with the blend, the motion, then a set param restoring
+    /// the motion param last set in the sequence, see [`CommandSequence::current_param`].
+    /// a set param directly before the motion is merged into the blend.
+    /// ## Error
+    /// an [`RobotError::InvalidArgument`] if no param is set earlier in the sequence,
+    /// the param of the robot to restore after the blend being unknown
+    /// ```
+    /// use inovo_rs::robot::*;
+    /// use inovo_rs::iva::*;
+    /// use inovo_rs::geometry::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let param = MotionParam::new().set_speed(50.0).set_blend_linear(1.0);
+    /// let path = CommandSequence::new()
+    ///     .then_set_param(param.clone())
+    ///     .then_linear_blended(Transform::from_x(100.0), 20.0)?
+    ///     .then_linear_blended(Transform::from_x(200.0), 5.0)?
+    ///     .then_linear(Transform::from_x(300.0));
+    ///
+    /// // the first set param is merged in the blend of the first motion
+    /// assert_eq!(path.len(), 6);
+    /// assert_eq!(path[0], RobotCommand::SetParameter(param.clone().set_blend_linear(20.0)));
+    /// assert_eq!(path[2], RobotCommand::SetParameter(param.clone().set_blend_linear(5.0)));
+    /// assert_eq!(path[4], RobotCommand::SetParameter(param));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn then_linear_blended(self, target: Transform, blend_mm: f64) -> Result<Self, RobotError> {
+        self.then_blended(RobotCommand::linear(target), blend_mm)
+    }
+    /// append a joint motion with its own linear blend, in mm, see [`CommandSequence::then_linear_blended`]
+    pub fn then_joint_blended(
+        self,
+        target: impl Into<MotionTarget>,
+        blend_mm: f64,
+    ) -> Result<Self, RobotError> {
+        self.then_blended(RobotCommand::joint(target), blend_mm)
+    }
+
+    fn then_blended(mut self, motion: RobotCommand, blend_mm: f64) -> Result<Self, RobotError> {
+        let Some(base) = self.current_param().cloned() else {
+            return Err(RobotError::InvalidArgument(
+                "blended motion without a param set earlier in the sequence".to_string(),
+            ));
+        };
+        // the restore of the previous blended motion is overridden right away
+        if matches!(self.seq.last(), Some(RobotCommand::SetParameter(param)) if *param == base) {
+            self.seq.pop();
+        }
+        Ok(self
+            .then_set_param(base.clone().set_blend_linear(blend_mm))
+            .then(motion)
+            .then_set_param(base))
+    }
+
+    /// get the motion param last set in the sequence, the base of the blended motions
+    pub fn current_param(&self) -> Option<&MotionParam> {
+        self.seq.iter().rev().find_map(|command| match command {
+            RobotCommand::SetParameter(param) => Some(param),
+            _ => None,
+        })
+    }
+
+    /// attach an io action to the last command, see [`RobotCommand::with_io`]
+    /// ```
+    /// use inovo_rs::robot::*;
+    /// use inovo_rs::iva::*;
+    /// use inovo_rs::geometry::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// // glue valve on while moving along the bead
+    /// let bead = CommandSequence::new()
+    ///     .then_linear(Transform::from_vector([400.0, 0.0, 100.0]))
+    ///     .then_linear(Transform::from_vector([400.0, 200.0, 100.0]))
+    ///     .with_io(IOAction::set(IOTarget::Beckhoff, 3, true))?
+    ///     .with_io(IOAction::set(IOTarget::Beckhoff, 3, false).at_end())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// ## Error
+    /// an [`RobotError::InvalidArgument`] if the sequence is empty or its last command is not a motion
+    pub fn with_io(mut self, action: IOAction) -> Result<Self, RobotError> {
+        let last = self.seq.pop().ok_or_else(|| {
+            RobotError::InvalidArgument("io action attached to an empty sequence".to_string())
+        })?;
+        self.seq.push(last.with_io(action)?);
+        Ok(self)
+    }
+
+    /// append the commands of another sequence
+    pub fn concat(mut self, other: CommandSequence) -> Self {
+        self.seq.extend(other.seq);
+        self
+    }
+
+    /// map the transform targets of the absolute motions
+    ///
+    /// the relative motions and the joint coord targets are kept as is.
+    /// ```
+    /// use inovo_rs::robot::*;
+    /// use inovo_rs::iva::*;
+    /// use inovo_rs::geometry::*;
+    ///
+    /// // the same path, 50 mm higher
+    /// let path = CommandSequence::new()
+    ///     .then_linear(Transform::from_vector([400.0, 0.0, 100.0]))
+    ///     .then_linear_relative(Transform::from_y(100.0))
+    ///     .map_targets(|target| target.then_z(50.0));
+    ///
+    /// assert_eq!(path[0], RobotCommand::linear(Transform::from_vector([400.0, 0.0, 150.0])));
+    /// assert_eq!(path[1], RobotCommand::linear_relative(Transform::from_y(100.0)));
+    /// ```
+    pub fn map_targets(self, mut f: impl FnMut(Transform) -> Transform) -> Self {
+        self.map_motions(|motion_mode, target| match motion_mode {
+            MotionMode::Linear | MotionMode::Joint => f(target),
+            MotionMode::LinearRelative | MotionMode::JointRelative => target,
+        })
+    }
+
+    /// shift the whole sequence by a transform in the robot base frame, e.g. to a moved fixture
+    ///
+    /// the absolute targets are appended the offset, see [`Transform::then`],
+    /// and the relative motions are rotated along, so the path keeps its shape.
+    /// the joint coord targets are kept as is.
+    /// ```
+    /// use inovo_rs::robot::*;
+    /// use inovo_rs::iva::*;
+    /// use inovo_rs::geometry::*;
+    ///
+    /// let taught_fixture = Transform::from_vector([400.0, 0.0, 0.0]);
+    /// let fixture = Transform::from_rz(90.0).then_vector([0.0, 400.0, 0.0]);
+    ///
+    /// let taught = CommandSequence::new()
+    ///     .then_linear(Transform::from_vector([400.0, 0.0, 100.0]))
+    ///     .then_linear_relative(Transform::from_x(50.0));
+    /// let shifted = taught.offset_by(taught_fixture.inverse().then(fixture));
+    ///
+    /// let targets: Vec<&Transform> = shifted
+    ///     .iter()
+    ///     .filter_map(|command| match command {
+    ///         RobotCommand::Motion { target: MotionTarget::Transform(target), .. } => Some(target),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// // above the new fixture, moving along its x axis
+    /// assert!(targets[0].get_vector().iter().zip([0.0, 400.0, 100.0]).all(|(a, b)| (a - b).abs() < 1e-9));
+    /// assert!((targets[1].get_y() - 50.0).abs() < 1e-9);
+    /// ```
+    pub fn offset_by(self, offset: Transform) -> Self {
+        let rotation = offset.eular_only();
+        self.map_motions(|motion_mode, target| match motion_mode {
+            MotionMode::Linear | MotionMode::Joint => target.then(offset.clone()),
+            MotionMode::LinearRelative | MotionMode::JointRelative => rotation
+                .clone()
+                .inverse()
+                .then(target)
+                .then(rotation.clone()),
+        })
+    }
+
+    /// mirror the whole sequence across a plane of the robot base, e.g. the left-hand variant of a taught path
+    ///
+    /// the absolute targets are mirrored, see [`Transform::mirrored`],
+    /// and the relative motions are mirrored along, so the path keeps its shape.
+    /// the joint coord targets are kept as is.
+    /// ```
+    /// use inovo_rs::robot::*;
+    /// use inovo_rs::iva::*;
+    /// use inovo_rs::geometry::*;
+    ///
+    /// // the fixtures are symmetric about the xz plane
+    /// let right = CommandSequence::new()
+    ///     .then_linear(Transform::from_vector([400.0, -200.0, 100.0]))
+    ///     .then_linear_relative(Transform::from_y(-50.0));
+    /// let left = right.mirrored(&Plane::xz(0.0));
+    ///
+    /// assert_eq!(left[0], RobotCommand::linear(Transform::from_vector([400.0, 200.0, 100.0])));
+    /// assert_eq!(left[1], RobotCommand::linear_relative(Transform::from_y(50.0)));
+    /// ```
+    pub fn mirrored(self, plane: &Plane) -> Self {
+        // a relative motion is mirrored about the same plane through its start
+        let origin = Plane::new(Vector3::zeros(), plane.get_normal()).unwrap_or(*plane);
+        self.map_motions(|motion_mode, target| match motion_mode {
+            MotionMode::Linear | MotionMode::Joint => target.mirrored(plane),
+            MotionMode::LinearRelative | MotionMode::JointRelative => target.mirrored(&origin),
+        })
+    }
+
+    /// generate the reverse path, from the end of the sequence back along its motions, e.g. to retract
+    ///
+    /// a relative motion is undone by its inverse, and an absolute motion by a motion of the same mode
+    /// to the target it started from. each motion is reversed with the motion param it ran with,
+    /// the sleeps, synchronizes and io actions are left out.
+    /// the reverse path stops at an absolute motion with an unknown start, e.g. the first motion of the sequence.
+    /// ```
+    /// use inovo_rs::robot::*;
+    /// use inovo_rs::iva::*;
+    /// use inovo_rs::geometry::*;
+    ///
+    /// let approach = Transform::from_vector([400.0, 0.0, 100.0]);
+    /// let insert = CommandSequence::new()
+    ///     .then_joint(approach.clone())
+    ///     .then_linear_relative(Transform::from_z(-80.0))
+    ///     .then_linear_relative(Transform::from_x(20.0));
+    ///
+    /// let retract = insert.reversed();
+    /// assert_eq!(retract.len(), 2);
+    /// assert_eq!(retract[0], RobotCommand::linear_relative(Transform::from_x(-20.0)));
+    /// assert_eq!(retract[1], RobotCommand::linear_relative(Transform::from_z(80.0)));
+    /// ```
+    pub fn reversed(&self) -> Self {
+        // the motions with the target they start from, when known, and the param they run with
+        let mut motions = vec![];
+        let mut from: Option<MotionTarget> = None;
+        let mut param: Option<&MotionParam> = None;
+        for command in self.seq.iter() {
+            if let RobotCommand::SetParameter(set) = command {
+                param = Some(set);
+            }
+            let Some((motion_mode, target)) = command.motion() else {
+                continue;
+            };
+            let to = match (motion_mode, target, &from) {
+                (MotionMode::Linear | MotionMode::Joint, target, _) => Some(target.clone()),
+                (_, MotionTarget::Transform(delta), Some(MotionTarget::Transform(from))) => {
+                    Some(from.clone().then_relative(delta.clone()).into())
+                }
+                (_, MotionTarget::JointCoord(delta), Some(MotionTarget::JointCoord(from))) => {
+                    Some((from.clone() + delta.clone()).into())
+                }
+                _ => None,
+            };
+            motions.push((motion_mode, target, std::mem::replace(&mut from, to), param));
+        }
+
+        let mut reversed = CommandSequence::new();
+        for (motion_mode, target, from, param) in motions.into_iter().rev() {
+            let target = match (motion_mode, target) {
+                (MotionMode::Linear | MotionMode::Joint, _) => match from {
+                    Some(from) => from,
+                    None => break,
+                },
+                // the inverse of a relative motion about its end point
+                (_, MotionTarget::Transform(delta)) => delta
+                    .eular_only()
+                    .inverse()
+                    .then_vector(delta.get_vector().map(|mm| -mm))
+                    .into(),
+                (_, MotionTarget::JointCoord(delta)) => (-delta).into(),
+            };
+            if let Some(param) = param.filter(|param| reversed.current_param() != Some(*param)) {
+                reversed = reversed.then_set_param(param.clone());
+            }
+            reversed = reversed.then(RobotCommand::Motion {
+                motion_mode: motion_mode.clone(),
+                target,
+            });
+        }
+        reversed
+    }
+
+    /// map the transform target of every motion
+    fn map_motions(mut self, mut f: impl FnMut(&MotionMode, Transform) -> Transform) -> Self {
+        for command in self.seq.iter_mut() {
+            if let Some((motion_mode, MotionTarget::Transform(target))) = command.motion_mut() {
+                *target = f(motion_mode, target.clone());
+            }
+        }
+        self
+    }
 }
 
 impl IntoIterator for CommandSequence {
@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+use crate::robot::FromRobot;
+
+/// A robot-side extension called through a custom command, with a typed request and response
+///
+/// the request is sent with [`IvaRobot::invoke`](crate::robot::IvaRobot::invoke), its fields converted as
+/// [`CustomCommand::from_serialize`](crate::iva::CustomCommand::from_serialize),
+/// with the name of the handler under the key [`CUSTOM_HANDLER_KEY`].
+/// # Example
+/// ```no_run
+/// use inovo_rs::robot::*;
+/// use serde::Serialize;
+///
+/// /// the glue dispenser driven by the robot program
+/// struct Dispense;
+///
+/// #[derive(Serialize)]
+/// struct DispenseRequest {
+///     volume_ml: f64,
+/// }
+///
+/// impl CustomHandler for Dispense {
+///     const NAME: &'static str = "dispense";
+///     type Request = DispenseRequest;
+///     // the volume left in the cartridge
+///     type Response = f64;
+/// }
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+/// let left = bot.invoke::<Dispense>(DispenseRequest { volume_ml: 2.5 })?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait CustomHandler {
+    /// the name of the handler in the robot program
+    const NAME: &'static str;
+    /// the arguments of the call, a struct or a map
+    type Request: Serialize;
+    /// the response of the call, `()` for `"OK"`
+    type Response: FromRobot;
+}
+
+/// the key of the handler name in the custom command of [`CustomHandler`]
+pub const CUSTOM_HANDLER_KEY: &str = "handler";
+
+impl FromRobot for () {
+    fn from_robot(res: String) -> Result<Self, String> {
+        match res.as_str() {
+            "OK" => Ok(()),
+            _ => Err(res),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use crate::geometry::JointCoord;
+use crate::iva::IOTarget;
+use crate::robot::{RobotSnapshot, RobotState};
+
+/// the change below which a distance or an angle is reported as unchanged
+const EPSILON: f64 = 1e-6;
+
+/// A change of a digital io port between two states
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IOChange {
+    pub target: IOTarget,
+    pub port: u16,
+    /// the state of the port in the other state
+    pub state: bool,
+}
+
+/// The delta between two robot states, see [`RobotState::diff`] and [`RobotSnapshot::diff`]
+///
+/// displayed as a short summary, e.g. `moved 12.000 mm, beckhoff 3 on, gripper 20.000 -> none`.
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::robot::*;
+/// use std::time::SystemTime;
+///
+/// let state = |x: f64| RobotState {
+///     transform: Transform::from_x(x),
+///     joints: JointCoord::identity(),
+///     timestamp: SystemTime::now(),
+/// };
+/// let diff = state(100.0).diff(&state(112.0));
+/// assert_eq!(diff.translation_mm, 12.0);
+/// assert_eq!(diff.to_string(), "moved 12.000 mm");
+/// assert!(diff.is_within(15.0, 0.0));
+/// assert!(state(100.0).diff(&state(100.0)).is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StateDiff {
+    /// the distance the tcp moved, in mm
+    pub translation_mm: f64,
+    /// the angle the tcp rotated, in degree
+    pub rotation_deg: f64,
+    /// the change of each joint, in degree
+    pub joints: JointCoord,
+    /// the io ports changed, by target and port
+    pub io: Vec<IOChange>,
+    /// the gripper width before and after, `None` if unchanged
+    pub gripper: Option<(Option<f64>, Option<f64>)>,
+    /// whether the motion param changed
+    pub params_changed: bool,
+}
+
+impl StateDiff {
+    /// check the two states are the same within a tolerance
+    ///
+    /// the tcp and the gripper within `tolerance_mm`, the tcp rotation and the joints within `tolerance_deg`,
+    /// and the same io and motion param.
+    pub fn is_within(&self, tolerance_mm: f64, tolerance_deg: f64) -> bool {
+        let gripper = match self.gripper {
+            None => true,
+            Some((Some(a), Some(b))) => (a - b).abs() <= tolerance_mm,
+            Some(_) => false,
+        };
+        self.translation_mm <= tolerance_mm + EPSILON
+            && self.rotation_deg <= tolerance_deg + EPSILON
+            && self.joints.max_joint_delta(&JointCoord::identity()) <= tolerance_deg + EPSILON
+            && self.io.is_empty()
+            && gripper
+            && !self.params_changed
+    }
+    /// check the two states are the same
+    pub fn is_empty(&self) -> bool {
+        self.is_within(0.0, 0.0)
+    }
+}
+
+impl std::fmt::Display for StateDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        if self.translation_mm > EPSILON {
+            parts.push(format!("moved {:.3} mm", self.translation_mm));
+        }
+        if self.rotation_deg > EPSILON {
+            parts.push(format!("rotated {:.3} deg", self.rotation_deg));
+        }
+        let joint_deg = self.joints.max_joint_delta(&JointCoord::identity());
+        if joint_deg > EPSILON {
+            parts.push(format!("joints moved up to {:.3} deg", joint_deg));
+        }
+        for change in &self.io {
+            let target = match change.target {
+                IOTarget::Beckhoff => "beckhoff",
+                IOTarget::Wrist => "wrist",
+            };
+            let state = if change.state { "on" } else { "off" };
+            parts.push(format!("{} {} {}", target, change.port, state));
+        }
+        if let Some((from, to)) = self.gripper {
+            let width = |w: Option<f64>| w.map_or("none".to_string(), |w| format!("{:.3}", w));
+            parts.push(format!("gripper {} -> {}", width(from), width(to)));
+        }
+        if self.params_changed {
+            parts.push("params changed".to_string());
+        }
+        match parts.is_empty() {
+            true => f.write_str("no change"),
+            false => f.write_str(&parts.join(", ")),
+        }
+    }
+}
+
+impl RobotState {
+    /// compute the delta from this state to an other, e.g. from a golden state to the actual one
+    pub fn diff(&self, other: &RobotState) -> StateDiff {
+        StateDiff {
+            translation_mm: self.transform.translation_distance(&other.transform),
+            rotation_deg: self.transform.rotation_distance_deg(&other.transform),
+            joints: &other.joints - &self.joints,
+            ..Default::default()
+        }
+    }
+}
+
+impl RobotSnapshot {
+    /// compute the delta from this snapshot to an other, see [`RobotState::diff`]
+    ///
+    /// the io of a target missing from either snapshot is not compared.
+    /// ```
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::iva::IOTarget;
+    /// use inovo_rs::robot::*;
+    /// use std::time::SystemTime;
+    ///
+    /// let golden = RobotSnapshot {
+    ///     transform: Transform::identity(),
+    ///     joints: JointCoord::identity(),
+    ///     io: [(IOTarget::Beckhoff, 0b0001)].into(),
+    ///     gripper: Some(20.0),
+    ///     params: None,
+    ///     timestamp: SystemTime::now(),
+    /// };
+    /// let mut actual = golden.clone();
+    /// actual.io.insert(IOTarget::Beckhoff, 0b1000);
+    /// actual.gripper = None;
+    ///
+    /// let diff = golden.diff(&actual);
+    /// assert_eq!(diff.to_string(), "beckhoff 0 off, beckhoff 3 on, gripper 20.000 -> none");
+    /// ```
+    pub fn diff(&self, other: &RobotSnapshot) -> StateDiff {
+        let mut io = vec![];
+        for target in [IOTarget::Beckhoff, IOTarget::Wrist] {
+            let (Some(from), Some(to)) = (self.io.get(&target), other.io.get(&target)) else {
+                continue;
+            };
+            let changed = from ^ to;
+            for port in (0..32).filter(|port| changed & 1 << port != 0) {
+                io.push(IOChange {
+                    target: target.clone(),
+                    port,
+                    state: to & 1 << port != 0,
+                });
+            }
+        }
+        let gripper_changed = match (self.gripper, other.gripper) {
+            (Some(a), Some(b)) => (a - b).abs() > EPSILON,
+            (a, b) => a.is_some() != b.is_some(),
+        };
+        StateDiff {
+            io,
+            gripper: gripper_changed.then_some((self.gripper, other.gripper)),
+            params_changed: self.params != other.params,
+            ..RobotState::from(self.clone()).diff(&RobotState::from(other.clone()))
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Object safe robot abstraction
+//!
+//! [`DynRobot`] mirrors the non-generic part of [`IvaRobot`], so a real robot and a mock
+//! can be swapped at runtime behind a `Box<dyn DynRobot>`.
+//! every [`IvaRobot`] is a [`DynRobot`].
+//!
+//! the method names are the same as [`IvaRobot`], hence this module is not glob exported with [`crate::robot`],
+//! calling a method on a concrete robot with both traits in scope is ambiguous.
+//!
+//! # Example
+//! ```no_run
+//! use inovo_rs::geometry::Transform;
+//! use inovo_rs::robot::dynamic::DynRobot;
+//! use inovo_rs::robot::{Robot, RobotError};
+//!
+//! fn pick(bot: &mut dyn DynRobot) -> Result<(), RobotError> {
+//!     bot.linear_relative(Transform::from_z(-50.0))?
+//!         .gripper_set("close")?
+//!         .linear_relative(Transform::from_z(50.0))?;
+//!     Ok(())
+//! }
+//!
+//! # fn main() -> Result<(), RobotError> {
+//! let mut bot: Box<dyn DynRobot> = Box::new(Robot::defaut_logger(50003, "psu002")?);
+//! pick(bot.as_mut())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::context::Context;
+use crate::geometry::{JointCoord, Transform, Wrench};
+use crate::iva::{CustomCommand, IOTarget, Instruction, MotionTarget, RobotCommand};
+use crate::logger::Logger;
+use crate::robot::{CommandSequence, IvaContext, IvaRobot, MotionParam, RobotError, TcpVelocity};
+
+/// An object safe version of [`IvaRobot`], see the [module](self) documentation
+pub trait DynRobot {
+    /// get the logger of the robot
+    fn logger(&mut self) -> &mut Logger;
+
+    /// send an instruction to the robot and read the response
+    fn instruction(&mut self, inst: Instruction) -> Result<String, RobotError>;
+    /// instruct the robot to execute a [`RobotCommand`]
+    fn execute(&mut self, robot_command: RobotCommand) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to sleep
+    fn sleep(&mut self, second: f64) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to synchronize, returning once all blended motions are completed
+    fn sync(&mut self) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to set the motion param
+    fn set_param(&mut self, motion_param: MotionParam) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to move linearly to a target
+    fn linear(&mut self, target: Transform) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to move linearly relative to its current transform
+    fn linear_relative(&mut self, target: Transform) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to move in joint space to a target
+    fn joint(&mut self, target: MotionTarget) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to move in joint space relative to its current transform
+    fn joint_relative(&mut self, target: Transform) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to execute a [`CommandSequence`]
+    fn sequence(
+        &mut self,
+        command_sequence: CommandSequence,
+    ) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to pop a context
+    fn pop(&mut self) -> Result<&mut dyn DynRobot, RobotError>;
+    /// instruct the robot to pop all contexts
+    fn pop_all(&mut self) -> Result<&mut dyn DynRobot, RobotError>;
+    /// get the number of contexts currently pushed on the robot side
+    fn context_depth(&mut self) -> Result<u32, RobotError>;
+
+    /// get the current [`Transform`] of the robot
+    fn get_current_transform(&mut self) -> Result<Transform, RobotError>;
+    /// get the current [`JointCoord`] of the robot
+    fn get_current_joint(&mut self) -> Result<JointCoord, RobotError>;
+    /// get the current torque of each joint, in Nm
+    fn get_joint_torques(&mut self) -> Result<[f64; 6], RobotError>;
+    /// get the current speed of the tcp
+    fn get_tcp_velocity(&mut self) -> Result<TcpVelocity, RobotError>;
+    /// get the wrench measured by the wrist force/torque sensor
+    fn get_wrench(&mut self) -> Result<Wrench, RobotError>;
+    /// get a value from the data dictionary, as the raw response
+    fn get_data(&mut self, key: &str) -> Result<String, RobotError>;
+    /// get many keys of the data dictionary in one round trip, as the raw response of each key
+    fn get_data_batch(&mut self, keys: &[&str]) -> Result<HashMap<String, String>, RobotError>;
+
+    /// set a digital output
+    fn io_set(
+        &mut self,
+        io_target: IOTarget,
+        port: u16,
+        state: bool,
+    ) -> Result<&mut dyn DynRobot, RobotError>;
+    /// get a digital input
+    fn io_get(&mut self, io_target: IOTarget, port: u16) -> Result<bool, RobotError>;
+    /// set a digital output high for a duration then low again
+    fn pulse_output(
+        &mut self,
+        io_target: IOTarget,
+        port: u16,
+        second: f64,
+    ) -> Result<&mut dyn DynRobot, RobotError>;
+    /// get a range of digital inputs as a bit mask
+    fn io_get_bank(&mut self, io_target: IOTarget, range: Range<u16>) -> Result<u32, RobotError>;
+
+    /// activate the gripper
+    fn gripper_activate(&mut self) -> Result<&mut dyn DynRobot, RobotError>;
+    /// set the gripper to a labeled position
+    fn gripper_set(&mut self, label: &str) -> Result<&mut dyn DynRobot, RobotError>;
+    /// set the gripper width and force
+    fn gripper_set_width(&mut self, mm: f64, force: f64) -> Result<&mut dyn DynRobot, RobotError>;
+    /// get the gripper width
+    fn gripper_get(&mut self) -> Result<f64, RobotError>;
+
+    /// turn on the vacuum
+    fn vacuum_on(&mut self) -> Result<&mut dyn DynRobot, RobotError>;
+    /// turn off the vacuum
+    fn vacuum_off(&mut self) -> Result<&mut dyn DynRobot, RobotError>;
+    /// get the vacuum pressure
+    fn vacuum_pressure(&mut self) -> Result<f64, RobotError>;
+
+    /// instruct the robot to execute a [`CustomCommand`], and return the response
+    fn custom(&mut self, custom_command: CustomCommand) -> Result<String, RobotError>;
+}
+
+impl<R: IvaRobot> DynRobot for R
+where
+    IvaContext: Context<R>,
+{
+    fn logger(&mut self) -> &mut Logger {
+        self.get_logger()
+    }
+
+    fn instruction(&mut self, inst: Instruction) -> Result<String, RobotError> {
+        IvaRobot::instruction(self, inst)
+    }
+    fn execute(&mut self, robot_command: RobotCommand) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::execute(self, robot_command)?)
+    }
+    fn sleep(&mut self, second: f64) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::sleep(self, second)?)
+    }
+    fn sync(&mut self) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::sync(self)?)
+    }
+    fn set_param(&mut self, motion_param: MotionParam) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::set_param(self, motion_param)?)
+    }
+    fn linear(&mut self, target: Transform) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::linear(self, target)?)
+    }
+    fn linear_relative(&mut self, target: Transform) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::linear_relative(self, target)?)
+    }
+    fn joint(&mut self, target: MotionTarget) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::joint(self, target)?)
+    }
+    fn joint_relative(&mut self, target: Transform) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::joint_relative(self, target)?)
+    }
+    fn sequence(
+        &mut self,
+        command_sequence: CommandSequence,
+    ) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::sequence(self, command_sequence)?)
+    }
+    fn pop(&mut self) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::pop(self)?)
+    }
+    fn pop_all(&mut self) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::pop_all(self)?)
+    }
+    fn context_depth(&mut self) -> Result<u32, RobotError> {
+        IvaRobot::context_depth(self)
+    }
+
+    fn get_current_transform(&mut self) -> Result<Transform, RobotError> {
+        IvaRobot::get_current_transform(self)
+    }
+    fn get_current_joint(&mut self) -> Result<JointCoord, RobotError> {
+        IvaRobot::get_current_joint(self)
+    }
+    fn get_joint_torques(&mut self) -> Result<[f64; 6], RobotError> {
+        IvaRobot::get_joint_torques(self)
+    }
+    fn get_tcp_velocity(&mut self) -> Result<TcpVelocity, RobotError> {
+        IvaRobot::get_tcp_velocity(self)
+    }
+    fn get_wrench(&mut self) -> Result<Wrench, RobotError> {
+        IvaRobot::get_wrench(self)
+    }
+    fn get_data(&mut self, key: &str) -> Result<String, RobotError> {
+        IvaRobot::get_data(self, key)
+    }
+    fn get_data_batch(&mut self, keys: &[&str]) -> Result<HashMap<String, String>, RobotError> {
+        IvaRobot::get_data_batch(self, keys)
+    }
+
+    fn io_set(
+        &mut self,
+        io_target: IOTarget,
+        port: u16,
+        state: bool,
+    ) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::io_set(self, io_target, port, state)?)
+    }
+    fn io_get(&mut self, io_target: IOTarget, port: u16) -> Result<bool, RobotError> {
+        IvaRobot::io_get(self, io_target, port)
+    }
+    fn pulse_output(
+        &mut self,
+        io_target: IOTarget,
+        port: u16,
+        second: f64,
+    ) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::pulse_output(self, io_target, port, second)?)
+    }
+    fn io_get_bank(&mut self, io_target: IOTarget, range: Range<u16>) -> Result<u32, RobotError> {
+        IvaRobot::io_get_bank(self, io_target, range)
+    }
+
+    fn gripper_activate(&mut self) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::gripper_activate(self)?)
+    }
+    fn gripper_set(&mut self, label: &str) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::gripper_set(self, label)?)
+    }
+    fn gripper_set_width(&mut self, mm: f64, force: f64) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::gripper_set_width(self, mm, force)?)
+    }
+    fn gripper_get(&mut self) -> Result<f64, RobotError> {
+        IvaRobot::gripper_get(self)
+    }
+
+    fn vacuum_on(&mut self) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::vacuum_on(self)?)
+    }
+    fn vacuum_off(&mut self) -> Result<&mut dyn DynRobot, RobotError> {
+        Ok(IvaRobot::vacuum_off(self)?)
+    }
+    fn vacuum_pressure(&mut self) -> Result<f64, RobotError> {
+        IvaRobot::vacuum_pressure(self)
+    }
+
+    fn custom(&mut self, custom_command: CustomCommand) -> Result<String, RobotError> {
+        IvaRobot::custom(self, custom_command)
+    }
+}
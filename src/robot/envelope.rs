@@ -0,0 +1,224 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+use crate::geometry::JointCoord;
+use crate::robot::{Robot, RobotError, RobotState, StateStream};
+
+/// The min and max reached position and joint angles during a run
+///
+/// positions are of the tcp in the base frame, in mm, joint angles in degree.
+/// # Example
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::robot::*;
+/// use std::time::SystemTime;
+///
+/// let mut envelope = MotionEnvelope::new();
+/// for x in [-100.0, 250.0] {
+///     envelope.record(&RobotState {
+///         transform: Transform::from_x(x),
+///         joints: JointCoord::from_j1(x / 10.0),
+///         timestamp: SystemTime::now(),
+///     });
+/// }
+/// assert_eq!(envelope.size_mm(), [350.0, 0.0, 0.0]);
+/// assert_eq!(envelope.joint_range_deg()[0], 35.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MotionEnvelope {
+    /// number of states recorded
+    pub samples: usize,
+    pub min_mm: [f64; 3],
+    pub max_mm: [f64; 3],
+    pub joint_min: JointCoord,
+    pub joint_max: JointCoord,
+    /// time of the first and last state recorded
+    pub span: Option<(SystemTime, SystemTime)>,
+}
+
+impl MotionEnvelope {
+    /// create an empty envelope
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// whether no state was recorded
+    pub fn is_empty(&self) -> bool {
+        self.samples == 0
+    }
+
+    /// extend the envelope with a state
+    pub fn record(&mut self, state: &RobotState) {
+        let position = state.transform.get_vector();
+        let span = (state.timestamp, state.timestamp);
+        self.extend(position, position, &state.joints, &state.joints, span, 1);
+    }
+    /// extend the envelope with another one
+    pub fn merge(&mut self, other: &MotionEnvelope) {
+        if let Some(span) = other.span {
+            let (min_mm, max_mm) = (other.min_mm, other.max_mm);
+            self.extend(
+                min_mm,
+                max_mm,
+                &other.joint_min,
+                &other.joint_max,
+                span,
+                other.samples,
+            );
+        }
+    }
+
+    fn extend(
+        &mut self,
+        min_mm: [f64; 3],
+        max_mm: [f64; 3],
+        joint_min: &JointCoord,
+        joint_max: &JointCoord,
+        (first, last): (SystemTime, SystemTime),
+        samples: usize,
+    ) {
+        if self.is_empty() {
+            self.min_mm = min_mm;
+            self.max_mm = max_mm;
+            self.joint_min = joint_min.clone();
+            self.joint_max = joint_max.clone();
+            self.span = Some((first, last));
+        } else {
+            self.min_mm = std::array::from_fn(|i| self.min_mm[i].min(min_mm[i]));
+            self.max_mm = std::array::from_fn(|i| self.max_mm[i].max(max_mm[i]));
+            let lower: [f64; 6] = std::array::from_fn(|i| self.joint_min[i].min(joint_min[i]));
+            let upper: [f64; 6] = std::array::from_fn(|i| self.joint_max[i].max(joint_max[i]));
+            self.joint_min = lower.into();
+            self.joint_max = upper.into();
+            self.span = self
+                .span
+                .map(|(self_first, self_last)| (self_first.min(first), self_last.max(last)));
+        }
+        self.samples += samples;
+    }
+
+    /// get the size of the bounding box of the tcp, in mm
+    pub fn size_mm(&self) -> [f64; 3] {
+        std::array::from_fn(|i| self.max_mm[i] - self.min_mm[i])
+    }
+    /// get the range swept by each joint, in degree
+    pub fn joint_range_deg(&self) -> [f64; 6] {
+        std::array::from_fn(|i| self.joint_max[i] - self.joint_min[i])
+    }
+    /// whether the tcp stayed within a box, in mm
+    pub fn within_mm(&self, min_mm: [f64; 3], max_mm: [f64; 3]) -> bool {
+        (0..3).all(|i| self.min_mm[i] >= min_mm[i] && self.max_mm[i] <= max_mm[i])
+    }
+}
+
+impl std::fmt::Display for MotionEnvelope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "motion envelope of {} samples", self.samples)?;
+        if let Some((first, last)) = self.span {
+            let duration = last.duration_since(first).unwrap_or_default();
+            writeln!(f, "  duration : {:.1}s", duration.as_secs_f64())?;
+        }
+        for (i, axis) in ["x", "y", "z"].iter().enumerate() {
+            writeln!(
+                f,
+                "  {}  : {:>9.2} .. {:>9.2} mm  ({:.2} mm)",
+                axis,
+                self.min_mm[i],
+                self.max_mm[i],
+                self.max_mm[i] - self.min_mm[i]
+            )?;
+        }
+        for i in 0..6 {
+            writeln!(
+                f,
+                "  j{} : {:>9.2} .. {:>9.2} deg ({:.2} deg)",
+                i + 1,
+                self.joint_min[i],
+                self.joint_max[i],
+                self.joint_max[i] - self.joint_min[i]
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A background recorder of the [`MotionEnvelope`] of a run, fed by a [`StateStream`]
+/// # Example
+/// ```no_run
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::robot::*;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+/// let monitor = Robot::defaut_logger(50004, "psu002")?;
+///
+/// let recorder = EnvelopeRecorder::spawn(StateStreamer::new().set_rate_hz(50.0).spawn(monitor)?);
+/// bot.linear_relative(Transform::from_x(200.0))?;
+/// bot.linear_relative(Transform::from_x(-200.0))?;
+///
+/// let (envelope, _monitor) = recorder.stop()?;
+/// println!("{}", envelope);
+/// # Ok(())
+/// # }
+/// ```
+pub struct EnvelopeRecorder {
+    envelope: Arc<Mutex<MotionEnvelope>>,
+    stop: Arc<AtomicBool>,
+    thread: JoinHandle<(Result<(), RobotError>, Option<Robot>)>,
+}
+
+impl EnvelopeRecorder {
+    /// record the states of a stream in a background thread
+    pub fn spawn(stream: StateStream) -> Self {
+        let envelope = Arc::new(Mutex::new(MotionEnvelope::new()));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let (thread_envelope, thread_stop) = (envelope.clone(), stop.clone());
+        let thread = std::thread::spawn(move || {
+            let mut res = Ok(());
+            while !thread_stop.load(Ordering::Relaxed) {
+                match stream.recv_timeout(Duration::from_millis(50)) {
+                    Some(Ok(state)) => thread_envelope.lock().unwrap().record(&state),
+                    Some(Err(e)) => {
+                        res = Err(e);
+                        break;
+                    }
+                    None => {}
+                }
+            }
+            // record the states left in the stream
+            while let Some(state) = stream.try_recv() {
+                match state {
+                    Ok(state) => thread_envelope.lock().unwrap().record(&state),
+                    Err(e) => res = Err(e),
+                }
+            }
+            (res, stream.stop())
+        });
+
+        Self {
+            envelope,
+            stop,
+            thread,
+        }
+    }
+
+    /// get the envelope recorded so far
+    pub fn envelope(&self) -> MotionEnvelope {
+        self.envelope.lock().unwrap().clone()
+    }
+
+    /// stop the recording, returning the envelope and the dedicated robot connection of the stream if any,
+    /// or the error that stopped the stream
+    pub fn stop(self) -> Result<(MotionEnvelope, Option<Robot>), RobotError> {
+        self.stop.store(true, Ordering::Relaxed);
+        let (res, robot) = self
+            .thread
+            .join()
+            .unwrap_or_else(|e| std::panic::resume_unwind(e));
+        res?;
+        let envelope = self.envelope.lock().unwrap().clone();
+        Ok((envelope, robot))
+    }
+}
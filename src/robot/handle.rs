@@ -0,0 +1,125 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::context::{Context, ContextGuard, TryContext};
+use crate::iva::Instruction;
+use crate::logger::{Logable, Logger};
+use crate::robot::{CommandSequence, IvaContext, IvaRobot, MotionParam, Robot, RobotError};
+
+/// A shared handle of a [`Robot`], cheap to clone and send across threads
+///
+/// the robot is locked for every call, so commands from different threads never interleave.
+///
+/// the handle is an [`IvaRobot`] itself, locking the robot for each instruction, or once for a whole sequence.
+/// use [`RobotHandle::with`] for several instructions that must run together.
+/// ```no_run
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::robot::*;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let handle = RobotHandle::new(Robot::defaut_logger(50003, "psu002")?);
+///
+/// let hmi = handle.clone();
+/// std::thread::spawn(move || hmi.with(|bot| bot.get_current_transform()));
+///
+/// handle.with(|bot| bot.linear(Transform::from_z(100.0)).map(|_| ()))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RobotHandle {
+    robot: Arc<Mutex<Robot>>,
+    /// a clone of the logger of the robot, logging without locking
+    logger: Logger,
+}
+
+impl RobotHandle {
+    /// create a new handle owning the robot
+    pub fn new(robot: Robot) -> Self {
+        Self::from_shared(Arc::new(Mutex::new(robot)))
+    }
+    /// create a new handle from a shared robot
+    pub fn from_shared(robot: Arc<Mutex<Robot>>) -> Self {
+        let logger = robot
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .logger
+            .clone();
+        Self { robot, logger }
+    }
+    /// get the shared robot, e.g. for an [`OwnedContextGuard`](crate::context::OwnedContextGuard)
+    pub fn shared(&self) -> &Arc<Mutex<Robot>> {
+        &self.robot
+    }
+
+    /// lock the robot, a poisoned lock is recovered
+    pub fn lock(&self) -> MutexGuard<'_, Robot> {
+        self.robot.lock().unwrap_or_else(|e| e.into_inner())
+    }
+    /// run a function with the locked robot
+    pub fn with<T>(&self, f: impl FnOnce(&mut Robot) -> T) -> T {
+        f(&mut self.lock())
+    }
+}
+
+impl From<Robot> for RobotHandle {
+    fn from(robot: Robot) -> Self {
+        Self::new(robot)
+    }
+}
+
+impl Logable for RobotHandle {
+    fn get_logger(&mut self) -> &mut Logger {
+        &mut self.logger
+    }
+}
+
+impl IvaRobot for RobotHandle {
+    fn instruction(&mut self, inst: Instruction) -> Result<String, RobotError> {
+        self.with(|bot| bot.instruction(inst))
+    }
+    fn response_error(&mut self, res: String) -> RobotError {
+        self.with(|bot| bot.response_error(res))
+    }
+    fn prepare_sequence(
+        &mut self,
+        command_sequence: CommandSequence,
+    ) -> Result<CommandSequence, RobotError> {
+        self.with(|bot| bot.prepare_sequence(command_sequence))
+    }
+    fn set_param(&mut self, motion_param: MotionParam) -> Result<&mut Self, RobotError> {
+        self.with(|bot| bot.set_param(motion_param).map(|_| ()))?;
+        Ok(self)
+    }
+    fn sequence(&mut self, command_sequence: CommandSequence) -> Result<&mut Self, RobotError> {
+        self.with(|bot| bot.sequence(command_sequence).map(|_| ()))?;
+        Ok(self)
+    }
+    fn with_sequence(
+        &mut self,
+        command_sequence: CommandSequence,
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
+        self.with(|bot| {
+            let command_sequence = bot.prepare_sequence(command_sequence)?;
+            for robot_command in command_sequence.into_iter() {
+                bot.enqueue(robot_command)?;
+            }
+            bot.instruction_assert_ok(Instruction::dequeue_push())
+                .map(|_| ())
+        })?;
+        Ok(ContextGuard::new(self, IvaContext))
+    }
+}
+
+impl Context<RobotHandle> for IvaContext {
+    fn context_enter(&mut self, _: &mut RobotHandle) {}
+    fn context_drop(&mut self, machine: &mut RobotHandle) {
+        machine.with(|bot| self.context_drop(bot))
+    }
+}
+
+impl TryContext<RobotHandle> for IvaContext {
+    type Error = RobotError;
+    fn try_context_drop(&mut self, machine: &mut RobotHandle) -> Result<(), RobotError> {
+        machine.with(|bot| self.try_context_drop(bot))
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use crate::iva::Instruction;
+use crate::robot::{Robot, RobotError};
+
+type BeforeHook = Box<dyn FnMut(&Instruction) + Send + Sync>;
+type AfterHook = Box<dyn FnMut(&Instruction, &str, Duration) + Send + Sync>;
+type ErrorHook = Box<dyn FnMut(&Instruction, &RobotError) + Send + Sync>;
+
+/// The hooks registered on a [`Robot`], called around every instruction
+#[derive(Default)]
+pub(crate) struct Hooks {
+    before: Vec<BeforeHook>,
+    after: Vec<AfterHook>,
+    error: Vec<ErrorHook>,
+    /// the last instruction sent, kept for the error hooks of rejected responses
+    last: Option<Instruction>,
+}
+
+impl Hooks {
+    pub(crate) fn before(&mut self, inst: &Instruction) {
+        for hook in self.before.iter_mut() {
+            hook(inst);
+        }
+        if !self.error.is_empty() {
+            self.last = Some(inst.clone());
+        }
+    }
+    pub(crate) fn after(
+        &mut self,
+        inst: &Instruction,
+        res: &Result<String, RobotError>,
+        latency: Duration,
+    ) {
+        match res {
+            Ok(res) => {
+                for hook in self.after.iter_mut() {
+                    hook(inst, res, latency);
+                }
+            }
+            Err(e) => {
+                for hook in self.error.iter_mut() {
+                    hook(inst, e);
+                }
+            }
+        }
+    }
+    pub(crate) fn error(&mut self, error: &RobotError) {
+        if let Some(inst) = &self.last {
+            for hook in self.error.iter_mut() {
+                hook(inst, error);
+            }
+        }
+    }
+}
+
+impl Robot {
+    /// register a hook, called with every instruction before it is sent
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// bot.on_before_instruction(|inst| println!("-> {:?}", inst))
+    ///     .on_after_instruction(|_, res, latency| println!("<- {} in {:?}", res, latency))
+    ///     .on_error(|inst, e| eprintln!("{:?} failed: {}", inst, e));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_before_instruction(
+        &mut self,
+        hook: impl FnMut(&Instruction) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.hooks.before.push(Box::new(hook));
+        self
+    }
+    /// register a hook, called with every instruction, its response and the latency, after the response is read
+    pub fn on_after_instruction(
+        &mut self,
+        hook: impl FnMut(&Instruction, &str, Duration) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.hooks.after.push(Box::new(hook));
+        self
+    }
+    /// register a hook, called with the instruction and the error,
+    /// when the connection fail or the response is rejected
+    pub fn on_error(
+        &mut self,
+        hook: impl FnMut(&Instruction, &RobotError) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.hooks.error.push(Box::new(hook));
+        self
+    }
+    /// remove all the registered hooks
+    pub fn clear_hooks(&mut self) -> &mut Self {
+        self.hooks = Hooks::default();
+        self
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::iva::GetTarget;
+use crate::robot::{IvaRobot, Robot, RobotError};
+
+/// The identity of an arm, to tell which arm executed a job, see [`Robot::info`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RobotInfo {
+    pub model: String,
+    pub serial_number: String,
+    pub software_version: String,
+    /// the attached tool, `None` without a tool
+    pub tool: Option<String>,
+}
+
+impl Display for RobotInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} (software {})",
+            self.model, self.serial_number, self.software_version
+        )?;
+        match &self.tool {
+            Some(tool) => write!(f, " with {}", tool),
+            None => write!(f, " without tool"),
+        }
+    }
+}
+
+impl Robot {
+    /// get the model, serial number, software version and attached tool of the arm
+    ///
+    /// the robot responds [`GetTarget::Info`] with a json object of the fields as strings,
+    /// the tool being `"None"` without a tool.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::logger::Logable;
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// let info = bot.info()?;
+    /// bot.get_logger().info(format!("job started on {}", info));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn info(&mut self) -> Result<RobotInfo, RobotError> {
+        let mut fields: HashMap<String, String> = self.get(GetTarget::Info)?;
+        let mut field = |key: &str| {
+            fields
+                .remove(key)
+                .ok_or_else(|| RobotError::ResponseError(format!("info without {}", key)))
+        };
+        Ok(RobotInfo {
+            model: field("model")?,
+            serial_number: field("serial_number")?,
+            software_version: field("software_version")?,
+            tool: Some(field("tool")?).filter(|tool| tool != "None"),
+        })
+    }
+}
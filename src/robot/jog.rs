@@ -0,0 +1,168 @@
+use crate::geometry::{JointCoord, Transform};
+use crate::robot::{IvaRobot, Robot, RobotError};
+
+/// The axis the tcp is jogged along or about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JogAxis {
+    X,
+    Y,
+    Z,
+    Rx,
+    Ry,
+    Rz,
+}
+
+impl JogAxis {
+    /// get the transform of jogging by a distance, in mm or degree
+    pub fn delta(&self, mm_or_deg: f64) -> Transform {
+        match self {
+            JogAxis::X => Transform::from_x(mm_or_deg),
+            JogAxis::Y => Transform::from_y(mm_or_deg),
+            JogAxis::Z => Transform::from_z(mm_or_deg),
+            JogAxis::Rx => Transform::from_rx(mm_or_deg),
+            JogAxis::Ry => Transform::from_ry(mm_or_deg),
+            JogAxis::Rz => Transform::from_rz(mm_or_deg),
+        }
+    }
+    /// check if the axis is a rotation
+    pub fn is_rotation(&self) -> bool {
+        matches!(self, JogAxis::Rx | JogAxis::Ry | JogAxis::Rz)
+    }
+}
+
+/// The frame the tcp is jogged in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JogFrame {
+    /// along and about the axes of the robot base, rotating about the tcp
+    #[default]
+    Base,
+    /// along and about the axes of the tcp
+    Tool,
+}
+
+impl JogFrame {
+    /// compute the target of jogging the current pose by a delta
+    pub fn jog(&self, current: &Transform, delta: Transform) -> Transform {
+        match self {
+            JogFrame::Base => current.clone().then_relative(delta),
+            JogFrame::Tool => current * delta,
+        }
+    }
+}
+
+/// The persistent jog settings of a [`Robot`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JogSettings {
+    step_mm: f64,
+    step_deg: f64,
+    frame: JogFrame,
+}
+
+impl Default for JogSettings {
+    fn default() -> Self {
+        Self {
+            step_mm: 10.0,
+            step_deg: 5.0,
+            frame: JogFrame::Base,
+        }
+    }
+}
+
+impl JogSettings {
+    /// create the default settings, 10 mm and 5 degree steps in the base frame
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// set the translation step, in mm
+    pub fn set_step_mm(mut self, mm: f64) -> Self {
+        self.step_mm = mm.abs();
+        self
+    }
+    /// set the rotation and joint step, in degree
+    pub fn set_step_deg(mut self, degree: f64) -> Self {
+        self.step_deg = degree.abs();
+        self
+    }
+    /// set the jog frame
+    pub fn set_frame(mut self, frame: JogFrame) -> Self {
+        self.frame = frame;
+        self
+    }
+    /// get the translation step, in mm
+    pub fn step_mm(&self) -> f64 {
+        self.step_mm
+    }
+    /// get the rotation and joint step, in degree
+    pub fn step_deg(&self) -> f64 {
+        self.step_deg
+    }
+    /// get the jog frame
+    pub fn frame(&self) -> JogFrame {
+        self.frame
+    }
+    /// get the step of an axis, in mm or degree
+    pub fn step(&self, axis: JogAxis) -> f64 {
+        if axis.is_rotation() {
+            self.step_deg
+        } else {
+            self.step_mm
+        }
+    }
+}
+
+impl Robot {
+    /// get the jog settings
+    pub fn jog_settings(&self) -> &JogSettings {
+        &self.jog_settings
+    }
+    /// set the jog settings, kept until set again
+    pub fn set_jog_settings(&mut self, jog_settings: JogSettings) -> &mut Self {
+        self.jog_settings = jog_settings;
+        self
+    }
+
+    /// jog the tcp along or about an axis in the jog frame, by a distance in mm or degree
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// bot.set_jog_settings(JogSettings::new().set_step_mm(1.0).set_frame(JogFrame::Tool));
+    ///
+    /// // the buttons of a gui
+    /// bot.jog_step(JogAxis::Z, false)?;
+    /// bot.jog_joint_step(6, true)?;
+    /// // an explicit distance
+    /// bot.jog(JogAxis::Rz, 45.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn jog(&mut self, axis: JogAxis, mm_or_deg: f64) -> Result<&mut Self, RobotError> {
+        let current = self.get_current_transform()?;
+        let target = self.jog_settings.frame.jog(&current, axis.delta(mm_or_deg));
+        self.linear(target)
+    }
+    /// jog the tcp by one step of the jog settings, forward or backward
+    pub fn jog_step(&mut self, axis: JogAxis, forward: bool) -> Result<&mut Self, RobotError> {
+        let step = self.jog_settings.step(axis);
+        self.jog(axis, if forward { step } else { -step })
+    }
+    /// jog a joint, from 1 to 6, by a degree
+    pub fn jog_joint(&mut self, joint: usize, degree: f64) -> Result<&mut Self, RobotError> {
+        if !(1..=6).contains(&joint) {
+            return Err(RobotError::InvalidArgument(format!(
+                "joint {} is not from 1 to 6",
+                joint
+            )));
+        }
+        let mut target: JointCoord = self.get_current_joint()?;
+        target[joint - 1] += degree;
+        self.joint(target)
+    }
+    /// jog a joint, from 1 to 6, by one degree step of the jog settings, forward or backward
+    pub fn jog_joint_step(&mut self, joint: usize, forward: bool) -> Result<&mut Self, RobotError> {
+        let step = self.jog_settings.step_deg;
+        self.jog_joint(joint, if forward { step } else { -step })
+    }
+}
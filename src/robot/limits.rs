@@ -0,0 +1,164 @@
+use crate::geometry::{JointCoord, Transform};
+use crate::iva::{Instruction, MotionMode, MotionTarget};
+use crate::robot::{IvaRobot, Robot, RobotError};
+
+/// A motion target out of the [`SoftLimits`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum LimitViolation {
+    /// the tcp is out of the box, in mm
+    #[error("{axis} = {value:.2} mm out of [{min:.2}, {max:.2}] mm")]
+    Cartesian {
+        axis: char,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+    /// a joint is out of its range, joints are numbered from 1, in degree
+    #[error("j{joint} = {value:.2} deg out of [{min:.2}, {max:.2}] deg")]
+    Joint {
+        joint: usize,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+}
+
+/// Client side limits every motion target of a [`Robot`] is checked against, see [`Robot::set_soft_limits`]
+///
+/// a target out of the limits is rejected with [`RobotError::SoftLimit`] before being sent.
+/// # Example
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::robot::*;
+///
+/// let limits = SoftLimits::new()
+///     .set_box([-500.0, -500.0, 0.0], [500.0, 500.0, 800.0])
+///     .set_joint_range(1, -170.0, 170.0);
+///
+/// assert!(limits.check_transform(&Transform::from_z(100.0)).is_ok());
+/// assert_eq!(
+///     limits.check_transform(&Transform::from_z(-20.0)).unwrap_err().to_string(),
+///     "z = -20.00 mm out of [0.00, 800.00] mm"
+/// );
+/// assert!(limits.check_joints(&JointCoord::from_j1(175.0)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SoftLimits {
+    cartesian: Option<([f64; 3], [f64; 3])>,
+    joints: [Option<(f64, f64)>; 6],
+}
+
+impl SoftLimits {
+    /// create new limits, allowing everything
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// set the box the tcp is kept in, in the base frame, in mm
+    pub fn set_box(mut self, min_mm: [f64; 3], max_mm: [f64; 3]) -> Self {
+        self.cartesian = Some((min_mm, max_mm));
+        self
+    }
+    /// set the range of a joint from 1 to 6, in degree
+    ///
+    /// # Panics
+    /// if the joint is not from 1 to 6
+    pub fn set_joint_range(mut self, joint: usize, min_deg: f64, max_deg: f64) -> Self {
+        assert!((1..=6).contains(&joint), "invalid joint: {}", joint);
+        self.joints[joint - 1] = Some((min_deg, max_deg));
+        self
+    }
+    /// set the range of every joint, in degree
+    pub fn set_joint_ranges(mut self, ranges: [(f64, f64); 6]) -> Self {
+        self.joints = ranges.map(Some);
+        self
+    }
+
+    /// check a tcp target against the box
+    pub fn check_transform(&self, transform: &Transform) -> Result<(), LimitViolation> {
+        let Some((min, max)) = self.cartesian else {
+            return Ok(());
+        };
+        let position = transform.get_vector();
+        for (i, axis) in ['x', 'y', 'z'].into_iter().enumerate() {
+            if !(min[i]..=max[i]).contains(&position[i]) {
+                return Err(LimitViolation::Cartesian {
+                    axis,
+                    value: position[i],
+                    min: min[i],
+                    max: max[i],
+                });
+            }
+        }
+        Ok(())
+    }
+    /// check a joint target against the joint ranges
+    pub fn check_joints(&self, joints: &JointCoord) -> Result<(), LimitViolation> {
+        for (i, range) in self.joints.iter().enumerate() {
+            if let Some((min, max)) = *range {
+                if !(min..=max).contains(&joints[i]) {
+                    return Err(LimitViolation::Joint {
+                        joint: i + 1,
+                        value: joints[i],
+                        min,
+                        max,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Robot {
+    /// get the soft limits, if any
+    pub fn soft_limits(&self) -> Option<&SoftLimits> {
+        self.soft_limits.as_ref()
+    }
+    /// set the soft limits, checked against every motion target until cleared
+    ///
+    /// the target of an executed relative motion is computed from the current pose,
+    /// applying the relative transform in the base frame, as [`JogFrame::Base`](crate::robot::JogFrame::Base).
+    /// the relative motions enqueued in a sequence are not checked, the pose they start from is unknown.
+    pub fn set_soft_limits(&mut self, soft_limits: SoftLimits) -> &mut Self {
+        self.soft_limits = Some(soft_limits);
+        self
+    }
+    /// remove the soft limits
+    pub fn clear_soft_limits(&mut self) -> &mut Self {
+        self.soft_limits = None;
+        self
+    }
+
+    /// check the target of a motion instruction against the soft limits
+    pub(crate) fn check_soft_limits(&mut self, inst: &Instruction) -> Result<(), RobotError> {
+        let Some(limits) = self.soft_limits.clone() else {
+            return Ok(());
+        };
+        let (robot_command, queued) = match inst {
+            Instruction::Execute { robot_command, .. } => (robot_command, false),
+            Instruction::Enqueue(robot_command) => (robot_command, true),
+            _ => return Ok(()),
+        };
+        let Some((motion_mode, target)) = robot_command.motion() else {
+            return Ok(());
+        };
+        let relative = matches!(
+            motion_mode,
+            MotionMode::LinearRelative | MotionMode::JointRelative
+        );
+
+        match target {
+            _ if relative && queued => Ok(()),
+            MotionTarget::Transform(delta) if relative => {
+                let current = self.get_current_transform()?;
+                Ok(limits.check_transform(&current.then_relative(delta.clone()))?)
+            }
+            MotionTarget::JointCoord(delta) if relative => {
+                let current = self.get_current_joint()?;
+                Ok(limits.check_joints(&(current + delta.clone()))?)
+            }
+            MotionTarget::Transform(transform) => Ok(limits.check_transform(transform)?),
+            MotionTarget::JointCoord(joints) => Ok(limits.check_joints(joints)?),
+        }
+    }
+}
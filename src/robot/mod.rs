@@ -1,17 +1,70 @@
 //! Module for interacting with inovo robot arm
 
-use crate::context::{Context, ContextGuard};
+use crate::context::{Context, ContextGuard, TryContext};
 use crate::geometry::*;
 use crate::iva::*;
 use crate::logger::{Logable, Logger};
 use crate::ros_bridge::*;
 use crate::socket;
 
+use std::collections::HashMap;
+use std::ops::Range;
+
+mod beckhoff;
+mod cancel;
+mod collision;
 mod command_sequence;
+mod custom;
+mod diff;
+pub mod dynamic;
+mod envelope;
+mod handle;
+mod hooks;
+mod info;
+mod jog;
+mod limits;
+mod motion_handle;
 mod motion_param;
+mod path;
+mod query;
+mod recovery;
+mod restart;
+mod safe_mode;
+mod state;
+mod stats;
+mod supervisor;
+mod velocity;
+mod watch;
+mod waypoint;
+mod zones;
 
+pub use beckhoff::*;
+pub use cancel::*;
+pub use collision::*;
 pub use command_sequence::*;
+pub use custom::*;
+pub use diff::*;
+pub use envelope::*;
+pub use handle::*;
+use hooks::Hooks;
+pub use info::*;
+pub use jog::*;
+pub use limits::*;
+pub use motion_handle::*;
 pub use motion_param::*;
+pub use path::*;
+pub use query::*;
+pub use recovery::*;
+pub use restart::*;
+pub use safe_mode::*;
+pub use state::*;
+use stats::StatsCollector;
+pub use stats::{InstructionStats, RobotStats, STATS_WINDOW};
+pub use supervisor::*;
+pub use velocity::*;
+pub use watch::*;
+pub use waypoint::*;
+pub use zones::*;
 
 /// A struct of a inovo robot arm
 ///
@@ -44,6 +97,7 @@ pub use motion_param::*;
 ///     bot.gripper_activate()?;
 ///     let _ : f64 = bot.gripper_get()?;
 ///     bot.gripper_set("open")?;
+///     bot.gripper_set_width(40.0, 50.0)?;
 ///
 ///     // get/set digital IO
 ///     let _ = bot.beckhoff_get(0)?;
@@ -55,6 +109,12 @@ pub use motion_param::*;
 ///         .add_float("meaning of the universe", 42.0);
 ///     let _ : String = bot.custom(custom_command)?;
 ///
+///     // scoped context, popped after the closure even if it fail
+///     let part_height = bot.scoped_linear_relative(Transform::from_z(-50.0), |bot| {
+///         bot.gripper_set("close")?;
+///         bot.get_current_transform()
+///     })?;
+///
 ///     Ok(())
 /// }
 /// ```
@@ -63,6 +123,30 @@ pub struct Robot {
     logger: Logger,
     /// the tcp socket connection with the psu
     stream: socket::Stream,
+    /// the motion param last set with [`IvaRobot::set_param`], `None` if unknown
+    motion_param: Option<MotionParam>,
+    /// the persistent settings of jogging
+    jog_settings: JogSettings,
+    /// the hooks called around every instruction
+    hooks: Hooks,
+    /// the statistics of the instructions, and the metrics, see [`Robot::stats`]
+    stats: StatsCollector,
+    /// the token cancelling the instructions, see [`Robot::cancel_token`]
+    cancel: CancelToken,
+    /// the limits the motion targets are checked against, see [`Robot::set_soft_limits`]
+    soft_limits: Option<SoftLimits>,
+    /// the speed cap of the safe mode in percent, see [`Robot::safe_mode`]
+    speed_cap: Option<f64>,
+    /// the speed zones the sequences are capped to, see [`Robot::set_speed_zones`]
+    speed_zones: Option<SpeedZones>,
+    /// the contexts entered, to recover the robot-side stack, see [`Robot::reconnect`]
+    contexts: recovery::ContextTracker,
+    /// how the context stack is recovered, see [`Robot::set_context_recovery`]
+    context_recovery: ContextRecovery,
+    /// the restart of the iva sequence, see [`Robot::set_auto_restart`]
+    auto_restart: Option<AutoRestart>,
+    /// the ros bridge the iva sequence was run with, see [`Robot::runtime_state`]
+    ros_bridge: Option<RosBridge>,
 }
 
 impl Logable for Robot {
@@ -74,10 +158,28 @@ impl Logable for Robot {
 impl Robot {
     /// construct a new [`Robot`]
     pub fn new(stream: socket::Stream, logger: Logger) -> Self {
-        Self { stream, logger }
+        Self {
+            stream,
+            logger,
+            motion_param: None,
+            jog_settings: JogSettings::new(),
+            hooks: Hooks::default(),
+            stats: StatsCollector::default(),
+            cancel: CancelToken::new(),
+            soft_limits: None,
+            speed_cap: None,
+            speed_zones: None,
+            contexts: Default::default(),
+            context_recovery: ContextRecovery::default(),
+            auto_restart: None,
+            ros_bridge: None,
+        }
     }
 
     /// create a new instance, and call ros bridge run sequence to remotly start
+    ///
+    /// the logger default to the one registered with the host name, see [`Logger::get_or_create`],
+    /// and the listener and stream logger default to the robot's logger.
     pub fn new_inovo(
         port: u16,
         host: impl Into<String>,
@@ -86,18 +188,51 @@ impl Robot {
         stream_logger: Option<Logger>,
     ) -> Result<Self, RobotError> {
         let host = host.into();
-        let logger = logger.unwrap_or_else(|| Logger::default_target(host.clone()));
+        let logger = logger.unwrap_or_else(|| Logger::get_or_create(host.clone()));
 
-        let mut listener = socket::Listener::new(port, listener_logger)?;
+        let listener_logger = listener_logger.unwrap_or_else(|| logger.clone());
+        let mut listener = socket::Listener::new(port, Some(listener_logger))?;
 
-        RosBridge::new(host.clone(), 1000).run_sequence("iva")?;
+        let mut ros_bridge = RosBridge::new(host.clone(), 1000);
+        ros_bridge.run_sequence("iva")?;
 
-        let stream_logger =
-            stream_logger.unwrap_or_else(|| Logger::default_target(format!("Inovo - {}", host)));
+        let stream_logger = stream_logger.unwrap_or_else(|| logger.clone());
 
         let stream = listener.accept(Some(stream_logger))?;
 
-        Ok(Self::new(stream, logger))
+        let mut robot = Self::new(stream, logger);
+        robot.set_ros_bridge(Some(ros_bridge));
+        Ok(robot)
+    }
+    /// set the ros bridge of the psu, kept by [`Robot::new_inovo`]
+    pub fn set_ros_bridge(&mut self, ros_bridge: Option<RosBridge>) -> &mut Self {
+        self.ros_bridge = ros_bridge;
+        self
+    }
+    /// get the state of the runtime, through the ros bridge of the psu
+    ///
+    /// ## Error
+    /// an [`RobotError::InvalidArgument`] for a robot without ros bridge, see [`Robot::set_ros_bridge`].
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::robot::*;
+    /// use inovo_rs::ros_bridge::RuntimeState;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// if bot.runtime_state()? == RuntimeState::Pause {
+    ///     println!("waiting for the operator to resume");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn runtime_state(&mut self) -> Result<RuntimeState, RobotError> {
+        match &mut self.ros_bridge {
+            Some(ros_bridge) => Ok(ros_bridge.get_runtime_state()?),
+            None => Err(RobotError::InvalidArgument(
+                "no ros bridge to read the runtime state".to_string(),
+            )),
+        }
     }
     /// create and run sequence with of inovo arm with default logger
     pub fn defaut_logger(port: u16, host: impl Into<String>) -> Result<Self, RobotError> {
@@ -112,12 +247,250 @@ impl Robot {
     pub fn read(&mut self) -> Result<String, RobotError> {
         Ok(self.stream.read()?)
     }
+    /// tee the socket traffic to a capture file, see [`Stream::set_capture`](socket::Stream::set_capture)
+    pub fn set_capture(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<&mut Self, RobotError> {
+        self.stream.set_capture(path)?;
+        Ok(self)
+    }
+    /// stop teeing the socket traffic to the capture file
+    pub fn stop_capture(&mut self) -> &mut Self {
+        self.stream.stop_capture();
+        self
+    }
+
+    /// log every raw json line sent and received, with the round trip time, to a dedicated logger
+    ///
+    /// the logger is the one registered as `wire {peer addr}`, see [`Logger::register`],
+    /// or a new one with a [`RollingFileTarget`](crate::logger::target::RollingFileTarget) only,
+    /// of level [`LogLevel::Trace`](crate::logger::LogLevel::Trace), so the lines do not reach the console.
+    /// the level of a registered logger is left as is.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// bot.set_wire_trace(true)?;
+    /// // >>> {"op_code": "get", "target": "transform"}
+    /// // <<< {rx: 0, ry: 0, rz: 0, x: 0.1, y: 0.2, z: 0.3} (1.204 ms)
+    /// bot.get_current_transform()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_wire_trace(&mut self, enabled: bool) -> Result<&mut Self, RobotError> {
+        if !enabled {
+            self.stream.set_wire_trace(None);
+            return Ok(self);
+        }
+        let name = format!("wire {}", self.stream.peer_addr()?).replace(":", "-");
+        let logger = match Logger::get(&name) {
+            Some(logger) => logger,
+            None => {
+                let file = crate::logger::target::RollingFileTarget::new(
+                    &name,
+                    1 << 20,
+                    10,
+                    crate::logger::LogLevel::Trace,
+                )?;
+                let logger = Logger::new(vec![Box::new(file)]);
+                Logger::register(name, logger.clone());
+                logger
+            }
+        };
+        Ok(self.set_wire_trace_logger(logger))
+    }
+    /// log every raw json line sent and received to a logger, see [`Robot::set_wire_trace`]
+    pub fn set_wire_trace_logger(&mut self, logger: Logger) -> &mut Self {
+        self.stream.set_wire_trace(Some(logger));
+        self
+    }
+    /// whether the raw json lines are logged, see [`Robot::set_wire_trace`]
+    pub fn is_wire_traced(&self) -> bool {
+        self.stream.is_wire_traced()
+    }
+
+    /// get the motion param last set with [`IvaRobot::set_param`],
+    /// `None` if none was set since connected, the param of the robot is unknown
+    ///
+    /// params set within iva contexts, e.g. with [`IvaRobot::with_set_param`], are not tracked
+    pub fn motion_param(&self) -> Option<&MotionParam> {
+        self.motion_param.as_ref()
+    }
+
+    /// set the motion param, and restore the previous one when the guard is drop
+    ///
+    /// unlike [`IvaRobot::with_set_param`], the previous param is restored with [`IvaRobot::set_param`]
+    /// instead of a robot-side pop, so it is usable outside iva contexts.
+    /// the previous param is the one tracked by [`Robot::motion_param`],
+    /// if unknown the param is not restored, with a warning.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// bot.set_param(MotionParam::new().set_speed(80.0))?;
+    /// {
+    ///     let mut slow = bot.with_param(MotionParam::new().set_speed(10.0))?;
+    ///     slow.linear_relative(Transform::from_z(-20.0))?;
+    /// }
+    /// // back to 80% speed
+    /// bot.linear_relative(Transform::from_z(20.0))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_param(
+        &mut self,
+        motion_param: MotionParam,
+    ) -> Result<ContextGuard<'_, Self, ParamContext>, RobotError> {
+        let previous = self.motion_param.clone();
+        self.set_param(motion_param)?;
+        Ok(ContextGuard::new(self, ParamContext { previous }))
+    }
+}
+
+/// An instruction sent, whose response is not read yet, see [`Robot::send_instruction`]
+pub(crate) struct PendingInstruction {
+    inst: Instruction,
+    /// the json line sent
+    json: Option<String>,
+    start: std::time::Instant,
+    /// the result known without a response, e.g. a failed write
+    res: Option<Result<String, RobotError>>,
+    /// a pop of an orphaned context, never sent, see [`Robot::recover_contexts`]
+    orphan: bool,
+}
+
+impl Robot {
+    /// check, then write an instruction, without reading its response
+    pub(crate) fn send_instruction(&mut self, inst: Instruction) -> PendingInstruction {
+        // the context was already dropped on the robot side, see [`Robot::recover_contexts`]
+        if inst == Instruction::Pop && self.contexts.take_orphan() {
+            return PendingInstruction {
+                inst,
+                json: None,
+                start: std::time::Instant::now(),
+                res: Some(Ok("OK".to_string())),
+                orphan: true,
+            };
+        }
+        let inst = self.apply_speed_cap(inst);
+        // may query the current pose, for a relative motion
+        let checked = self.check_soft_limits(&inst);
+        let start = std::time::Instant::now();
+
+        self.hooks.before(&inst);
+        let (json, res) = match (checked, inst.clone().to_json()) {
+            (_, Ok(_)) if self.cancel.is_cancelled() => (None, Some(Err(RobotError::Cancelled))),
+            (Err(e), _) => (None, Some(Err(e))),
+            (Ok(_), Ok(json)) => {
+                let written = self.write(json.clone()).err().map(Err);
+                (Some(json), written)
+            }
+            (Ok(_), Err(e)) => (None, Some(Err(e.into()))),
+        };
+        PendingInstruction {
+            inst,
+            json,
+            start,
+            res,
+            orphan: false,
+        }
+    }
+    /// read the response of an instruction sent, restarting the iva sequence if the connection dropped
+    pub(crate) fn receive_instruction(
+        &mut self,
+        pending: PendingInstruction,
+    ) -> Result<String, RobotError> {
+        let PendingInstruction {
+            inst,
+            json,
+            start,
+            res,
+            orphan,
+        } = pending;
+        if orphan {
+            return res.unwrap_or_else(|| Ok("OK".to_string()));
+        }
+        let res = match (res, json) {
+            (None, Some(json)) => self
+                .read()
+                .or_else(|e| self.retry_after_restart(e, &inst, json)),
+            (Some(Err(e)), Some(json)) => self.retry_after_restart(e, &inst, json),
+            (Some(res), _) => res,
+            (None, None) => Err(RobotError::InvalidArgument("nothing sent".to_string())),
+        };
+        let latency = start.elapsed();
+        self.hooks.after(&inst, &res, latency);
+        match &res {
+            Ok(res) if res == "OK" => {
+                self.contexts.record(&inst);
+                self.stats.record_instruction(&inst, latency)
+            }
+            Ok(_) => self.stats.record_instruction(&inst, latency),
+            Err(e) => self.stats.record_error(e.category()),
+        }
+
+        res
+    }
 }
 
 impl IvaRobot for Robot {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "iva_instruction", skip_all, fields(op_code = inst.op_code()), err)
+    )]
     fn instruction(&mut self, inst: Instruction) -> Result<String, RobotError> {
-        self.write(inst.to_json()?)?;
-        self.read()
+        let pending = self.send_instruction(inst);
+        self.receive_instruction(pending)
+    }
+
+    fn prepare_sequence(
+        &mut self,
+        command_sequence: CommandSequence,
+    ) -> Result<CommandSequence, RobotError> {
+        self.apply_speed_zones(command_sequence)
+    }
+
+    fn set_param(&mut self, motion_param: MotionParam) -> Result<&mut Self, RobotError> {
+        self.execute(RobotCommand::SetParameter(motion_param.clone()))?;
+        self.motion_param = Some(motion_param);
+        Ok(self)
+    }
+
+    fn response_error(&mut self, res: String) -> RobotError {
+        let error = RobotError::ResponseError(res);
+        self.hooks.error(&error);
+        self.stats.record_error(error.category());
+        error
+    }
+}
+
+/// forward to the robot, so a helper taking `&mut impl IvaRobot` can be given a borrowed robot
+impl<R> IvaRobot for &mut R
+where
+    R: IvaRobot,
+    IvaContext: Context<R>,
+{
+    fn instruction(&mut self, inst: Instruction) -> Result<String, RobotError> {
+        (**self).instruction(inst)
+    }
+    fn response_error(&mut self, res: String) -> RobotError {
+        (**self).response_error(res)
+    }
+    fn prepare_sequence(
+        &mut self,
+        command_sequence: CommandSequence,
+    ) -> Result<CommandSequence, RobotError> {
+        (**self).prepare_sequence(command_sequence)
+    }
+    fn set_param(&mut self, motion_param: MotionParam) -> Result<&mut Self, RobotError> {
+        (**self).set_param(motion_param)?;
+        Ok(self)
     }
 }
 
@@ -129,12 +502,27 @@ where
     /// send an instruction to the robot and read the response
     fn instruction(&mut self, inst: Instruction) -> Result<String, RobotError>;
 
+    /// create the error for an unexpected response,
+    /// implementors can override this to observe rejected responses
+    fn response_error(&mut self, res: String) -> RobotError {
+        RobotError::ResponseError(res)
+    }
+
+    /// rewrite a [`CommandSequence`] before it is enqueued,
+    /// implementors can override this, e.g. [`Robot`] caps the sequences to its [`SpeedZones`]
+    fn prepare_sequence(
+        &mut self,
+        command_sequence: CommandSequence,
+    ) -> Result<CommandSequence, RobotError> {
+        Ok(command_sequence)
+    }
+
     /// send an instruction to the robot and assert the response to be `"OK"`, then return self
     fn instruction_assert_ok(&mut self, inst: Instruction) -> Result<&mut Self, RobotError> {
         let res = self.instruction(inst)?;
         match res.as_str() {
             "OK" => Ok(self),
-            _ => Err(RobotError::ResponseError(res)),
+            _ => Err(self.response_error(res)),
         }
     }
 
@@ -143,7 +531,7 @@ where
         let res = self.instruction(inst)?;
         match T::from_robot(res) {
             Ok(t) => Ok(t),
-            Err(s) => Err(RobotError::ResponseError(s)),
+            Err(s) => Err(self.response_error(s)),
         }
     }
 
@@ -156,6 +544,28 @@ where
     fn sleep(&mut self, second: f64) -> Result<&mut Self, RobotError> {
         self.execute(RobotCommand::Sleep { second })
     }
+    /// instruct the robot to synchronize, returning once all blended motions are physically completed
+    ///
+    /// a motion with a blend returns as soon as the robot starts blending into the next one,
+    /// synchronize before e.g. reading a sensor at the final position.
+    /// ```no_run
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::iva::IOTarget;
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    ///
+    /// bot.set_param(MotionParam::new().set_blend_linear(20.0))?;
+    /// bot.linear(Transform::from_vector([400.0, 0.0, 100.0]))?;
+    /// bot.sync()?;
+    /// let part_present = bot.io_get(IOTarget::Wrist, 0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn sync(&mut self) -> Result<&mut Self, RobotError> {
+        self.execute(RobotCommand::Synchronize)
+    }
 
     /// instruct the robot to set the motion param
     fn set_param(&mut self, motion_param: MotionParam) -> Result<&mut Self, RobotError> {
@@ -194,19 +604,22 @@ where
     fn with_execute(
         &mut self,
         robot_command: RobotCommand,
-    ) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
         self.instruction_assert_ok(Instruction::exec_push(robot_command))?;
         Ok(ContextGuard::new(self, IvaContext))
     }
     /// instruct the robot to enter a context with a sleep
-    fn with_sleep(&mut self, second: f64) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    fn with_sleep(
+        &mut self,
+        second: f64,
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
         self.with_execute(RobotCommand::Sleep { second })
     }
     /// instruct the robot to enter a context with motion param
     fn with_set_param(
         &mut self,
         motion_param: MotionParam,
-    ) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
         self.with_execute(RobotCommand::SetParameter(motion_param))
     }
     /// instruct the robot to enter a context with a motion
@@ -214,7 +627,7 @@ where
         &mut self,
         mode: MotionMode,
         target: Transform,
-    ) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
         self.with_execute(RobotCommand::Motion {
             motion_mode: mode,
             target: target.into(),
@@ -224,21 +637,21 @@ where
     fn with_linear(
         &mut self,
         target: Transform,
-    ) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
         self.with_motion(MotionMode::Linear, target)
     }
     /// instruct the robot to enter a context with a linear relative motion
     fn with_linear_relative(
         &mut self,
         target: Transform,
-    ) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
         self.with_motion(MotionMode::LinearRelative, target)
     }
     /// instruct the robot to enter a context with a joint motion, can take both [`Transform`] and [`JointCoord`] as target
     fn with_joint(
         &mut self,
         target: impl Into<MotionTarget>,
-    ) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
         self.with_execute(RobotCommand::Motion {
             motion_mode: MotionMode::Joint,
             target: target.into(),
@@ -248,10 +661,88 @@ where
     fn with_joint_relative(
         &mut self,
         target: Transform,
-    ) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
         self.with_motion(MotionMode::JointRelative, target)
     }
 
+    /// enter a context with a [`RobotCommand`], run a closure, then pop the context, even if the closure fail
+    fn scoped_execute<T>(
+        &mut self,
+        robot_command: RobotCommand,
+        f: impl FnOnce(&mut Self) -> Result<T, RobotError>,
+    ) -> Result<T, RobotError>
+    where
+        IvaContext: TryContext<Self, Error = RobotError>,
+    {
+        self.with_execute(robot_command)?.scope(f)
+    }
+    /// enter a context with a sleep, run a closure, then pop the context, even if the closure fail
+    fn scoped_sleep<T>(
+        &mut self,
+        second: f64,
+        f: impl FnOnce(&mut Self) -> Result<T, RobotError>,
+    ) -> Result<T, RobotError>
+    where
+        IvaContext: TryContext<Self, Error = RobotError>,
+    {
+        self.with_sleep(second)?.scope(f)
+    }
+    /// enter a context with motion param, run a closure, then pop the context, even if the closure fail
+    fn scoped_set_param<T>(
+        &mut self,
+        motion_param: MotionParam,
+        f: impl FnOnce(&mut Self) -> Result<T, RobotError>,
+    ) -> Result<T, RobotError>
+    where
+        IvaContext: TryContext<Self, Error = RobotError>,
+    {
+        self.with_set_param(motion_param)?.scope(f)
+    }
+    /// enter a context with a linear motion, run a closure, then pop the context, even if the closure fail
+    fn scoped_linear<T>(
+        &mut self,
+        target: Transform,
+        f: impl FnOnce(&mut Self) -> Result<T, RobotError>,
+    ) -> Result<T, RobotError>
+    where
+        IvaContext: TryContext<Self, Error = RobotError>,
+    {
+        self.with_linear(target)?.scope(f)
+    }
+    /// enter a context with a linear relative motion, run a closure, then pop the context, even if the closure fail
+    fn scoped_linear_relative<T>(
+        &mut self,
+        target: Transform,
+        f: impl FnOnce(&mut Self) -> Result<T, RobotError>,
+    ) -> Result<T, RobotError>
+    where
+        IvaContext: TryContext<Self, Error = RobotError>,
+    {
+        self.with_linear_relative(target)?.scope(f)
+    }
+    /// enter a context with a joint motion, run a closure, then pop the context, even if the closure fail
+    fn scoped_joint<T>(
+        &mut self,
+        target: impl Into<MotionTarget>,
+        f: impl FnOnce(&mut Self) -> Result<T, RobotError>,
+    ) -> Result<T, RobotError>
+    where
+        IvaContext: TryContext<Self, Error = RobotError>,
+    {
+        self.with_joint(target)?.scope(f)
+    }
+    /// enter a context with a joint relative motion, run a closure, then pop the context, even if the closure fail
+    fn scoped_joint_relative<T>(
+        &mut self,
+        target: Transform,
+        f: impl FnOnce(&mut Self) -> Result<T, RobotError>,
+    ) -> Result<T, RobotError>
+    where
+        IvaContext: TryContext<Self, Error = RobotError>,
+    {
+        self.with_joint_relative(target)?.scope(f)
+    }
+
     /// instruct the robot to enqueue a [`RobotCommand`]
     fn enqueue(&mut self, robot_command: RobotCommand) -> Result<&mut Self, RobotError> {
         self.instruction_assert_ok(Instruction::enqueue(robot_command))
@@ -260,8 +751,60 @@ where
     fn dequeue(&mut self) -> Result<&mut Self, RobotError> {
         self.instruction_assert_ok(Instruction::dequeue())
     }
+    /// instruct the robot to execute only the first `count` enqueued [`RobotCommand`],
+    /// e.g. to step through a taught sequence during commissioning
+    /// ```no_run
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    ///
+    /// let taught = CommandSequence::new()
+    ///     .then_joint(Transform::from_vector([400.0, 0.0, 300.0]))
+    ///     .then_linear(Transform::from_vector([400.0, 0.0, 100.0]));
+    /// for robot_command in taught {
+    ///     bot.enqueue(robot_command)?;
+    /// }
+    /// while !bot.queued()?.is_empty() {
+    ///     // wait for the operator between every step
+    ///     std::io::stdin().read_line(&mut String::new())?;
+    ///     bot.dequeue_n(1)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn dequeue_n(&mut self, count: u32) -> Result<&mut Self, RobotError> {
+        self.instruction_assert_ok(Instruction::dequeue_n(count))
+    }
+    /// get the [`RobotCommand`] enqueued on the robot side, and not yet dequeued
+    fn queued(&mut self) -> Result<Vec<RobotCommand>, RobotError> {
+        self.get(GetTarget::Queue)
+    }
+    /// instruct the robot to drop the enqueued [`RobotCommand`] without executing them,
+    /// e.g. so an aborted plan does not run on the next dequeue
+    /// ```no_run
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::iva::RobotCommand;
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    ///
+    /// // a plan aborted halfway through enqueuing
+    /// bot.enqueue(RobotCommand::linear(Transform::from_z(300.0)))?;
+    /// assert_eq!(bot.queued()?.len(), 1);
+    ///
+    /// bot.clear_queue()?;
+    /// assert!(bot.queued()?.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn clear_queue(&mut self) -> Result<&mut Self, RobotError> {
+        self.instruction_assert_ok(Instruction::clear_queue())
+    }
     /// instruct the robot to enter a context with by dequeuing all [`RobotCommand`]
-    fn with_dequeue(&mut self) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    fn with_dequeue(&mut self) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
         self.instruction_assert_ok(Instruction::dequeue_push())?;
 
         Ok(ContextGuard::new(self, IvaContext))
@@ -269,6 +812,7 @@ where
 
     /// instruct the robot to execute a [`CommandSequence`]
     fn sequence(&mut self, command_sequence: CommandSequence) -> Result<&mut Self, RobotError> {
+        let command_sequence = self.prepare_sequence(command_sequence)?;
         for robot_command in command_sequence.into_iter() {
             self.enqueue(robot_command)?;
         }
@@ -278,17 +822,40 @@ where
     fn with_sequence(
         &mut self,
         command_sequence: CommandSequence,
-    ) -> Result<ContextGuard<Self, IvaContext>, RobotError> {
+    ) -> Result<ContextGuard<'_, Self, IvaContext>, RobotError> {
+        let command_sequence = self.prepare_sequence(command_sequence)?;
         for robot_command in command_sequence.into_iter() {
             self.enqueue(robot_command)?;
         }
         self.with_dequeue()
     }
+    /// enter a context by executing a [`CommandSequence`], run a closure, then pop the context, even if the closure fail
+    fn scoped_sequence<T>(
+        &mut self,
+        command_sequence: CommandSequence,
+        f: impl FnOnce(&mut Self) -> Result<T, RobotError>,
+    ) -> Result<T, RobotError>
+    where
+        IvaContext: TryContext<Self, Error = RobotError>,
+    {
+        self.with_sequence(command_sequence)?.scope(f)
+    }
 
     /// instruct the robot to pop a context
     fn pop(&mut self) -> Result<&mut Self, RobotError> {
         self.instruction_assert_ok(Instruction::Pop)
     }
+    /// instruct the robot to pop all contexts, e.g. to recover after an aborted run
+    fn pop_all(&mut self) -> Result<&mut Self, RobotError> {
+        self.instruction_assert_ok(Instruction::PopAll)
+    }
+    /// get the number of contexts currently pushed on the robot side
+    ///
+    /// the depth should match the number of live [`IvaContext`] guards,
+    /// it can be used to verify the stack is balanced after an aborted run.
+    fn context_depth(&mut self) -> Result<u32, RobotError> {
+        self.get(GetTarget::ContextDepth)
+    }
 
     /// get the current [`Transform`] of the robot
     fn get_current_transform(&mut self) -> Result<Transform, RobotError> {
@@ -298,10 +865,46 @@ where
     fn get_current_joint(&mut self) -> Result<JointCoord, RobotError> {
         self.get(GetTarget::JointCoord)
     }
+    /// get the current torque of each joint, in Nm, e.g. to detect jams or monitor tool wear
+    fn get_joint_torques(&mut self) -> Result<[f64; 6], RobotError> {
+        self.get(GetTarget::JointTorques)
+    }
+    /// get the current speed of the tcp, e.g. to confirm the arm is stationary
+    fn get_tcp_velocity(&mut self) -> Result<TcpVelocity, RobotError> {
+        self.get(GetTarget::TcpVelocity)
+    }
+    /// get the wrench measured by the wrist force/torque sensor attached to the robot
+    fn get_wrench(&mut self) -> Result<Wrench, RobotError> {
+        self.get(GetTarget::Wrench)
+    }
     /// get data from data dict in robot runtime
     fn get_data<T: FromRobot>(&mut self, key: impl Into<String>) -> Result<T, RobotError> {
         self.get(GetTarget::Data { key: key.into() })
     }
+    /// get many keys of the data dict in one round trip, as the raw response of each key
+    ///
+    /// a value is formatted as the robot prints it, e.g. a pose as `{x: 0.1, ...}`,
+    /// so it parses as with [`IvaRobot::get_data`] with [`FromRobot::from_robot`],
+    /// the keys missing from the data dict are missing from the map.
+    /// ```no_run
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    ///
+    /// let data = bot.get_data_batch(&["part_count", "pick_pose"])?;
+    /// let count = i64::from_robot(data["part_count"].clone()).map_err(RobotError::ResponseError)?;
+    /// let pose = Transform::from_robot(data["pick_pose"].clone()).map_err(RobotError::ResponseError)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn get_data_batch(
+        &mut self,
+        keys: &[impl AsRef<str>],
+    ) -> Result<HashMap<String, String>, RobotError> {
+        self.get(GetTarget::data_batch(keys.iter().map(|key| key.as_ref())))
+    }
     /// get data from robot
     fn get<T: FromRobot>(&mut self, get_target: GetTarget) -> Result<T, RobotError> {
         self.instruction_return(Instruction::Get(get_target))
@@ -320,6 +923,44 @@ where
     fn io_get(&mut self, io_target: IOTarget, port: u16) -> Result<bool, RobotError> {
         self.instruction_return(Instruction::io_get(io_target, port))
     }
+    /// set a digital output high for a duration then low again,
+    /// the timing is handled on the robot side and this return once the pulse is started
+    ///
+    /// the duration must be finite and positive
+    fn pulse_output(
+        &mut self,
+        io_target: IOTarget,
+        port: u16,
+        second: f64,
+    ) -> Result<&mut Self, RobotError> {
+        if !(second.is_finite() && second > 0.0) {
+            return Err(RobotError::InvalidArgument(format!(
+                "pulse of {} second",
+                second
+            )));
+        }
+        self.instruction_assert_ok(Instruction::io_pulse(io_target, port, second))
+    }
+    /// get the digital io states of a range of ports in a single round trip
+    ///
+    /// bit `i` of the returned mask is the state of port `range.start + i`,
+    /// the range can cover 1 to 32 ports
+    fn io_get_bank(&mut self, io_target: IOTarget, range: Range<u16>) -> Result<u32, RobotError> {
+        if range.is_empty() {
+            return Err(RobotError::InvalidArgument(format!(
+                "empty io bank {:?}",
+                range
+            )));
+        }
+        let count = range.end - range.start;
+        if count > 32 {
+            return Err(RobotError::InvalidArgument(format!(
+                "io bank of {} ports does not fit in 32 bits",
+                count
+            )));
+        }
+        self.instruction_return(Instruction::io_get_bank(io_target, range.start, count))
+    }
     /// set the beckhoff io
     fn beckhoff_set(&mut self, port: u16, state: bool) -> Result<&mut Self, RobotError> {
         self.io_set(IOTarget::Beckhoff, port, state)
@@ -336,6 +977,18 @@ where
     fn wrist_get(&mut self, port: u16) -> Result<bool, RobotError> {
         self.io_get(IOTarget::Wrist, port)
     }
+    /// get a range of beckhoff io as a bitmask
+    fn beckhoff_get_bank(&mut self, range: Range<u16>) -> Result<u32, RobotError> {
+        self.io_get_bank(IOTarget::Beckhoff, range)
+    }
+    /// get a range of wrist io as a bitmask
+    fn wrist_get_bank(&mut self, range: Range<u16>) -> Result<u32, RobotError> {
+        self.io_get_bank(IOTarget::Wrist, range)
+    }
+    /// get the beckhoff io modules and their number of ports, see [`BeckhoffModules::validate`]
+    fn beckhoff_modules(&mut self) -> Result<BeckhoffModules, RobotError> {
+        self.get(GetTarget::BeckhoffModules)
+    }
 
     /// activate the robot gripper
     fn gripper_activate(&mut self) -> Result<&mut Self, RobotError> {
@@ -347,11 +1000,40 @@ where
             label: label.into(),
         }))
     }
+    /// set the robot gripper to a width in mm, with a grip force in percent
+    fn gripper_set_width(&mut self, mm: f64, force: f64) -> Result<&mut Self, RobotError> {
+        self.instruction_assert_ok(Instruction::gripper(GripperCommand::SetWidth { mm, force }))
+    }
     /// get the robot gripper width
     fn gripper_get(&mut self) -> Result<f64, RobotError> {
         self.instruction_return(Instruction::gripper(GripperCommand::Get))
     }
 
+    /// turn on the vacuum gripper
+    fn vacuum_on(&mut self) -> Result<&mut Self, RobotError> {
+        self.instruction_assert_ok(Instruction::vacuum(VacuumCommand::On))
+    }
+    /// turn off the vacuum gripper
+    fn vacuum_off(&mut self) -> Result<&mut Self, RobotError> {
+        self.instruction_assert_ok(Instruction::vacuum(VacuumCommand::Off))
+    }
+    /// get the vacuum pressure reported by the robot
+    fn vacuum_pressure(&mut self) -> Result<f64, RobotError> {
+        self.instruction_return(Instruction::vacuum(VacuumCommand::Get))
+    }
+    /// turn on the vacuum gripper, and turn it off when the guard is drop
+    fn with_vacuum(&mut self) -> Result<ContextGuard<'_, Self, VacuumContext>, RobotError> {
+        self.vacuum_on()?;
+        Ok(ContextGuard::new(self, VacuumContext))
+    }
+    /// turn on the vacuum gripper, run a closure, then turn it off, even if the closure fail
+    fn scoped_vacuum<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, RobotError>,
+    ) -> Result<T, RobotError> {
+        self.with_vacuum()?.scope(f)
+    }
+
     /// instruct the robot to perform a custom command and get the return resposne
     fn custom(&mut self, custom_command: CustomCommand) -> Result<String, RobotError> {
         self.instruction(Instruction::custom(custom_command))
@@ -360,10 +1042,14 @@ where
     fn custom_and(&mut self, custom_command: CustomCommand) -> Result<&mut Self, RobotError> {
         self.instruction_assert_ok(Instruction::custom(custom_command))
     }
+    /// instruct the robot to call a [`CustomHandler`] and parse its response
+    fn invoke<H: CustomHandler>(&mut self, request: H::Request) -> Result<H::Response, RobotError> {
+        let custom_command =
+            CustomCommand::from_serialize(&request)?.add_string(CUSTOM_HANDLER_KEY, H::NAME);
+        self.instruction_return(Instruction::custom(custom_command))
+    }
 }
 
-unsafe impl Send for Robot {}
-
 /// A trait for all data structure that can be deserialize from robot response
 pub trait FromRobot: Sized {
     /// parse from robto response string
@@ -380,6 +1066,11 @@ impl FromRobot for i64 {
         res.parse::<i64>().map_err(|e| format!("{}", e))
     }
 }
+impl FromRobot for u32 {
+    fn from_robot(res: String) -> Result<Self, String> {
+        res.parse::<u32>().map_err(|e| format!("{}", e))
+    }
+}
 impl FromRobot for bool {
     fn from_robot(res: String) -> Result<Self, String> {
         match res.as_str() {
@@ -394,21 +1085,205 @@ impl FromRobot for String {
         Ok(res)
     }
 }
+impl FromRobot for [f64; 6] {
+    fn from_robot(res: String) -> Result<Self, String> {
+        parse_list(&res)?
+            .try_into()
+            .map_err(|_| format!("expected 6 values: {}", res))
+    }
+}
+
+/// a json list of commands, as enqueued
+impl FromRobot for Vec<RobotCommand> {
+    fn from_robot(res: String) -> Result<Self, String> {
+        serde_json::from_str(&res).map_err(|e| format!("{}: {}", e, res))
+    }
+}
+
+/// a json object, the string values kept as is and the others as python would print them
+///
+/// e.g. a pose `{"x": 0.1, ...}` is kept as `{x: 0.1, ...}`, as the robot prints it for [`GetTarget::Data`]
+impl FromRobot for HashMap<String, String> {
+    fn from_robot(res: String) -> Result<Self, String> {
+        let object: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&res).map_err(|e| format!("{}: {}", e, res))?;
+        Ok(object
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::String(s) => s,
+                    value => python_format(&value),
+                };
+                (key, value)
+            })
+            .collect())
+    }
+}
+
+/// format a json value as the robot prints it, with the keys of an object unquoted
+fn python_format(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Bool(true) => "True".to_string(),
+        serde_json::Value::Bool(false) => "False".to_string(),
+        serde_json::Value::Null => "None".to_string(),
+        serde_json::Value::String(s) => format!("'{}'", s),
+        serde_json::Value::Array(values) => format!(
+            "[{}]",
+            values
+                .iter()
+                .map(python_format)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        serde_json::Value::Object(object) => format!(
+            "{{{}}}",
+            object
+                .iter()
+                .map(|(key, value)| format!("{}: {}", key, python_format(value)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        value => value.to_string(),
+    }
+}
+
+/// parse a response list of numbers, e.g. `[1.0, 2.0]`
+pub(crate) fn parse_list(res: &str) -> Result<Vec<f64>, String> {
+    res.trim()
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or_else(|| format!("unexpected response: {}", res))?
+        .split(',')
+        .map(|s| s.trim().parse::<f64>().map_err(|e| format!("{}", e)))
+        .collect()
+}
 
 /// context representing iva context
 ///
-/// pop a context in iva when exit
+/// pop a context in iva when exit, a failed pop is logged as an error.
+///
+/// use [`ContextGuard::finish`] to get the error of the pop instead.
+/// ```no_run
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::robot::*;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+///
+/// let mut guard = bot.with_linear_relative(Transform::from_z(50.0))?;
+/// guard.sleep(1.0)?;
+/// guard.finish()?;
+/// # Ok(())
+/// # }
+/// ```
 pub struct IvaContext;
 
 impl Context<Robot> for IvaContext {
     fn context_enter(&mut self, _: &mut Robot) {}
     fn context_drop(&mut self, machine: &mut Robot) {
-        let _ = machine.pop();
+        if let Err(e) = machine.pop() {
+            machine.error(format!("fail to pop context: {}", e));
+        }
+    }
+}
+
+impl TryContext<Robot> for IvaContext {
+    type Error = RobotError;
+    fn try_context_drop(&mut self, machine: &mut Robot) -> Result<(), RobotError> {
+        machine.pop()?;
+        Ok(())
+    }
+}
+
+impl<R: ?Sized> Context<&mut R> for IvaContext
+where
+    IvaContext: Context<R>,
+{
+    fn context_enter(&mut self, machine: &mut &mut R) {
+        Context::<R>::context_enter(self, machine)
+    }
+    fn context_drop(&mut self, machine: &mut &mut R) {
+        Context::<R>::context_drop(self, machine)
+    }
+}
+
+impl<R: ?Sized> TryContext<&mut R> for IvaContext
+where
+    IvaContext: TryContext<R>,
+{
+    type Error = <IvaContext as TryContext<R>>::Error;
+    fn try_context_drop(&mut self, machine: &mut &mut R) -> Result<(), Self::Error> {
+        TryContext::<R>::try_context_drop(self, machine)
+    }
+}
+
+/// context representing a temporary motion param
+///
+/// restore the previous motion param when exit, a failure is logged as an error.
+/// an unknown previous param is not restored.
+///
+/// see [`Robot::with_param`]
+pub struct ParamContext {
+    previous: Option<MotionParam>,
+}
+
+impl Context<Robot> for ParamContext {
+    fn context_enter(&mut self, _: &mut Robot) {}
+    fn context_drop(&mut self, machine: &mut Robot) {
+        if let Err(e) = self.try_context_drop(machine) {
+            machine.error(format!("fail to restore motion param: {}", e));
+        }
+    }
+}
+
+impl TryContext<Robot> for ParamContext {
+    type Error = RobotError;
+    fn try_context_drop(&mut self, machine: &mut Robot) -> Result<(), RobotError> {
+        match self.previous.clone() {
+            Some(previous) => {
+                machine.set_param(previous)?;
+            }
+            None => machine.warn("the previous motion param is unknown, not restored"),
+        }
+        Ok(())
+    }
+}
+
+/// context representing an active vacuum grip
+///
+/// turn off the vacuum when exit, a failure is logged as an error.
+///
+/// use [`ContextGuard::finish`] to get the error instead.
+pub struct VacuumContext;
+
+impl<R: IvaRobot + ?Sized> Context<R> for VacuumContext
+where
+    IvaContext: Context<R>,
+{
+    fn context_enter(&mut self, _: &mut R) {}
+    fn context_drop(&mut self, machine: &mut R) {
+        if let Err(e) = machine.vacuum_off() {
+            machine.error(format!("fail to turn off vacuum: {}", e));
+        }
+    }
+}
+
+impl<R: IvaRobot + ?Sized> TryContext<R> for VacuumContext
+where
+    IvaContext: Context<R>,
+{
+    type Error = RobotError;
+    fn try_context_drop(&mut self, machine: &mut R) -> Result<(), RobotError> {
+        machine.vacuum_off()?;
+        Ok(())
     }
 }
 
 /// Representing Robot Error
+///
+/// non exhaustive, as the `modbus` and `force_torque` features add a variant each
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum RobotError {
     #[error(transparent)]
     SocketError(#[from] std::io::Error),
@@ -416,6 +1291,45 @@ pub enum RobotError {
     RosBridgeError(#[from] RosBridgeError),
     #[error(transparent)]
     JsonSer(#[from] serde_json::Error),
+    /// only with the `modbus` feature
+    #[cfg(feature = "modbus")]
+    #[error(transparent)]
+    ModbusError(#[from] crate::modbus::ModbusError),
+    /// only with the `force_torque` feature
+    #[cfg(feature = "force_torque")]
+    #[error(transparent)]
+    ForceTorqueError(#[from] crate::force_torque::ForceTorqueError),
     #[error("Response Error")]
     ResponseError(String),
+    #[error("Invalid Argument: {0}")]
+    InvalidArgument(String),
+    #[error("Cancelled")]
+    Cancelled,
+    #[error("Soft Limit: {0}")]
+    SoftLimit(#[from] LimitViolation),
+    #[error("Timeout: {0}")]
+    Timeout(String),
+    #[error("Context Mismatch: {depth} on robot, {tracked} tracked")]
+    ContextMismatch { depth: usize, tracked: usize },
+}
+
+impl RobotError {
+    /// get the category of the error, for reporting
+    pub fn category(&self) -> &'static str {
+        match self {
+            RobotError::SocketError(_) => "socket",
+            RobotError::RosBridgeError(_) => "ros_bridge",
+            RobotError::JsonSer(_) => "json",
+            #[cfg(feature = "modbus")]
+            RobotError::ModbusError(_) => "modbus",
+            #[cfg(feature = "force_torque")]
+            RobotError::ForceTorqueError(_) => "force_torque",
+            RobotError::ResponseError(_) => "response",
+            RobotError::InvalidArgument(_) => "invalid_argument",
+            RobotError::Cancelled => "cancelled",
+            RobotError::SoftLimit(_) => "soft_limit",
+            RobotError::Timeout(_) => "timeout",
+            RobotError::ContextMismatch { .. } => "context_mismatch",
+        }
+    }
 }
@@ -0,0 +1,95 @@
+use crate::geometry::Transform;
+use crate::iva::{Instruction, MotionTarget};
+use crate::logger::Logable;
+use crate::robot::{CommandSequence, IvaRobot, PendingInstruction, Robot, RobotError};
+
+/// A motion running on the robot, whose completion is not read yet, see [`Robot::linear_async`]
+///
+/// the robot is borrowed until the motion is done, the motion is waited on drop, and a failure is logged as an error.
+pub struct MotionHandle<'a> {
+    robot: &'a mut Robot,
+    pending: Option<PendingInstruction>,
+}
+
+impl MotionHandle<'_> {
+    /// check the robot reported the completion, without blocking
+    pub fn is_done(&mut self) -> Result<bool, RobotError> {
+        match self.pending {
+            Some(_) => Ok(self.robot.stream.is_readable()?),
+            None => Ok(true),
+        }
+    }
+    /// wait for the completion of the motion
+    pub fn wait(mut self) -> Result<(), RobotError> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<(), RobotError> {
+        let Some(pending) = self.pending.take() else {
+            return Ok(());
+        };
+        let res = self.robot.receive_instruction(pending)?;
+        match res.as_str() {
+            "OK" => Ok(()),
+            _ => Err(self.robot.response_error(res)),
+        }
+    }
+}
+
+impl Drop for MotionHandle<'_> {
+    fn drop(&mut self) {
+        if let Err(e) = self.finish() {
+            self.robot.error(format!("fail to complete motion: {}", e));
+        }
+    }
+}
+
+impl Robot {
+    /// start a linear motion, returning once the robot accepted it, see [`Robot::sequence_async`]
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    ///
+    /// let motion = bot.linear_async(Transform::from_vector([400.0, 0.0, 300.0]))?;
+    /// // locate the next part while the arm moves
+    /// let pick = Transform::from_vector([400.0, 100.0, 50.0]);
+    /// motion.wait()?;
+    ///
+    /// bot.linear(pick)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn linear_async(&mut self, target: Transform) -> Result<MotionHandle<'_>, RobotError> {
+        self.sequence_async(CommandSequence::new().then_linear(target))
+    }
+    /// start a joint motion, returning once the robot accepted it, see [`Robot::sequence_async`]
+    pub fn joint_async(
+        &mut self,
+        target: impl Into<MotionTarget>,
+    ) -> Result<MotionHandle<'_>, RobotError> {
+        self.sequence_async(CommandSequence::new().then_joint(target))
+    }
+    /// start a [`CommandSequence`], returning once the robot accepted it
+    ///
+    /// the commands are enqueued, followed by a synchronize as the completion marker,
+    /// then the dequeue is sent without waiting for its response, read by [`MotionHandle::wait`].
+    /// the connection is busy until then, use a [`QueryChannel`](crate::robot::QueryChannel) to query the robot meanwhile.
+    pub fn sequence_async(
+        &mut self,
+        command_sequence: CommandSequence,
+    ) -> Result<MotionHandle<'_>, RobotError> {
+        let command_sequence = self.prepare_sequence(command_sequence)?.then_sync();
+        for robot_command in command_sequence.into_iter() {
+            self.enqueue(robot_command)?;
+        }
+        let pending = self.send_instruction(Instruction::dequeue());
+        Ok(MotionHandle {
+            robot: self,
+            pending: Some(pending),
+        })
+    }
+}
@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::geometry::deg_to_rad;
+use crate::geometry::{deg_to_rad, rad_to_deg};
 
 /// Data structure representing robot's motion parameter
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct MotionParam {
     #[serde(default)]
@@ -18,6 +18,9 @@ pub struct MotionParam {
     tcp_speed_linear: f64,
     #[serde(default)]
     tcp_speed_angular: f64,
+    /// the velocity cap of each joint, in rad/s, a `null` joint is uncapped, not sent if never capped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    joint_speed: Option<[Option<f64>; 6]>,
 }
 
 impl MotionParam {
@@ -37,6 +40,18 @@ impl MotionParam {
         self.speed = percent.clamp(MotionParam::MIN_PRECENT, MotionParam::MAX_PRECENT) / 100.0;
         self
     }
+    /// get the speed in percent, 0 if unset
+    pub fn get_speed(&self) -> f64 {
+        self.speed * 100.0
+    }
+    /// clamp the speed to a cap in percent, an unset speed is set to the cap
+    pub(crate) fn cap_speed(mut self, percent: f64) -> MotionParam {
+        let cap = percent.clamp(MotionParam::MIN_PRECENT, MotionParam::MAX_PRECENT) / 100.0;
+        if self.speed <= 0.0 || self.speed > cap {
+            self.speed = cap;
+        }
+        self
+    }
     /// set accel with percent, clamp to [`MotionParam::MIN_PRECENT`] and [`MotionParam::MAX_PRECENT`]
     pub fn set_accel(mut self, percent: f64) -> MotionParam {
         self.accel = percent.clamp(MotionParam::MIN_PRECENT, MotionParam::MAX_PRECENT) / 100.0;
@@ -63,4 +78,42 @@ impl MotionParam {
             deg_to_rad(deg.clamp(MotionParam::MIN_ANGLE, MotionParam::MAX_ANGLE));
         self
     }
+    /// set the velocity cap of a joint from 1 to 6 in degree/s, clamp to [`MotionParam::MIN_ANGLE`] and [`MotionParam::MAX_ANGLE`]
+    ///
+    /// the other joints are left as is, the caps are only sent when set.
+    /// a joint not from 1 to 6 is ignored.
+    /// ```
+    /// use inovo_rs::robot::MotionParam;
+    ///
+    /// let param = MotionParam::new().set_speed(80.0).set_joint_speed(6, 45.0);
+    /// assert_eq!(param.get_joint_speed()[5], Some(45.0));
+    ///
+    /// let json = serde_json::to_value(MotionParam::new()).unwrap();
+    /// assert!(json.get("joint_speed").is_none());
+    /// ```
+    pub fn set_joint_speed(mut self, joint: usize, deg: f64) -> MotionParam {
+        if let Some(i) = joint.checked_sub(1).filter(|i| *i < 6) {
+            self.joint_speed.get_or_insert([None; 6])[i] = Some(deg_to_rad(
+                deg.clamp(MotionParam::MIN_ANGLE, MotionParam::MAX_ANGLE),
+            ));
+        }
+        self
+    }
+    /// set the velocity cap of the wrist joints 4 to 6 in degree/s, e.g. for a large reorientation
+    pub fn set_wrist_speed(self, deg: f64) -> MotionParam {
+        (4..=6).fold(self, |param, joint| param.set_joint_speed(joint, deg))
+    }
+    /// remove the velocity caps of all joints
+    ///
+    /// the joints are sent as uncapped, so the caps the robot already received are lifted
+    pub fn clear_joint_speed(mut self) -> MotionParam {
+        self.joint_speed = Some([None; 6]);
+        self
+    }
+    /// get the velocity cap of each joint, in degree/s
+    pub fn get_joint_speed(&self) -> [Option<f64>; 6] {
+        self.joint_speed
+            .unwrap_or_default()
+            .map(|cap| cap.map(rad_to_deg))
+    }
 }
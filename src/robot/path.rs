@@ -0,0 +1,165 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::geometry::*;
+use crate::iva::*;
+use crate::robot::{CommandSequence, RobotError};
+
+/// A point of a [`SampledPath`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathPoint {
+    /// the index of the command moving to the point, in the sequence
+    pub command: usize,
+    pub motion_mode: MotionMode,
+    pub transform: Transform,
+}
+
+/// The tcp path of a [`CommandSequence`], sampled to review a planned path before running it on the arm
+///
+/// the motions are sampled along the straight line between their start and target,
+/// the joint motions are drawn straight as well, while the arm bends away from it.
+/// the path is written as csv of the poses, or as ply of the positions for a point cloud viewer.
+/// # Example
+/// ```no_run
+/// use inovo_rs::robot::*;
+/// use inovo_rs::geometry::*;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let start = Transform::from_vector([400.0, 0.0, 300.0]).then_rx(180.0);
+/// let path = CommandSequence::new()
+///     .then_linear(Transform::from_vector([400.0, 200.0, 100.0]).then_rx(180.0))
+///     .then_linear_relative(Transform::from_z(-50.0));
+///
+/// let sampled = SampledPath::sample(&path, start, 5.0)?;
+/// sampled.write_csv("path.csv")?;
+/// sampled.write_ply("path.ply")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SampledPath {
+    points: Vec<PathPoint>,
+}
+
+impl SampledPath {
+    /// sample the tcp path of a sequence from a start pose, with a point at most every `step_mm`
+    ///
+    /// the start pose is the first point, of command 0.
+    /// ## Error
+    /// an [`RobotError::InvalidArgument`] for a step not positive,
+    /// or a motion with a joint coord target, having no tcp pose without the kinematics of the arm
+    pub fn sample(
+        sequence: &CommandSequence,
+        start: Transform,
+        step_mm: f64,
+    ) -> Result<Self, RobotError> {
+        if step_mm.is_nan() || step_mm <= 0.0 {
+            return Err(RobotError::InvalidArgument(format!(
+                "sample step of {} mm",
+                step_mm
+            )));
+        }
+        let mut points = vec![PathPoint {
+            command: 0,
+            motion_mode: MotionMode::Joint,
+            transform: start.clone(),
+        }];
+        let mut from = start;
+        for (command, robot_command) in sequence.iter().enumerate() {
+            let (motion_mode, target) = match robot_command.motion() {
+                Some((motion_mode, MotionTarget::Transform(target))) => (motion_mode, target),
+                Some(_) => {
+                    return Err(RobotError::InvalidArgument(format!(
+                        "command {} has a joint coord target, the tcp pose is unknown",
+                        command
+                    )))
+                }
+                None => continue,
+            };
+            let to = match motion_mode {
+                MotionMode::Linear | MotionMode::Joint => target.clone(),
+                MotionMode::LinearRelative | MotionMode::JointRelative => {
+                    from.clone().then_relative(target.clone())
+                }
+            };
+            let steps = (from.translation_distance(&to) / step_mm).ceil().max(1.0) as usize;
+            for i in 1..=steps {
+                let t = i as f64 / steps as f64;
+                // the rotation of a half turn has no unique interpolation, hold the target one
+                let transform = from.interpolate(&to, t).unwrap_or_else(|| {
+                    let [a, b] = [from.get_vector(), to.get_vector()];
+                    to.clone()
+                        .set_vector([0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t))
+                });
+                points.push(PathPoint {
+                    command,
+                    motion_mode: motion_mode.clone(),
+                    transform,
+                });
+            }
+            from = to;
+        }
+        Ok(Self { points })
+    }
+
+    /// get the sampled points
+    pub fn points(&self) -> &[PathPoint] {
+        &self.points
+    }
+    /// get the length of the path, in mm
+    pub fn length_mm(&self) -> f64 {
+        self.points
+            .windows(2)
+            .map(|pair| pair[0].transform.translation_distance(&pair[1].transform))
+            .sum()
+    }
+
+    /// format as csv, a header then a row of command, motion mode, x, y, z in mm and rx, ry, rz in degree per point
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("command,motion_mode,x,y,z,rx,ry,rz\n");
+        for point in self.points.iter() {
+            let [x, y, z] = point.transform.get_vector();
+            let [rx, ry, rz] = point.transform.get_euler();
+            let mode = serde_json::to_value(&point.motion_mode).unwrap_or_default();
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{}",
+                point.command,
+                mode.as_str().unwrap_or_default(),
+                x,
+                y,
+                z,
+                rx,
+                ry,
+                rz
+            );
+        }
+        csv
+    }
+    /// format as ascii ply, the positions in mm as vertices joined by edges in path order
+    pub fn to_ply(&self) -> String {
+        let mut ply = format!(
+            "ply\nformat ascii 1.0\nelement vertex {}\nproperty double x\nproperty double y\nproperty double z\n\
+             element edge {}\nproperty int vertex1\nproperty int vertex2\nend_header\n",
+            self.points.len(),
+            self.points.len().saturating_sub(1)
+        );
+        for point in self.points.iter() {
+            let [x, y, z] = point.transform.get_vector();
+            let _ = writeln!(ply, "{} {} {}", x, y, z);
+        }
+        for i in 1..self.points.len() {
+            let _ = writeln!(ply, "{} {}", i - 1, i);
+        }
+        ply
+    }
+
+    /// write the path to a csv file, see [`SampledPath::to_csv`]
+    pub fn write_csv(&self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        std::fs::write(path, self.to_csv())
+    }
+    /// write the path to a ply file, see [`SampledPath::to_ply`]
+    pub fn write_ply(&self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        std::fs::write(path, self.to_ply())
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::geometry::{JointCoord, Transform};
+use crate::iva::{GetTarget, IOTarget, Instruction};
+use crate::logger::{Logable, Logger};
+use crate::robot::{FromRobot, IvaRobot, Robot, RobotError, RobotHandle, RobotSnapshot};
+use crate::socket;
+
+/// A second iva connection dedicated to queries, polling the status while a motion blocks the primary connection
+///
+/// the robot program opens the second connection to another port, running only the queries,
+/// so they are answered during a motion.
+/// the channel is cheap to clone and send across threads, and refuses every instruction but a query,
+/// see [`Instruction::is_query`].
+/// # Example
+/// ```no_run
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::robot::*;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+/// let query = QueryChannel::accept(50004, None)?;
+///
+/// let hmi = query.clone();
+/// std::thread::spawn(move || loop {
+///     if let Ok(transform) = hmi.get_current_transform() {
+///         println!("{}", transform);
+///     }
+///     std::thread::sleep(std::time::Duration::from_millis(100));
+/// });
+///
+/// bot.linear(Transform::from_z(300.0))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct QueryChannel {
+    robot: RobotHandle,
+}
+
+impl QueryChannel {
+    /// create a query channel from a robot connection
+    pub fn new(robot: Robot) -> Self {
+        Self {
+            robot: RobotHandle::new(robot),
+        }
+    }
+    /// listen on a port and accept the query connection of the robot program
+    ///
+    /// the logger default to the one of the listener.
+    pub fn accept(port: u16, logger: Option<Logger>) -> Result<Self, RobotError> {
+        let mut listener = socket::Listener::new(port, logger.clone())?;
+        let stream = listener.accept(logger.clone())?;
+        let logger = logger.unwrap_or_else(|| listener.get_logger().clone());
+        Ok(Self::new(Robot::new(stream, logger)))
+    }
+
+    /// send a query instruction and parse the response
+    ///
+    /// ## Error
+    /// an [`RobotError::InvalidArgument`] for an instruction changing the robot, e.g. a motion.
+    pub fn query<T: FromRobot>(&self, inst: Instruction) -> Result<T, RobotError> {
+        if !inst.is_query() {
+            return Err(RobotError::InvalidArgument(format!(
+                "not a query: {}",
+                inst.op_code()
+            )));
+        }
+        self.robot.with(|bot| bot.instruction_return(inst))
+    }
+    /// get data from robot
+    pub fn get<T: FromRobot>(&self, get_target: GetTarget) -> Result<T, RobotError> {
+        self.query(Instruction::Get(get_target))
+    }
+
+    /// get the current [`Transform`] of the robot
+    pub fn get_current_transform(&self) -> Result<Transform, RobotError> {
+        self.get(GetTarget::Transform)
+    }
+    /// get the current [`JointCoord`] of the robot
+    pub fn get_current_joint(&self) -> Result<JointCoord, RobotError> {
+        self.get(GetTarget::JointCoord)
+    }
+    /// get data from data dict in robot runtime
+    pub fn get_data<T: FromRobot>(&self, key: impl Into<String>) -> Result<T, RobotError> {
+        self.get(GetTarget::Data { key: key.into() })
+    }
+    /// get many keys of the data dict in one round trip, see [`IvaRobot::get_data_batch`]
+    pub fn get_data_batch(
+        &self,
+        keys: &[impl AsRef<str>],
+    ) -> Result<HashMap<String, String>, RobotError> {
+        self.get(GetTarget::data_batch(keys.iter().map(|key| key.as_ref())))
+    }
+    /// get the digital io state of the robot
+    pub fn io_get(&self, io_target: IOTarget, port: u16) -> Result<bool, RobotError> {
+        self.robot.with(|bot| bot.io_get(io_target, port))
+    }
+    /// get the digital io states of a range of ports in a single round trip, see [`IvaRobot::io_get_bank`]
+    pub fn io_get_bank(&self, io_target: IOTarget, range: Range<u16>) -> Result<u32, RobotError> {
+        self.robot.with(|bot| bot.io_get_bank(io_target, range))
+    }
+    /// get the pose, joints, io and gripper sampled at once, see [`Robot::snapshot`]
+    ///
+    /// the motion param of the snapshot is the one of the query connection, the default.
+    pub fn snapshot(&self) -> Result<RobotSnapshot, RobotError> {
+        self.robot.with(|bot| bot.snapshot())
+    }
+}
+
+impl From<Robot> for QueryChannel {
+    fn from(robot: Robot) -> Self {
+        Self::new(robot)
+    }
+}
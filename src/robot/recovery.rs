@@ -0,0 +1,152 @@
+use crate::iva::Instruction;
+use crate::logger::Logable;
+use crate::robot::{IvaRobot, Robot, RobotError};
+use crate::socket;
+
+/// How the context stack is recovered after a reconnect, see [`Robot::reconnect`]
+///
+/// the robot-side stack is compared with the contexts entered on this side, whose guards are still alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContextRecovery {
+    /// pop every robot-side context, the guards still alive then exit without a pop
+    #[default]
+    PopAll,
+    /// enter again the contexts the robot lost, and pop the ones it has in excess
+    ///
+    /// the commands entering the lost contexts are executed again, e.g. a motion is performed again.
+    Replay,
+    /// only check the depths match, failing with [`RobotError::ContextMismatch`] otherwise
+    Verify,
+}
+
+/// the contexts entered on this side, tracked from the instructions sent
+#[derive(Debug, Default)]
+pub(crate) struct ContextTracker {
+    /// the instructions entering each context, e.g. the enqueues then the dequeue
+    stack: Vec<Vec<Instruction>>,
+    /// the commands enqueued since the last dequeue
+    queue: Vec<Instruction>,
+    /// the contexts dropped by a recovery, whose pop is not sent
+    orphaned: usize,
+}
+
+impl ContextTracker {
+    /// record an instruction the robot accepted
+    pub(crate) fn record(&mut self, inst: &Instruction) {
+        match inst {
+            Instruction::Enqueue(_) => self.queue.push(inst.clone()),
+            Instruction::Dequeue { enter_context, .. } if *enter_context > 0.0 => {
+                let mut entry = std::mem::take(&mut self.queue);
+                entry.push(inst.clone());
+                self.stack.push(entry);
+            }
+            Instruction::Dequeue {
+                count: Some(count), ..
+            } => {
+                let count = (*count as usize).min(self.queue.len());
+                self.queue.drain(..count);
+            }
+            Instruction::Dequeue { .. } | Instruction::ClearQueue => self.queue.clear(),
+            Instruction::Execute { enter_context, .. } if *enter_context > 0.0 => {
+                self.stack.push(vec![inst.clone()])
+            }
+            Instruction::Pop => {
+                self.stack.pop();
+            }
+            Instruction::PopAll => self.stack.clear(),
+            _ => {}
+        }
+    }
+    /// check a pop is for a context dropped by a recovery, consuming it
+    ///
+    /// the contexts entered after the recovery are on top, and popped first.
+    pub(crate) fn take_orphan(&mut self) -> bool {
+        if !self.stack.is_empty() || self.orphaned == 0 {
+            return false;
+        }
+        self.orphaned -= 1;
+        true
+    }
+}
+
+impl Robot {
+    /// set how the context stack is recovered after a reconnect, default to [`ContextRecovery::PopAll`]
+    pub fn set_context_recovery(&mut self, recovery: ContextRecovery) -> &mut Self {
+        self.context_recovery = recovery;
+        self
+    }
+    /// get how the context stack is recovered after a reconnect
+    pub fn context_recovery(&self) -> ContextRecovery {
+        self.context_recovery
+    }
+    /// get the number of contexts entered on this side and not yet popped
+    pub fn tracked_context_depth(&self) -> usize {
+        self.contexts.stack.len()
+    }
+
+    /// replace the connection after it dropped, then recover the context stack, see [`Robot::recover_contexts`]
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::logger::Logger;
+    /// use inovo_rs::robot::*;
+    /// use inovo_rs::socket::Listener;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut listener = Listener::new(50003, None)?;
+    /// let mut bot = Robot::new(listener.accept(None)?, Logger::get_or_create("psu002"));
+    /// bot.set_context_recovery(ContextRecovery::Replay);
+    ///
+    /// let mut guard = bot.with_linear(Transform::from_z(300.0))?;
+    /// if let Err(RobotError::SocketError(_)) = guard.sleep(1.0) {
+    ///     // the robot program restarted and connected again, the motion is performed again
+    ///     guard.reconnect(listener.accept(None)?)?;
+    /// }
+    /// guard.finish()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reconnect(&mut self, stream: socket::Stream) -> Result<&mut Self, RobotError> {
+        self.stream = stream;
+        self.stats.record_reconnect();
+        self.logger.info("reconnected");
+        self.recover_contexts()
+    }
+
+    /// bring the robot-side context stack in sync with the contexts entered on this side,
+    /// as set with [`Robot::set_context_recovery`]
+    ///
+    /// the commands enqueued but not yet dequeued are forgotten.
+    pub fn recover_contexts(&mut self) -> Result<&mut Self, RobotError> {
+        let depth = self.context_depth()? as usize;
+        // the recovery instructions are not tracked
+        let mut tracker = std::mem::take(&mut self.contexts);
+        tracker.queue.clear();
+        let tracked = tracker.stack.len();
+
+        let res = match self.context_recovery {
+            _ if depth == tracked => Ok(()),
+            ContextRecovery::Verify => Err(RobotError::ContextMismatch { depth, tracked }),
+            ContextRecovery::PopAll => {
+                tracker.orphaned += tracker.stack.drain(..).count();
+                self.pop_all().map(|_| ())
+            }
+            ContextRecovery::Replay if depth > tracked => {
+                (tracked..depth).try_for_each(|_| self.pop().map(|_| ()))
+            }
+            ContextRecovery::Replay => tracker.stack[depth..]
+                .iter()
+                .flatten()
+                .try_for_each(|inst| self.instruction_assert_ok(inst.clone()).map(|_| ())),
+        };
+        self.contexts = tracker;
+        match &res {
+            Ok(()) => self.logger.info(format!(
+                "context stack recovered, {} on robot, {} tracked",
+                depth, tracked
+            )),
+            Err(e) => self.error(format!("fail to recover context stack: {}", e)),
+        }
+        res.map(|_| self)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::iva::Instruction;
+use crate::logger::Logable;
+use crate::robot::{Robot, RobotError};
+use crate::ros_bridge::{RosBridge, RuntimeState};
+use crate::socket;
+
+/// The restart of the iva sequence when it stops unexpectedly, see [`Robot::set_auto_restart`]
+///
+/// when the connection drops, the runtime state is read with [`RosBridge`],
+/// and only a stopped sequence is restarted, a paused or disabled runtime is left to the operator.
+pub struct AutoRestart {
+    ros_bridge: RosBridge,
+    listener: socket::Listener,
+    procedure_name: String,
+    max_restarts: u32,
+    restarts: u32,
+}
+
+impl AutoRestart {
+    /// create a new auto restart of the `"iva"` sequence, listening on a port for the new connection
+    ///
+    /// ## Argument
+    /// - `port`: the port the iva sequence connects to
+    /// - `host`: host of the psu
+    pub fn new(port: u16, host: impl Into<String>) -> Result<Self, RobotError> {
+        Ok(Self {
+            ros_bridge: RosBridge::new(host, 1000),
+            listener: socket::Listener::new(port, None)?,
+            procedure_name: "iva".to_string(),
+            max_restarts: 3,
+            restarts: 0,
+        })
+    }
+    /// set the name of the sequence to restart, default to `"iva"`
+    pub fn set_procedure_name(mut self, procedure_name: impl Into<String>) -> Self {
+        self.procedure_name = procedure_name.into();
+        self
+    }
+    /// set the maximum number of restarts, default to 3
+    pub fn set_max_restarts(mut self, max_restarts: u32) -> Self {
+        self.max_restarts = max_restarts;
+        self
+    }
+    /// get the number of restarts done
+    pub fn restarts(&self) -> u32 {
+        self.restarts
+    }
+}
+
+impl Robot {
+    /// restart the iva sequence when it stops unexpectedly, `None` to disable
+    ///
+    /// after the context stack is recovered, see [`Robot::reconnect`], a query failing with a socket error
+    /// is sent again on the new connection, see [`Instruction::is_query`].
+    /// other instructions may have run in part before the connection dropped, e.g. a relative motion,
+    /// so they are not sent again, and the socket error is returned for the caller to re-plan.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// bot.set_auto_restart(Some(AutoRestart::new(50003, "psu002")?.set_max_restarts(5)));
+    ///
+    /// // resumed even if the sequence is stopped in between
+    /// loop {
+    ///     bot.sleep(1.0)?;
+    /// }
+    /// # }
+    /// ```
+    pub fn set_auto_restart(&mut self, auto_restart: Option<AutoRestart>) -> &mut Self {
+        self.auto_restart = auto_restart;
+        self
+    }
+    /// get the auto restart of the iva sequence
+    pub fn auto_restart(&self) -> Option<&AutoRestart> {
+        self.auto_restart.as_ref()
+    }
+
+    /// after a socket error, restart the iva sequence then send the json line of a query again and read the response
+    pub(crate) fn retry_after_restart(
+        &mut self,
+        error: RobotError,
+        inst: &Instruction,
+        json: String,
+    ) -> Result<String, RobotError> {
+        if !matches!(error, RobotError::SocketError(_)) {
+            return Err(error);
+        }
+        // taken while restarting, the recovery does not restart again
+        let Some(mut auto_restart) = self.auto_restart.take() else {
+            return Err(error);
+        };
+        let restarted = self.restart_sequence(&mut auto_restart);
+        self.auto_restart = Some(auto_restart);
+        match restarted {
+            Ok(true) if inst.is_query() => self.write(json).and_then(|_| self.read()),
+            Ok(true) => {
+                self.warn("sequence restarted, the instruction is not sent again");
+                Err(error)
+            }
+            Ok(false) => Err(error),
+            Err(e) => {
+                self.error(format!("fail to restart sequence: {}", e));
+                Err(error)
+            }
+        }
+    }
+
+    /// restart a stopped sequence and accept its connection, `false` if not stopped
+    fn restart_sequence(&mut self, auto_restart: &mut AutoRestart) -> Result<bool, RobotError> {
+        let state = auto_restart.ros_bridge.get_runtime_state()?;
+        if state != RuntimeState::Stop {
+            self.warn(format!("connection dropped, runtime {:?}", state));
+            return Ok(false);
+        }
+        if auto_restart.restarts >= auto_restart.max_restarts {
+            self.error(format!(
+                "sequence stopped, {} restarts already",
+                auto_restart.restarts
+            ));
+            return Ok(false);
+        }
+        auto_restart.restarts += 1;
+        self.warn(format!(
+            "sequence stopped, restarting {} ({}/{})",
+            auto_restart.procedure_name, auto_restart.restarts, auto_restart.max_restarts
+        ));
+        auto_restart
+            .ros_bridge
+            .run_sequence(&auto_restart.procedure_name)?;
+        let stream = auto_restart.listener.accept(Some(self.logger.clone()))?;
+        self.reconnect(stream)?;
+        Ok(true)
+    }
+}
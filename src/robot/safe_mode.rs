@@ -0,0 +1,106 @@
+use crate::context::{Context, ContextGuard, TryContext};
+use crate::iva::{Instruction, RobotCommand};
+use crate::logger::Logable;
+use crate::robot::{IvaRobot, Robot, RobotError};
+
+impl Robot {
+    /// cap the speed while people are inside the cell, until the guard is drop
+    ///
+    /// every motion param sent is clamped to `max_speed_percent`, an unset speed included,
+    /// and the current motion param is sent again clamped when entering.
+    /// the uncapped motion param is sent again when exiting.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// bot.set_param(MotionParam::new().set_speed(80.0))?;
+    /// {
+    ///     let mut setup = bot.safe_mode(10.0)?;
+    ///     // sent at 10% speed
+    ///     setup.set_param(MotionParam::new().set_speed(50.0))?;
+    ///     setup.linear_relative(Transform::from_z(-20.0))?;
+    /// }
+    /// // back to 50% speed
+    /// bot.linear_relative(Transform::from_z(20.0))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn safe_mode(
+        &mut self,
+        max_speed_percent: f64,
+    ) -> Result<ContextGuard<'_, Self, SafeModeContext>, RobotError> {
+        if !(max_speed_percent.is_finite() && max_speed_percent > 0.0) {
+            return Err(RobotError::InvalidArgument(format!(
+                "invalid speed cap: {}%",
+                max_speed_percent
+            )));
+        }
+        let previous = self.speed_cap;
+        let cap = previous.map_or(max_speed_percent, |cap| cap.min(max_speed_percent));
+        self.speed_cap = Some(cap);
+        self.warn(format!("safe mode: speed capped to {}%", cap));
+        if let Err(e) = self.set_param(self.motion_param.clone().unwrap_or_default()) {
+            self.speed_cap = previous;
+            return Err(e);
+        }
+        Ok(ContextGuard::new(self, SafeModeContext { previous }))
+    }
+
+    /// get the speed cap of the safe mode in percent, if any
+    pub fn speed_cap(&self) -> Option<f64> {
+        self.speed_cap
+    }
+
+    /// clamp the motion param of an instruction to the speed cap
+    pub(crate) fn apply_speed_cap(&self, inst: Instruction) -> Instruction {
+        let Some(cap) = self.speed_cap else {
+            return inst;
+        };
+        let cap_command = |command| match command {
+            RobotCommand::SetParameter(param) => RobotCommand::SetParameter(param.cap_speed(cap)),
+            command => command,
+        };
+        match inst {
+            Instruction::Execute {
+                robot_command,
+                enter_context,
+            } => Instruction::Execute {
+                robot_command: cap_command(robot_command),
+                enter_context,
+            },
+            Instruction::Enqueue(robot_command) => Instruction::Enqueue(cap_command(robot_command)),
+            inst => inst,
+        }
+    }
+}
+
+/// context representing the safe mode
+///
+/// restore the previous speed cap and send the uncapped motion param again when exit, a failure is logged as an error.
+///
+/// see [`Robot::safe_mode`]
+pub struct SafeModeContext {
+    previous: Option<f64>,
+}
+
+impl Context<Robot> for SafeModeContext {
+    fn context_enter(&mut self, _: &mut Robot) {}
+    fn context_drop(&mut self, machine: &mut Robot) {
+        if let Err(e) = self.try_context_drop(machine) {
+            machine.error(format!("fail to exit safe mode: {}", e));
+        }
+    }
+}
+
+impl TryContext<Robot> for SafeModeContext {
+    type Error = RobotError;
+    fn try_context_drop(&mut self, machine: &mut Robot) -> Result<(), RobotError> {
+        machine.speed_cap = self.previous;
+        machine.logger.info("safe mode exited");
+        machine.set_param(machine.motion_param.clone().unwrap_or_default())?;
+        Ok(())
+    }
+}
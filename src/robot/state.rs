@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::geometry::{JointCoord, Transform};
+use crate::iva::{GetTarget, IOTarget};
+use crate::robot::{FromRobot, IvaRobot, MotionParam, Robot, RobotError, RobotHandle};
+
+/// A snapshot of the robot state
+#[derive(Debug, Clone, PartialEq)]
+pub struct RobotState {
+    pub transform: Transform,
+    pub joints: JointCoord,
+    pub timestamp: SystemTime,
+}
+
+impl RobotState {
+    /// query the state of a robot
+    pub fn query(bot: &mut Robot) -> Result<Self, RobotError> {
+        Ok(Self {
+            transform: bot.get_current_transform()?,
+            joints: bot.get_current_joint()?,
+            timestamp: SystemTime::now(),
+        })
+    }
+}
+
+/// A consistent view of the robot, sampled by the robot in one exchange, see [`Robot::snapshot`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RobotSnapshot {
+    pub transform: Transform,
+    pub joints: JointCoord,
+    /// the digital io of each target, bit `i` is the state of port `i`
+    pub io: HashMap<IOTarget, u32>,
+    /// the gripper width, `None` without a gripper
+    pub gripper: Option<f64>,
+    /// the motion param tracked by [`Robot::motion_param`], `None` if unknown
+    pub params: Option<MotionParam>,
+    pub timestamp: SystemTime,
+}
+
+impl From<RobotSnapshot> for RobotState {
+    fn from(snapshot: RobotSnapshot) -> Self {
+        Self {
+            transform: snapshot.transform,
+            joints: snapshot.joints,
+            timestamp: snapshot.timestamp,
+        }
+    }
+}
+
+impl Robot {
+    /// get the pose, joints, io and gripper sampled at once by the robot, in one round trip
+    ///
+    /// the robot responds [`GetTarget::Snapshot`] with a json object of the responses to
+    /// `transform`, `joint_coord` and `gripper` as strings, and the io masks under `beckhoff` and `wrist`.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::iva::IOTarget;
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// let snapshot = bot.snapshot()?;
+    /// let part_present = snapshot.io[&IOTarget::Beckhoff] & 1 << 3 != 0;
+    /// println!("{} {:?} {}", snapshot.transform, snapshot.gripper, part_present);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn snapshot(&mut self) -> Result<RobotSnapshot, RobotError> {
+        let fields: HashMap<String, String> = self.get(GetTarget::Snapshot)?;
+        let timestamp = SystemTime::now();
+        let field = |key: &str| {
+            fields
+                .get(key)
+                .cloned()
+                .ok_or_else(|| RobotError::ResponseError(format!("snapshot without {}", key)))
+        };
+        fn parse<T: FromRobot>(res: String) -> Result<T, RobotError> {
+            T::from_robot(res).map_err(RobotError::ResponseError)
+        }
+
+        let gripper = match field("gripper")? {
+            res if res == "None" => None,
+            res => Some(parse(res)?),
+        };
+        let mut io = HashMap::new();
+        for (target, key) in [(IOTarget::Beckhoff, "beckhoff"), (IOTarget::Wrist, "wrist")] {
+            io.insert(target, parse(field(key)?)?);
+        }
+        Ok(RobotSnapshot {
+            transform: parse(field("transform")?)?,
+            joints: parse(field("joint_coord")?)?,
+            io,
+            gripper,
+            params: self.motion_param().cloned(),
+            timestamp,
+        })
+    }
+}
+
+/// the robot a background monitor queries
+pub(crate) enum StateSource {
+    /// a dedicated connection, never contending with the motion commands
+    Owned(Box<Robot>),
+    /// a shared robot, the queries are interleaved with the other commands
+    Shared(RobotHandle),
+}
+
+impl StateSource {
+    /// run a function with the robot, locking a shared one
+    pub(crate) fn with<T>(&mut self, f: impl FnOnce(&mut Robot) -> T) -> T {
+        match self {
+            StateSource::Owned(bot) => f(bot),
+            StateSource::Shared(handle) => handle.with(f),
+        }
+    }
+    /// get back the dedicated robot connection, if any
+    pub(crate) fn into_robot(self) -> Option<Robot> {
+        match self {
+            StateSource::Owned(robot) => Some(*robot),
+            StateSource::Shared(_) => None,
+        }
+    }
+}
+
+/// A background thread polling at a fixed rate, the loop of the monitors of a [`StateSource`]
+///
+/// the poll is called right away then every period, skipping the missed periods when it falls behind,
+/// until it breaks or the poller is stopped. the thread gives back the state it polled with.
+/// it waits on a stop channel between the polls, so a stop does not wait for the end of a period.
+pub(crate) struct Poller<S> {
+    /// dropped to stop the thread
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<S>>,
+}
+
+impl<S: Send + 'static> Poller<S> {
+    /// spawn the thread, polling with a state every period
+    pub(crate) fn spawn(
+        period: Duration,
+        mut state: S,
+        mut poll: impl FnMut(&mut S) -> ControlFlow<()> + Send + 'static,
+    ) -> Self {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = std::thread::spawn(move || {
+            let mut deadline = Instant::now();
+            while poll(&mut state).is_continue() {
+                // skip the missed periods when falling behind
+                deadline = (deadline + period).max(Instant::now());
+                match stopped.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    _ => break,
+                }
+            }
+            state
+        });
+        Self {
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+}
+
+impl<S> Poller<S> {
+    /// whether the thread finished, after a break of the poll
+    pub(crate) fn is_finished(&self) -> bool {
+        self.thread.as_ref().is_none_or(|t| t.is_finished())
+    }
+    /// stop the thread and wait for it, returning the state, `None` if already stopped
+    pub(crate) fn stop(&mut self) -> Option<std::thread::Result<S>> {
+        self.stop.take();
+        self.thread.take().map(|thread| thread.join())
+    }
+}
+
+impl<S> Drop for Poller<S> {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+/// A builder of a background thread, publishing the [`RobotState`] at a fixed rate
+///
+/// the streamer stops after the first error, which is published as the last message,
+/// even if the receiver is full.
+/// # Example
+/// ```no_run
+/// use inovo_rs::robot::*;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// // a second iva connection, for monitoring only
+/// let monitor = Robot::defaut_logger(50004, "psu002")?;
+/// let stream = StateStreamer::new().set_rate_hz(20.0).spawn(monitor)?;
+///
+/// for state in stream.iter().take(100) {
+///     let state = state?;
+///     println!("{:?} {}", state.timestamp, state.transform);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StateStreamer {
+    rate_hz: f64,
+    capacity: usize,
+}
+
+impl Default for StateStreamer {
+    fn default() -> Self {
+        Self {
+            rate_hz: 10.0,
+            capacity: 64,
+        }
+    }
+}
+
+impl StateStreamer {
+    /// create a new streamer, at 10 Hz with a capacity of 64 states
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// set the rate of the queries, in Hz
+    pub fn set_rate_hz(mut self, rate_hz: f64) -> Self {
+        self.rate_hz = rate_hz;
+        self
+    }
+    /// set the number of states kept for the receiver, the newest states are skipped when full
+    pub fn set_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// stream the state of a dedicated robot connection
+    pub fn spawn(self, robot: Robot) -> Result<StateStream, RobotError> {
+        self.spawn_source(StateSource::Owned(Box::new(robot)))
+    }
+    /// stream the state of a shared robot, interleaving the queries with the other commands
+    pub fn spawn_shared(self, handle: RobotHandle) -> Result<StateStream, RobotError> {
+        self.spawn_source(StateSource::Shared(handle))
+    }
+
+    fn spawn_source(self, source: StateSource) -> Result<StateStream, RobotError> {
+        if !(self.rate_hz.is_finite() && self.rate_hz > 0.0) {
+            return Err(RobotError::InvalidArgument(format!(
+                "invalid state rate: {} Hz",
+                self.rate_hz
+            )));
+        }
+        let period = Duration::from_secs_f64(1.0 / self.rate_hz);
+        let (sender, receiver) = mpsc::sync_channel(self.capacity);
+        let error = Arc::new(Mutex::new(None));
+
+        let thread_error = error.clone();
+        let poller = Poller::spawn(period, source, move |source| {
+            let state = source.with(RobotState::query);
+            let failed = state.is_err();
+            match sender.try_send(state) {
+                Err(mpsc::TrySendError::Disconnected(_)) => ControlFlow::Break(()),
+                // the receiver is full, keep the error to be received after the pending states
+                Err(mpsc::TrySendError::Full(Err(e))) => {
+                    *thread_error.lock().unwrap() = Some(e);
+                    ControlFlow::Break(())
+                }
+                Ok(_) if failed => ControlFlow::Break(()),
+                Ok(_) | Err(mpsc::TrySendError::Full(_)) => ControlFlow::Continue(()),
+            }
+        });
+
+        Ok(StateStream {
+            receiver,
+            error,
+            poller,
+        })
+    }
+}
+
+/// The receiving end of a [`StateStreamer`], stopping the streamer on drop
+pub struct StateStream {
+    receiver: Receiver<Result<RobotState, RobotError>>,
+    /// the final error of the streamer, if the receiver was full
+    error: Arc<Mutex<Option<RobotError>>>,
+    poller: Poller<StateSource>,
+}
+
+impl StateStream {
+    /// wait for the next state, `None` if the streamer stopped
+    pub fn recv(&self) -> Option<Result<RobotState, RobotError>> {
+        self.receiver.recv().ok().or_else(|| self.take_error())
+    }
+    /// wait for the next state with a timeout, `None` if timeout or the streamer stopped
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Result<RobotState, RobotError>> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(state) => Some(state),
+            Err(mpsc::RecvTimeoutError::Timeout) => None,
+            Err(mpsc::RecvTimeoutError::Disconnected) => self.take_error(),
+        }
+    }
+    /// get the next state without waiting
+    pub fn try_recv(&self) -> Option<Result<RobotState, RobotError>> {
+        match self.receiver.try_recv() {
+            Ok(state) => Some(state),
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => self.take_error(),
+        }
+    }
+    /// drain the pending states, returning the newest
+    pub fn latest(&self) -> Option<Result<RobotState, RobotError>> {
+        let latest = self.receiver.try_iter().last();
+        self.take_error().or(latest)
+    }
+    /// iterate over the states, until the streamer stopped
+    pub fn iter(&self) -> impl Iterator<Item = Result<RobotState, RobotError>> + '_ {
+        std::iter::from_fn(|| self.recv())
+    }
+
+    /// take the final error, kept by the streamer when the receiver was full
+    fn take_error(&self) -> Option<Result<RobotState, RobotError>> {
+        self.error.lock().unwrap().take().map(Err)
+    }
+
+    /// stop the streamer, returning the dedicated robot connection if any
+    pub fn stop(mut self) -> Option<Robot> {
+        self.poller.stop()?.ok()?.into_robot()
+    }
+}
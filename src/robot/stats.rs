@@ -0,0 +1,183 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::iva::Instruction;
+#[cfg(feature = "metrics")]
+use crate::metrics::Metrics;
+use crate::robot::Robot;
+
+/// number of latest latencies kept per op code for the percentiles
+pub const STATS_WINDOW: usize = 1024;
+
+/// The statistics of a [`Robot`], see [`Robot::stats`]
+#[derive(Debug, Clone, Default)]
+pub struct RobotStats {
+    /// instruction statistics, by op code
+    pub instructions: BTreeMap<String, InstructionStats>,
+    /// number of motion commands sent, executed or enqueued
+    pub motions: u64,
+    /// number of errors, by category
+    pub errors: BTreeMap<String, u64>,
+    /// time since the robot connected, or since the statistics were reset
+    pub uptime: Duration,
+}
+
+/// The statistics of an op code
+///
+/// the percentiles are over the latest [`STATS_WINDOW`] instructions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InstructionStats {
+    /// number of instructions answered
+    pub count: u64,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl InstructionStats {
+    fn new(count: u64, window: &VecDeque<Duration>) -> Self {
+        let mut sorted: Vec<Duration> = window.iter().copied().collect();
+        sorted.sort();
+        let percentile = |p: f64| {
+            let i = ((sorted.len() as f64 * p).ceil() as usize).saturating_sub(1);
+            sorted.get(i).copied().unwrap_or_default()
+        };
+        Self {
+            count,
+            p50: percentile(0.50),
+            p90: percentile(0.90),
+            p99: percentile(0.99),
+            max: sorted.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// the internal collector of the statistics of a [`Robot`], the single recording point,
+/// forwarding to the metrics if any
+#[derive(Debug)]
+pub(crate) struct StatsCollector {
+    since: Instant,
+    counts: BTreeMap<&'static str, u64>,
+    latencies: BTreeMap<&'static str, VecDeque<Duration>>,
+    motions: u64,
+    errors: BTreeMap<&'static str, u64>,
+    #[cfg(feature = "metrics")]
+    metrics: Option<Metrics>,
+}
+
+impl Default for StatsCollector {
+    fn default() -> Self {
+        Self {
+            since: Instant::now(),
+            counts: BTreeMap::new(),
+            latencies: BTreeMap::new(),
+            motions: 0,
+            errors: BTreeMap::new(),
+            #[cfg(feature = "metrics")]
+            metrics: None,
+        }
+    }
+}
+
+impl StatsCollector {
+    pub(crate) fn record_instruction(&mut self, inst: &Instruction, latency: Duration) {
+        let op_code = inst.op_code();
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = &self.metrics {
+            metrics.record_instruction(op_code, latency);
+        }
+        *self.counts.entry(op_code).or_default() += 1;
+        let window = self.latencies.entry(op_code).or_default();
+        if window.len() == STATS_WINDOW {
+            window.pop_front();
+        }
+        window.push_back(latency);
+
+        if let Instruction::Execute { robot_command, .. } | Instruction::Enqueue(robot_command) =
+            inst
+        {
+            if robot_command.motion().is_some() {
+                self.motions += 1;
+            }
+        }
+    }
+    pub(crate) fn record_error(&mut self, category: &'static str) {
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = &self.metrics {
+            metrics.record_error(category);
+        }
+        *self.errors.entry(category).or_default() += 1;
+    }
+    /// the reconnects are only counted by the metrics
+    pub(crate) fn record_reconnect(&mut self) {
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = &self.metrics {
+            metrics.record_reconnect();
+        }
+    }
+
+    fn snapshot(&self) -> RobotStats {
+        RobotStats {
+            instructions: self
+                .counts
+                .iter()
+                .map(|(op_code, count)| {
+                    let window = self.latencies.get(op_code).cloned().unwrap_or_default();
+                    (op_code.to_string(), InstructionStats::new(*count, &window))
+                })
+                .collect(),
+            motions: self.motions,
+            errors: self
+                .errors
+                .iter()
+                .map(|(category, count)| (category.to_string(), *count))
+                .collect(),
+            uptime: self.since.elapsed(),
+        }
+    }
+}
+
+impl Robot {
+    /// get the statistics of the instructions sent since connected
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::robot::*;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    /// bot.sleep(0.5)?;
+    ///
+    /// let stats = bot.stats();
+    /// let execute = &stats.instructions["execute"];
+    /// println!("{} execute, p99 {:?}, up {:?}", execute.count, execute.p99, stats.uptime);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&self) -> RobotStats {
+        self.stats.snapshot()
+    }
+    /// reset the statistics, including the uptime, the metrics are kept
+    pub fn reset_stats(&mut self) -> &mut Self {
+        let stats = StatsCollector::default();
+        #[cfg(feature = "metrics")]
+        let stats = StatsCollector {
+            metrics: self.stats.metrics.take(),
+            ..stats
+        };
+        self.stats = stats;
+        self
+    }
+
+    /// set the metrics the robot report instructions, latency and errors to
+    #[cfg(feature = "metrics")]
+    pub fn set_metrics(&mut self, metrics: Metrics) -> &mut Self {
+        self.stats.metrics = Some(metrics);
+        self
+    }
+    /// get the metrics the robot report to
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> Option<&Metrics> {
+        self.stats.metrics.as_ref()
+    }
+}
@@ -0,0 +1,230 @@
+use std::ops::ControlFlow;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::robot::{AutoRestart, IvaRobot, Poller, RobotError, RobotHandle};
+
+/// The health of the cell, as seen by a [`Supervisor`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Health {
+    Healthy,
+    /// the robot answers, but slowly or with errors
+    Degraded {
+        reason: String,
+    },
+    /// the robot does not answer
+    Down,
+}
+
+/// The severity of an error of the heartbeat, see [`Supervisor::set_classifier`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// not affecting the health
+    Ignore,
+    /// degrading the health, down after [`Supervisor::set_max_failures`] in a row
+    Degraded,
+    /// the robot is down
+    Fatal,
+}
+
+/// the default classification, a socket error is fatal, a cancellation is ignored
+pub fn classify_error(error: &RobotError) -> ErrorClass {
+    match error {
+        RobotError::SocketError(_) => ErrorClass::Fatal,
+        RobotError::Cancelled => ErrorClass::Ignore,
+        _ => ErrorClass::Degraded,
+    }
+}
+
+/// A builder of a background thread, watching the health of a shared robot with a heartbeat
+///
+/// the heartbeat is a query of the context depth, interleaved with the other commands.
+/// the restart of the iva sequence is installed on the robot, so a dropped connection is restored
+/// by the heartbeat as well as by the application.
+/// # Example
+/// ```no_run
+/// use inovo_rs::robot::*;
+/// use std::time::Duration;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// let handle = RobotHandle::new(Robot::defaut_logger(50003, "psu002")?);
+/// let supervisor = Supervisor::new()
+///     .set_interval(Duration::from_millis(500))
+///     .set_auto_restart(AutoRestart::new(50003, "psu002")?)
+///     .spawn(handle.clone())?;
+///
+/// while let Some(health) = supervisor.recv() {
+///     match health {
+///         Health::Healthy => println!("cell ok"),
+///         Health::Degraded { reason } => println!("cell degraded: {}", reason),
+///         Health::Down => println!("cell down"),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Supervisor {
+    interval: Duration,
+    heartbeat_timeout: Duration,
+    max_failures: u32,
+    classifier: fn(&RobotError) -> ErrorClass,
+    auto_restart: Option<AutoRestart>,
+}
+
+impl Default for Supervisor {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            heartbeat_timeout: Duration::from_millis(500),
+            max_failures: 3,
+            classifier: classify_error,
+            auto_restart: None,
+        }
+    }
+}
+
+impl Supervisor {
+    /// create a new supervisor, with a heartbeat every second
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// set the interval of the heartbeat
+    pub fn set_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+    /// set the round trip above which the heartbeat degrades the health, default to 500 ms
+    pub fn set_heartbeat_timeout(mut self, timeout: Duration) -> Self {
+        self.heartbeat_timeout = timeout;
+        self
+    }
+    /// set the number of degrading errors in a row before down, default to 3
+    pub fn set_max_failures(mut self, max_failures: u32) -> Self {
+        self.max_failures = max_failures.max(1);
+        self
+    }
+    /// set the classification of the errors of the heartbeat, default to [`classify_error`]
+    pub fn set_classifier(mut self, classifier: fn(&RobotError) -> ErrorClass) -> Self {
+        self.classifier = classifier;
+        self
+    }
+    /// set the restart of the iva sequence, installed on the robot, see [`Robot::set_auto_restart`](crate::robot::Robot::set_auto_restart)
+    pub fn set_auto_restart(mut self, auto_restart: AutoRestart) -> Self {
+        self.auto_restart = Some(auto_restart);
+        self
+    }
+
+    /// start supervising a shared robot, starting healthy
+    pub fn spawn(mut self, handle: RobotHandle) -> Result<SupervisorTask, RobotError> {
+        if self.interval.is_zero() {
+            return Err(RobotError::InvalidArgument(
+                "invalid heartbeat interval: 0s".to_string(),
+            ));
+        }
+        if let Some(auto_restart) = self.auto_restart.take() {
+            handle.with(|bot| {
+                bot.set_auto_restart(Some(auto_restart));
+            });
+        }
+        let health = Arc::new(Mutex::new(Health::Healthy));
+        let (sender, receiver) = mpsc::channel();
+
+        let thread_health = health.clone();
+        let poller = Poller::spawn(self.interval, 0, move |failures| {
+            let next = self.heartbeat(&handle, failures);
+            let mut current = thread_health.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(next) = next.filter(|next| *next != *current) {
+                handle.with(|bot| match &next {
+                    Health::Healthy => bot.logger.info("cell healthy"),
+                    Health::Degraded { reason } => {
+                        bot.logger.warn(format!("cell degraded: {}", reason))
+                    }
+                    Health::Down => bot.logger.error("cell down"),
+                });
+                *current = next.clone();
+                // the changes are not needed by every application
+                let _ = sender.send(next);
+            }
+            ControlFlow::Continue(())
+        });
+
+        Ok(SupervisorTask {
+            health,
+            receiver,
+            poller,
+        })
+    }
+
+    /// send a heartbeat, returning the health, `None` for an ignored error
+    fn heartbeat(&self, handle: &RobotHandle, failures: &mut u32) -> Option<Health> {
+        let start = Instant::now();
+        let res = handle.with(|bot| bot.context_depth());
+        let elapsed = start.elapsed();
+        let error = match res {
+            Ok(_) => {
+                *failures = 0;
+                return match elapsed > self.heartbeat_timeout {
+                    true => Some(Health::Degraded {
+                        reason: format!("slow heartbeat, {:.0} ms", elapsed.as_secs_f64() * 1e3),
+                    }),
+                    false => Some(Health::Healthy),
+                };
+            }
+            Err(e) => e,
+        };
+        match (self.classifier)(&error) {
+            ErrorClass::Ignore => None,
+            ErrorClass::Fatal => Some(Health::Down),
+            ErrorClass::Degraded => {
+                *failures += 1;
+                match *failures >= self.max_failures {
+                    true => Some(Health::Down),
+                    false => Some(Health::Degraded {
+                        reason: match error {
+                            RobotError::ResponseError(res) => {
+                                format!("unexpected response: {}", res)
+                            }
+                            e => e.to_string(),
+                        },
+                    }),
+                }
+            }
+        }
+    }
+}
+
+/// A running [`Supervisor`], the single source of truth for the health of the cell, stopping on drop
+pub struct SupervisorTask {
+    health: Arc<Mutex<Health>>,
+    receiver: Receiver<Health>,
+    /// the heartbeat, with the number of degrading errors in a row
+    poller: Poller<u32>,
+}
+
+impl SupervisorTask {
+    /// get the current health
+    pub fn health(&self) -> Health {
+        self.health
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+    /// check the current health is [`Health::Healthy`]
+    pub fn is_healthy(&self) -> bool {
+        self.health() == Health::Healthy
+    }
+    /// wait for the next change of health, `None` if the supervisor stopped
+    pub fn recv(&self) -> Option<Health> {
+        self.receiver.recv().ok()
+    }
+    /// wait for the next change of health with a timeout, `None` if timeout or the supervisor stopped
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Health> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+
+    /// stop the supervisor
+    pub fn stop(mut self) {
+        let _ = self.poller.stop();
+    }
+}
@@ -0,0 +1,168 @@
+use std::time::{Duration, Instant};
+
+use crate::geometry::{rad_to_deg, Transform};
+use crate::robot::{parse_list, FromRobot, IvaRobot, Robot, RobotError};
+
+/// The speed of the tcp, see [`IvaRobot::get_tcp_velocity`](crate::robot::IvaRobot::get_tcp_velocity)
+///
+/// the robot responds `[linear m/s, angular rad/s]`.
+/// # Example
+/// ```
+/// use inovo_rs::robot::*;
+///
+/// let velocity = TcpVelocity::from_robot("[0.0001, 0.0]".to_string()).unwrap();
+/// assert!((velocity.linear_mm_s - 0.1).abs() < 1e-9);
+/// assert!(velocity.is_stationary(0.5, 0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TcpVelocity {
+    /// linear speed in mm/s
+    pub linear_mm_s: f64,
+    /// angular speed in degree/s
+    pub angular_deg_s: f64,
+}
+
+impl TcpVelocity {
+    /// whether both speeds are within the tolerances, e.g. before opening a door interlock
+    pub fn is_stationary(&self, tolerance_mm_s: f64, tolerance_deg_s: f64) -> bool {
+        self.linear_mm_s.abs() <= tolerance_mm_s && self.angular_deg_s.abs() <= tolerance_deg_s
+    }
+}
+
+impl FromRobot for TcpVelocity {
+    fn from_robot(res: String) -> Result<Self, String> {
+        match parse_list(&res)?[..] {
+            [linear, angular] => Ok(Self {
+                linear_mm_s: linear * 1000.0,
+                angular_deg_s: rad_to_deg(angular),
+            }),
+            _ => Err(format!("expected 2 values: {}", res)),
+        }
+    }
+}
+
+/// The tolerance of a settled arm, see [`Robot::wait_until_settled`]
+///
+/// the arm is settled when the tcp speed stays within the velocity tolerance,
+/// and the tcp within the pose tolerance of where it stopped, for the whole settle window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SettleTolerance {
+    /// linear speed in mm/s
+    pub linear_mm_s: f64,
+    /// angular speed in degree/s
+    pub angular_deg_s: f64,
+    /// drift of the tcp position in mm
+    pub position_mm: f64,
+    /// drift of the tcp orientation in degree
+    pub rotation_deg: f64,
+    /// the time the arm stays still
+    pub window: Duration,
+    /// the interval between the polls
+    pub interval: Duration,
+}
+
+impl Default for SettleTolerance {
+    fn default() -> Self {
+        Self {
+            linear_mm_s: 0.5,
+            angular_deg_s: 0.5,
+            position_mm: 0.05,
+            rotation_deg: 0.05,
+            window: Duration::from_millis(100),
+            interval: Duration::from_millis(20),
+        }
+    }
+}
+
+impl SettleTolerance {
+    /// create a new tolerance, of 0.5 mm/s and 0.05 mm for 100 ms, polled every 20 ms
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// set the tolerance of the tcp speed
+    pub fn set_velocity(mut self, linear_mm_s: f64, angular_deg_s: f64) -> Self {
+        self.linear_mm_s = linear_mm_s;
+        self.angular_deg_s = angular_deg_s;
+        self
+    }
+    /// set the tolerance of the drift of the tcp
+    pub fn set_pose(mut self, position_mm: f64, rotation_deg: f64) -> Self {
+        self.position_mm = position_mm;
+        self.rotation_deg = rotation_deg;
+        self
+    }
+    /// set the time the arm stays still
+    pub fn set_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+    /// set the interval between the polls
+    pub fn set_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+impl Robot {
+    /// poll the tcp speed and pose until the arm is stationary within a tolerance, returning the settled pose
+    ///
+    /// e.g. before a precision measurement or a camera trigger.
+    ///
+    /// ## Error
+    /// a [`RobotError::Timeout`] if the arm did not settle in time.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::geometry::*;
+    /// use inovo_rs::robot::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// let mut bot = Robot::defaut_logger(50003, "psu002")?;
+    ///
+    /// bot.linear(Transform::from_vector([400.0, 0.0, 200.0]))?;
+    /// let tolerance = SettleTolerance::new().set_window(Duration::from_millis(250));
+    /// let pose = bot.wait_until_settled(tolerance, Duration::from_secs(2))?;
+    /// println!("measuring at {}", pose);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_until_settled(
+        &mut self,
+        tolerance: SettleTolerance,
+        timeout: Duration,
+    ) -> Result<Transform, RobotError> {
+        let deadline = Instant::now() + timeout;
+        // the time and the pose the arm stopped at
+        let mut still: Option<(Instant, Transform)> = None;
+        loop {
+            let velocity = self.get_tcp_velocity()?;
+            let pose = self.get_current_transform()?;
+            let now = Instant::now();
+
+            still = match still {
+                _ if !velocity.is_stationary(tolerance.linear_mm_s, tolerance.angular_deg_s) => {
+                    None
+                }
+                Some((since, stopped))
+                    if stopped.translation_distance(&pose) <= tolerance.position_mm
+                        && stopped.rotation_distance_deg(&pose) <= tolerance.rotation_deg =>
+                {
+                    Some((since, stopped))
+                }
+                _ => Some((now, pose.clone())),
+            };
+            if let Some((since, _)) = &still {
+                if now - *since >= tolerance.window {
+                    return Ok(pose);
+                }
+            }
+            if now >= deadline {
+                return Err(RobotError::Timeout(format!(
+                    "not settled after {:.3} s",
+                    timeout.as_secs_f64()
+                )));
+            }
+            std::thread::sleep(tolerance.interval);
+        }
+    }
+}
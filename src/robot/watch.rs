@@ -0,0 +1,337 @@
+use std::fmt::Display;
+use std::ops::{ControlFlow, Range};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::iva::IOTarget;
+use crate::robot::{FromRobot, IvaRobot, Poller, Robot, RobotError, RobotHandle, StateSource};
+
+/// A change of a value of the data dict, see [`Robot::watch_data`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataChange<T> {
+    /// the new value
+    pub value: T,
+    /// the value before, `None` for the first value read
+    pub previous: Option<T>,
+    pub timestamp: SystemTime,
+}
+
+impl Robot {
+    /// watch a key of the data dict on a dedicated robot connection, publishing its value on every change
+    ///
+    /// the key is polled every `interval`, the first value read is published as a change from `None`.
+    /// the watch stops after the first error, e.g. a value failing to parse, which is published as the last message.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::robot::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// // a second iva connection, for monitoring only
+    /// let monitor = Robot::defaut_logger(50004, "psu002")?;
+    /// // the robot program counts the parts it placed
+    /// let parts = monitor.watch_data::<i64>("parts_placed", Duration::from_millis(100))?;
+    ///
+    /// for change in parts.iter() {
+    ///     println!("part {} placed", change?.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_data<T>(
+        self,
+        key: impl Into<String>,
+        interval: Duration,
+    ) -> Result<DataWatch<T>, RobotError>
+    where
+        T: FromRobot + Clone + PartialEq + Send + 'static,
+    {
+        DataWatch::spawn(StateSource::Owned(Box::new(self)), key.into(), interval)
+    }
+}
+
+impl RobotHandle {
+    /// watch a key of the data dict of a shared robot, interleaving the polls with the other commands,
+    /// see [`Robot::watch_data`]
+    pub fn watch_data<T>(
+        &self,
+        key: impl Into<String>,
+        interval: Duration,
+    ) -> Result<DataWatch<T>, RobotError>
+    where
+        T: FromRobot + Clone + PartialEq + Send + 'static,
+    {
+        DataWatch::spawn(StateSource::Shared(self.clone()), key.into(), interval)
+    }
+}
+
+/// The receiving end of a data watch, stopping the polls on drop
+pub struct DataWatch<T> {
+    receiver: Receiver<Result<DataChange<T>, RobotError>>,
+    poller: Poller<StateSource>,
+}
+
+impl<T> DataWatch<T>
+where
+    T: FromRobot + Clone + PartialEq + Send + 'static,
+{
+    fn spawn(source: StateSource, key: String, interval: Duration) -> Result<Self, RobotError> {
+        if interval.is_zero() {
+            return Err(RobotError::InvalidArgument(
+                "invalid watch interval: 0s".to_string(),
+            ));
+        }
+        let (sender, receiver) = mpsc::channel();
+
+        let mut last: Option<T> = None;
+        let poller = Poller::spawn(interval, source, move |source| {
+            let change = match source.with(|bot| bot.get_data::<T>(key.clone())) {
+                Ok(value) if last.as_ref() == Some(&value) => return ControlFlow::Continue(()),
+                Ok(value) => Ok(DataChange {
+                    previous: last.replace(value.clone()),
+                    value,
+                    timestamp: SystemTime::now(),
+                }),
+                Err(e) => Err(e),
+            };
+            let failed = change.is_err();
+            if sender.send(change).is_err() || failed {
+                return ControlFlow::Break(());
+            }
+            ControlFlow::Continue(())
+        });
+
+        Ok(Self { receiver, poller })
+    }
+}
+
+impl<T> DataWatch<T> {
+    /// wait for the next change, `None` if the watch stopped
+    pub fn recv(&self) -> Option<Result<DataChange<T>, RobotError>> {
+        self.receiver.recv().ok()
+    }
+    /// wait for the next change with a timeout, `None` if timeout or the watch stopped
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Result<DataChange<T>, RobotError>> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+    /// get the next change without waiting
+    pub fn try_recv(&self) -> Option<Result<DataChange<T>, RobotError>> {
+        self.receiver.try_recv().ok()
+    }
+    /// iterate over the changes, until the watch stopped
+    pub fn iter(&self) -> impl Iterator<Item = Result<DataChange<T>, RobotError>> + '_ {
+        self.receiver.iter()
+    }
+
+    /// stop the watch, returning the dedicated robot connection if any
+    pub fn stop(mut self) -> Option<Robot> {
+        self.poller.stop()?.ok()?.into_robot()
+    }
+}
+
+/// An edge of a digital io, see [`Robot::watch_io`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IOEdge {
+    pub target: IOTarget,
+    pub port: u16,
+    /// the new state
+    pub state: bool,
+    /// the time the new state was first read, before the debounce
+    pub timestamp: SystemTime,
+}
+
+impl IOEdge {
+    /// check the io went from low to high
+    pub fn is_rising(&self) -> bool {
+        self.state
+    }
+}
+
+impl Display for IOEdge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let target = match self.target {
+            IOTarget::Beckhoff => "beckhoff",
+            IOTarget::Wrist => "wrist",
+        };
+        write!(
+            f,
+            "{} port {}: {} -> {}",
+            target, self.port, !self.state, self.state
+        )
+    }
+}
+
+/// A builder of a background thread, polling digital io and publishing their edges, see [`Robot::watch_io`]
+#[derive(Debug, Clone)]
+pub struct IOWatcher {
+    targets: Vec<(IOTarget, Range<u16>)>,
+    interval: Duration,
+    debounce: Duration,
+}
+
+impl IOWatcher {
+    /// create a new watcher of no io, polling every `interval`
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            targets: vec![],
+            interval,
+            debounce: Duration::ZERO,
+        }
+    }
+    /// watch a range of ports, read in a single round trip, see [`IvaRobot::io_get_bank`]
+    pub fn watch(mut self, io_target: IOTarget, range: Range<u16>) -> Self {
+        self.targets.push((io_target, range));
+        self
+    }
+    /// set the duration a new state must hold before its edge is published, default to none
+    ///
+    /// a pulse shorter than the debounce is not published.
+    pub fn set_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    fn spawn(self, source: StateSource) -> Result<IOWatch, RobotError> {
+        if self.interval.is_zero() {
+            return Err(RobotError::InvalidArgument(
+                "invalid watch interval: 0s".to_string(),
+            ));
+        }
+        if self.targets.is_empty() {
+            return Err(RobotError::InvalidArgument("no io to watch".to_string()));
+        }
+        for (_, range) in self.targets.iter() {
+            if range.len() > 32 {
+                return Err(RobotError::InvalidArgument(format!(
+                    "io bank of {} ports does not fit in 32 bits",
+                    range.len()
+                )));
+            }
+        }
+        let (sender, receiver) = mpsc::channel();
+
+        // the published mask of each range, and since when each port differs from it
+        let mut states: Vec<Option<u32>> = vec![None; self.targets.len()];
+        let mut pending: Vec<Vec<Option<(Instant, SystemTime)>>> = self
+            .targets
+            .iter()
+            .map(|(_, range)| vec![None; range.len()])
+            .collect();
+        let poller = Poller::spawn(self.interval, source, move |source| {
+            let masks = source.with(|bot| {
+                self.targets
+                    .iter()
+                    .map(|(target, range)| bot.io_get_bank(target.clone(), range.clone()))
+                    .collect::<Result<Vec<u32>, RobotError>>()
+            });
+            let masks = match masks {
+                Ok(masks) => masks,
+                Err(e) => {
+                    let _ = sender.send(Err(e));
+                    return ControlFlow::Break(());
+                }
+            };
+            let read = (Instant::now(), SystemTime::now());
+            for (i, mask) in masks.into_iter().enumerate() {
+                // the first read is the initial state, not an edge
+                let state = states[i].get_or_insert(mask);
+                let (target, range) = &self.targets[i];
+                for (bit, since) in pending[i].iter_mut().enumerate() {
+                    if (mask ^ *state) >> bit & 1 == 0 {
+                        *since = None;
+                        continue;
+                    }
+                    let (instant, timestamp) = *since.get_or_insert(read);
+                    if read.0 - instant < self.debounce {
+                        continue;
+                    }
+                    *since = None;
+                    *state ^= 1 << bit;
+                    let edge = IOEdge {
+                        target: target.clone(),
+                        port: range.start + bit as u16,
+                        state: mask >> bit & 1 == 1,
+                        timestamp,
+                    };
+                    if sender.send(Ok(edge)).is_err() {
+                        return ControlFlow::Break(());
+                    }
+                }
+            }
+            ControlFlow::Continue(())
+        });
+
+        Ok(IOWatch { receiver, poller })
+    }
+}
+
+impl Robot {
+    /// watch digital io on a dedicated robot connection, publishing their edges
+    ///
+    /// the state first read is the initial state, the watch stops after the first error,
+    /// which is published as the last message.
+    /// # Example
+    /// ```no_run
+    /// use inovo_rs::iva::IOTarget;
+    /// use inovo_rs::robot::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), RobotError> {
+    /// // a second iva connection, for monitoring only
+    /// let monitor = Robot::defaut_logger(50004, "psu002")?;
+    /// let watcher = IOWatcher::new(Duration::from_millis(20))
+    ///     .watch(IOTarget::Beckhoff, 0..8)
+    ///     .set_debounce(Duration::from_millis(50));
+    /// let edges = monitor.watch_io(watcher)?;
+    ///
+    /// for edge in edges.iter() {
+    ///     let edge = edge?;
+    ///     if edge.port == 3 && edge.is_rising() {
+    ///         println!("part detected at {:?}", edge.timestamp);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_io(self, watcher: IOWatcher) -> Result<IOWatch, RobotError> {
+        watcher.spawn(StateSource::Owned(Box::new(self)))
+    }
+}
+
+impl RobotHandle {
+    /// watch digital io of a shared robot, interleaving the polls with the other commands,
+    /// see [`Robot::watch_io`]
+    pub fn watch_io(&self, watcher: IOWatcher) -> Result<IOWatch, RobotError> {
+        watcher.spawn(StateSource::Shared(self.clone()))
+    }
+}
+
+/// The receiving end of an [`IOWatcher`], stopping the polls on drop
+pub struct IOWatch {
+    receiver: Receiver<Result<IOEdge, RobotError>>,
+    poller: Poller<StateSource>,
+}
+
+impl IOWatch {
+    /// wait for the next edge, `None` if the watch stopped
+    pub fn recv(&self) -> Option<Result<IOEdge, RobotError>> {
+        self.receiver.recv().ok()
+    }
+    /// wait for the next edge with a timeout, `None` if timeout or the watch stopped
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Result<IOEdge, RobotError>> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+    /// get the next edge without waiting
+    pub fn try_recv(&self) -> Option<Result<IOEdge, RobotError>> {
+        self.receiver.try_recv().ok()
+    }
+    /// iterate over the edges, until the watch stopped
+    pub fn iter(&self) -> impl Iterator<Item = Result<IOEdge, RobotError>> + '_ {
+        self.receiver.iter()
+    }
+
+    /// stop the watch, returning the dedicated robot connection if any
+    pub fn stop(mut self) -> Option<Robot> {
+        self.poller.stop()?.ok()?.into_robot()
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::Transform;
+use crate::robot::CommandSequence;
+
+/// A library of named waypoints, saved as json
+///
+/// # Example
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::robot::*;
+///
+/// let mut library = WaypointLibrary::new();
+/// library.insert("pick", Transform::from_vector([300.0, 0.0, 50.0]));
+/// library.insert("place", Transform::from_vector([0.0, 300.0, 50.0]));
+///
+/// let sequence = library.sequence(["pick", "place"]).unwrap();
+/// assert_eq!(sequence.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct WaypointLibrary {
+    waypoints: BTreeMap<String, Transform>,
+}
+
+impl WaypointLibrary {
+    /// create a new empty library
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// load a library from a json file, an empty library if the file does not exist
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e),
+        }
+    }
+    /// save the library to a json file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+    /// insert a waypoint, returning the previous one with the same name
+    pub fn insert(&mut self, name: impl Into<String>, transform: Transform) -> Option<Transform> {
+        self.waypoints.insert(name.into(), transform)
+    }
+    /// remove a waypoint
+    pub fn remove(&mut self, name: &str) -> Option<Transform> {
+        self.waypoints.remove(name)
+    }
+    /// get a waypoint
+    pub fn get(&self, name: &str) -> Option<&Transform> {
+        self.waypoints.get(name)
+    }
+    /// iterate over the waypoints, ordered by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Transform)> {
+        self.waypoints.iter().map(|(n, t)| (n.as_str(), t))
+    }
+    /// get the number of waypoints
+    pub fn len(&self) -> usize {
+        self.waypoints.len()
+    }
+    /// check if the library has no waypoint
+    pub fn is_empty(&self) -> bool {
+        self.waypoints.is_empty()
+    }
+    /// create a sequence of linear motions through named waypoints, returning the first unknown name on failure
+    pub fn sequence<'a>(
+        &self,
+        names: impl IntoIterator<Item = &'a str>,
+    ) -> Result<CommandSequence, String> {
+        names
+            .into_iter()
+            .try_fold(CommandSequence::new(), |sequence, name| {
+                let waypoint = self.get(name).ok_or_else(|| name.to_string())?;
+                Ok(sequence.then_linear(waypoint.clone()))
+            })
+    }
+}
@@ -0,0 +1,209 @@
+use crate::geometry::Transform;
+use crate::iva::{MotionMode, MotionTarget, RobotCommand};
+use crate::robot::{CommandSequence, IvaRobot, MotionParam, Robot, RobotError};
+
+/// A box of the workspace with a max speed
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeedZone {
+    /// the corners of the box, in the base frame, in mm
+    pub min_mm: [f64; 3],
+    pub max_mm: [f64; 3],
+    /// the max speed in the box, in percent
+    pub max_speed_percent: f64,
+}
+
+impl SpeedZone {
+    /// whether a position is in the box
+    pub fn contains(&self, position: [f64; 3]) -> bool {
+        (0..3).all(|i| (self.min_mm[i]..=self.max_mm[i]).contains(&position[i]))
+    }
+    /// whether the segment between two positions goes through the box
+    pub fn crosses(&self, from: [f64; 3], to: [f64; 3]) -> bool {
+        // clip the segment against the slab of each axis
+        let (mut enter, mut exit) = (0.0f64, 1.0f64);
+        for i in 0..3 {
+            let delta = to[i] - from[i];
+            if delta == 0.0 {
+                if !(self.min_mm[i]..=self.max_mm[i]).contains(&from[i]) {
+                    return false;
+                }
+                continue;
+            }
+            let a = (self.min_mm[i] - from[i]) / delta;
+            let b = (self.max_mm[i] - from[i]) / delta;
+            enter = enter.max(a.min(b));
+            exit = exit.min(a.max(b));
+        }
+        enter <= exit
+    }
+}
+
+/// A map of the workspace regions with a max speed, e.g. slow near the operator side
+///
+/// the motions of a sequence are capped to the slowest zone their path goes through,
+/// with set params inserted where the cap changes, see [`SpeedZones::apply`].
+/// # Example
+/// ```
+/// use inovo_rs::geometry::*;
+/// use inovo_rs::iva::*;
+/// use inovo_rs::robot::*;
+///
+/// // the operator stands on the positive y side
+/// let zones = SpeedZones::new().add_zone([-1000.0, 300.0, -500.0], [1000.0, 1000.0, 1500.0], 20.0);
+///
+/// let base = MotionParam::new().set_speed(80.0);
+/// let path = CommandSequence::new()
+///     .then_linear(Transform::from_vector([400.0, 0.0, 200.0]))
+///     .then_linear(Transform::from_vector([400.0, 500.0, 200.0]))
+///     .then_linear(Transform::from_vector([400.0, 0.0, 200.0]))
+///     .then_linear(Transform::from_vector([0.0, 0.0, 200.0]));
+/// let zoned = zones.apply(path, None, &base);
+///
+/// assert_eq!(zoned[1], RobotCommand::SetParameter(base.clone().set_speed(20.0)));
+/// assert_eq!(zoned[4], RobotCommand::SetParameter(base));
+/// assert_eq!(zoned.len(), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SpeedZones {
+    zones: Vec<SpeedZone>,
+}
+
+impl SpeedZones {
+    /// create a new empty map
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// add a zone from its corners in the base frame in mm, and its max speed in percent
+    pub fn add_zone(mut self, min_mm: [f64; 3], max_mm: [f64; 3], max_speed_percent: f64) -> Self {
+        self.zones.push(SpeedZone {
+            min_mm: [0, 1, 2].map(|i| min_mm[i].min(max_mm[i])),
+            max_mm: [0, 1, 2].map(|i| min_mm[i].max(max_mm[i])),
+            max_speed_percent,
+        });
+        self
+    }
+    /// get the zones
+    pub fn zones(&self) -> &[SpeedZone] {
+        &self.zones
+    }
+
+    /// get the max speed at a position in percent, the slowest of the zones it is in, if any
+    pub fn speed_at(&self, transform: &Transform) -> Option<f64> {
+        let position = transform.get_vector();
+        self.slowest(|zone| zone.contains(position))
+    }
+    /// get the max speed along the segment between two positions in percent,
+    /// the slowest of the zones it goes through, if any
+    pub fn speed_along(&self, from: &Transform, to: &Transform) -> Option<f64> {
+        let (from, to) = (from.get_vector(), to.get_vector());
+        self.slowest(|zone| zone.crosses(from, to))
+    }
+    /// get the speed of the slowest zone, if any
+    pub fn slowest_speed(&self) -> Option<f64> {
+        self.slowest(|_| true)
+    }
+
+    fn slowest(&self, f: impl Fn(&SpeedZone) -> bool) -> Option<f64> {
+        self.zones
+            .iter()
+            .filter(|zone| f(zone))
+            .map(|zone| zone.max_speed_percent)
+            .reduce(f64::min)
+    }
+
+    /// cap the motions of a sequence to the zones their path goes through
+    ///
+    /// ## Argument
+    /// - `start`: the position the sequence starts from, if known
+    /// - `base`: the motion param set on the robot when the sequence starts
+    ///
+    /// the path of a motion is approximated by the segment from the previous target,
+    /// joint motions included. only its target is checked if the previous target is unknown.
+    /// a motion to a [`JointCoord`](crate::geometry::JointCoord), or relative to an unknown position,
+    /// has an unknown path and is capped to the slowest zone.
+    ///
+    /// the set params of the sequence are kept as the base, capped where needed,
+    /// and the base is set again at the end if it was capped.
+    pub fn apply(
+        &self,
+        sequence: CommandSequence,
+        start: Option<Transform>,
+        base: &MotionParam,
+    ) -> CommandSequence {
+        let mut base = base.clone();
+        let mut sent = base.clone();
+        let mut position = start;
+        let mut first = true;
+        let mut zoned = vec![];
+
+        for command in sequence {
+            if let RobotCommand::SetParameter(param) = &command {
+                base = param.clone();
+                continue;
+            }
+            if let Some((motion_mode, target)) = command.motion() {
+                let from = position.take();
+                position = match (motion_mode, target) {
+                    (_, MotionTarget::JointCoord(_)) => None,
+                    (
+                        MotionMode::LinearRelative | MotionMode::JointRelative,
+                        MotionTarget::Transform(delta),
+                    ) => from.clone().map(|from| from.then_relative(delta.clone())),
+                    (_, MotionTarget::Transform(transform)) => Some(transform.clone()),
+                };
+                let cap = match (&from, &position) {
+                    (Some(from), Some(to)) => self.speed_along(from, to),
+                    (None, Some(to)) if first => self.speed_at(to),
+                    _ => self.slowest_speed(),
+                };
+                first = false;
+                let param = match cap {
+                    Some(cap) => base.clone().cap_speed(cap),
+                    None => base.clone(),
+                };
+                if param != sent {
+                    zoned.push(RobotCommand::SetParameter(param.clone()));
+                    sent = param;
+                }
+            }
+            zoned.push(command);
+        }
+        if sent != base {
+            zoned.push(RobotCommand::SetParameter(base));
+        }
+        zoned.into_iter().collect()
+    }
+}
+
+impl Robot {
+    /// get the speed zones, if any
+    pub fn speed_zones(&self) -> Option<&SpeedZones> {
+        self.speed_zones.as_ref()
+    }
+    /// set the speed zones every sequence is capped to until cleared, see [`SpeedZones::apply`]
+    ///
+    /// the sequences start from the current pose, and from the param tracked by [`Robot::motion_param`].
+    /// single motions, e.g. [`IvaRobot::linear`], are not capped.
+    pub fn set_speed_zones(&mut self, speed_zones: SpeedZones) -> &mut Self {
+        self.speed_zones = Some(speed_zones);
+        self
+    }
+    /// remove the speed zones
+    pub fn clear_speed_zones(&mut self) -> &mut Self {
+        self.speed_zones = None;
+        self
+    }
+
+    /// cap a sequence to the speed zones, querying the current pose
+    pub(crate) fn apply_speed_zones(
+        &mut self,
+        sequence: CommandSequence,
+    ) -> Result<CommandSequence, RobotError> {
+        let Some(zones) = self.speed_zones.clone() else {
+            return Ok(sequence);
+        };
+        let start = self.get_current_transform()?;
+        let param = self.motion_param.clone().unwrap_or_default();
+        Ok(zones.apply(sequence, Some(start), &param))
+    }
+}
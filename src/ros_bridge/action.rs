@@ -0,0 +1,251 @@
+use std::net::TcpStream;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use websocket::sync::Client;
+
+use crate::logger::Logger;
+use crate::ros_bridge::{close, recv_json, send_json, Fragments, RosBridge, RosBridgeError};
+
+/// The status of a goal, as `actionlib_msgs/GoalStatus`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoalStatus {
+    Pending,
+    Active,
+    /// canceled after it started executing
+    Preempted,
+    Succeeded,
+    Aborted,
+    Rejected,
+    Preempting,
+    Recalling,
+    /// canceled before it started executing
+    Recalled,
+    Lost,
+}
+
+impl GoalStatus {
+    fn from_code(code: u64) -> Result<Self, RosBridgeError> {
+        Ok(match code {
+            0 => GoalStatus::Pending,
+            1 => GoalStatus::Active,
+            2 => GoalStatus::Preempted,
+            3 => GoalStatus::Succeeded,
+            4 => GoalStatus::Aborted,
+            5 => GoalStatus::Rejected,
+            6 => GoalStatus::Preempting,
+            7 => GoalStatus::Recalling,
+            8 => GoalStatus::Recalled,
+            9 => GoalStatus::Lost,
+            _ => return Err(RosBridgeError::UnexpectedValue),
+        })
+    }
+    /// whether the goal is done, i.e. no more feedback or result will come
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            GoalStatus::Preempted
+                | GoalStatus::Succeeded
+                | GoalStatus::Aborted
+                | GoalStatus::Rejected
+                | GoalStatus::Recalled
+                | GoalStatus::Lost
+        )
+    }
+}
+
+/// A message of an action for a goal, see [`ActionGoal::next_event`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionEvent {
+    /// the feedback of the action, the `feedback` field of the message
+    Feedback(serde_json::Value),
+    /// the result of the action, the `result` field of the message
+    Result {
+        status: GoalStatus,
+        result: serde_json::Value,
+    },
+}
+
+impl RosBridge {
+    /// send a goal to an action server, over the topics of actionlib
+    ///
+    /// ## Argument
+    /// - `action`: name of the action, e.g. `/calibration`
+    /// - `action_type`: type of the action, e.g. `calibration_msgs/Calibrate`
+    /// - `args`: the goal message
+    /// ```no_run
+    /// use inovo_rs::ros_bridge::*;
+    ///
+    /// let mut ros_bridge = RosBridge::new("psu002", 500);
+    /// let goal = serde_json::json!({ "samples": 20 });
+    /// let mut calibration = ros_bridge
+    ///     .send_action_goal("/calibration", "calibration_msgs/Calibrate", goal)
+    ///     .unwrap();
+    /// loop {
+    ///     match calibration.next_event().unwrap() {
+    ///         ActionEvent::Feedback(feedback) => println!("{}", feedback["progress"]),
+    ///         ActionEvent::Result { status, result } => {
+    ///             println!("{:?} {}", status, result);
+    ///             break;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn send_action_goal(
+        &mut self,
+        action: &str,
+        action_type: &str,
+        args: serde_json::Value,
+    ) -> Result<ActionGoal, RosBridgeError> {
+        self.subscriptions += 1;
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let goal_id = format!(
+            "goal:{}:{}:{}",
+            action,
+            self.subscriptions,
+            stamp.as_millis()
+        );
+        let stamp = serde_json::json!({ "secs": stamp.as_secs(), "nsecs": stamp.subsec_nanos() });
+
+        let mut goal = ActionGoal {
+            client: self.connect()?,
+            action: action.to_string(),
+            goal_id,
+            stamp,
+            logger: self.logger.clone(),
+            fragments: Fragments::default(),
+            status: GoalStatus::Pending,
+        };
+        let topics = [
+            ("advertise", "goal", format!("{}ActionGoal", action_type)),
+            ("advertise", "cancel", "actionlib_msgs/GoalID".to_string()),
+            (
+                "subscribe",
+                "feedback",
+                format!("{}ActionFeedback", action_type),
+            ),
+            (
+                "subscribe",
+                "result",
+                format!("{}ActionResult", action_type),
+            ),
+        ];
+        for (op, topic, msg_type) in topics {
+            let json = serde_json::json!({
+                "op": op,
+                "id": format!("{}:{}", goal.goal_id, topic),
+                "topic": goal.topic(topic),
+                "type": msg_type,
+            });
+            goal.send(json)?;
+        }
+        let msg = serde_json::json!({ "goal_id": goal.goal_id_msg(), "goal": args });
+        goal.publish("goal", msg)?;
+        Ok(goal)
+    }
+}
+
+/// A goal sent to an action server, see [`RosBridge::send_action_goal`]
+///
+/// the topics are unadvertised and unsubscribed, and the connection closed on drop,
+/// a goal still running is not canceled.
+pub struct ActionGoal {
+    client: Client<TcpStream>,
+    action: String,
+    goal_id: String,
+    stamp: serde_json::Value,
+    logger: Logger,
+    fragments: Fragments,
+    status: GoalStatus,
+}
+
+impl ActionGoal {
+    /// get the id of the goal
+    pub fn goal_id(&self) -> &str {
+        &self.goal_id
+    }
+    /// get the last status of the goal
+    pub fn status(&self) -> GoalStatus {
+        self.status
+    }
+
+    /// wait for the next feedback or the result of the goal
+    pub fn next_event(&mut self) -> Result<ActionEvent, RosBridgeError> {
+        let (feedback, result) = (self.topic("feedback"), self.topic("result"));
+        loop {
+            let mut json = recv_json(&mut self.client, &self.logger, &mut self.fragments)?;
+            if json["op"] != "publish" {
+                continue;
+            }
+            let msg = &mut json["msg"];
+            if msg["status"]["goal_id"]["id"] != self.goal_id.as_str() {
+                continue;
+            }
+            let status = msg["status"]["status"]
+                .as_u64()
+                .ok_or(RosBridgeError::UnexpectedValue)?;
+            self.status = GoalStatus::from_code(status)?;
+            if json["topic"] == feedback.as_str() {
+                return Ok(ActionEvent::Feedback(json["msg"]["feedback"].take()));
+            }
+            if json["topic"] == result.as_str() {
+                return Ok(ActionEvent::Result {
+                    status: self.status,
+                    result: json["msg"]["result"].take(),
+                });
+            }
+        }
+    }
+    /// wait for the result of the goal, skipping the feedback
+    pub fn wait_result(&mut self) -> Result<(GoalStatus, serde_json::Value), RosBridgeError> {
+        loop {
+            if let ActionEvent::Result { status, result } = self.next_event()? {
+                return Ok((status, result));
+            }
+        }
+    }
+    /// request the action server to cancel the goal, its result still follows
+    pub fn cancel(&mut self) -> Result<(), RosBridgeError> {
+        self.publish("cancel", self.goal_id_msg())
+    }
+
+    /// the topic of the action, e.g. `/calibration/goal`
+    fn topic(&self, name: &str) -> String {
+        format!("{}/{}", self.action, name)
+    }
+    /// the `actionlib_msgs/GoalID` of the goal
+    fn goal_id_msg(&self) -> serde_json::Value {
+        serde_json::json!({ "stamp": self.stamp, "id": self.goal_id })
+    }
+    fn publish(&mut self, topic: &str, msg: serde_json::Value) -> Result<(), RosBridgeError> {
+        let json = serde_json::json!({ "op": "publish", "topic": self.topic(topic), "msg": msg });
+        self.send(json)
+    }
+    fn send(&mut self, json: serde_json::Value) -> Result<(), RosBridgeError> {
+        send_json(&mut self.client, &self.logger, json.to_string())
+    }
+}
+
+impl Drop for ActionGoal {
+    fn drop(&mut self) {
+        let topics = [
+            ("unadvertise", "goal"),
+            ("unadvertise", "cancel"),
+            ("unsubscribe", "feedback"),
+            ("unsubscribe", "result"),
+        ];
+        for (op, topic) in topics {
+            let json = serde_json::json!({
+                "op": op,
+                "id": format!("{}:{}", self.goal_id, topic),
+                "topic": self.topic(topic),
+            });
+            if let Err(e) = self.send(json) {
+                self.logger
+                    .warn(format!("fail to {} {}: {}", op, self.topic(topic), e));
+            }
+        }
+        close(&mut self.client, &self.logger);
+    }
+}
@@ -7,11 +7,22 @@
 //! ros_bridge.run_sequence("some sequence").unwrap();
 //! ```
 
+use std::collections::HashMap;
+use std::net::TcpStream;
+
 use serde_json;
+use websocket::sync::Client;
 use websocket::{ClientBuilder, Message, OwnedMessage, WebSocketError};
 
 use crate::logger::{Logable, Logger};
 
+mod action;
+#[cfg(feature = "procedures")]
+mod program;
+pub use action::*;
+#[cfg(feature = "procedures")]
+pub use program::*;
+
 /// Data structure for ROSbridge communication
 ///
 /// ## Example
@@ -35,6 +46,8 @@ pub struct RosBridge {
     host: String,
     logger: Logger,
     interval_ms: u64,
+    /// the number of subscriptions made, for their ids
+    subscriptions: u64,
 }
 
 impl RosBridge {
@@ -45,54 +58,40 @@ impl RosBridge {
     /// - `interval_ms`: retry interval
     pub fn new(host: impl Into<String>, interval_ms: u64) -> RosBridge {
         let host = host.into();
-        let logger = Logger::default_target(format!("ros {}", host));
+        let logger = Logger::get_or_create(format!("ros {}", host));
         RosBridge {
             host,
             logger,
             interval_ms,
+            subscriptions: 0,
         }
     }
 
-    fn make_request(&mut self, json: String) -> Result<serde_json::Value, RosBridgeError> {
+    /// connect a new websocket to the ros bridge
+    fn connect(&mut self) -> Result<Client<TcpStream>, RosBridgeError> {
         // The websocket URL using the provided host
         let url = format!("ws://{}:9090/", self.host);
-        self.debug(format!("trying to send json to {}", url));
+        self.debug(format!("trying to connect to {}", url));
 
-        // Attempt to connect to Websocket server until it is successful
-        let mut client = ClientBuilder::new(&url).unwrap().connect_insecure()?;
+        let client = ClientBuilder::new(&url).unwrap().connect_insecure()?;
         self.debug("Successful connected to websocket");
+        Ok(client)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "ros_bridge_request", skip_all, fields(host = %self.host), err)
+    )]
+    fn make_request(&mut self, json: String) -> Result<serde_json::Value, RosBridgeError> {
+        let mut client = self.connect()?;
 
         // send the json message to call service
         self.debug("sending message . . .");
-        self.debug(format!(">>> {}", json));
-        let msg = Message::text(json);
-        client.send_message(&msg)?;
+        send_json(&mut client, &self.logger, json)?;
 
-        // read message from websocket in loop
-        loop {
-            self.debug("reading message . . .");
-            let message = client.recv_message()?;
-
-            match &message {
-                // If the message is text
-                OwnedMessage::Text(text) => {
-                    self.debug(format!("<<< {}", text));
-
-                    // try to pares it into json
-                    match serde_json::from_str(text) {
-                        Ok(json) => {
-                            self.debug(format!("<<< {:?}", json));
-                            return Ok(json);
-                        }
-                        _ => self.error("Invaild json."),
-                    }
-                }
-                // if the message is not in text, just log the message
-                _ => {
-                    self.debug(format!("<<< <<< {:?}", message));
-                }
-            }
-        }
+        let res = recv_json(&mut client, &self.logger, &mut Fragments::default());
+        close(&mut client, &self.logger);
+        res
     }
 
     fn stop_json() -> String {
@@ -121,21 +120,46 @@ impl RosBridge {
         )
         .to_string()
     }
-    fn runtime_json() -> String {
-        serde_json::json!(
+    fn subscribe_json(topic: &str, msg_type: &str, id: &str, options: &SubscribeOptions) -> String {
+        let mut json = serde_json::json!(
             {
                 "op": "subscribe",
-                "topic": "/sequence/runtime_state",
-                "type": "commander_msgs/RuntimeState",
+                "id": id,
+                "topic": topic,
+                "type": msg_type,
+            }
+        );
+        let fields = [
+            ("throttle_rate", options.throttle_rate_ms),
+            ("queue_length", options.queue_length),
+            ("fragment_size", options.fragment_size),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                json[key] = value.into();
+            }
+        }
+        json.to_string()
+    }
+    fn unsubscribe_json(topic: &str, id: &str) -> String {
+        serde_json::json!(
+            {
+                "op": "unsubscribe",
+                "id": id,
+                "topic": topic,
             }
         )
         .to_string()
     }
 
     fn call_service(&mut self, json: String) -> Result<(), RosBridgeError> {
-        let value = self.make_request(json)?["values"]["success"].clone();
-        match value {
-            serde_json::Value::Bool(true) => Ok(()),
+        self.call_service_values(json).map(|_| ())
+    }
+    /// call a service, returning the values of a successful response
+    fn call_service_values(&mut self, json: String) -> Result<serde_json::Value, RosBridgeError> {
+        let mut values = self.make_request(json)?["values"].take();
+        match values["success"] {
+            serde_json::Value::Bool(true) => Ok(values.take()),
             _ => Err(RosBridgeError::UnexpectedValue),
         }
     }
@@ -184,7 +208,8 @@ impl RosBridge {
 
     /// get the current runtime state
     pub fn get_runtime_state(&mut self) -> Result<RuntimeState, RosBridgeError> {
-        let value = self.make_request(RosBridge::runtime_json())?["msg"]["state"].clone();
+        let msg = self.read_topic("/sequence/runtime_state", "commander_msgs/RuntimeState")?;
+        let value = msg["state"].clone();
         match value {
             serde_json::Value::Number(i) => match i.as_i64() {
                 Some(0) => Ok(RuntimeState::Stop),
@@ -197,15 +222,83 @@ impl RosBridge {
         }
     }
 
+    /// subscribe to a topic on a dedicated connection, unsubscribed and closed when the subscription is dropped
+    ///
+    /// ## Argument
+    /// - `topic`: name of the topic, e.g. `/conveyor/position`
+    /// - `msg_type`: type of the message, e.g. `std_msgs/Float64`
+    /// ```no_run
+    /// use inovo_rs::ros_bridge::*;
+    ///
+    /// let mut ros_bridge = RosBridge::new("psu002", 500);
+    /// let mut position = ros_bridge.subscribe("/conveyor/position", "std_msgs/Float64").unwrap();
+    /// for _ in 0..10 {
+    ///     println!("{}", position.next_message().unwrap()["data"]);
+    /// }
+    /// ```
+    pub fn subscribe(
+        &mut self,
+        topic: &str,
+        msg_type: &str,
+    ) -> Result<Subscription, RosBridgeError> {
+        self.subscribe_with(topic, msg_type, SubscribeOptions::new())
+    }
+    /// subscribe to a topic with options, e.g. throttling a high rate topic, see [`RosBridge::subscribe`]
+    /// ```no_run
+    /// use inovo_rs::ros_bridge::*;
+    ///
+    /// let mut ros_bridge = RosBridge::new("psu002", 500);
+    /// // at most 10 messages per second, the newest only
+    /// let options = SubscribeOptions::new().set_throttle_rate_ms(100).set_queue_length(1);
+    /// let mut joints = ros_bridge
+    ///     .subscribe_with("/joint_states", "sensor_msgs/JointState", options)
+    ///     .unwrap();
+    /// println!("{}", joints.next_message().unwrap()["position"]);
+    /// ```
+    pub fn subscribe_with(
+        &mut self,
+        topic: &str,
+        msg_type: &str,
+        options: SubscribeOptions,
+    ) -> Result<Subscription, RosBridgeError> {
+        self.subscriptions += 1;
+        let id = format!("subscribe:{}:{}", topic, self.subscriptions);
+        let mut client = self.connect()?;
+        send_json(
+            &mut client,
+            &self.logger,
+            RosBridge::subscribe_json(topic, msg_type, &id, &options),
+        )?;
+        Ok(Subscription {
+            client,
+            topic: topic.to_string(),
+            id,
+            logger: self.logger.clone(),
+            fragments: Fragments::default(),
+        })
+    }
+
+    /// read the next message published on a topic, then unsubscribe
+    ///
+    /// ## Argument
+    /// - `topic`: name of the topic, e.g. `/conveyor/position`
+    /// - `msg_type`: type of the message, e.g. `std_msgs/Float64`
+    pub fn read_topic(
+        &mut self,
+        topic: &str,
+        msg_type: &str,
+    ) -> Result<serde_json::Value, RosBridgeError> {
+        self.subscribe(topic, msg_type)?.next_message()
+    }
+
     /// wait until the runtime finish running current sequence,
     ///
     /// it will keep waiting if the sequence is pause or error.
     pub fn until_sequence_stop(&mut self) -> Result<(), RosBridgeError> {
         loop {
             let runtime_state = self.get_runtime_state()?;
-            match runtime_state {
-                RuntimeState::Stop => break,
-                _ => {}
+            if let RuntimeState::Stop = runtime_state {
+                break;
             }
             std::thread::sleep(std::time::Duration::from_millis(self.interval_ms));
         }
@@ -227,8 +320,184 @@ impl Logable for RosBridge {
     }
 }
 
+/// The options of a subscription, see [`RosBridge::subscribe_with`]
+///
+/// an option not set is left to the ros bridge default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubscribeOptions {
+    throttle_rate_ms: Option<u32>,
+    queue_length: Option<u32>,
+    fragment_size: Option<u32>,
+}
+
+impl SubscribeOptions {
+    /// create new options, all left to the ros bridge default
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// set the minimum time between two messages, in ms
+    pub fn set_throttle_rate_ms(mut self, throttle_rate_ms: u32) -> Self {
+        self.throttle_rate_ms = Some(throttle_rate_ms);
+        self
+    }
+    /// set the number of messages buffered by the ros bridge when throttled, the oldest dropped first
+    pub fn set_queue_length(mut self, queue_length: u32) -> Self {
+        self.queue_length = Some(queue_length);
+        self
+    }
+    /// set the maximum size of a message, in bytes, a bigger message is sent in fragments
+    pub fn set_fragment_size(mut self, fragment_size: u32) -> Self {
+        self.fragment_size = Some(fragment_size);
+        self
+    }
+}
+
+/// A subscription to a topic of the ros bridge, see [`RosBridge::subscribe`]
+///
+/// unsubscribed and its connection closed on drop, so polling does not leak subscriptions on the robot.
+pub struct Subscription {
+    client: Client<TcpStream>,
+    topic: String,
+    id: String,
+    logger: Logger,
+    /// the fragments of the messages received in part
+    fragments: Fragments,
+}
+
+impl Subscription {
+    /// get the id of the subscription, unique for a [`RosBridge`]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    /// get the name of the topic
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+    /// wait for the next message published on the topic
+    pub fn next_message(&mut self) -> Result<serde_json::Value, RosBridgeError> {
+        loop {
+            let mut json = recv_json(&mut self.client, &self.logger, &mut self.fragments)?;
+            if json["op"] == "publish" && json["topic"] == self.topic.as_str() {
+                return Ok(json["msg"].take());
+            }
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let json = RosBridge::unsubscribe_json(&self.topic, &self.id);
+        if let Err(e) = send_json(&mut self.client, &self.logger, json) {
+            self.logger
+                .warn(format!("fail to unsubscribe {}: {}", self.id, e));
+        }
+        close(&mut self.client, &self.logger);
+    }
+}
+
+/// send a json message on a websocket
+fn send_json(
+    client: &mut Client<TcpStream>,
+    logger: &Logger,
+    json: String,
+) -> Result<(), RosBridgeError> {
+    logger.debug(format!(">>> {}", json));
+    client.send_message(&Message::text(json))?;
+    Ok(())
+}
+
+/// The fragments of the messages received in part, see [`SubscribeOptions::set_fragment_size`]
+///
+/// a fragment is `{"op": "fragment", "id": ..., "data": ..., "num": ..., "total": ...}`,
+/// the data of all the fragments of an id joined is the json message.
+#[derive(Debug, Default)]
+struct Fragments {
+    messages: HashMap<String, Vec<Option<String>>>,
+}
+
+impl Fragments {
+    /// add a fragment, returning the message once all its fragments are received
+    fn add(&mut self, fragment: &serde_json::Value) -> Result<Option<String>, RosBridgeError> {
+        let field = |key: &str| fragment[key].as_u64().map(|n| n as usize);
+        let (Some(id), Some(data), Some(num), Some(total)) = (
+            fragment["id"].as_str(),
+            fragment["data"].as_str(),
+            field("num"),
+            field("total"),
+        ) else {
+            return Err(RosBridgeError::UnexpectedValue);
+        };
+        if num >= total {
+            return Err(RosBridgeError::UnexpectedValue);
+        }
+        let parts = self
+            .messages
+            .entry(id.to_string())
+            .or_insert_with(|| vec![None; total]);
+        if parts.len() != total {
+            return Err(RosBridgeError::UnexpectedValue);
+        }
+        parts[num] = Some(data.to_string());
+        if parts.iter().any(Option::is_none) {
+            return Ok(None);
+        }
+        let parts = self.messages.remove(id).unwrap_or_default();
+        Ok(Some(parts.into_iter().flatten().collect()))
+    }
+}
+
+/// read json messages from a websocket until one is valid, reassembling the fragmented ones
+fn recv_json(
+    client: &mut Client<TcpStream>,
+    logger: &Logger,
+    fragments: &mut Fragments,
+) -> Result<serde_json::Value, RosBridgeError> {
+    loop {
+        logger.debug("reading message . . .");
+        let message = client.recv_message()?;
+
+        let text = match &message {
+            // If the message is text
+            OwnedMessage::Text(text) => text,
+            OwnedMessage::Close(_) => return Err(RosBridgeError::Closed),
+            // if the message is not in text, just log the message
+            _ => {
+                logger.debug(format!("<<< <<< {:?}", message));
+                continue;
+            }
+        };
+        logger.debug(format!("<<< {}", text));
+
+        // try to pares it into json
+        let json: serde_json::Value = match serde_json::from_str(text) {
+            Ok(json) => json,
+            _ => {
+                logger.error("Invaild json.");
+                continue;
+            }
+        };
+        if json["op"] != "fragment" {
+            return Ok(json);
+        }
+        if let Some(text) = fragments.add(&json)? {
+            match serde_json::from_str(&text) {
+                Ok(json) => return Ok(json),
+                _ => logger.error("Invaild json of fragments."),
+            }
+        }
+    }
+}
+
+/// close a websocket, a failure is only logged
+fn close(client: &mut Client<TcpStream>, logger: &Logger) {
+    let _ = client.send_message(&Message::close());
+    if let Err(e) = client.shutdown() {
+        logger.debug(format!("fail to close websocket: {}", e));
+    }
+}
+
 /// Runtime state of the robot
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RuntimeState {
     Stop,
     Running,
@@ -243,4 +512,6 @@ pub enum RosBridgeError {
     WebSocketError(#[from] WebSocketError),
     #[error("Unexpected Value")]
     UnexpectedValue,
+    #[error("Connection Closed")]
+    Closed,
 }
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ros_bridge::{RosBridge, RosBridgeError};
+
+/// The procedures of a sequencer project by name, saved as json to be version controlled
+///
+/// enabled with the `procedures` feature. the procedures are read and written with the services
+/// `/sequence/list_procedures`, `/sequence/get_procedure` and `/sequence/set_procedure`,
+/// of types `sequencer/ListProcedures`, `sequencer/GetProcedure` and `sequencer/SetProcedure`.
+/// they follow the naming of `/sequence/start`, but are not documented by inovo nor verified against a PSU,
+/// check them with `rosservice list` on the robot before relying on them.
+///
+/// # Example
+/// ```no_run
+/// use inovo_rs::ros_bridge::*;
+///
+/// let mut ros_bridge = RosBridge::new("psu002", 500);
+///
+/// // keep the program of the robot with the source
+/// ros_bridge.download_project().unwrap().save("iva.json").unwrap();
+///
+/// // provision another robot
+/// let project = Project::load("iva.json").unwrap();
+/// RosBridge::new("psu003", 500).upload_project(&project).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct Project {
+    procedures: BTreeMap<String, serde_json::Value>,
+}
+
+impl Project {
+    /// create a new empty project
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// load a project from a json file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+    /// save the project to a json file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+    /// insert a procedure, returning the previous definition with the same name
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        definition: serde_json::Value,
+    ) -> Option<serde_json::Value> {
+        self.procedures.insert(name.into(), definition)
+    }
+    /// remove a procedure
+    pub fn remove(&mut self, name: &str) -> Option<serde_json::Value> {
+        self.procedures.remove(name)
+    }
+    /// get the definition of a procedure
+    pub fn get(&self, name: &str) -> Option<&serde_json::Value> {
+        self.procedures.get(name)
+    }
+    /// iterate over the procedures, ordered by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &serde_json::Value)> {
+        self.procedures.iter().map(|(n, d)| (n.as_str(), d))
+    }
+    /// get the number of procedures
+    pub fn len(&self) -> usize {
+        self.procedures.len()
+    }
+    /// check if the project has no procedure
+    pub fn is_empty(&self) -> bool {
+        self.procedures.is_empty()
+    }
+}
+
+impl RosBridge {
+    fn procedure_json(service: &str, msg_type: &str, args: serde_json::Value) -> String {
+        serde_json::json!(
+            {
+                "op": "call_service",
+                "service": service,
+                "id": format!("call_service:{}", service),
+                "type": msg_type,
+                "args": args,
+            }
+        )
+        .to_string()
+    }
+
+    /// get the names of the procedures of the sequencer
+    pub fn list_procedures(&mut self) -> Result<Vec<String>, RosBridgeError> {
+        let json = RosBridge::procedure_json(
+            "/sequence/list_procedures",
+            "sequencer/ListProcedures",
+            serde_json::json!({}),
+        );
+        let values = self.call_service_values(json)?;
+        values["procedure_names"]
+            .as_array()
+            .ok_or(RosBridgeError::UnexpectedValue)?
+            .iter()
+            .map(|name| {
+                name.as_str()
+                    .map(str::to_string)
+                    .ok_or(RosBridgeError::UnexpectedValue)
+            })
+            .collect()
+    }
+
+    /// get the definition of a procedure from the sequencer
+    ///
+    /// ## Error
+    /// a [`RosBridgeError::UnexpectedValue`] if the procedure does not exist
+    pub fn download_procedure(
+        &mut self,
+        procedure_name: impl Into<String>,
+    ) -> Result<serde_json::Value, RosBridgeError> {
+        let json = RosBridge::procedure_json(
+            "/sequence/get_procedure",
+            "sequencer/GetProcedure",
+            serde_json::json!({ "procedure_name": procedure_name.into() }),
+        );
+        let values = self.call_service_values(json)?;
+        // the definition is sent as a json string
+        let definition = values["definition"]
+            .as_str()
+            .ok_or(RosBridgeError::UnexpectedValue)?;
+        serde_json::from_str(definition).map_err(|_| RosBridgeError::UnexpectedValue)
+    }
+
+    /// create or replace a procedure of the sequencer
+    pub fn upload_procedure(
+        &mut self,
+        procedure_name: impl Into<String>,
+        definition: &serde_json::Value,
+    ) -> Result<(), RosBridgeError> {
+        let json = RosBridge::procedure_json(
+            "/sequence/set_procedure",
+            "sequencer/SetProcedure",
+            serde_json::json!({
+                "procedure_name": procedure_name.into(),
+                "definition": definition.to_string(),
+            }),
+        );
+        self.call_service(json)
+    }
+
+    /// get every procedure of the sequencer
+    pub fn download_project(&mut self) -> Result<Project, RosBridgeError> {
+        let mut project = Project::new();
+        for name in self.list_procedures()? {
+            let definition = self.download_procedure(&name)?;
+            project.insert(name, definition);
+        }
+        Ok(project)
+    }
+
+    /// create or replace every procedure of a project, the other procedures of the sequencer are kept
+    pub fn upload_project(&mut self, project: &Project) -> Result<(), RosBridgeError> {
+        for (name, definition) in project.iter() {
+            self.upload_procedure(name, definition)?;
+        }
+        Ok(())
+    }
+}
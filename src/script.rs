@@ -0,0 +1,309 @@
+//! Embedded scripting, tweaking cell logic with [`rhai`](https://rhai.rs) scripts without recompiling.
+//!
+//! the script engine exposes [`Transform`], [`JointCoord`], [`MotionParam`], [`CommandSequence`],
+//! and the robot as the `bot` variable when created with [`ScriptEngine::with_robot`].
+//!
+//! numbers are floats in the script, write `100.0` rather than `100`.
+//! ```
+//! use inovo_rs::geometry::Transform;
+//! use inovo_rs::script::ScriptEngine;
+//!
+//! let engine = ScriptEngine::new();
+//! let pose = engine
+//!     .eval(
+//!         r#"
+//!         let approach = transform(300.0, 0.0, 200.0, 0.0, 180.0, 0.0);
+//!         approach.then_z(-50.0)
+//!         "#,
+//!     )
+//!     .unwrap()
+//!     .cast::<Transform>();
+//! assert_eq!(pose.get_z(), 150.0);
+//! ```
+//!
+//! # Robot
+//! ```no_run
+//! use inovo_rs::robot::*;
+//! use inovo_rs::script::ScriptEngine;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let bot = Robot::defaut_logger(50003, "psu002")?;
+//! let engine = ScriptEngine::with_robot(bot);
+//! engine.run(
+//!     r#"
+//!     bot.set_param(motion_param().set_speed(50.0));
+//!     let home = bot.get_transform();
+//!     for i in 0..3 {
+//!         bot.linear_relative(from_z(-10.0));
+//!         bot.beckhoff_set(1, true);
+//!         bot.sleep(0.5);
+//!         bot.beckhoff_set(1, false);
+//!     }
+//!     bot.linear(home);
+//!     "#,
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use rhai::{Dynamic, Engine, EvalAltResult, Scope, INT};
+
+use crate::geometry::{JointCoord, Transform};
+use crate::robot::{CommandSequence, IvaRobot, MotionParam, Robot, RobotError};
+
+/// Error of the script engine
+#[derive(Debug, thiserror::Error)]
+pub enum ScriptError {
+    #[error(transparent)]
+    Eval(#[from] Box<EvalAltResult>),
+}
+
+/// the robot in the script, shared with the engine
+#[derive(Clone)]
+struct ScriptRobot(Rc<RefCell<Robot>>);
+
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+fn robot_error(e: RobotError) -> Box<EvalAltResult> {
+    e.to_string().into()
+}
+
+fn port(port: INT) -> ScriptResult<u16> {
+    u16::try_from(port).map_err(|_| format!("invalid io port: {}", port).into())
+}
+
+impl ScriptRobot {
+    /// run a robot function, converting the error for the script
+    fn with<T>(&mut self, f: impl FnOnce(&mut Robot) -> Result<T, RobotError>) -> ScriptResult<T> {
+        f(&mut self.0.borrow_mut()).map_err(robot_error)
+    }
+}
+
+/// The script engine
+pub struct ScriptEngine {
+    engine: Engine,
+    robot: Option<ScriptRobot>,
+}
+
+impl Default for ScriptEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScriptEngine {
+    /// create a new script engine, without a robot
+    pub fn new() -> Self {
+        let mut engine = Engine::new();
+        register_geometry(&mut engine);
+        register_robot_types(&mut engine);
+        Self {
+            engine,
+            robot: None,
+        }
+    }
+    /// create a new script engine, with the robot as the `bot` variable
+    pub fn with_robot(robot: Robot) -> Self {
+        let mut script = Self::new();
+        register_robot(&mut script.engine);
+        script.robot = Some(ScriptRobot(Rc::new(RefCell::new(robot))));
+        script
+    }
+
+    /// get the rhai engine
+    pub fn engine(&self) -> &Engine {
+        &self.engine
+    }
+    /// get the rhai engine mutably, to register more functions
+    pub fn engine_mut(&mut self) -> &mut Engine {
+        &mut self.engine
+    }
+
+    /// take back the robot from the engine
+    pub fn into_robot(self) -> Option<Robot> {
+        let ScriptRobot(robot) = self.robot?;
+        Rc::try_unwrap(robot).ok().map(RefCell::into_inner)
+    }
+
+    fn scope(&self) -> Scope<'static> {
+        let mut scope = Scope::new();
+        if let Some(robot) = &self.robot {
+            scope.push("bot", robot.clone());
+        }
+        scope
+    }
+
+    /// evaluate a script, returning the value of the last expression
+    pub fn eval(&self, script: &str) -> Result<Dynamic, ScriptError> {
+        Ok(self.engine.eval_with_scope(&mut self.scope(), script)?)
+    }
+    /// run a script
+    pub fn run(&self, script: &str) -> Result<(), ScriptError> {
+        Ok(self.engine.run_with_scope(&mut self.scope(), script)?)
+    }
+    /// run a script file
+    pub fn run_file(&self, path: impl Into<PathBuf>) -> Result<(), ScriptError> {
+        let ast = self.engine.compile_file(path.into())?;
+        Ok(self.engine.run_ast_with_scope(&mut self.scope(), &ast)?)
+    }
+}
+
+fn register_geometry(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<Transform>("Transform")
+        .register_fn("transform", Transform::new)
+        .register_fn("identity", Transform::identity)
+        .register_fn("from_x", |mm: f64| Transform::from_x(mm))
+        .register_fn("from_y", |mm: f64| Transform::from_y(mm))
+        .register_fn("from_z", |mm: f64| Transform::from_z(mm))
+        .register_fn("from_rx", |deg: f64| Transform::from_rx(deg))
+        .register_fn("from_ry", |deg: f64| Transform::from_ry(deg))
+        .register_fn("from_rz", |deg: f64| Transform::from_rz(deg))
+        .register_get("x", |t: &mut Transform| t.get_x())
+        .register_get("y", |t: &mut Transform| t.get_y())
+        .register_get("z", |t: &mut Transform| t.get_z())
+        .register_get("rx", |t: &mut Transform| t.get_rx())
+        .register_get("ry", |t: &mut Transform| t.get_ry())
+        .register_get("rz", |t: &mut Transform| t.get_rz())
+        .register_fn("then", Transform::then)
+        .register_fn("then_x", |t: Transform, mm: f64| t.then_x(mm))
+        .register_fn("then_y", |t: Transform, mm: f64| t.then_y(mm))
+        .register_fn("then_z", |t: Transform, mm: f64| t.then_z(mm))
+        .register_fn("then_rx", |t: Transform, deg: f64| t.then_rx(deg))
+        .register_fn("then_ry", |t: Transform, deg: f64| t.then_ry(deg))
+        .register_fn("then_rz", |t: Transform, deg: f64| t.then_rz(deg))
+        .register_fn("then_relative", Transform::then_relative)
+        .register_fn("inverse", |t: &mut Transform| t.inverse())
+        .register_fn("distance", |a: &mut Transform, b: Transform| {
+            a.translation_distance(&b)
+        })
+        .register_fn("*", |a: Transform, b: Transform| a * b)
+        .register_fn("/", |a: Transform, b: Transform| a / b)
+        .register_fn("to_string", |t: &mut Transform| t.to_string())
+        .register_fn("to_debug", |t: &mut Transform| t.to_string());
+
+    engine
+        .register_type_with_name::<JointCoord>("JointCoord")
+        .register_fn("joint_coord", JointCoord::new)
+        .register_get("j1", |j: &mut JointCoord| j[0])
+        .register_get("j2", |j: &mut JointCoord| j[1])
+        .register_get("j3", |j: &mut JointCoord| j[2])
+        .register_get("j4", |j: &mut JointCoord| j[3])
+        .register_get("j5", |j: &mut JointCoord| j[4])
+        .register_get("j6", |j: &mut JointCoord| j[5])
+        .register_fn("+", |a: JointCoord, b: JointCoord| a + b)
+        .register_fn("-", |a: JointCoord, b: JointCoord| a - b)
+        .register_fn("to_string", |j: &mut JointCoord| j.to_string())
+        .register_fn("to_debug", |j: &mut JointCoord| j.to_string());
+}
+
+fn register_robot_types(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<MotionParam>("MotionParam")
+        .register_fn("motion_param", MotionParam::new)
+        .register_fn("set_speed", MotionParam::set_speed)
+        .register_fn("set_accel", MotionParam::set_accel)
+        .register_fn("set_blend_linear", MotionParam::set_blend_linear)
+        .register_fn("set_blend_angular", MotionParam::set_blend_angular)
+        .register_fn("set_tcp_speed_linear", MotionParam::set_tcp_speed_linear)
+        .register_fn("set_tcp_speed_angular", MotionParam::set_tcp_speed_angular);
+
+    engine
+        .register_type_with_name::<CommandSequence>("CommandSequence")
+        .register_fn("sequence", CommandSequence::new)
+        .register_fn("then_linear", CommandSequence::then_linear)
+        .register_fn(
+            "then_linear_relative",
+            CommandSequence::then_linear_relative,
+        )
+        .register_fn("then_joint", |s: CommandSequence, t: Transform| {
+            s.then_joint(t)
+        })
+        .register_fn("then_joint", |s: CommandSequence, j: JointCoord| {
+            s.then_joint(j)
+        })
+        .register_fn("then_joint_relative", CommandSequence::then_joint_relative)
+        .register_fn("then_sleep", CommandSequence::then_sleep)
+        .register_fn("then_sync", CommandSequence::then_sync)
+        .register_fn("then_set_param", CommandSequence::then_set_param)
+        .register_fn("len", |s: &mut CommandSequence| s.len() as INT);
+}
+
+fn register_robot(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<ScriptRobot>("Robot")
+        .register_fn("linear", |r: &mut ScriptRobot, t: Transform| {
+            r.with(|bot| bot.linear(t).map(|_| ()))
+        })
+        .register_fn("linear_relative", |r: &mut ScriptRobot, t: Transform| {
+            r.with(|bot| bot.linear_relative(t).map(|_| ()))
+        })
+        .register_fn("joint", |r: &mut ScriptRobot, t: Transform| {
+            r.with(|bot| bot.joint(t).map(|_| ()))
+        })
+        .register_fn("joint", |r: &mut ScriptRobot, j: JointCoord| {
+            r.with(|bot| bot.joint(j).map(|_| ()))
+        })
+        .register_fn("joint_relative", |r: &mut ScriptRobot, t: Transform| {
+            r.with(|bot| bot.joint_relative(t).map(|_| ()))
+        })
+        .register_fn("sleep", |r: &mut ScriptRobot, second: f64| {
+            r.with(|bot| bot.sleep(second).map(|_| ()))
+        })
+        .register_fn("set_param", |r: &mut ScriptRobot, p: MotionParam| {
+            r.with(|bot| bot.set_param(p).map(|_| ()))
+        })
+        .register_fn("sequence", |r: &mut ScriptRobot, s: CommandSequence| {
+            r.with(|bot| bot.sequence(s).map(|_| ()))
+        })
+        .register_fn("get_transform", |r: &mut ScriptRobot| {
+            r.with(|bot| bot.get_current_transform())
+        })
+        .register_fn("get_joint", |r: &mut ScriptRobot| {
+            r.with(|bot| bot.get_current_joint())
+        })
+        .register_fn("get_data", |r: &mut ScriptRobot, key: &str| {
+            r.with(|bot| bot.get_data::<String>(key))
+        })
+        .register_fn(
+            "beckhoff_set",
+            |r: &mut ScriptRobot, p: INT, state: bool| {
+                let p = port(p)?;
+                r.with(|bot| bot.beckhoff_set(p, state).map(|_| ()))
+            },
+        )
+        .register_fn("beckhoff_get", |r: &mut ScriptRobot, p: INT| {
+            let p = port(p)?;
+            r.with(|bot| bot.beckhoff_get(p))
+        })
+        .register_fn("wrist_set", |r: &mut ScriptRobot, p: INT, state: bool| {
+            let p = port(p)?;
+            r.with(|bot| bot.wrist_set(p, state).map(|_| ()))
+        })
+        .register_fn("wrist_get", |r: &mut ScriptRobot, p: INT| {
+            let p = port(p)?;
+            r.with(|bot| bot.wrist_get(p))
+        })
+        .register_fn("gripper_activate", |r: &mut ScriptRobot| {
+            r.with(|bot| bot.gripper_activate().map(|_| ()))
+        })
+        .register_fn("gripper_set", |r: &mut ScriptRobot, label: &str| {
+            r.with(|bot| bot.gripper_set(label).map(|_| ()))
+        })
+        .register_fn("gripper_get", |r: &mut ScriptRobot| {
+            r.with(|bot| bot.gripper_get())
+        })
+        .register_fn("vacuum_on", |r: &mut ScriptRobot| {
+            r.with(|bot| bot.vacuum_on().map(|_| ()))
+        })
+        .register_fn("vacuum_off", |r: &mut ScriptRobot| {
+            r.with(|bot| bot.vacuum_off().map(|_| ()))
+        })
+        .register_fn("vacuum_pressure", |r: &mut ScriptRobot| {
+            r.with(|bot| bot.vacuum_pressure())
+        });
+}
@@ -0,0 +1,329 @@
+//! Interactive shell, a minimal teach pendant for bring-up.
+//!
+//! the shell runs every command of the [`cli`](crate::cli), plus:
+//! ```text
+//! jog                   jog the tcp with the keyboard, until esc or enter
+//! step <mm> [deg]       set the jog step
+//! frame <base|tool>     set the jog frame
+//! where                 print the current pose
+//! save <name>           save the current pose to the waypoint library
+//! goto <name>           linear motion to a saved waypoint
+//! delete <name>         delete a saved waypoint
+//! list                  list the saved waypoints
+//! play <name>...        play the saved waypoints as a sequence
+//! quit                  leave the shell
+//! ```
+//!
+//! in jog mode, `w`/`s` or up/down jog x, `a`/`d` or left/right jog y, `r`/`f` jog z,
+//! `q`/`e` rotate about z, and `[`/`]` halve or double the step.
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::terminal;
+
+use crate::cli::{CliCommand, CliError};
+use crate::geometry::Transform;
+use crate::robot::{IvaRobot, JogAxis, JogFrame, Robot, WaypointLibrary};
+
+/// the usage of the shell commands
+pub const SHELL_USAGE: &str = "\
+shell commands:
+    jog                   jog the tcp with the keyboard, until esc or enter
+    step <mm> [deg]       set the jog step
+    frame <base|tool>     set the jog frame
+    where                 print the current pose
+    save <name>           save the current pose to the waypoint library
+    goto <name>           linear motion to a saved waypoint
+    delete <name>         delete a saved waypoint
+    list                  list the saved waypoints
+    play <name>...        play the saved waypoints as a sequence
+    quit                  leave the shell
+
+jog keys:
+    w/s or up/down        jog x
+    a/d or left/right     jog y
+    r/f                   jog z
+    q/e                   rotate about z
+    [/]                   halve or double the step
+    esc or enter          leave jog mode";
+
+/// A command of the shell
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShellCommand {
+    Cli(CliCommand),
+    Jog,
+    Step { mm: f64, deg: Option<f64> },
+    Frame(JogFrame),
+    Where,
+    Save(String),
+    Goto(String),
+    Delete(String),
+    List,
+    Play(Vec<String>),
+    Quit,
+}
+
+impl ShellCommand {
+    /// parse a line of the shell
+    pub fn parse(line: &str) -> Result<Self, CliError> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let number = |word: &str| {
+            word.parse::<f64>()
+                .map_err(|_| CliError::Usage(format!("invalid number: {}", word)))
+        };
+        let command = match words.as_slice() {
+            ["jog"] => ShellCommand::Jog,
+            ["step", mm] => ShellCommand::Step {
+                mm: number(mm)?,
+                deg: None,
+            },
+            ["step", mm, deg] => ShellCommand::Step {
+                mm: number(mm)?,
+                deg: Some(number(deg)?),
+            },
+            ["frame", "base"] => ShellCommand::Frame(JogFrame::Base),
+            ["frame", "tool"] => ShellCommand::Frame(JogFrame::Tool),
+            ["where"] => ShellCommand::Where,
+            ["save", name] => ShellCommand::Save(name.to_string()),
+            ["goto", name] => ShellCommand::Goto(name.to_string()),
+            ["delete", name] => ShellCommand::Delete(name.to_string()),
+            ["list"] => ShellCommand::List,
+            ["play", names @ ..] if !names.is_empty() => {
+                ShellCommand::Play(names.iter().map(|n| n.to_string()).collect())
+            }
+            ["quit"] | ["exit"] => ShellCommand::Quit,
+            _ => {
+                let wrist = words.contains(&"--wrist");
+                let words = words
+                    .into_iter()
+                    .filter(|w| *w != "--wrist")
+                    .map(String::from)
+                    .collect::<Vec<_>>();
+                ShellCommand::Cli(CliCommand::parse(&words, wrist)?)
+            }
+        };
+        Ok(command)
+    }
+}
+
+/// An action of a key in jog mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JogAction {
+    /// jog one step along or about an axis, forward or backward
+    Jog(JogAxis, bool),
+    /// double the steps
+    StepUp,
+    /// halve the steps
+    StepDown,
+    /// leave jog mode
+    Exit,
+}
+
+impl JogAction {
+    /// get the action of a key
+    pub fn from_key(key: KeyCode) -> Option<Self> {
+        let action = match key {
+            KeyCode::Char('w') | KeyCode::Up => JogAction::Jog(JogAxis::X, true),
+            KeyCode::Char('s') | KeyCode::Down => JogAction::Jog(JogAxis::X, false),
+            KeyCode::Char('a') | KeyCode::Left => JogAction::Jog(JogAxis::Y, true),
+            KeyCode::Char('d') | KeyCode::Right => JogAction::Jog(JogAxis::Y, false),
+            KeyCode::Char('r') => JogAction::Jog(JogAxis::Z, true),
+            KeyCode::Char('f') => JogAction::Jog(JogAxis::Z, false),
+            KeyCode::Char('q') => JogAction::Jog(JogAxis::Rz, true),
+            KeyCode::Char('e') => JogAction::Jog(JogAxis::Rz, false),
+            KeyCode::Char(']') => JogAction::StepUp,
+            KeyCode::Char('[') => JogAction::StepDown,
+            KeyCode::Esc | KeyCode::Enter => JogAction::Exit,
+            _ => return None,
+        };
+        Some(action)
+    }
+}
+
+/// The interactive shell
+///
+/// the jog steps and frame are the [`JogSettings`](crate::robot::JogSettings) of the robot
+pub struct Shell {
+    library: WaypointLibrary,
+    library_path: PathBuf,
+}
+
+/// restore the terminal from raw mode on drop
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> Result<Self, std::io::Error> {
+        terminal::enable_raw_mode()?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+impl Shell {
+    /// create a new shell, loading the waypoint library from a json file
+    pub fn new(library_path: impl Into<PathBuf>) -> Result<Self, std::io::Error> {
+        let library_path = library_path.into();
+        Ok(Self {
+            library: WaypointLibrary::load(&library_path)?,
+            library_path,
+        })
+    }
+    /// get the waypoint library
+    pub fn library(&self) -> &WaypointLibrary {
+        &self.library
+    }
+
+    /// read and run commands from the standard input, until `quit` or the end of input
+    pub fn run(&mut self, bot: &mut Robot) -> Result<(), CliError> {
+        let stdin = std::io::stdin();
+        let mut lines = stdin.lock().lines();
+        loop {
+            print!("inovo> ");
+            std::io::stdout().flush()?;
+            let Some(line) = lines.next().transpose()? else {
+                return Ok(());
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let result = ShellCommand::parse(&line).and_then(|command| match command {
+                ShellCommand::Quit => Ok(None),
+                command => self.execute(bot, command).map(Some),
+            });
+            match result {
+                Ok(Some(output)) if !output.is_empty() => println!("{}", output),
+                Ok(Some(_)) => {}
+                Ok(None) => return Ok(()),
+                Err(CliError::Usage(msg)) => println!("{}\n\n{}", msg, SHELL_USAGE),
+                Err(e) => println!("error: {}", e),
+            }
+        }
+    }
+
+    /// execute a shell command, returning the output to print
+    pub fn execute(&mut self, bot: &mut Robot, command: ShellCommand) -> Result<String, CliError> {
+        let output = match command {
+            ShellCommand::Cli(CliCommand::Help) => {
+                format!("{}\n\n{}", crate::cli::USAGE, SHELL_USAGE)
+            }
+            ShellCommand::Cli(command) => command.run(bot)?,
+            ShellCommand::Jog => {
+                self.jog(bot)?;
+                String::new()
+            }
+            ShellCommand::Step { mm, deg } => {
+                let settings = *bot.jog_settings();
+                let deg = deg.unwrap_or(settings.step_deg());
+                bot.set_jog_settings(settings.set_step_mm(mm).set_step_deg(deg));
+                let settings = bot.jog_settings();
+                format!(
+                    "step: {} mm, {} deg",
+                    settings.step_mm(),
+                    settings.step_deg()
+                )
+            }
+            ShellCommand::Frame(frame) => {
+                let settings = bot.jog_settings().set_frame(frame);
+                bot.set_jog_settings(settings);
+                format!("frame: {:?}", frame)
+            }
+            ShellCommand::Where => bot.get_current_transform()?.to_string(),
+            ShellCommand::Save(name) => {
+                let pose = bot.get_current_transform()?;
+                let output = format!("{}: {}", name, pose);
+                self.library.insert(name, pose);
+                self.library.save(&self.library_path)?;
+                output
+            }
+            ShellCommand::Goto(name) => {
+                let pose = self.waypoint(&name)?;
+                bot.linear(pose)?;
+                "OK".to_string()
+            }
+            ShellCommand::Delete(name) => {
+                self.waypoint(&name)?;
+                self.library.remove(&name);
+                self.library.save(&self.library_path)?;
+                "OK".to_string()
+            }
+            ShellCommand::List => self
+                .library
+                .iter()
+                .map(|(name, pose)| format!("{}: {}", name, pose))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            ShellCommand::Play(names) => {
+                let sequence = self
+                    .library
+                    .sequence(names.iter().map(String::as_str))
+                    .map_err(|name| CliError::Usage(format!("unknown waypoint: {}", name)))?;
+                bot.sequence(sequence)?;
+                "OK".to_string()
+            }
+            ShellCommand::Quit => String::new(),
+        };
+        Ok(output)
+    }
+
+    fn waypoint(&self, name: &str) -> Result<Transform, CliError> {
+        self.library
+            .get(name)
+            .cloned()
+            .ok_or_else(|| CliError::Usage(format!("unknown waypoint: {}", name)))
+    }
+
+    /// jog the tcp with the keyboard, until esc or enter
+    fn jog(&mut self, bot: &mut Robot) -> Result<(), CliError> {
+        let _raw = RawMode::enable()?;
+        let mut current = bot.get_current_transform()?;
+        loop {
+            let settings = *bot.jog_settings();
+            print!(
+                "\r{:.1} | step {} mm {} deg ({:?})    ",
+                current,
+                settings.step_mm(),
+                settings.step_deg(),
+                settings.frame()
+            );
+            std::io::stdout().flush()?;
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind == KeyEventKind::Release {
+                continue;
+            }
+            match JogAction::from_key(key.code) {
+                Some(JogAction::Jog(axis, forward)) => {
+                    bot.jog_step(axis, forward)?;
+                    current = bot.get_current_transform()?;
+                }
+                Some(JogAction::StepUp) => {
+                    bot.set_jog_settings(
+                        settings
+                            .set_step_mm(settings.step_mm() * 2.0)
+                            .set_step_deg(settings.step_deg() * 2.0),
+                    );
+                }
+                Some(JogAction::StepDown) => {
+                    bot.set_jog_settings(
+                        settings
+                            .set_step_mm(settings.step_mm() / 2.0)
+                            .set_step_deg(settings.step_deg() / 2.0),
+                    );
+                }
+                Some(JogAction::Exit) => break,
+                None => {}
+            }
+        }
+        print!("\r\n");
+        Ok(())
+    }
+}
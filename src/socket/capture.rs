@@ -0,0 +1,218 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::thread;
+
+use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+
+use crate::logger::Logger;
+use crate::socket::Stream;
+
+/// The direction of a captured line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// written to the socket, `>` in the capture file
+    Sent,
+    /// read from the socket, `<` in the capture file
+    Received,
+}
+
+impl Direction {
+    fn symbol(&self) -> char {
+        match self {
+            Direction::Sent => '>',
+            Direction::Received => '<',
+        }
+    }
+}
+
+/// A line of a capture file
+///
+/// each line of the file is `<rfc3339 timestamp> <direction> <line>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptureLine {
+    pub timestamp: DateTime<FixedOffset>,
+    pub direction: Direction,
+    pub line: String,
+}
+
+impl CaptureLine {
+    /// parse a line of a capture file
+    pub fn parse(s: &str) -> Result<Self, io::Error> {
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid capture line: {}", s),
+            )
+        };
+        let (timestamp, rest) = s.split_once(' ').ok_or_else(invalid)?;
+        let timestamp = DateTime::parse_from_rfc3339(timestamp).map_err(|_| invalid())?;
+        let mut chars = rest.chars();
+        let direction = match chars.next() {
+            Some('>') => Direction::Sent,
+            Some('<') => Direction::Received,
+            _ => return Err(invalid()),
+        };
+        let line = chars
+            .as_str()
+            .strip_prefix(' ')
+            .unwrap_or_default()
+            .to_string();
+        Ok(Self {
+            timestamp,
+            direction,
+            line,
+        })
+    }
+}
+
+impl std::fmt::Display for CaptureLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.timestamp.to_rfc3339_opts(SecondsFormat::Micros, true),
+            self.direction.symbol(),
+            self.line
+        )
+    }
+}
+
+/// the file a [`Stream`] tees its traffic to
+pub(crate) struct Capture {
+    writer: BufWriter<File>,
+}
+
+impl Capture {
+    pub(crate) fn create(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+        })
+    }
+    /// record every line of a message, flushing so the capture survives a crash
+    pub(crate) fn record(&mut self, direction: Direction, msg: &str) -> Result<(), io::Error> {
+        let timestamp = Utc::now().fixed_offset();
+        for line in msg.lines() {
+            let line = CaptureLine {
+                timestamp,
+                direction,
+                line: line.to_string(),
+            };
+            writeln!(self.writer, "{}", line)?;
+        }
+        self.writer.flush()
+    }
+}
+
+/// a recorded exchange, the lines sent and the response
+#[derive(Debug, Clone, PartialEq)]
+struct Exchange {
+    sent: Vec<String>,
+    response: String,
+}
+
+/// A replay of a capture file, serving the recorded responses without the physical robot
+///
+/// the lines received are grouped by the number of lines of each recorded request,
+/// and answered with the recorded response in order.
+/// in strict mode, a request differing from the recording is answered with `replay mismatch: ..`.
+///
+/// # Example
+/// ```no_run
+/// use inovo_rs::logger::Logger;
+/// use inovo_rs::robot::*;
+/// use inovo_rs::socket::ReplayStream;
+///
+/// # fn main() -> Result<(), RobotError> {
+/// // recorded in the field with `bot.set_capture("cell.capture")`
+/// let replay = ReplayStream::load("cell.capture")?.set_strict(true);
+/// let mut bot = Robot::new(replay.connect(Logger::empty())?, Logger::empty());
+/// let transform = bot.get_current_transform()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ReplayStream {
+    exchanges: Vec<Exchange>,
+    strict: bool,
+}
+
+impl ReplayStream {
+    /// load a capture file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        let file = BufReader::new(File::open(path)?);
+        let lines = file
+            .lines()
+            .map(|line| CaptureLine::parse(&line?))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_lines(lines))
+    }
+    /// create a replay from the lines of a capture
+    pub fn from_lines(lines: impl IntoIterator<Item = CaptureLine>) -> Self {
+        let mut exchanges = vec![];
+        let mut sent = vec![];
+        for line in lines {
+            match line.direction {
+                Direction::Sent => sent.push(line.line),
+                Direction::Received => exchanges.push(Exchange {
+                    sent: std::mem::take(&mut sent),
+                    response: line.line,
+                }),
+            }
+        }
+        Self {
+            exchanges,
+            strict: false,
+        }
+    }
+    /// set whether the requests must match the recording
+    pub fn set_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+    /// get the number of recorded exchanges
+    pub fn len(&self) -> usize {
+        self.exchanges.len()
+    }
+    /// whether there is no recorded exchange
+    pub fn is_empty(&self) -> bool {
+        self.exchanges.is_empty()
+    }
+
+    /// serve the recording on a local socket in a background thread, and connect a [`Stream`] to it
+    ///
+    /// the connection is closed after the last recorded response.
+    pub fn connect(self, logger: Logger) -> Result<Stream, io::Error> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let client = TcpStream::connect(listener.local_addr()?)?;
+        let (server, _) = listener.accept()?;
+        thread::spawn(move || self.serve(server));
+        Stream::new(client, logger)
+    }
+
+    fn serve(self, server: TcpStream) -> Result<(), io::Error> {
+        let mut writer = server.try_clone()?;
+        let mut lines = BufReader::new(server).lines();
+        for exchange in self.exchanges {
+            let mut sent = Vec::with_capacity(exchange.sent.len());
+            for _ in 0..exchange.sent.len() {
+                match lines.next() {
+                    Some(line) => sent.push(line?),
+                    None => return Ok(()),
+                }
+            }
+            let response = if self.strict && sent != exchange.sent {
+                format!(
+                    "replay mismatch: expected {:?}, got {:?}",
+                    exchange.sent.join(" "),
+                    sent.join(" ")
+                )
+            } else {
+                exchange.response
+            };
+            writeln!(writer, "{}", response)?;
+        }
+        Ok(())
+    }
+}
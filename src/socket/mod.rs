@@ -22,6 +22,13 @@
 use net2::TcpBuilder;
 use std::io::{self, BufRead, BufReader, BufWriter, Write};
 use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::Path;
+use std::time::Instant;
+
+mod capture;
+
+use capture::Capture;
+pub use capture::{CaptureLine, Direction, ReplayStream};
 
 use crate::logger::*;
 
@@ -53,9 +60,9 @@ impl Listener {
         let ip = local_ip_address::local_ip().unwrap();
         let addr = SocketAddr::from((ip, port));
 
-        let mut logger = logger.unwrap_or_else(|| {
+        let logger = logger.unwrap_or_else(|| {
             let name = format!("Listener {}", addr).replace(":", "-");
-            Logger::default_target(&name)
+            Logger::get_or_create(name)
         });
 
         logger.info("creating new socket . . .");
@@ -72,7 +79,7 @@ impl Listener {
     /// accept a new connection and return `Stream`
     ///
     /// ## Argument
-    /// - `logger : Option<Logger>` : a logger for the accepted stream.
+    /// - `logger : Option<Logger>` : a logger for the accepted stream, default to the listener's logger.
     pub fn accept(&mut self, logger: Option<Logger>) -> Result<Stream, io::Error> {
         self.info("accepting new connection . . .");
 
@@ -81,20 +88,7 @@ impl Listener {
         self.info("successful accept new connection.");
         self.info(format!("    {}", tcp_stream.peer_addr()?));
 
-        let logger = logger.unwrap_or_else(|| {
-            let local_addr = self
-                .tcp_listener
-                .local_addr()
-                .unwrap()
-                .to_string()
-                .replace(":", "-");
-            let peer_addr = tcp_stream
-                .peer_addr()
-                .unwrap()
-                .to_string()
-                .replace(":", "-");
-            Logger::default_target(format!("Handle {} {}", local_addr, peer_addr))
-        });
+        let logger = logger.unwrap_or_else(|| self.logger.clone());
 
         Stream::new(tcp_stream, logger)
     }
@@ -125,6 +119,12 @@ pub struct Stream {
     buffer: String,
     /// Logger of tcp stream
     logger: Logger,
+    /// The file the traffic is teed to, see [`Stream::set_capture`]
+    capture: Option<Capture>,
+    /// The logger of the raw lines, see [`Stream::set_wire_trace`]
+    wire_trace: Option<Logger>,
+    /// The time the last line was sent, for the round trip of the wire trace
+    wire_sent: Option<Instant>,
 }
 
 impl Logable for Stream {
@@ -139,7 +139,7 @@ impl Stream {
     /// ## Argument
     /// - `name : Option<String>` : a name for the accepted stream, default to ip address
     /// - `logger : Option<Logger>` : a logger for the accepted stream.
-    pub fn new(tcp_stream: TcpStream, mut logger: Logger) -> Result<Self, io::Error> {
+    pub fn new(tcp_stream: TcpStream, logger: Logger) -> Result<Self, io::Error> {
         let buf_writer = BufWriter::new(tcp_stream.try_clone()?);
         let buf_reader = BufReader::new(tcp_stream.try_clone()?);
         let buffer = String::new();
@@ -151,6 +151,9 @@ impl Stream {
             buf_reader,
             buffer,
             logger,
+            capture: None,
+            wire_trace: None,
+            wire_sent: None,
         })
     }
     /// connect to a socket
@@ -166,7 +169,7 @@ impl Stream {
         let logger = logger.unwrap_or_else(|| {
             let peer_addr = addr.to_string().replace(":", "-");
             let local_addr = local_addr.clone().to_string().replace(":", "-");
-            Logger::default_target(format!("Client {} {}", local_addr, peer_addr))
+            Logger::get_or_create(format!("Client {} {}", local_addr, peer_addr))
         });
 
         let tcp_stream = TcpBuilder::new_v4()?.bind(local_addr)?.connect(addr)?;
@@ -175,15 +178,30 @@ impl Stream {
     }
 
     /// write a message ends with `\r\n` to the socket stream
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "socket_write", skip_all, err)
+    )]
     pub fn write(&mut self, msg: impl Into<String>) -> Result<(), io::Error> {
         let msg: String = format!("{}\r\n", msg.into());
+        #[cfg(feature = "tracing")]
+        tracing::trace!(line = msg.trim(), "write");
         self.debug(format!(">>> {}", msg.trim()));
-        self.buf_writer.write(msg.as_bytes())?;
+        self.buf_writer.write_all(msg.as_bytes())?;
         self.buf_writer.flush()?;
+        self.capture(Direction::Sent, msg.trim_end());
+        if let Some(wire) = &self.wire_trace {
+            wire.trace(format!(">>> {}", msg.trim_end()));
+            self.wire_sent = Some(Instant::now());
+        }
         Ok(())
     }
 
     /// read a message ends with `\n` from the socket stream
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "socket_read", skip_all, err)
+    )]
     pub fn read(&mut self) -> Result<String, io::Error> {
         self.buffer.clear();
         let size = self.buf_reader.read_line(&mut self.buffer)?;
@@ -191,9 +209,71 @@ impl Stream {
             return Err(std::io::Error::other("0 input bytes, diconnected"));
         }
         let msg = self.buffer.clone().trim().to_string();
+        #[cfg(feature = "tracing")]
+        tracing::trace!(line = msg.as_str(), "read");
         self.debug(format!("<<< {}", msg));
+        self.capture(Direction::Received, &msg);
+        if let Some(wire) = &self.wire_trace {
+            match self.wire_sent.take() {
+                Some(sent) => wire.trace(format!(
+                    "<<< {} ({:.3} ms)",
+                    msg,
+                    sent.elapsed().as_secs_f64() * 1000.0
+                )),
+                None => wire.trace(format!("<<< {}", msg)),
+            }
+        }
         Ok(msg)
     }
+
+    /// check a message can be read without blocking, or the connection is closed
+    pub fn is_readable(&mut self) -> Result<bool, io::Error> {
+        if !self.buf_reader.buffer().is_empty() {
+            return Ok(true);
+        }
+        let tcp_stream = self.buf_reader.get_ref();
+        tcp_stream.set_nonblocking(true)?;
+        let peeked = tcp_stream.peek(&mut [0; 1]);
+        tcp_stream.set_nonblocking(false)?;
+        match peeked {
+            Ok(_) => Ok(true),
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// tee the sent and received lines, with timestamps, to a capture file, see [`ReplayStream`]
+    pub fn set_capture(&mut self, path: impl AsRef<Path>) -> Result<&mut Self, io::Error> {
+        self.capture = Some(Capture::create(path)?);
+        Ok(self)
+    }
+    /// stop teeing the traffic to the capture file
+    pub fn stop_capture(&mut self) -> &mut Self {
+        self.capture = None;
+        self
+    }
+    /// log every raw line sent and received at trace level to a logger, or stop if `None`
+    ///
+    /// a received line is logged with the time since the last line sent, the round trip of a request.
+    pub fn set_wire_trace(&mut self, logger: Option<Logger>) -> &mut Self {
+        self.wire_trace = logger;
+        self.wire_sent = None;
+        self
+    }
+    /// whether the raw lines are logged, see [`Stream::set_wire_trace`]
+    pub fn is_wire_traced(&self) -> bool {
+        self.wire_trace.is_some()
+    }
+    /// record a message to the capture file, stopping the capture if it fail
+    fn capture(&mut self, direction: Direction, msg: &str) {
+        let Some(capture) = &mut self.capture else {
+            return;
+        };
+        if let Err(e) = capture.record(direction, msg) {
+            self.capture = None;
+            self.warn(format!("capture stopped: {}", e));
+        }
+    }
     /// get the local socket address of the stream
     pub fn local_addr(&self) -> Result<SocketAddr, io::Error> {
         self.buf_writer.get_ref().local_addr()
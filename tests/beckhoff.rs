@@ -0,0 +1,70 @@
+mod common;
+
+use common::{fake_psu, FakePsu};
+use inovo_rs::iva::IOTarget;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn beckhoff_modules() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut robot = fake_psu(records.clone())?;
+
+    let modules = robot.beckhoff_modules()?;
+    let names: Vec<&str> = modules.modules().iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, ["EL1008", "EL2008", "EL1004"]);
+    assert_eq!((modules.inputs(), modules.outputs()), (12, 8));
+
+    // the inputs numbered across the modules, in slot order
+    assert_eq!(modules.input_module(7).unwrap().name, "EL1008");
+    assert_eq!(modules.input_module(8).unwrap().name, "EL1004");
+    assert!(modules.input_module(12).is_none());
+    assert_eq!(modules.output_module(0).unwrap().slot, 2);
+
+    modules.validate([0, 11], [7])?;
+    let error = modules.validate([3, 12], [8]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        RobotError::InvalidArgument(
+            "beckhoff input 12, output 8 not available, 12 inputs and 8 outputs".to_string()
+        )
+        .to_string()
+    );
+    assert!(records.lock().unwrap()[0].contains("\"target\":\"beckhoff_modules\""));
+    Ok(())
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn io_get_bank_range() -> Result<(), Box<dyn std::error::Error>> {
+    let psu = FakePsu::new()?;
+    let mut robot = psu.connect()?;
+
+    assert_eq!(robot.beckhoff_get_bank(0..8)?, 0);
+    for range in [3..3, 8..0, 0..33] {
+        assert!(matches!(
+            robot.wrist_get_bank(range),
+            Err(RobotError::InvalidArgument(_))
+        ));
+    }
+    // only the valid range is sent
+    assert_eq!(psu.instructions().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn pulse_output_duration() -> Result<(), Box<dyn std::error::Error>> {
+    let psu = FakePsu::new()?;
+    let mut robot = psu.connect()?;
+
+    robot.pulse_output(IOTarget::Beckhoff, 1, 0.5)?;
+    for second in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            robot.pulse_output(IOTarget::Beckhoff, 1, second),
+            Err(RobotError::InvalidArgument(_))
+        ));
+    }
+    // only the valid pulse is sent
+    assert_eq!(psu.instructions().len(), 1);
+    Ok(())
+}
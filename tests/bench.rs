@@ -0,0 +1,47 @@
+#![cfg(feature = "bench")]
+
+use inovo_rs::bench::{self, BenchReport, FakePsu};
+use inovo_rs::geometry::Transform;
+use inovo_rs::iva::{Instruction, RobotCommand};
+use inovo_rs::robot::*;
+use std::time::Duration;
+
+#[test]
+fn fake_psu() -> Result<(), Box<dyn std::error::Error>> {
+    let psu = FakePsu::new()?;
+    let mut bot = psu.connect()?;
+
+    bot.linear(Transform::from_x(100.0))?;
+    assert!(bot
+        .get_current_transform()?
+        .approx_eq(&Transform::identity(), 1e-9, 1e-9));
+    assert_eq!(bot.get_current_joint()?, Default::default());
+    assert!(!bot.beckhoff_get(0)?);
+    assert_eq!(bot.context_depth()?, 0);
+    assert_eq!(psu.instructions().len(), 5);
+
+    let psu = FakePsu::with_responder(|_| "Error".to_string())?;
+    assert!(psu.connect()?.sleep(0.0).is_err());
+    Ok(())
+}
+
+#[test]
+fn bench_report() -> Result<(), Box<dyn std::error::Error>> {
+    let samples = (1..=100).map(Duration::from_millis).collect();
+    let report = BenchReport::new("report", samples);
+    assert_eq!(report.iterations, 100);
+    assert_eq!(report.min, Duration::from_millis(1));
+    assert_eq!(report.p50, Duration::from_millis(50));
+    assert_eq!(report.p99, Duration::from_millis(99));
+    assert_eq!(report.max, Duration::from_millis(100));
+
+    let psu = FakePsu::new()?;
+    let mut bot = psu.connect()?;
+    let sleep = Instruction::exec(RobotCommand::sleep(0.0));
+    let report = bench::round_trip(&mut bot, sleep.clone(), 20)?;
+    assert_eq!(report.iterations, 20);
+    assert!(report.min <= report.p50 && report.p50 <= report.max);
+    assert_eq!(psu.instructions().len(), 20 + bench::WARMUP);
+    assert_eq!(bench::serialization(&sleep, 20)?.iterations, 20);
+    Ok(())
+}
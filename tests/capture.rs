@@ -0,0 +1,109 @@
+mod common;
+
+use common::{fake_psu, FakePsu};
+use inovo_rs::geometry::Transform;
+use inovo_rs::logger::target::{CaptureTarget, LoggingTarget};
+use inovo_rs::logger::{LogLevel, Logger};
+use inovo_rs::robot::*;
+use inovo_rs::socket::{CaptureLine, Direction, ReplayStream};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn capture_and_replay() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::temp_dir().join(format!("inovo-capture-{}.txt", std::process::id()));
+
+    let mut bot = fake_psu(Arc::new(Mutex::new(vec![])))?;
+    bot.set_capture(&path)?;
+    bot.sleep(1.0)?;
+    let transform = bot.get_current_transform()?;
+    assert!(bot.linear(Transform::from_x(10.0)).is_err());
+    bot.stop_capture().sleep(2.0)?;
+
+    let capture = std::fs::read_to_string(&path)?;
+    let lines = capture
+        .lines()
+        .map(CaptureLine::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    let received: Vec<_> = lines
+        .iter()
+        .filter(|line| line.direction == Direction::Received)
+        .map(|line| line.line.as_str())
+        .collect();
+    assert_eq!(received[0], "OK");
+    assert_eq!(received[2], "Error");
+    assert_eq!(lines[0].to_string(), capture.lines().next().unwrap());
+
+    // replay the same run without the psu
+    let replay = ReplayStream::load(&path)?.set_strict(true);
+    assert_eq!(replay.len(), 3);
+    let mut bot = Robot::new(replay.connect(Logger::empty())?, Logger::empty());
+    bot.sleep(1.0)?;
+    assert_eq!(bot.get_current_transform()?, transform);
+    assert!(bot.linear(Transform::from_x(10.0)).is_err());
+    // the recording is exhausted
+    assert!(bot.sleep(1.0).is_err());
+
+    // a diverging run is answered with a mismatch
+    let replay = ReplayStream::load(&path)?.set_strict(true);
+    let mut bot = Robot::new(replay.connect(Logger::empty())?, Logger::empty());
+    match bot.sleep(5.0) {
+        Err(RobotError::ResponseError(res)) => assert!(res.starts_with("replay mismatch")),
+        res => panic!("unexpected result: {:?}", res.map(|_| ())),
+    }
+
+    std::fs::remove_file(path)?;
+    Ok(())
+}
+
+/// a target recording every message
+struct RecordTarget(Arc<Mutex<Vec<String>>>);
+
+impl LoggingTarget for RecordTarget {
+    fn log_message(&mut self, msg: &String, _: LogLevel) {
+        self.0.lock().unwrap().push(msg.trim_end().to_string());
+    }
+    fn set_level(&mut self, _: LogLevel) {}
+    fn get_level(&self) -> LogLevel {
+        LogLevel::Trace
+    }
+    fn flush(&mut self) {}
+}
+
+#[test]
+fn wire_trace() -> Result<(), RobotError> {
+    let traced = Arc::new(Mutex::new(vec![]));
+    let mut bot = fake_psu(Arc::new(Mutex::new(vec![])))?;
+    assert!(!bot.is_wire_traced());
+
+    bot.set_wire_trace_logger(Logger::new(vec![Box::new(RecordTarget(traced.clone()))]));
+    bot.get_current_transform()?;
+    bot.set_wire_trace(false)?.sleep(1.0)?;
+    assert!(!bot.is_wire_traced());
+
+    let traced = traced.lock().unwrap().clone();
+    assert_eq!(traced.len(), 2);
+    assert!(traced[0].starts_with(">>> {"));
+    assert!(traced[0].contains(r#""target": "transform""#));
+    assert!(traced[1].starts_with("<<< {rx: 0, ry: 0, rz: 0, x: 0.1, y: 0.2, z: 0.3} ("));
+    assert!(traced[1].ends_with(" ms)"));
+
+    bot.set_wire_trace(true)?;
+    assert!(bot.is_wire_traced());
+    Ok(())
+}
+
+#[test]
+fn wire_trace_keeps_registered_level() -> Result<(), Box<dyn std::error::Error>> {
+    let psu = FakePsu::new()?;
+    let mut capture = CaptureTarget::new();
+    capture.set_level(LogLevel::Info);
+    let name = format!("wire {}", psu.addr()).replace(':', "-");
+    Logger::register(name, Logger::new(vec![Box::new(capture.clone())]));
+
+    // the registered logger is used as is, not raised to trace
+    let mut bot = psu.connect()?;
+    bot.set_wire_trace(true)?.sleep(1.0)?;
+    assert!(bot.is_wire_traced());
+    assert!(capture.messages().is_empty());
+    Ok(())
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "cli")]
+
+use inovo_rs::cli::*;
+use inovo_rs::geometry::*;
+use inovo_rs::iva::*;
+
+fn parse(args: &str) -> Result<CliOptions, CliError> {
+    CliOptions::parse(args.split_whitespace())
+}
+
+#[test]
+fn cli_parse() {
+    let options = parse("--host psu002 --port 50010 io set 3 on").unwrap();
+    assert_eq!(options.port, 50010);
+    assert!(!options.no_color);
+    assert!(parse("--no-color --host h get joint").unwrap().no_color);
+    assert_eq!(
+        options.command,
+        CliCommand::IoSet {
+            target: IOTarget::Beckhoff,
+            port: 3,
+            state: true
+        }
+    );
+
+    let commands = [
+        (
+            "io get 2 --wrist",
+            CliCommand::IoGet {
+                target: IOTarget::Wrist,
+                port: 2,
+            },
+        ),
+        ("get transform", CliCommand::GetTransform),
+        ("get data my_key", CliCommand::GetData("my_key".to_string())),
+        (
+            "joint 0 90 0 0 0 0",
+            CliCommand::Joint(JointCoord::from_j2(90.0)),
+        ),
+        (
+            "gripper width 20 50",
+            CliCommand::Gripper(GripperCommand::SetWidth {
+                mm: 20.0,
+                force: 50.0,
+            }),
+        ),
+        ("vacuum off", CliCommand::Vacuum(VacuumCommand::Off)),
+        (
+            "run-seq seq.json",
+            CliCommand::RunSequence("seq.json".into()),
+        ),
+    ];
+    for (args, command) in commands {
+        assert_eq!(
+            parse(&format!("--host h {}", args)).unwrap().command,
+            command
+        );
+    }
+
+    // help does not need a host
+    assert_eq!(parse("--help").unwrap().command, CliCommand::Help);
+
+    for invalid in [
+        "--host h",
+        "--host h linear 1 2 3",
+        "--host h linear 1 2 3 4 5 x",
+        "--host h io set 3 maybe",
+        "--host h get nothing",
+        "--host h --port none sleep 1",
+    ] {
+        assert!(
+            matches!(parse(invalid), Err(CliError::Usage(_))),
+            "{}",
+            invalid
+        );
+    }
+}
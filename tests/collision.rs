@@ -0,0 +1,90 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+fn wait_triggered(watch: &CollisionWatch) {
+    let start = Instant::now();
+    while !watch.is_triggered() {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "no anomaly detected"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+pub fn collision_torque_limit() -> Result<(), RobotError> {
+    let mut bot = fake_psu(Arc::new(Mutex::new(vec![])))?;
+    let monitor = fake_psu(Arc::new(Mutex::new(vec![])))?;
+
+    let called = Arc::new(Mutex::new(vec![]));
+    let callback_called = called.clone();
+    // the fake psu responds the torques [1.5, -2, 0, 0.25, 0, 0]
+    let watch = CollisionMonitor::new()
+        .set_rate_hz(100.0)
+        .set_torque_limits([1.0, 5.0, 5.0, 5.0, 5.0, 5.0])
+        .set_torque_jump(0.1)
+        .set_cancel_token(bot.cancel_token())
+        .on_anomaly(move |anomaly| callback_called.lock().unwrap().push(anomaly.clone()))
+        .spawn(monitor)?;
+    wait_triggered(&watch);
+
+    assert!(matches!(bot.sleep(0.0), Err(RobotError::Cancelled)));
+    assert_eq!(bot.stats().errors.get("cancelled"), Some(&1));
+    bot.cancel_token().reset();
+    bot.sleep(0.0)?;
+
+    std::thread::sleep(Duration::from_millis(50));
+    let (anomalies, monitor) = watch.stop()?;
+    assert!(monitor.is_some());
+    // reported once while over the limit, the torques never jump
+    let expected = Anomaly::TorqueLimit {
+        joint: 1,
+        torque: 1.5,
+        limit: 1.0,
+    };
+    assert_eq!(anomalies, vec![expected.clone()]);
+    assert_eq!(*called.lock().unwrap(), vec![expected.clone()]);
+    assert_eq!(expected.to_string(), "j1 torque 1.50 Nm over limit 1.00 Nm");
+    Ok(())
+}
+
+#[test]
+pub fn collision_shared_cancel() -> Result<(), RobotError> {
+    let handle = RobotHandle::new(fake_psu(Arc::new(Mutex::new(vec![])))?);
+    let token = handle.with(|bot| bot.cancel_token());
+
+    let watch = CollisionMonitor::new()
+        .set_torque_limits([0.0; 6])
+        .set_cancel_token(token.clone())
+        .spawn_shared(handle.clone())?;
+    wait_triggered(&watch);
+
+    let start = Instant::now();
+    while !watch.is_finished() {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "monitor not stopped"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let (anomalies, monitor) = watch.stop()?;
+    assert!(monitor.is_none());
+    assert_eq!(anomalies.len(), 3);
+    assert!(token.is_cancelled());
+    Ok(())
+}
+
+#[test]
+pub fn collision_invalid_rate() -> Result<(), RobotError> {
+    let monitor = fake_psu(Arc::new(Mutex::new(vec![])))?;
+    assert!(matches!(
+        CollisionMonitor::new().set_rate_hz(0.0).spawn(monitor),
+        Err(RobotError::InvalidArgument(_))
+    ));
+    Ok(())
+}
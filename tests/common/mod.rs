@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+pub use inovo_rs::fake_psu::FakePsu;
+use inovo_rs::robot::Robot;
+use std::sync::{Arc, Mutex};
+
+/// a fake psu answering every instruction, recording the instructions without spaces
+pub fn fake_psu(records: Arc<Mutex<Vec<String>>>) -> Result<Robot, std::io::Error> {
+    FakePsu::with_json_responder(move |json| {
+        let instruction: String = json.lines().map(|line| line.replace(" ", "")).collect();
+        let get = instruction.contains("\"op_code\":\"get\"");
+        // changes every third instruction
+        let counter = (records.lock().unwrap().len() / 3).to_string();
+        let response = if get && instruction.contains("\"key\":\"counter\"") {
+            &counter
+        } else if instruction.contains("\"action\":\"get\"") {
+            "True"
+        } else if get && instruction.contains("\"target\":\"snapshot\"") {
+            r#"{"transform": "{rx: 0, ry: 0, rz: 0, x: 0.1, y: 0.2, z: 0.3}", "joint_coord": "[0, 0, 0, 0, 0, 0]", "beckhoff": 9, "wrist": 0, "gripper": "None"}"#
+        } else if get && instruction.contains("\"target\":\"info\"") {
+            r#"{"model": "IVA-5", "serial_number": "IV5-0042", "software_version": "2.4.1", "tool": "Robotiq 2F-85"}"#
+        } else if get && instruction.contains("\"target\":\"beckhoff_modules\"") {
+            r#"[{"slot": 2, "name": "EL2008", "inputs": 0, "outputs": 8}, {"slot": 1, "name": "EL1008", "inputs": 8, "outputs": 0}, {"slot": 3, "name": "EL1004", "inputs": 4, "outputs": 0}]"#
+        } else if get && instruction.contains("\"target\":\"queue\"") {
+            r#"[{"action": "sleep", "second": 1.5}, {"action": "synchronize"}]"#
+        } else if get && instruction.contains("\"target\":\"transform\"") {
+            "{rx: 0, ry: 0, rz: 0, x: 0.1, y: 0.2, z: 0.3}"
+        } else if get && instruction.contains("\"target\":\"joint_coord\"") {
+            "[0, 0, 0, 0, 0, 0]"
+        } else if get && instruction.contains("\"target\":\"joint_torques\"") {
+            "[1.5, -2, 0, 0.25, 0, 0]"
+        } else if get && instruction.contains("\"target\":\"wrench\"") {
+            "[0, 0, -9.81, 0, 0.5, 0]"
+        } else if get && instruction.contains("\"target\":\"context_depth\"") {
+            "0"
+        } else if instruction.contains("\"linear\"") {
+            "Error"
+        } else {
+            "OK"
+        }
+        .to_string();
+        records.lock().unwrap().push(instruction);
+        Some(response)
+    })?
+    .connect()
+}
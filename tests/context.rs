@@ -0,0 +1,126 @@
+use inovo_rs::context::*;
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct Machine {
+    events: Vec<&'static str>,
+}
+
+impl Machine {
+    fn with_context(&mut self) -> ContextGuard<'_, Self, Tracked> {
+        ContextGuard::new(self, Tracked)
+    }
+}
+
+struct Tracked;
+
+impl Context<Machine> for Tracked {
+    fn context_enter(&mut self, machine: &mut Machine) {
+        machine.events.push("enter");
+    }
+    fn context_drop(&mut self, machine: &mut Machine) {
+        machine.events.push("drop");
+    }
+}
+
+#[test]
+fn guard_commit() {
+    let mut machine = Machine::default();
+
+    // rolled back on the error path
+    {
+        let mut guard = machine.with_context();
+        guard.events.push("failed");
+    }
+    assert_eq!(machine.events, vec!["enter", "failed", "drop"]);
+    machine.events.clear();
+
+    // kept when committed
+    let mut guard = machine.with_context();
+    guard.events.push("succeed");
+    let mut context = guard.commit();
+    assert_eq!(machine.events, vec!["enter", "succeed"]);
+
+    // and can be exited manually
+    context.context_drop(&mut machine);
+    assert_eq!(machine.events, vec!["enter", "succeed", "drop"]);
+}
+
+struct Fallible;
+
+impl Context<Machine> for Fallible {
+    fn context_enter(&mut self, _: &mut Machine) {}
+    fn context_drop(&mut self, machine: &mut Machine) {
+        let _ = self.try_context_drop(machine);
+    }
+}
+
+impl TryContext<Machine> for Fallible {
+    type Error = String;
+    fn try_context_drop(&mut self, machine: &mut Machine) -> Result<(), String> {
+        machine.events.push("drop");
+        Err("fail to reverse".to_string())
+    }
+}
+
+#[test]
+fn guard_finish() {
+    let mut machine = Machine::default();
+
+    let guard = ContextGuard::new(&mut machine, Fallible);
+    assert_eq!(guard.finish(), Err("fail to reverse".to_string()));
+
+    // exited exactly once
+    assert_eq!(machine.events, vec!["drop"]);
+}
+
+#[test]
+fn guard_scope() {
+    let mut machine = Machine::default();
+
+    // the closure error is returned, and the context still exited
+    let result: Result<(), String> = ContextGuard::new(&mut machine, Fallible).scope(|m| {
+        m.events.push("failed");
+        Err("closure failed".to_string())
+    });
+    assert_eq!(result, Err("closure failed".to_string()));
+    assert_eq!(machine.events, vec!["failed", "drop"]);
+    machine.events.clear();
+
+    // the exit error is returned after a successful closure
+    let result = ContextGuard::new(&mut machine, Fallible).scope(|m| {
+        m.events.push("succeed");
+        Ok::<_, String>(42)
+    });
+    assert_eq!(result, Err("fail to reverse".to_string()));
+    assert_eq!(machine.events, vec!["succeed", "drop"]);
+}
+
+struct App {
+    machine: Arc<Mutex<Machine>>,
+    open: Option<OwnedContextGuard<Machine, Tracked>>,
+}
+
+#[test]
+fn owned_guard() {
+    let machine = Arc::new(Mutex::new(Machine::default()));
+    let mut app = App {
+        machine: machine.clone(),
+        open: None,
+    };
+
+    app.open = Some(OwnedContextGuard::enter(machine, |m| Ok::<_, ()>(m.with_context())).unwrap());
+    app.open.as_ref().unwrap().lock().events.push("working");
+    assert_eq!(app.machine.lock().unwrap().events, vec!["enter", "working"]);
+
+    app.open = None;
+    assert_eq!(
+        app.machine.lock().unwrap().events,
+        vec!["enter", "working", "drop"]
+    );
+
+    // entered directly, exited with finish
+    let guard = OwnedContextGuard::new(app.machine.clone(), Fallible);
+    assert!(guard.finish().is_err());
+    assert_eq!(app.machine.lock().unwrap().events.last(), Some(&"drop"));
+}
@@ -0,0 +1,58 @@
+use inovo_rs::conveyor::*;
+use inovo_rs::geometry::*;
+use inovo_rs::robot::RobotError;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn approx_eq(a: [f64; 3], b: [f64; 3]) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-6)
+}
+
+#[test]
+pub fn conveyor_shift() -> Result<(), RobotError> {
+    let position = Arc::new(Mutex::new(100.0));
+    let source_position = position.clone();
+    let source = move || Ok(*source_position.lock().unwrap());
+
+    let mut conveyor = Conveyor::new(source, [0.0, 2.0, 0.0])?;
+    assert_eq!(conveyor.direction(), [0.0, 1.0, 0.0]);
+
+    let part = conveyor.latch(Transform::from_vector([300.0, -200.0, 50.0]).set_rz(90.0))?;
+    assert_eq!(part.latch_mm, 100.0);
+
+    *position.lock().unwrap() = 250.0;
+    let shifted = conveyor.shift(&part)?;
+    assert!(approx_eq(shifted.get_vector(), [300.0, -50.0, 50.0]));
+    assert!((shifted.get_rz() - 90.0).abs() < 1e-6);
+    assert!(conveyor.speed_mm_s() > 0.0);
+
+    assert_eq!(conveyor.travelled_mm(&part)?, 150.0);
+    // no motion since the last reading, the speed estimate is 0
+    let predicted = conveyor.predict(&part, Duration::from_secs(1))?;
+    assert!(approx_eq(predicted.get_vector(), [300.0, -50.0, 50.0]));
+    Ok(())
+}
+
+#[test]
+pub fn conveyor_predict() -> Result<(), RobotError> {
+    let start = std::time::Instant::now();
+    // 100 mm/s
+    let source = move || Ok(start.elapsed().as_secs_f64() * 100.0);
+    let mut conveyor = Conveyor::new(source, [1.0, 0.0, 0.0])?;
+
+    let part = conveyor.latch(Transform::identity())?;
+    std::thread::sleep(Duration::from_millis(50));
+    let predicted = conveyor.predict(&part, Duration::from_secs(1))?;
+    assert!((conveyor.speed_mm_s() - 100.0).abs() < 1.0);
+    assert!((predicted.get_x() - 105.0).abs() < 5.0);
+    Ok(())
+}
+
+#[test]
+pub fn conveyor_invalid_direction() {
+    let source = || Ok(0.0);
+    assert!(matches!(
+        Conveyor::new(source, [0.0; 3]),
+        Err(RobotError::InvalidArgument(_))
+    ));
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::iva::*;
+use inovo_rs::robot::*;
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+#[test]
+pub fn custom_handler_invoke() -> Result<(), RobotError> {
+    #[derive(Serialize)]
+    struct Tray {
+        slot: u32,
+        label: String,
+        offset: [f64; 2],
+    }
+    struct SetTray;
+    impl CustomHandler for SetTray {
+        const NAME: &'static str = "set_tray";
+        type Request = Tray;
+        type Response = ();
+    }
+
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut bot = fake_psu(records.clone())?;
+    bot.invoke::<SetTray>(Tray {
+        slot: 3,
+        label: "A".to_string(),
+        offset: [1.0, -2.0],
+    })?;
+    let sent: serde_json::Value = serde_json::from_str(&records.lock().unwrap()[0]).unwrap();
+    assert_eq!(
+        sent,
+        serde_json::json!({
+            "op_code": "custom",
+            "handler": "set_tray",
+            "slot": 3.0,
+            "label": "A",
+            "offset": "[1.0,-2.0]",
+        })
+    );
+
+    assert!(CustomCommand::from_serialize(&42.0).is_err());
+    assert_eq!(
+        CustomCommand::from_serialize(&()).unwrap(),
+        CustomCommand::new()
+    );
+    assert_eq!(
+        <()>::from_robot("Error".to_string()),
+        Err("Error".to_string())
+    );
+    Ok(())
+}
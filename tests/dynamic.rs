@@ -0,0 +1,106 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::context::{Context, TryContext};
+use inovo_rs::geometry::Transform;
+use inovo_rs::iva::Instruction;
+use inovo_rs::logger::{Logable, Logger};
+use inovo_rs::robot::dynamic::DynRobot;
+use inovo_rs::robot::{
+    CommandSequence, IvaContext, IvaRobot, MotionParam, Robot, RobotError, RobotHandle,
+};
+use std::sync::{Arc, Mutex};
+
+/// a mock robot, answering every query with `"0"` and command with `"OK"`
+struct MockRobot {
+    logger: Logger,
+    instructions: Vec<Instruction>,
+}
+
+impl Logable for MockRobot {
+    fn get_logger(&mut self) -> &mut Logger {
+        &mut self.logger
+    }
+}
+
+impl IvaRobot for MockRobot {
+    fn instruction(&mut self, inst: Instruction) -> Result<String, RobotError> {
+        let res = match inst {
+            Instruction::Get(_) => "0",
+            _ => "OK",
+        };
+        self.instructions.push(inst);
+        Ok(res.to_string())
+    }
+}
+
+impl Context<MockRobot> for IvaContext {
+    fn context_enter(&mut self, _: &mut MockRobot) {}
+    fn context_drop(&mut self, machine: &mut MockRobot) {
+        let _ = IvaRobot::pop(machine);
+    }
+}
+
+fn cycle(bot: &mut dyn DynRobot) -> Result<(), RobotError> {
+    bot.sleep(0.0)?
+        .linear_relative(Transform::from_z(-10.0))?
+        .sync()?
+        .gripper_set("close")?;
+    Ok(())
+}
+
+#[test]
+fn dyn_robot() -> Result<(), Box<dyn std::error::Error>> {
+    let mut robots: Vec<Box<dyn DynRobot>> = vec![
+        Box::new(MockRobot {
+            logger: Logger::empty(),
+            instructions: vec![],
+        }),
+        Box::new(fake_psu(Arc::new(Mutex::new(vec![])))?),
+    ];
+
+    for bot in robots.iter_mut() {
+        cycle(bot.as_mut())?;
+        bot.logger().info("cycle done");
+        assert_eq!(bot.context_depth()?, 0);
+    }
+    Ok(())
+}
+
+/// a helper written against the trait, entering and leaving a context
+fn place<R: IvaRobot>(bot: &mut R) -> Result<(), RobotError>
+where
+    IvaContext: TryContext<R, Error = RobotError>,
+{
+    bot.set_param(MotionParam::new().set_speed(20.0))?;
+    let sequence = CommandSequence::new().then_sleep(0.0).then_sync();
+    bot.with_sequence(sequence)?.finish()?;
+    bot.sleep(0.0)?;
+    Ok(())
+}
+
+#[test]
+fn shared_wrappers() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut robot = fake_psu(records.clone())?;
+
+    let mut borrowed: &mut Robot = &mut robot;
+    place(&mut borrowed)?;
+    // the overrides of the robot are kept
+    assert_eq!(
+        robot.motion_param(),
+        Some(&MotionParam::new().set_speed(20.0))
+    );
+    let count = records.lock().unwrap().len();
+    assert!(records.lock().unwrap()[count - 2].contains("\"pop\""));
+
+    let mut handle = RobotHandle::new(robot);
+    let mut other = handle.clone();
+    std::thread::spawn(move || place(&mut other))
+        .join()
+        .unwrap()?;
+    place(&mut handle)?;
+    assert_eq!(records.lock().unwrap().len(), count * 3);
+    assert_eq!(IvaRobot::context_depth(&mut handle)?, 0);
+    Ok(())
+}
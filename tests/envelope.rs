@@ -0,0 +1,54 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::geometry::*;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+fn state(x: f64, j1: f64) -> RobotState {
+    RobotState {
+        transform: Transform::from_x(x).then_z(50.0),
+        joints: JointCoord::from_j1(j1),
+        timestamp: SystemTime::now(),
+    }
+}
+
+#[test]
+fn motion_envelope() {
+    let mut a = MotionEnvelope::new();
+    assert!(a.is_empty());
+    a.record(&state(-100.0, -10.0));
+    a.record(&state(50.0, 20.0));
+    assert_eq!(a.samples, 2);
+    assert_eq!(a.min_mm, [-100.0, 0.0, 50.0]);
+    assert_eq!(a.size_mm(), [150.0, 0.0, 0.0]);
+    assert_eq!(a.joint_range_deg(), [30.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    assert!(a.within_mm([-200.0, -1.0, 0.0], [200.0, 1.0, 100.0]));
+    assert!(!a.within_mm([-50.0, -1.0, 0.0], [200.0, 1.0, 100.0]));
+
+    let mut b = MotionEnvelope::new();
+    b.record(&state(300.0, 5.0));
+    b.merge(&a);
+    assert_eq!(b.samples, 3);
+    assert_eq!(b.min_mm[0], -100.0);
+    assert_eq!(b.max_mm[0], 300.0);
+    assert!(b.to_string().contains("j1"));
+}
+
+#[test]
+fn envelope_recorder() -> Result<(), Box<dyn std::error::Error>> {
+    let monitor = fake_psu(Arc::new(Mutex::new(vec![])))?;
+    let stream = StateStreamer::new().set_rate_hz(200.0).spawn(monitor)?;
+    let recorder = EnvelopeRecorder::spawn(stream);
+
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(!recorder.envelope().is_empty());
+
+    let (envelope, monitor) = recorder.stop()?;
+    assert!(monitor.is_some());
+    assert!(envelope.samples > 1);
+    assert!((envelope.min_mm[0] - 100.0).abs() < 1e-6);
+    assert_eq!(envelope.size_mm().map(|d| d.round()), [0.0, 0.0, 0.0]);
+    Ok(())
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "force_torque")]
+
+mod common;
+
+use common::fake_psu;
+use inovo_rs::force_torque::*;
+use inovo_rs::geometry::Wrench;
+use inovo_rs::robot::RobotError;
+use std::net::UdpSocket;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// a minimal Net F/T sensor, answering each request with a record of the next counts
+fn sensor(socket: UdpSocket, samples: Vec<(u32, [i32; 6])>) -> Result<(), std::io::Error> {
+    for (i, (status, counts)) in samples.into_iter().enumerate() {
+        let mut request = [0u8; 8];
+        let (size, peer) = socket.recv_from(&mut request)?;
+        assert_eq!(size, 8);
+        assert_eq!(request, [0x12, 0x34, 0, 2, 0, 0, 0, 1]);
+
+        let mut record = Vec::with_capacity(36);
+        record.extend((i as u32).to_be_bytes());
+        record.extend((i as u32 * 10).to_be_bytes());
+        record.extend(status.to_be_bytes());
+        for count in counts {
+            record.extend(count.to_be_bytes());
+        }
+        socket.send_to(&record, peer)?;
+    }
+    Ok(())
+}
+
+#[test]
+pub fn force_torque_net_ft() -> Result<(), RobotError> {
+    let socket = UdpSocket::bind("127.0.0.1:0")?;
+    let addr = socket.local_addr()?;
+    let device = thread::spawn(move || {
+        sensor(
+            socket,
+            vec![
+                (0, [0, 0, -2_000_000, 0, 0, 0]),
+                (0, [1_000_000, 0, -12_000_000, 0, 500_000, 0]),
+                (0, [0; 6]),
+                (0x8000_0000, [0; 6]),
+            ],
+        )
+    });
+
+    let mut net_ft = NetFtSensor::connect(addr, None)?;
+    net_ft.set_counts(1_000_000.0, 1_000_000.0);
+
+    net_ft.tare()?;
+    assert_eq!(net_ft.bias(), Wrench::new(0.0, 0.0, -2.0, 0.0, 0.0, 0.0));
+
+    let wrench = net_ft.read_wrench()?;
+    assert_eq!(wrench, Wrench::new(1.0, 0.0, -10.0, 0.0, 0.5, 0.0));
+
+    let record = net_ft.read_record()?;
+    assert_eq!((record.rdt_sequence, record.ft_sequence), (2, 20));
+
+    assert!(matches!(
+        net_ft.read_wrench(),
+        Err(RobotError::ForceTorqueError(ForceTorqueError::Status(
+            0x8000_0000
+        )))
+    ));
+
+    device.join().unwrap()?;
+    Ok(())
+}
+
+#[test]
+pub fn force_torque_record_size() {
+    assert!(matches!(
+        NetFtRecord::parse(&[0; 12]),
+        Err(ForceTorqueError::UnexpectedResponse(_))
+    ));
+}
+
+#[test]
+pub fn force_torque_robot() -> Result<(), RobotError> {
+    let mut bot = fake_psu(Arc::new(Mutex::new(vec![])))?;
+
+    let sensors: Vec<&mut dyn ForceTorqueSensor> = vec![&mut bot];
+    for sensor in sensors {
+        let wrench = sensor.read_wrench()?;
+        assert_eq!(wrench.get_force(), [0.0, 0.0, -9.81]);
+        assert_eq!(wrench.torque_norm(), 0.5);
+    }
+    Ok(())
+}
@@ -0,0 +1,383 @@
+use inovo_rs::geometry::*;
+
+#[test]
+fn display_and_parse() {
+    let t = Transform::new(100.5, -20.0, 50.0, 0.0, 30.0, -45.25);
+    assert_eq!(t.to_string(), "x=100.5 y=-20 z=50 | rx=0 ry=30 rz=-45.25");
+    let parsed: Transform = t.to_string().parse().unwrap();
+    assert_eq!(parsed.to_string(), t.to_string());
+
+    let parsed: Transform = "RZ=10,x=1 | y=2".parse().unwrap();
+    assert_eq!(parsed.to_string(), "x=1 y=2 z=0 | rx=0 ry=0 rz=10");
+
+    assert_eq!(
+        "x=1 w=2".parse::<Transform>().unwrap_err(),
+        ParseCoordError::UnknownKey("w".to_string())
+    );
+    assert_eq!(
+        "x=one".parse::<Transform>().unwrap_err(),
+        ParseCoordError::InvalidComponent("x=one".to_string())
+    );
+
+    let j = JointCoord::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.5);
+    assert_eq!(
+        format!("{:.2}", j),
+        "j1=1.00 j2=2.00 j3=3.00 j4=4.00 j5=5.00 j6=6.50"
+    );
+    let parsed: JointCoord = j.to_string().parse().unwrap();
+    assert_eq!(parsed.into_array(), j.into_array());
+    assert!("j7=1".parse::<JointCoord>().is_err());
+}
+
+#[test]
+fn quaternion_transform() {
+    // the wire format keeps the euler components
+    let t = Transform::new(1.0, 2.0, 3.0, 10.0, 20.0, 30.0);
+    let json = serde_json::to_value(&t).unwrap();
+    let t: Transform = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(json.as_object().unwrap().len(), 6);
+    assert!((json["ry"].as_f64().unwrap() - 20.0).abs() < 1e-9);
+    assert!((t.get_rz() - 30.0).abs() < 1e-9);
+
+    // composing many rotations stays on the quaternion
+    let mut chained = Transform::identity();
+    for _ in 0..360 {
+        chained = chained.then_rx(1.0);
+    }
+    assert!(chained.get_quaternion().angle() < 1e-9);
+
+    let q = Transform::from_euler([90.0, 0.0, 0.0]).get_quaternion();
+    assert_eq!(
+        Transform::from_quaternion(q).set_x(5.0).to_string(),
+        "x=5 y=0 z=0 | rx=90 ry=0 rz=0"
+    );
+}
+
+#[test]
+fn euler_round_trip() {
+    // the euler angles set are sent as set, at the gimbal lock and outside of ±180
+    for euler in [[10.0, 90.0, 0.0], [10.0, -90.0, 20.0], [270.0, 0.0, -200.0]] {
+        let t = Transform::from_euler(euler);
+        let json = serde_json::to_value(&t).unwrap();
+        assert_eq!(
+            [&json["rx"], &json["ry"], &json["rz"]].map(|v| v.as_f64().unwrap()),
+            euler
+        );
+        let back: Transform = serde_json::from_value(json).unwrap();
+        assert_eq!(back.get_euler(), euler);
+    }
+    assert_eq!(Transform::from_rx(270.0).get_rx(), 270.0);
+    assert_eq!(
+        Transform::from_rx(200.0)
+            .set_ry(90.0)
+            .set_rz(-190.0)
+            .get_euler(),
+        [200.0, 90.0, -190.0]
+    );
+
+    // a composed rotation at the gimbal lock decomposes to the same rotation
+    for ry in [90.0, -90.0] {
+        let t = Transform::new(0.0, 0.0, 0.0, 10.0, ry, 0.0) * Transform::identity();
+        assert_eq!(t.get_ry(), ry);
+        let back: Transform = serde_json::from_value(serde_json::to_value(&t).unwrap()).unwrap();
+        assert!(back.approx_eq(&t, 1e-9, 1e-6));
+    }
+}
+
+#[test]
+fn homogeneous_matrix() {
+    let t = Transform::new(10.0, -20.0, 30.0, 15.0, -25.0, 40.0);
+    let m = t.to_matrix();
+    assert_eq!(m[3], [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!([m[0][3], m[1][3], m[2][3]], [10.0, -20.0, 30.0]);
+    assert_eq!(Transform::from_matrix(m).to_string(), t.to_string());
+
+    // composition matches matrix multiplication
+    let other = Transform::new(1.0, 2.0, 3.0, 0.0, 90.0, 0.0);
+    let product = nalgebra::Matrix4::from(t.clone()) * nalgebra::Matrix4::from(other.clone());
+    assert_eq!(
+        Transform::from(product).to_string(),
+        (t * other).to_string()
+    );
+}
+
+#[test]
+fn axis_angle() {
+    let diagonal = Transform::from_axis_angle([1.0, 1.0, 0.0], 180.0);
+    let moved = Transform::from_x(10.0).then(diagonal);
+    assert_eq!(
+        moved.vector_only().to_string(),
+        "x=0 y=10 z=0 | rx=0 ry=0 rz=0"
+    );
+
+    assert_eq!(
+        Transform::from_axis_angle([0.0, 0.0, 0.0], 45.0).to_string(),
+        Transform::identity().to_string()
+    );
+    assert_eq!(
+        Transform::from_axis_angle([0.0, 2.0, 0.0], 30.0).to_string(),
+        Transform::from_ry(30.0).to_string()
+    );
+
+    // the point on the axis stays in place
+    let about = Transform::rotation_about([5.0, 5.0, 0.0], [0.0, 0.0, 1.0], 33.0);
+    let pin = Transform::from_vector([5.0, 5.0, 7.0]).then(about);
+    assert_eq!(pin.get_vector().map(|v| v.round()), [5.0, 5.0, 7.0]);
+}
+
+#[test]
+fn distances() {
+    let a = Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 170.0);
+    let b = Transform::new(3.0, 4.0, 0.0, 0.0, 0.0, -170.0);
+    assert!((a.translation_distance(&b) - 5.0).abs() < 1e-9);
+    // the short way round
+    assert!((a.rotation_distance_deg(&b) - 20.0).abs() < 1e-9);
+    assert_eq!(a.rotation_distance_deg(&a), 0.0);
+
+    let j = JointCoord::new(0.0, 10.0, -20.0, 0.0, 0.0, 5.0);
+    assert_eq!(j.max_joint_delta(&JointCoord::from_j3(10.0)), 30.0);
+    assert_eq!(j.max_joint_delta(&j), 0.0);
+}
+
+#[test]
+fn mean() {
+    assert!(Transform::mean(&[]).is_none());
+
+    let t = Transform::new(1.0, 2.0, 3.0, 10.0, 20.0, 30.0);
+    let single = Transform::mean(std::slice::from_ref(&t)).unwrap();
+    assert!(single.rotation_distance_deg(&t) < 1e-6);
+
+    let samples = [-2.0, -1.0, 1.0, 2.0].map(|d| t.clone().then_relative_rx(d).then_x(d));
+    let mean = Transform::mean(&samples).unwrap();
+    assert!(mean.rotation_distance_deg(&t) < 1e-3);
+    assert!(mean.translation_distance(&t) < 1e-9);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_sampling() {
+    let bounds = TransformBounds::new([100.0, -50.0, 0.0], [0.0, 50.0, 10.0])
+        .set_orientation(Transform::from_ry(90.0))
+        .set_cone(10.0)
+        .set_spin(45.0);
+    let mut rng = rand::thread_rng();
+    for _ in 0..1000 {
+        assert!(bounds.contains(&sample_uniform(&bounds, &mut rng)));
+    }
+
+    // degenerated bounds give the nominal pose
+    let fixed = TransformBounds::new([1.0, 2.0, 3.0], [1.0, 2.0, 3.0]);
+    let pose = sample_uniform(&fixed, &mut rng);
+    assert_eq!(pose.to_string(), "x=1 y=2 z=3 | rx=0 ry=0 rz=0");
+    assert!(!bounds.contains(&pose));
+}
+
+#[test]
+fn reference_operators() {
+    let a = Transform::new(10.0, 0.0, 0.0, 0.0, 0.0, 90.0);
+    let b = Transform::from_x(5.0);
+    assert_eq!((&a * &b).to_string(), "x=10 y=5 z=0 | rx=0 ry=0 rz=90");
+    assert_eq!((&b * &a / &b).to_string(), a.to_string());
+    assert_eq!((&a * b.clone()).to_string(), (a.clone() * &b).to_string());
+    assert_eq!((&a * -&a).to_string(), Transform::identity().to_string());
+
+    let mut c = b.clone();
+    c *= &a;
+    c /= b;
+    assert_eq!(c.to_string(), a.to_string());
+
+    let j = JointCoord::from_j1(10.0);
+    let mut k = &j + &j;
+    assert_eq!(k.to_string(), "j1=20 j2=0 j3=0 j4=0 j5=0 j6=0");
+    k -= &j;
+    k += JointCoord::from_j6(1.0);
+    assert_eq!((&k - &j).to_string(), "j1=0 j2=0 j3=0 j4=0 j5=0 j6=1");
+    assert_eq!((-&j).to_string(), "j1=-10 j2=0 j3=0 j4=0 j5=0 j6=0");
+}
+
+#[test]
+fn vector_and_rotation() {
+    let v = Vector3::new(10.0, 0.0, 0.0);
+    let r = Rotation::from_axis_angle([0.0, 0.0, 1.0], 90.0);
+    let rotated = (r * v).into_array().map(|c| c.round());
+    assert_eq!(rotated, [0.0, 10.0, 0.0]);
+    assert_eq!((v - v).norm(), 0.0);
+    assert_eq!(v.cross(&Vector3::new(0.0, 10.0, 0.0)).get_z(), 100.0);
+
+    let t = Transform::from_parts(v, r);
+    assert_eq!(t.to_string(), "x=10 y=0 z=0 | rx=0 ry=0 rz=90");
+    assert_eq!(Vector3::from(&t), v);
+    assert!(Rotation::from(&t).angle_to(&r) < 1e-9);
+    assert!((r * -r).angle_to(&Rotation::identity()) < 1e-9);
+
+    // typed and untyped vectors are interchangeable
+    assert_eq!(
+        Transform::identity().then_vector(v).to_string(),
+        Transform::identity()
+            .then_vector([10.0, 0.0, 0.0])
+            .to_string()
+    );
+    assert_eq!(
+        Transform::from_x(1.0).then_rotation(r).to_string(),
+        "x=0 y=1 z=0 | rx=0 ry=0 rz=90"
+    );
+}
+
+#[test]
+fn units() {
+    assert_eq!(Mm::from(Meters(0.25)), Mm(250.0));
+    assert_eq!(Meters::from(Mm(250.0)), Meters(0.25));
+    assert!((Degrees::from(Radians(std::f64::consts::PI)).0 - 180.0).abs() < 1e-12);
+    assert_eq!(Degrees(90.0).to_string(), "90 deg");
+
+    let t = Transform::from_x(Meters(0.1))
+        .set_y(20.0)
+        .set_rz(Radians(std::f64::consts::FRAC_PI_2));
+    assert_eq!(t.to_string(), "x=100 y=20 z=0 | rx=0 ry=0 rz=90");
+
+    let wire = t.to_m_rad();
+    assert!((wire[0] - 0.1).abs() < 1e-12);
+    assert!((wire[5] - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert_eq!(Transform::from_m_rad(wire).to_string(), t.to_string());
+
+    let j = JointCoord::from_j1(Radians(std::f64::consts::PI)).then_j2(Degrees(10.0));
+    assert_eq!(j.to_string(), "j1=180 j2=10 j3=0 j4=0 j5=0 j6=0");
+}
+
+#[test]
+fn transform_chain() {
+    let mut chain = TransformChain::default();
+    assert!(chain.is_empty());
+    assert_eq!(
+        chain.resolve().to_string(),
+        Transform::identity().to_string()
+    );
+
+    chain = chain
+        .then_link("base", Transform::from_z(100.0))
+        .then_link("tool", Transform::from_rx(180.0));
+    assert_eq!(chain.names().collect::<Vec<_>>(), vec!["base", "tool"]);
+    assert_eq!(
+        chain.resolve().to_string(),
+        (Transform::from_z(100.0) * Transform::from_rx(180.0)).to_string()
+    );
+
+    let previous = chain.set_link("base", Transform::from_z(50.0)).unwrap();
+    assert_eq!(previous.get_z(), 100.0);
+    assert_eq!(chain.resolve().get_z(), 50.0);
+    assert!(chain.set_link("missing", Transform::identity()).is_none());
+    assert!(chain.resolve_to("missing").is_none());
+    assert_eq!(chain.get_link("tool").unwrap().get_rx().abs(), 180.0);
+}
+
+#[test]
+fn plane_fitting() {
+    assert!(fit_plane(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]).is_none());
+    assert!(fit_plane(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [2.0, 0.0, 0.0]]).is_none());
+
+    // probed with the robot, slightly noisy
+    let probed = [
+        Transform::from_vector([0.0, 0.0, 10.01]),
+        Transform::from_vector([200.0, 0.0, 9.99]),
+        Transform::from_vector([0.0, 200.0, 10.0]),
+        Transform::from_vector([200.0, 200.0, 10.0]),
+    ];
+    let plane = fit_plane(&probed).unwrap();
+    assert!(plane.tilt_deg() < 0.01);
+    assert!(plane.distance([100.0, 100.0, 15.0]) - 5.0 < 0.01);
+    assert!(plane.get_normal().get_z() > 0.0);
+
+    let frame = plane.work_frame();
+    assert_eq!(
+        format!("{:.1}", frame.vector_only()),
+        "x=100.0 y=100.0 z=10.0 | rx=0.0 ry=0.0 rz=0.0"
+    );
+    assert!(frame.rotation_distance_deg(&Transform::identity()) < 0.01);
+}
+
+#[test]
+fn circle_fitting() {
+    assert!(fit_circle([0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [2.0, 2.0, 2.0]).is_none());
+
+    let circle = fit_circle([10.0, 0.0, 5.0], [0.0, 10.0, 5.0], [-10.0, 0.0, 5.0]).unwrap();
+    assert!((circle.get_radius() - 10.0).abs() < 1e-9);
+    assert!((circle.get_center() - Vector3::new(0.0, 0.0, 5.0)).norm() < 1e-9);
+    assert!((circle.get_normal().get_z() - 1.0).abs() < 1e-9);
+    assert!((circle.angle_between([10.0, 0.0, 5.0], [0.0, -10.0, 5.0]) - 270.0).abs() < 1e-9);
+
+    // the orientation follows the arc
+    let start = Transform::from_vector([10.0, 0.0, 5.0]);
+    let quarter = circle.arc(&start, 90.0, 2);
+    assert_eq!(quarter.len(), 2);
+    assert_eq!(
+        format!("{:.3}", quarter[1]),
+        "x=0.000 y=10.000 z=5.000 | rx=0.000 ry=0.000 rz=90.000"
+    );
+
+    // the long way round, passing the via point
+    let end = Transform::from_vector([0.0, -10.0, 5.0]);
+    let via = Transform::from_vector([-10.0, 0.0, 5.0]);
+    let waypoints = arc_waypoints(&start, &via, &end, 3).unwrap();
+    assert!(waypoints[1].translation_distance(&via) < 1e-9);
+}
+
+#[test]
+fn joint_coord_index_and_iter() {
+    let mut j = JointCoord::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert_eq!(j[0], 1.0);
+    j[5] = 60.0;
+    assert_eq!(
+        j.iter().collect::<Vec<_>>(),
+        vec![1.0, 2.0, 3.0, 4.0, 5.0, 60.0]
+    );
+    assert_eq!((&j).into_iter().sum::<f64>(), 75.0);
+
+    let clamped = j.map(|v| v.clamp(2.0, 5.0));
+    assert_eq!(clamped.into_array(), [2.0, 2.0, 3.0, 4.0, 5.0, 5.0]);
+
+    let mut max = 0.0;
+    for v in j {
+        max = f64::max(max, v);
+    }
+    assert_eq!(max, 60.0);
+
+    assert!(std::panic::catch_unwind(|| JointCoord::identity()[6]).is_err());
+}
+
+#[test]
+fn joint_coord_radians() {
+    use std::f64::consts::PI;
+    let j = JointCoord::from_radians([PI, -PI / 2.0, 0.0, 0.0, PI / 4.0, 0.0]);
+    assert_eq!(j.to_string(), "j1=180 j2=-90 j3=0 j4=0 j5=45 j6=0");
+    assert_eq!(j.to_radians(), [PI, -PI / 2.0, 0.0, 0.0, PI / 4.0, 0.0]);
+    assert_eq!(
+        JointCoord::from_j3(10.0).set_radians([0.0; 6]).into_array(),
+        [0.0; 6]
+    );
+}
+
+#[test]
+fn robot_units_serde() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Config {
+        #[serde(with = "m_rad")]
+        pose: Transform,
+        #[serde(with = "m_rad")]
+        joints: JointCoord,
+        home: Transform,
+    }
+
+    let json = r#"{
+        "pose": {"x": 0.5, "y": 0.0, "z": 0.25, "rx": 0.0, "ry": 0.0, "rz": 1.5707963267948966},
+        "joints": [0.0, 1.5707963267948966, 0.0, 0.0, 0.0, 0.0],
+        "home": {"x": 500.0, "y": 0.0, "z": 250.0, "rx": 0.0, "ry": 0.0, "rz": 90.0}
+    }"#;
+    let config: Config = serde_json::from_str(json).unwrap();
+    assert_eq!(config.pose.to_string(), "x=500 y=0 z=250 | rx=0 ry=0 rz=90");
+    assert_eq!(config.pose.to_string(), config.home.to_string());
+    assert_eq!(config.joints.to_string(), "j1=0 j2=90 j3=0 j4=0 j5=0 j6=0");
+
+    let value = serde_json::to_value(&config).unwrap();
+    assert_eq!(value["pose"]["x"], 0.5);
+    assert_eq!(value["home"]["x"], 500.0);
+}
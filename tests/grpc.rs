@@ -0,0 +1,61 @@
+#![cfg(feature = "grpc")]
+
+mod common;
+
+use common::fake_psu;
+use inovo_rs::grpc::{self, proto, IvaRobotClient};
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[test]
+fn grpc_server() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let handle = RobotHandle::new(fake_psu(records.clone())?);
+
+    // find a free port for the server
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.spawn(grpc::serve(addr, handle));
+
+    runtime.block_on(async {
+        let mut client = loop {
+            match IvaRobotClient::connect(format!("http://{}", addr)).await {
+                Ok(client) => break client,
+                Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        };
+
+        let io = proto::IoSetRequest {
+            target: proto::IoTarget::Wrist as i32,
+            port: 3,
+            state: true,
+        };
+        client.io_set(io).await?;
+        assert!(records.lock().unwrap()[0].contains("\"target\":\"wrist\""));
+
+        let io = proto::IoPort {
+            target: proto::IoTarget::Beckhoff as i32,
+            port: 1,
+        };
+        assert!(client.io_get(io).await?.into_inner().state);
+
+        // the psu rejects the linear motion
+        let status = client
+            .linear(proto::Transform::default())
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+        // invalid requests never reach the robot
+        let sequence = proto::CommandSequence {
+            commands: vec![proto::RobotCommand { command: None }],
+        };
+        let status = client.sequence(sequence).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        let io = proto::IoPort { target: 7, port: 1 };
+        assert!(client.io_get(io).await.is_err());
+        assert_eq!(records.lock().unwrap().len(), 3);
+        Ok(())
+    })
+}
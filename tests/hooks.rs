@@ -0,0 +1,35 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::geometry::Transform;
+use inovo_rs::iva::Instruction;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn instruction_hooks() -> Result<(), Box<dyn std::error::Error>> {
+    let mut bot = fake_psu(Arc::new(Mutex::new(vec![])))?;
+
+    let before = Arc::new(Mutex::new(vec![]));
+    let after = Arc::new(Mutex::new(vec![]));
+    let errors = Arc::new(Mutex::new(vec![]));
+    {
+        let (before, after, errors) = (before.clone(), after.clone(), errors.clone());
+        bot.on_before_instruction(move |inst| before.lock().unwrap().push(inst.clone()))
+            .on_after_instruction(move |_, res, _| after.lock().unwrap().push(res.to_string()))
+            .on_error(move |inst, e| errors.lock().unwrap().push((inst.clone(), e.category())));
+    }
+
+    bot.sleep(1.0)?;
+    assert!(bot.linear(Transform::from_x(10.0)).is_err());
+
+    assert_eq!(before.lock().unwrap().len(), 2);
+    assert_eq!(*after.lock().unwrap(), vec!["OK", "Error"]);
+    let errors = errors.lock().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0].0, Instruction::Execute { .. }));
+
+    bot.clear_hooks().sleep(1.0)?;
+    assert_eq!(before.lock().unwrap().len(), 2);
+    Ok(())
+}
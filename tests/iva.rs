@@ -1,11 +1,14 @@
 use inovo_rs::geometry::*;
 use inovo_rs::iva::*;
 use inovo_rs::logger::*;
-use inovo_rs::robot::MotionParam;
+use inovo_rs::robot::{
+    CommandSequence, FromRobot, MotionParam, RobotError, SampledPath, TcpVelocity,
+};
+use std::collections::HashMap;
 
 #[test]
 pub fn iva_test() {
-    let mut logger = Logger::default_target("IVA test");
+    let logger = Logger::default_target("IVA test");
 
     let cmds = vec![
         RobotCommand::Synchronize,
@@ -35,20 +38,34 @@ pub fn iva_test() {
     insts.push(Instruction::dequeue());
     insts.push(Instruction::dequeue_push());
     insts.push(Instruction::pop());
+    insts.push(Instruction::pop_all());
 
     insts.push(Instruction::gripper(GripperCommand::Activate));
     insts.push(Instruction::gripper(GripperCommand::Get));
     insts.push(Instruction::gripper(GripperCommand::Set {
         label: "open".to_string(),
     }));
+    insts.push(Instruction::gripper(GripperCommand::SetWidth {
+        mm: 42.0,
+        force: 50.0,
+    }));
+
+    insts.push(Instruction::vacuum(VacuumCommand::On));
+    insts.push(Instruction::vacuum(VacuumCommand::Off));
+    insts.push(Instruction::vacuum(VacuumCommand::Get));
 
     insts.push(Instruction::io_get(IOTarget::Beckhoff, 0));
     insts.push(Instruction::io_get(IOTarget::Wrist, 1));
     insts.push(Instruction::io_set(IOTarget::Beckhoff, 0, true));
     insts.push(Instruction::io_set(IOTarget::Wrist, 2, false));
+    insts.push(Instruction::io_get_bank(IOTarget::Beckhoff, 0, 16));
+    insts.push(Instruction::io_pulse(IOTarget::Beckhoff, 3, 0.5));
 
     insts.push(Instruction::get(GetTarget::Transform));
     insts.push(Instruction::get(GetTarget::JointCoord));
+    insts.push(Instruction::get(GetTarget::JointTorques));
+    insts.push(Instruction::get(GetTarget::TcpVelocity));
+    insts.push(Instruction::get(GetTarget::ContextDepth));
     insts.push(Instruction::get(GetTarget::data("some key")));
 
     let custom_command = CustomCommand::new()
@@ -59,14 +76,463 @@ pub fn iva_test() {
 
     for inst in insts {
         match inst.to_json() {
-            Ok(json) => logger.info(format!(
-                "{}",
-                json.split("\n")
+            Ok(json) => logger.info(
+                json.split('\n')
                     .map(|s| format!("{}{}", " ".repeat(0), s))
                     .collect::<Vec<_>>()
-                    .join("\n")
-            )),
+                    .join("\n"),
+            ),
             Err(e) => logger.error(e.to_string()),
         };
     }
 }
+
+#[test]
+pub fn iva_context_depth() {
+    let pop_all = serde_json::to_value(Instruction::pop_all()).unwrap();
+    assert_eq!(pop_all, serde_json::json!({ "op_code": "pop_all" }));
+
+    let depth = serde_json::to_value(Instruction::get(GetTarget::ContextDepth)).unwrap();
+    assert_eq!(
+        depth,
+        serde_json::json!({ "op_code": "get", "target": "context_depth" })
+    );
+}
+
+#[test]
+pub fn iva_joint_torques() {
+    let torques = serde_json::to_value(Instruction::get(GetTarget::JointTorques)).unwrap();
+    assert_eq!(
+        torques,
+        serde_json::json!({ "op_code": "get", "target": "joint_torques" })
+    );
+
+    let parsed = <[f64; 6]>::from_robot("[1.5, -2, 0, 0.25, 0, 0]".to_string());
+    assert_eq!(parsed, Ok([1.5, -2.0, 0.0, 0.25, 0.0, 0.0]));
+    assert!(<[f64; 6]>::from_robot("[1, 2, 3]".to_string()).is_err());
+    assert!(<[f64; 6]>::from_robot("Error".to_string()).is_err());
+}
+
+#[test]
+pub fn iva_tcp_velocity() {
+    let velocity = serde_json::to_value(Instruction::get(GetTarget::TcpVelocity)).unwrap();
+    assert_eq!(
+        velocity,
+        serde_json::json!({ "op_code": "get", "target": "tcp_velocity" })
+    );
+
+    let parsed = TcpVelocity::from_robot("[0.25, 3.14159265358979]".to_string()).unwrap();
+    assert!((parsed.linear_mm_s - 250.0).abs() < 1e-9);
+    assert!((parsed.angular_deg_s - 180.0).abs() < 1e-6);
+    assert!(!parsed.is_stationary(1.0, 1.0));
+    assert!(TcpVelocity::default().is_stationary(0.0, 0.0));
+    assert!(TcpVelocity::from_robot("[0.25]".to_string()).is_err());
+}
+
+#[test]
+pub fn iva_wrench() {
+    let wrench = serde_json::to_value(Instruction::get(GetTarget::Wrench)).unwrap();
+    assert_eq!(
+        wrench,
+        serde_json::json!({ "op_code": "get", "target": "wrench" })
+    );
+
+    let parsed = Wrench::from_robot("[1, 2, 2, 0, 0, -3]".to_string()).unwrap();
+    assert_eq!(parsed.force_norm(), 3.0);
+    assert_eq!(parsed.get_torque(), [0.0, 0.0, -3.0]);
+    assert_eq!(parsed + -parsed, Wrench::zeros());
+    assert!(Wrench::from_robot("[1, 2]".to_string()).is_err());
+}
+
+#[test]
+pub fn iva_data_batch() {
+    let batch = serde_json::to_value(Instruction::get(GetTarget::data_batch(["a", "b"]))).unwrap();
+    assert_eq!(
+        batch,
+        serde_json::json!({ "op_code": "get", "target": "data_batch", "keys": ["a", "b"] })
+    );
+
+    let res = r#"{"count": 3, "ready": true, "name": "tray", "pose": "{rx: 0, ry: 0, rz: 0, x: 0.1, y: 0, z: 0}"}"#;
+    let parsed = HashMap::<String, String>::from_robot(res.to_string()).unwrap();
+    assert_eq!(i64::from_robot(parsed["count"].clone()), Ok(3));
+    assert_eq!(bool::from_robot(parsed["ready"].clone()), Ok(true));
+    assert_eq!(parsed["name"], "tray");
+    assert_eq!(
+        Transform::from_robot(parsed["pose"].clone())
+            .unwrap()
+            .get_x(),
+        100.0
+    );
+    assert!(HashMap::<String, String>::from_robot("OK".to_string()).is_err());
+}
+
+#[test]
+pub fn iva_io_actions() {
+    let plain = serde_json::to_value(RobotCommand::linear(Transform::identity())).unwrap();
+    assert!(plain.get("io").is_none());
+
+    let sequence = CommandSequence::new()
+        .then_sleep(1.0)
+        .then_linear(Transform::from_x(100.0))
+        .with_io(IOAction::set(IOTarget::Beckhoff, 3, true))
+        .unwrap()
+        .with_io(IOAction::pulse(IOTarget::Wrist, 1, 0.5).at_end())
+        .unwrap();
+    let bead = serde_json::to_value(Instruction::enqueue(sequence[1].clone())).unwrap();
+    assert_eq!(bead["action"], "motion_io");
+    assert_eq!(bead["motion_mode"], "linear");
+    assert_eq!(
+        sequence[1].motion(),
+        RobotCommand::linear(Transform::from_x(100.0)).motion()
+    );
+    assert_eq!(
+        bead["io"],
+        serde_json::json!([
+            { "trigger": "start", "target": "beckhoff", "port": 3, "action": "set", "state": 1.0 },
+            { "trigger": "end", "target": "wrist", "port": 1, "action": "pulse", "second": 0.5 },
+        ])
+    );
+    assert_eq!(
+        serde_json::from_value::<Instruction>(bead).unwrap(),
+        Instruction::enqueue(sequence[1].clone())
+    );
+
+    let sleep = RobotCommand::sleep(1.0).with_io(IOAction::set(IOTarget::Beckhoff, 3, true));
+    assert!(matches!(sleep, Err(RobotError::InvalidArgument(_))));
+    let empty = CommandSequence::new().with_io(IOAction::set(IOTarget::Beckhoff, 3, true));
+    assert!(matches!(empty, Err(RobotError::InvalidArgument(_))));
+}
+
+#[test]
+pub fn iva_roundtrip_equality() {
+    let insts = vec![
+        Instruction::exec(RobotCommand::SetParameter(
+            MotionParam::new().set_speed(50.0),
+        )),
+        Instruction::exec(RobotCommand::linear(Transform::from_vector([
+            100.0, 0.0, 50.0,
+        ]))),
+        Instruction::exec(RobotCommand::joint(JointCoord::from_j1(90.0))),
+        Instruction::get(GetTarget::data("key")),
+        Instruction::custom(CustomCommand::new().add_float("value", 12.0)),
+    ];
+    for inst in insts {
+        let json = serde_json::to_string(&inst).unwrap();
+        let parsed: Instruction = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, inst);
+    }
+
+    assert_eq!(Transform::default(), Transform::identity());
+    assert_eq!(JointCoord::default(), JointCoord::identity());
+    assert_eq!(Transform::from_rx(30.0), Transform::from_rx(30.0));
+    assert_ne!(Transform::from_rx(30.0), Transform::from_rx(90.0));
+
+    // the euler conversion is not exact
+    let pose = Transform::new(100.0, 0.0, 50.0, 180.0, 0.0, 0.0);
+    let json = serde_json::to_string(&pose).unwrap();
+    let parsed: Transform = serde_json::from_str(&json).unwrap();
+    assert!(parsed.approx_eq(&pose, 1e-9, 1e-9));
+    assert!(!parsed.approx_eq(&pose.clone().then_x(1.0), 1e-9, 1e-9));
+}
+
+#[test]
+pub fn iva_blended_sequence() -> Result<(), RobotError> {
+    let path = CommandSequence::new()
+        .then_set_param(MotionParam::new())
+        .then_linear_blended(Transform::from_x(100.0), 10.0)?
+        .then_sleep(1.0)
+        .then_joint_blended(JointCoord::from_j1(90.0), 30.0)?;
+    let base = RobotCommand::SetParameter(MotionParam::new());
+    assert_eq!(
+        path.to_vec(),
+        vec![
+            RobotCommand::SetParameter(MotionParam::new().set_blend_linear(10.0)),
+            RobotCommand::linear(Transform::from_x(100.0)),
+            base.clone(),
+            RobotCommand::sleep(1.0),
+            RobotCommand::SetParameter(MotionParam::new().set_blend_linear(30.0)),
+            RobotCommand::joint(JointCoord::from_j1(90.0)),
+            base,
+        ]
+    );
+    assert_eq!(path.current_param(), Some(&MotionParam::new()));
+    assert_eq!(CommandSequence::new().current_param(), None);
+
+    // without a param set, the blend could not be undone
+    let unknown = CommandSequence::new()
+        .then_sleep(1.0)
+        .then_linear_blended(Transform::from_x(100.0), 10.0);
+    assert!(matches!(unknown, Err(RobotError::InvalidArgument(_))));
+    Ok(())
+}
+
+#[test]
+pub fn iva_joint_speed() {
+    let param = MotionParam::new().set_speed(50.0).set_wrist_speed(90.0);
+    let json = serde_json::to_value(&param).unwrap();
+    let caps = json["joint_speed"].as_array().unwrap();
+    assert_eq!(caps.len(), 6);
+    assert!(caps[..3].iter().all(|cap| cap.is_null()));
+    assert!((caps[3].as_f64().unwrap() - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    assert_eq!(serde_json::from_value::<MotionParam>(json).unwrap(), param);
+
+    // the cleared caps are sent, to lift the caps already on the robot
+    let uncapped = param.clear_joint_speed();
+    assert_eq!(uncapped.get_joint_speed(), [None; 6]);
+    let json = serde_json::to_value(&uncapped).unwrap();
+    assert_eq!(
+        json["joint_speed"],
+        serde_json::json!([null, null, null, null, null, null])
+    );
+    assert_eq!(
+        serde_json::from_value::<MotionParam>(json).unwrap(),
+        uncapped
+    );
+    for joint in [0, 7] {
+        let ignored = MotionParam::new().set_joint_speed(joint, 10.0);
+        assert_eq!(ignored, MotionParam::new());
+    }
+}
+
+#[test]
+pub fn iva_debug() {
+    use inovo_rs::iva::debug::*;
+
+    let insts = vec![
+        Instruction::exec(RobotCommand::SetParameter(
+            MotionParam::new().set_speed(50.0).set_joint_speed(2, 30.0),
+        )),
+        Instruction::enqueue(
+            RobotCommand::linear(Transform::from_x(100.0))
+                .with_io(IOAction::pulse(IOTarget::Beckhoff, 1, 0.5))
+                .unwrap(),
+        ),
+        Instruction::dequeue_push(),
+        Instruction::io_get_bank(IOTarget::Beckhoff, 0, 16),
+        Instruction::get(GetTarget::data_batch(["a", "b"])),
+        Instruction::custom(CustomCommand::new().add_string("action", "add_limit")),
+    ];
+    for inst in &insts {
+        assert_eq!(check_roundtrip(inst), Ok(()), "{:?}", inst);
+    }
+
+    // an unknown field, and a number written as an integer
+    let line = r#"{"op_code": "execute", "enter_context": 0, "action": "motion", "motion_mode": "linear",
+        "target": "transform", "x": 100, "y": 0, "z": 0, "rx": 0, "ry": 0, "rz": 0, "frame": "base"}"#;
+    let issues = validate(line).unwrap_err();
+    assert_eq!(issues, vec![Issue::UnknownField("/frame".to_string())]);
+    assert!(validate(&line.replace(r#", "frame": "base""#, "")).is_ok());
+    assert!(matches!(
+        validate(r#"{"op_code": "teleport"}"#).unwrap_err()[0],
+        Issue::Invalid(_)
+    ));
+
+    let diffs = diff(
+        &insts[3],
+        &Instruction::io_get_bank(IOTarget::Beckhoff, 0, 8),
+    );
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].path, "/count");
+    assert_eq!(
+        pretty(r#"{"op_code":"pop"}"#).unwrap(),
+        "{\n  \"op_code\": \"pop\"\n}"
+    );
+    assert!(pretty("OK").is_err());
+}
+
+#[test]
+pub fn command_sequence_offset() {
+    let start = Transform::from_vector([400.0, 0.0, 100.0]).then_rx(180.0);
+    let delta = Transform::from_vector([50.0, 20.0, 0.0]).then_rz(30.0);
+    let offset = Transform::from_rz(45.0).then_vector([100.0, -50.0, 10.0]);
+    let joints = JointCoord::identity();
+
+    let taught = CommandSequence::new()
+        .then_linear(start.clone())
+        .then_sleep(1.0)
+        .concat(
+            CommandSequence::new()
+                .then_linear_relative(delta.clone())
+                .then_joint(joints.clone()),
+        );
+    assert_eq!(taught.len(), 4);
+    let shifted = taught.clone().offset_by(offset.clone());
+
+    let targets: Vec<Transform> = shifted
+        .iter()
+        .filter_map(|command| match command {
+            RobotCommand::Motion {
+                target: MotionTarget::Transform(target),
+                ..
+            } => Some(target.clone()),
+            _ => None,
+        })
+        .collect();
+    // the end of the relative motion is shifted as the absolute targets
+    let expected = start.clone().then_relative(delta).then(offset.clone());
+    let reached = targets[0].clone().then_relative(targets[1].clone());
+    assert!(reached.approx_eq(&expected, 1e-9, 1e-6));
+    assert_eq!(shifted[1], RobotCommand::sleep(1.0));
+    assert_eq!(shifted[3], RobotCommand::joint(joints));
+
+    // mapping with the identity keeps the sequence
+    assert_eq!(taught.clone().map_targets(|target| target), taught);
+}
+
+#[test]
+pub fn command_sequence_mirrored() {
+    let start = Transform::from_vector([400.0, -200.0, 100.0]).then_rx(170.0);
+    let delta = Transform::from_vector([50.0, -20.0, 10.0]).then_rz(30.0);
+    let plane = Plane::new([0.0, 100.0, 0.0], [1.0, 2.0, 0.0]).unwrap();
+
+    let right = CommandSequence::new()
+        .then_linear(start.clone())
+        .then_linear_relative(delta.clone())
+        .then_joint(JointCoord::identity());
+    let left = right.clone().mirrored(&plane);
+
+    let targets: Vec<Transform> = left
+        .iter()
+        .filter_map(|command| match command {
+            RobotCommand::Motion {
+                target: MotionTarget::Transform(target),
+                ..
+            } => Some(target.clone()),
+            _ => None,
+        })
+        .collect();
+    // the end of the relative motion is mirrored as the absolute targets
+    let expected = start.then_relative(delta).mirrored(&plane);
+    let reached = targets[0].clone().then_relative(targets[1].clone());
+    assert!(reached.approx_eq(&expected, 1e-9, 1e-6));
+    assert_eq!(left[2], RobotCommand::joint(JointCoord::identity()));
+
+    // mirroring twice is the identity
+    let back = left.mirrored(&plane);
+    for (a, b) in back.iter().zip(right.iter()) {
+        match (a, b) {
+            (
+                RobotCommand::Motion {
+                    target: MotionTarget::Transform(a),
+                    ..
+                },
+                RobotCommand::Motion {
+                    target: MotionTarget::Transform(b),
+                    ..
+                },
+            ) => assert!(a.approx_eq(b, 1e-9, 1e-6)),
+            (a, b) => assert_eq!(a, b),
+        }
+    }
+    assert!(Plane::new([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]).is_none());
+}
+
+#[test]
+pub fn command_sequence_reversed() {
+    let start = Transform::from_vector([400.0, -200.0, 100.0]).then_rx(170.0);
+    let delta = Transform::from_vector([50.0, -20.0, 10.0]).then_rz(30.0);
+    let twist = Transform::from_vector([-5.0, 0.0, 15.0]).then_ry(-20.0);
+    let slow = MotionParam::new().set_speed(10.0);
+    let fast = MotionParam::new().set_speed(80.0);
+
+    let path = CommandSequence::new()
+        .then_set_param(slow.clone())
+        .then_linear(start.clone())
+        .then_linear_relative(delta.clone())
+        .then_sleep(1.0)
+        .then_set_param(fast.clone())
+        .then_joint(JointCoord::identity())
+        .then_joint_relative(twist.clone())
+        .then_sync();
+    let reversed = path.reversed();
+
+    assert_eq!(reversed.len(), 5);
+    assert_eq!(reversed[0], RobotCommand::SetParameter(fast));
+    assert_eq!(
+        reversed[2],
+        RobotCommand::joint(start.clone().then_relative(delta.clone()))
+    );
+    assert_eq!(reversed[3], RobotCommand::SetParameter(slow));
+
+    // the inverse relative motions undo the relative motions
+    let relative = |command: &RobotCommand| match command {
+        RobotCommand::Motion {
+            target: MotionTarget::Transform(target),
+            ..
+        } => target.clone(),
+        other => panic!("not a transform motion: {:?}", other),
+    };
+    let end = start.clone().then_relative(twist.clone());
+    let back = end.then_relative(relative(&reversed[1]));
+    assert!(back.approx_eq(&start, 1e-9, 1e-6));
+    let end = start.clone().then_relative(delta);
+    let back = end.then_relative(relative(&reversed[4]));
+    assert!(back.approx_eq(&start, 1e-9, 1e-6));
+    assert!(matches!(
+        reversed[4],
+        RobotCommand::Motion {
+            motion_mode: MotionMode::LinearRelative,
+            ..
+        }
+    ));
+
+    // the relative motion from a known joint coord is replayed to it
+    let joints = CommandSequence::new()
+        .then_joint(JointCoord::identity())
+        .then(RobotCommand::Motion {
+            motion_mode: MotionMode::JointRelative,
+            target: JointCoord::from_j1(10.0).into(),
+        })
+        .then_joint(Transform::from_z(500.0))
+        .reversed();
+    assert_eq!(joints[0], RobotCommand::joint(JointCoord::from_j1(10.0)));
+    assert_eq!(
+        joints[1],
+        RobotCommand::Motion {
+            motion_mode: MotionMode::JointRelative,
+            target: JointCoord::from_j1(-10.0).into(),
+        }
+    );
+    assert_eq!(joints.len(), 2);
+}
+
+#[test]
+pub fn sampled_path() {
+    let start = Transform::from_vector([400.0, 0.0, 300.0]).then_rx(180.0);
+    let path = CommandSequence::new()
+        .then_set_param(MotionParam::new().set_speed(50.0))
+        .then_linear(Transform::from_vector([400.0, 0.0, 100.0]).then_rx(180.0))
+        .then_sleep(1.0)
+        .then_linear_relative(Transform::from_y(25.0));
+
+    let sampled = SampledPath::sample(&path, start.clone(), 50.0).unwrap();
+    // the start, 4 points down and 1 point along
+    let points = sampled.points();
+    assert_eq!(points.len(), 6);
+    assert_eq!(points[0].transform, start);
+    assert_eq!(points[4].command, 1);
+    assert!(points[2].transform.approx_eq(
+        &Transform::from_vector([400.0, 0.0, 200.0]).then_rx(180.0),
+        1e-9,
+        1e-6
+    ));
+    assert_eq!(points[5].command, 3);
+    assert_eq!(points[5].motion_mode, MotionMode::LinearRelative);
+    assert!((points[5].transform.get_y() - 25.0).abs() < 1e-9);
+    assert!((sampled.length_mm() - 225.0).abs() < 1e-9);
+
+    let csv = sampled.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[0], "command,motion_mode,x,y,z,rx,ry,rz");
+    assert!(lines[6].starts_with("3,linear_relative,"));
+
+    let ply = sampled.to_ply();
+    assert!(ply.contains("element vertex 6\n"));
+    assert!(ply.contains("element edge 5\n"));
+    assert!(ply.ends_with("4 5\n"));
+
+    assert!(SampledPath::sample(&path, start.clone(), 0.0).is_err());
+    let joints = CommandSequence::new().then_joint(JointCoord::identity());
+    assert!(SampledPath::sample(&joints, start, 5.0).is_err());
+}
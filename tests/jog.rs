@@ -0,0 +1,11 @@
+use inovo_rs::robot::{JogAxis, JogFrame, JogSettings};
+
+#[test]
+fn jog_settings() {
+    let settings = JogSettings::new().set_step_mm(-2.0).set_step_deg(1.5);
+    assert_eq!(settings.step(JogAxis::Y), 2.0);
+    assert_eq!(settings.step(JogAxis::Rx), 1.5);
+    assert_eq!(settings.frame(), JogFrame::Base);
+    assert_eq!(JogAxis::Ry.delta(-1.5).get_ry(), -1.5);
+    assert_eq!(JogAxis::Z.delta(2.0).get_z(), 2.0);
+}
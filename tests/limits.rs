@@ -0,0 +1,60 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::geometry::*;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+
+#[test]
+pub fn limits_reject_motion() -> Result<(), RobotError> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut bot = fake_psu(records.clone())?;
+    bot.set_soft_limits(
+        SoftLimits::new()
+            .set_box([-500.0, -500.0, 0.0], [500.0, 500.0, 500.0])
+            .set_joint_range(1, -170.0, 170.0),
+    );
+
+    assert!(matches!(
+        bot.linear(Transform::from_z(-20.0)),
+        Err(RobotError::SoftLimit(LimitViolation::Cartesian {
+            axis: 'z',
+            ..
+        }))
+    ));
+    assert!(matches!(
+        bot.joint(JointCoord::from_j1(175.0)),
+        Err(RobotError::SoftLimit(LimitViolation::Joint {
+            joint: 1,
+            ..
+        }))
+    ));
+    // the fake psu is at z = 300 mm
+    assert!(matches!(
+        bot.joint_relative(Transform::from_z(250.0)),
+        Err(RobotError::SoftLimit(_))
+    ));
+    assert!(records
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|r| !r.contains("motion")));
+    assert_eq!(bot.stats().errors.get("soft_limit"), Some(&3));
+
+    bot.joint_relative(Transform::from_z(150.0))?;
+    bot.joint(JointCoord::from_j1(90.0))?;
+
+    // absolute targets are checked when enqueued
+    let sequence = CommandSequence::new()
+        .then_joint(Transform::from_z(100.0))
+        .then_joint(Transform::from_x(600.0));
+    assert!(matches!(
+        bot.sequence(sequence),
+        Err(RobotError::SoftLimit(_))
+    ));
+
+    bot.clear_soft_limits();
+    assert!(bot.soft_limits().is_none());
+    bot.joint(JointCoord::from_j1(175.0))?;
+    Ok(())
+}
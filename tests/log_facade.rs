@@ -0,0 +1,28 @@
+#![cfg(feature = "log")]
+
+use inovo_rs::logger::facade::*;
+use inovo_rs::logger::target::CaptureTarget;
+use inovo_rs::logger::*;
+
+#[test]
+fn log_facade_test() {
+    let capture = CaptureTarget::new();
+    let bridged = Logger::empty().push(Box::new(capture.clone()));
+    LogBridge::init(bridged, log::LevelFilter::Info).unwrap();
+
+    // log facade -> Logger
+    log::info!("from the facade");
+    log::debug!("filtered by the bridge");
+
+    // Logger -> log facade -> Logger
+    let logger = Logger::empty().push(Box::new(LogTarget::new("robot", LogLevel::Trace)));
+    logger.warn("from the logger");
+
+    assert_eq!(
+        capture.messages(),
+        vec!["[log_facade] from the facade", "[robot] from the logger"]
+    );
+    capture.assert_logged(LogLevel::Info, "from the facade");
+    capture.assert_logged(LogLevel::Warn, "from the logger");
+    capture.assert_not_logged(LogLevel::Debug, "filtered");
+}
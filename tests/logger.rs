@@ -1,15 +1,18 @@
 use inovo_rs::logger;
+use inovo_rs::logger::target::*;
+use std::io::{BufRead, BufReader, Read};
+use std::net::{TcpListener, UdpSocket};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
 #[test]
 fn logger_test() -> Result<(), String> {
     println!("Starting . . .");
-    let mut logger1 = logger::Logger::default_target("Test");
-    let mut i: u128 = 0;
+    let logger1 = logger::Logger::default_target("Test");
     let mut j: i32 = 0;
     let mut k: i32 = 1;
-    for _ in 0..100 {
+    for i in 0..100 {
         thread::sleep(Duration::from_millis(1));
         logger1.debug(format!(
             "this is a log message {:>50} {}",
@@ -31,7 +34,6 @@ fn logger_test() -> Result<(), String> {
             i,
             " X".repeat(j as usize)
         ));
-        i += 1;
         j += k;
         if j <= 0 {
             k = 1;
@@ -49,11 +51,11 @@ fn multi_logger() {
     let mut name = String::new();
     let mut loggers = vec![];
     for word in sentence.split(" ") {
-        if name.len() != 0 {
-            name.push_str(" ");
+        if !name.is_empty() {
+            name.push(' ');
         }
         name.push_str(word);
-        let mut logger = logger::Logger::default_target(name.clone());
+        let logger = logger::Logger::default_target(name.clone());
         logger.info("a message");
 
         loggers.push(logger);
@@ -63,3 +65,360 @@ fn multi_logger() {
         }
     }
 }
+
+#[test]
+fn syslog_target() -> Result<(), std::io::Error> {
+    let server = UdpSocket::bind("127.0.0.1:0")?;
+    let syslog = SyslogTarget::udp("syslog test", server.local_addr()?, logger::LogLevel::Info)?
+        .set_facility(SyslogFacility::Local3)
+        .set_hostname("cell-01");
+    let logger = logger::Logger::empty().push(Box::new(syslog));
+
+    logger.debug("filtered out");
+    logger.warn("a warning");
+
+    let mut buf = [0u8; 1024];
+    let size = server.recv(&mut buf)?;
+    let msg = String::from_utf8_lossy(&buf[..size]);
+    let fields = msg.splitn(8, ' ').collect::<Vec<_>>();
+
+    // local3 * 8 + warning
+    assert_eq!(fields[0], "<156>1");
+    assert_eq!(fields[2], "cell-01");
+    assert_eq!(fields[3], "syslog_test");
+    assert_eq!(fields[4], std::process::id().to_string());
+    assert_eq!(fields[7], "Warn  | a warning");
+    Ok(())
+}
+
+#[test]
+fn syslog_tcp_reconnect() -> Result<(), std::io::Error> {
+    let server = TcpListener::bind("127.0.0.1:0")?;
+    let addr = server.local_addr()?;
+    let syslog = SyslogTarget::tcp("syslog tcp test", addr, logger::LogLevel::Info)?
+        .set_retry_interval(Duration::ZERO);
+    let logger = logger::Logger::empty().push(Box::new(syslog));
+
+    // dropped while the server is down
+    drop(server.accept()?);
+    drop(server);
+    for _ in 0..3 {
+        logger.info("dropped");
+    }
+
+    let server = TcpListener::bind(addr)?;
+    logger.info("reconnected");
+    let (mut stream, _) = server.accept()?;
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+    let mut buf = [0u8; 1024];
+    let size = stream.read(&mut buf)?;
+    let msg = String::from_utf8_lossy(&buf[..size]);
+    assert!(msg.ends_with("Info  | reconnected"));
+    assert!(!msg.contains("dropped"));
+    Ok(())
+}
+
+#[test]
+fn tcp_target() -> Result<(), std::io::Error> {
+    // find a free port, and keep the collector down for now
+    let addr = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+
+    let tcp = TcpTarget::new("tcp test", addr.to_string(), logger::LogLevel::Info)
+        .set_capacity(2)
+        .set_retry_interval(Duration::ZERO);
+    let logger = logger::Logger::empty().push(Box::new(tcp));
+
+    // buffered while the collector is down, the oldest are dropped
+    logger.info("dropped");
+    logger.info("buffered 1");
+    logger.info("buffered 2");
+
+    let collector = TcpListener::bind(addr)?;
+    logger.info("connected");
+
+    let (stream, _) = collector.accept()?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let lines = BufReader::new(stream)
+        .lines()
+        .take(2)
+        .collect::<Result<Vec<_>, _>>()?;
+    let messages = lines
+        .iter()
+        .map(|l| l.split_once("] [tcp test] ").unwrap().1)
+        .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["Info  | buffered 2", "Info  | connected"]);
+    Ok(())
+}
+
+/// a target recording every message and flush, for testing wrappers
+struct RecordTarget {
+    records: Arc<Mutex<Vec<String>>>,
+}
+
+impl LoggingTarget for RecordTarget {
+    fn log_message(&mut self, msg: &String, _: logger::LogLevel) {
+        self.records
+            .lock()
+            .unwrap()
+            .push(msg.trim_end().to_string());
+    }
+    fn set_level(&mut self, _: logger::LogLevel) {}
+    fn get_level(&self) -> logger::LogLevel {
+        logger::LogLevel::Debug
+    }
+    fn flush(&mut self) {
+        self.records.lock().unwrap().push("flush".to_string());
+    }
+}
+
+#[test]
+fn background_target() {
+    let records = Arc::new(Mutex::new(vec![]));
+    let record = RecordTarget {
+        records: records.clone(),
+    };
+    let background =
+        BackgroundTarget::new(Box::new(record)).set_flush_interval(Duration::from_secs(3600));
+    assert_eq!(background.get_level(), logger::LogLevel::Debug);
+
+    let logger = logger::Logger::empty().push(Box::new(background));
+    logger.trace("filtered out");
+    logger.debug("message 1");
+    logger.info("message 2");
+    drop(logger);
+
+    assert_eq!(
+        *records.lock().unwrap(),
+        vec!["message 1", "message 2", "flush"]
+    );
+}
+
+#[test]
+fn buffered_rolling_file_target() -> Result<(), std::io::Error> {
+    let file = RollingFileTarget::default("buffered test")?.buffered(Duration::from_secs(3600));
+    let path = get_logger_dir(&"buffered test".to_string())?.join("buffered test.0.log");
+
+    let logger = logger::Logger::empty().push(Box::new(file));
+    // the messages wait in the buffer for the interval
+    logger.info("message 1");
+    logger.info("message 2");
+    assert_eq!(std::fs::read_to_string(&path)?.lines().count(), 0);
+
+    drop(logger);
+    assert_eq!(std::fs::read_to_string(&path)?.lines().count(), 2);
+    Ok(())
+}
+
+#[test]
+fn rolling_file_target_write_failure() -> Result<(), std::io::Error> {
+    let dir = get_logger_dir(&"failing test".to_string())?;
+    let _ = std::fs::remove_dir_all(dir.join("failing test.1.log"));
+    let file = RollingFileTarget::new("failing test", 1, 3, logger::LogLevel::Debug)?;
+    let logger = logger::Logger::empty().push(Box::new(file));
+    logger.info("message 1");
+
+    // a directory in place of the rotated file, the rotation fails
+    std::fs::create_dir_all(dir.join("failing test.1.log").join("blocked"))?;
+    logger.info("dropped");
+    logger.info("message 2");
+
+    // the file is reopened, keeping the lines already logged
+    let lines = std::fs::read_to_string(dir.join("failing test.0.log"))?;
+    let lines = lines.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].ends_with("message 1"));
+    assert!(lines[1].ends_with("message 2"));
+    std::fs::remove_dir_all(dir.join("failing test.1.log"))
+}
+
+#[test]
+fn shared_logger() {
+    let capture = CaptureTarget::new();
+    let logger = logger::Logger::empty().push(Box::new(capture.clone()));
+
+    let handles = (0..4)
+        .map(|i| {
+            let logger = logger.clone();
+            thread::spawn(move || (0..25).for_each(|j| logger.info(format!("{} {}", i, j))))
+        })
+        .collect::<Vec<_>>();
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    assert_eq!(capture.entries().len(), 100);
+    capture.assert_logged(logger::LogLevel::Info, "3 24");
+}
+
+#[test]
+fn logger_registry() {
+    let capture = CaptureTarget::new();
+    let registered = logger::Logger::empty().push(Box::new(capture.clone()));
+    assert!(logger::Logger::register("registry test", registered).is_none());
+
+    logger::Logger::get_or_create("registry test").info("first");
+    logger::Logger::get_or_create("registry test").info("second");
+
+    assert_eq!(capture.messages(), vec!["first", "second"]);
+}
+
+#[test]
+fn env_filter() {
+    let filter: logger::EnvFilter = " debug, ros=warn ,ros psu002=trace".parse().unwrap();
+    assert_eq!(filter.level_for("ROS psu001"), Some(logger::LogLevel::Warn));
+    assert_eq!(
+        filter.level_for("ros psu002"),
+        Some(logger::LogLevel::Trace)
+    );
+    assert_eq!(filter.level_for("psu002"), Some(logger::LogLevel::Debug));
+
+    let filter: logger::EnvFilter = "Socket=trace,client 10.0.0.2=warn".parse().unwrap();
+    assert_eq!(
+        filter.level_for("Listener 10.0.0.2-50003"),
+        Some(logger::LogLevel::Trace)
+    );
+    assert_eq!(
+        filter.level_for("Client 10.0.0.2-50003 10.0.0.9-41022"),
+        Some(logger::LogLevel::Warn)
+    );
+    assert_eq!(filter.level_for("socket"), None);
+
+    assert!("info,ros=loud".parse::<logger::EnvFilter>().is_err());
+    let lossy = logger::EnvFilter::parse_lossy("ros=loud,modbus=error");
+    assert_eq!(lossy.level_for("ros psu002"), None);
+    assert_eq!(
+        lossy.level_for("Modbus 10.0.0.3-502"),
+        Some(logger::LogLevel::Error)
+    );
+}
+
+#[test]
+fn template() -> Result<(), std::io::Error> {
+    let template = logger::Template::new("{level}|{name}|{message}|{unknown}|{timestamp:%Y}");
+    let year = chrono::Local::now().format("%Y").to_string();
+    assert_eq!(
+        template.render("robot", logger::LogLevel::Warn, "hello"),
+        format!("Warn |robot|hello|{{unknown}}|{}\n", year)
+    );
+    assert_eq!(
+        logger::Template::default().render("robot", logger::LogLevel::Info, "hello"),
+        "Info  | hello\n"
+    );
+
+    // targets format with the template
+    let collector = TcpListener::bind("127.0.0.1:0")?;
+    let tcp = TcpTarget::new(
+        "template test",
+        collector.local_addr()?.to_string(),
+        logger::LogLevel::Info,
+    )
+    .set_template(logger::Template::new("<{level}> {name}: {message}"));
+    let logger = logger::Logger::empty().push(Box::new(tcp));
+    logger.warn("templated");
+
+    let (stream, _) = collector.accept()?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let line = BufReader::new(stream).lines().next().unwrap()?;
+    assert_eq!(line, "<Warn > template test: templated");
+    Ok(())
+}
+
+#[test]
+fn ring_buffer_target() {
+    let recent = RingBufferTarget::new(2, logger::LogLevel::Info);
+    let logger = logger::Logger::empty().push(Box::new(recent.clone()));
+    assert!(recent.is_empty());
+
+    logger.info("dropped");
+    logger.debug("filtered out");
+    logger.warn("kept 1");
+    logger.error("kept 2");
+
+    let snapshot = recent.snapshot();
+    let messages = snapshot
+        .iter()
+        .map(|e| (e.log_level, e.message.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            (logger::LogLevel::Warn, "kept 1"),
+            (logger::LogLevel::Error, "kept 2")
+        ]
+    );
+    assert!(snapshot[1].to_string().ends_with("] Error | kept 2"));
+
+    recent.clear();
+    assert_eq!(recent.len(), 0);
+}
+
+#[test]
+fn capture_target() {
+    let capture = CaptureTarget::new();
+    let logger = logger::Logger::empty().push(Box::new(capture.clone()));
+    logger.trace("a trace");
+    logger.error("an error: timeout");
+
+    capture.assert_logged(logger::LogLevel::Trace, "trace");
+    capture.assert_logged(logger::LogLevel::Error, "timeout");
+    capture.assert_not_logged(logger::LogLevel::Warn, "timeout");
+
+    let missing = std::panic::catch_unwind(|| {
+        capture.assert_logged(logger::LogLevel::Info, "never logged");
+    });
+    assert!(missing.is_err());
+
+    capture.clear();
+    assert!(capture.entries().is_empty());
+}
+
+#[test]
+fn fallible_rolling_file_target() -> Result<(), std::io::Error> {
+    // a file in place of the logger directory
+    let logging_dir = get_logging_dir()?;
+    std::fs::write(logging_dir.join("not a dir"), "")?;
+
+    assert!(RollingFileTarget::default("not a dir").is_err());
+    assert!(logger::Logger::default_target_checked("not a dir").is_err());
+
+    // fallback to console only
+    logger::Logger::default_target("not a dir").info("still logging");
+    Ok(())
+}
+
+#[test]
+fn console_color() {
+    let template = logger::Template::new("{level} {message}");
+    let console = ConsoleTarget::default("color")
+        .set_template(template.clone())
+        .set_color_mode(ColorMode::Always);
+    assert_eq!(
+        console.render("stop", logger::LogLevel::Error),
+        "\x1b[31mError stop\x1b[0m\n"
+    );
+
+    let themed = console.set_theme(
+        ConsoleTheme::default()
+            .set_color(logger::LogLevel::Error, Color::Magenta)
+            .set_color(logger::LogLevel::Info, None),
+    );
+    assert_eq!(
+        themed.render("stop", logger::LogLevel::Error),
+        "\x1b[35mError stop\x1b[0m\n"
+    );
+    assert_eq!(themed.render("go", logger::LogLevel::Info), "Info  go\n");
+
+    let plain = ConsoleTarget::default("color")
+        .set_template(template)
+        .set_color_mode(ColorMode::Never)
+        .set_stderr(true);
+    assert_eq!(
+        plain.render("stop", logger::LogLevel::Error),
+        "Error stop\n"
+    );
+
+    assert!(ColorMode::Always.should_color(false));
+    assert!(!ColorMode::Never.should_color(true));
+    // the test output is not a terminal
+    assert!(!ColorMode::Auto.should_color(false));
+}
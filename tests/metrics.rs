@@ -0,0 +1,41 @@
+#![cfg(feature = "metrics")]
+
+use inovo_rs::metrics::Metrics;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+#[test]
+fn metrics_test() -> Result<(), std::io::Error> {
+    let metrics = Metrics::new();
+    metrics.record_instruction("execute", Duration::from_millis(20));
+    metrics.record_instruction("execute", Duration::from_secs(3));
+    metrics.record_instruction("get", Duration::from_millis(2));
+    metrics.record_error("response");
+    metrics.record_reconnect();
+
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.instructions.get("execute"), Some(&2));
+    assert_eq!(snapshot.errors.get("response"), Some(&1));
+    assert_eq!(snapshot.latency["execute"].count, 2);
+    assert_eq!(snapshot.reconnects, 1);
+
+    let rendered = metrics.render();
+    assert!(rendered.contains("inovo_instructions_total{op_code=\"get\"} 1"));
+    assert!(rendered
+        .contains("inovo_instruction_latency_seconds_bucket{op_code=\"execute\",le=\"0.025\"} 1"));
+    assert!(rendered
+        .contains("inovo_instruction_latency_seconds_bucket{op_code=\"execute\",le=\"+Inf\"} 2"));
+
+    let _ = metrics.serve("127.0.0.1:19184")?;
+    // an idle client does not block the scrapes
+    let _idle = TcpStream::connect("127.0.0.1:19184")?;
+    let mut stream = TcpStream::connect("127.0.0.1:19184")?;
+    stream.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.ends_with(&rendered));
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "modbus")]
+
+use inovo_rs::modbus::*;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
+
+/// a minimal modbus device, with 16 coils and 16 registers
+fn device(listener: TcpListener) -> Result<(), std::io::Error> {
+    let (mut stream, _) = listener.accept()?;
+    let mut coils = [false; 16];
+    let mut registers = [0u16; 16];
+    loop {
+        let mut header = [0u8; 7];
+        if stream.read_exact(&mut header).is_err() {
+            return Ok(());
+        }
+        let length = u16::from_be_bytes([header[4], header[5]]) as usize;
+        let mut pdu = vec![0u8; length - 1];
+        stream.read_exact(&mut pdu)?;
+
+        let address = u16::from_be_bytes([pdu[1], pdu[2]]) as usize;
+        let value = u16::from_be_bytes([pdu[3], pdu[4]]);
+        let res: Vec<u8> = match pdu[0] {
+            0x01 => {
+                let mut bits = vec![0u8; (value as usize).div_ceil(8)];
+                for i in 0..value as usize {
+                    if coils[address + i] {
+                        bits[i / 8] |= 1 << (i % 8);
+                    }
+                }
+                [vec![0x01, bits.len() as u8], bits].concat()
+            }
+            0x03 if address + value as usize > registers.len() => vec![0x83, 0x02],
+            0x03 => {
+                let mut res = vec![0x03, (value * 2) as u8];
+                for r in &registers[address..address + value as usize] {
+                    res.extend(r.to_be_bytes());
+                }
+                res
+            }
+            0x05 => {
+                coils[address] = value == 0xFF00;
+                pdu.clone()
+            }
+            0x10 => {
+                for i in 0..value as usize {
+                    registers[address + i] = u16::from_be_bytes([pdu[6 + 2 * i], pdu[7 + 2 * i]]);
+                }
+                pdu[..5].to_vec()
+            }
+            f => vec![f | 0x80, 0x01],
+        };
+
+        let mut frame = header[..4].to_vec();
+        frame.extend((res.len() as u16 + 1).to_be_bytes());
+        frame.push(header[6]);
+        frame.extend(res);
+        stream.write_all(&frame)?;
+    }
+}
+
+#[test]
+fn modbus_test() -> Result<(), ModbusError> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    let handle = thread::spawn(move || device(listener));
+
+    let mut client = ModbusClient::connect(addr, 1, None)?;
+
+    client.write_single_coil(3, true)?;
+    client.write_single_coil(9, true)?;
+    let coils = client.read_coils(0, 10)?;
+    assert_eq!(
+        coils,
+        vec![false, false, false, true, false, false, false, false, false, true]
+    );
+
+    client.write_multiple_registers(2, &[42, 1337])?;
+    assert_eq!(client.read_holding_registers(1, 3)?, vec![0, 42, 1337]);
+
+    // too many to fit in a request, not sent
+    assert!(matches!(
+        client.write_multiple_registers(0, &[0; 124]),
+        Err(ModbusError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        client.write_multiple_coils(0, &[true; 1969]),
+        Err(ModbusError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        client.read_holding_registers(0, 0),
+        Err(ModbusError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        client.read_input_registers(0, 126),
+        Err(ModbusError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        client.read_coils(0, 2001),
+        Err(ModbusError::InvalidArgument(_))
+    ));
+
+    match client.read_holding_registers(15, 4) {
+        Err(ModbusError::Exception {
+            function: 0x03,
+            code: 0x02,
+        }) => {}
+        res => panic!("expected illegal address exception, got {:?}", res),
+    }
+
+    drop(client);
+    handle.join().unwrap()?;
+    Ok(())
+}
+
+#[test]
+fn modbus_late_response() -> Result<(), ModbusError> {
+    // a device answering the registers with the transaction id, the first answer too late
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    let handle = thread::spawn(move || -> Result<(), std::io::Error> {
+        let (mut stream, _) = listener.accept()?;
+        let mut request = [0u8; 12];
+        while stream.read_exact(&mut request).is_ok() {
+            if request[1] == 1 {
+                thread::sleep(Duration::from_millis(300));
+            }
+            let frame = [
+                &request[..4],
+                &[0, 5, request[6], 0x03, 2, 0],
+                &request[1..2],
+            ]
+            .concat();
+            stream.write_all(&frame)?;
+        }
+        Ok(())
+    });
+
+    let mut client = ModbusClient::connect(addr, 1, None)?;
+    client.set_timeout(Duration::from_millis(100))?;
+    assert!(matches!(
+        client.read_holding_registers(0, 1),
+        Err(ModbusError::SocketError(_))
+    ));
+
+    // the late answer of the first request is discarded
+    client.set_timeout(ModbusClient::TIMEOUT)?;
+    assert_eq!(client.read_holding_registers(0, 1)?, vec![2]);
+    assert_eq!(client.read_holding_registers(0, 1)?, vec![3]);
+
+    drop(client);
+    handle.join().unwrap()?;
+    Ok(())
+}
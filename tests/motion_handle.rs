@@ -0,0 +1,37 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::geometry::{JointCoord, Transform};
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[test]
+fn motion_async() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut robot = fake_psu(records.clone())?;
+
+    let mut motion = robot.joint_async(JointCoord::from_j1(90.0))?;
+    let start = Instant::now();
+    while !motion.is_done()? {
+        assert!(start.elapsed() < Duration::from_secs(5));
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    motion.wait()?;
+
+    {
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 3);
+        assert!(records[0].contains("\"op_code\":\"enqueue\""));
+        assert!(records[1].contains("\"action\":\"synchronize\""));
+        assert!(records[2].contains("\"op_code\":\"dequeue\""));
+    }
+
+    // the motion is waited on drop, the connection stays in sync
+    drop(robot.sequence_async(CommandSequence::new().then_sleep(0.5))?);
+    assert_eq!(robot.context_depth()?, 0);
+
+    // the fake psu refuses linear motions
+    assert!(robot.linear_async(Transform::identity()).is_err());
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use inovo_rs::geometry::*;
+use inovo_rs::pallet::*;
+
+fn approx_eq(a: [f64; 3], b: [f64; 3]) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-6)
+}
+
+#[test]
+pub fn pallet_new() {
+    let origin = Transform::from_vector([100.0, 0.0, 0.0]).set_rz(90.0);
+    let pallet = Pallet::new(origin, 2, 3, 10.0, 20.0);
+    assert_eq!(pallet.len(), 6);
+    assert_eq!(pallet.skew_deg(), 0.0);
+
+    // the columns go along the x axis of the origin, the base y axis
+    let slot = pallet.slot(1, 2).unwrap();
+    assert!(approx_eq(slot.get_vector(), [90.0, 40.0, 0.0]));
+    assert!((slot.get_rz() - 90.0).abs() < 1e-6);
+    assert!(pallet.slot(2, 0).is_none());
+    assert_eq!(pallet.slots().count(), 6);
+    assert!(approx_eq(
+        pallet.slot_index(5).unwrap().get_vector(),
+        slot.get_vector()
+    ));
+
+    let frame = pallet.frame();
+    assert!(approx_eq(frame.get_vector(), [100.0, 0.0, 0.0]));
+    assert!((frame.get_rz() - 90.0).abs() < 1e-6);
+}
+
+#[test]
+pub fn pallet_calibrate() {
+    // a tilted 3 x 5 pallet, 25 mm between columns and 40 mm between rows
+    let frame = Transform::from_vector([400.0, -100.0, 50.0]).set_euler([10.0, 0.0, 30.0]);
+    let taught = Pallet::new(frame.clone(), 3, 5, 40.0, 25.0);
+    let p00 = taught.slot(0, 0).unwrap();
+    let p0n = taught.slot(0, 4).unwrap();
+    let pm0 = taught.slot(2, 0).unwrap();
+
+    let pallet = calibrate_from_corners(&p00, &p0n, &pm0, 3, 5).unwrap();
+    assert!((pallet.row_pitch_mm() - 40.0).abs() < 1e-9);
+    assert!((pallet.col_pitch_mm() - 25.0).abs() < 1e-9);
+    assert!(pallet.skew_deg().abs() < 1e-9);
+    for (calibrated, taught) in pallet.slots().zip(taught.slots()) {
+        assert!(approx_eq(calibrated.get_vector(), taught.get_vector()));
+    }
+    let euler = pallet.frame().get_euler();
+    assert!(approx_eq(euler, frame.get_euler()));
+
+    assert!(calibrate_from_corners(&p00, &p0n, &pm0, 1, 5).is_none());
+    assert!(calibrate_from_corners(&p00, &p0n, &p0n, 3, 5).is_none());
+}
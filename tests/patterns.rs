@@ -0,0 +1,64 @@
+use inovo_rs::geometry::*;
+use inovo_rs::iva::*;
+use inovo_rs::patterns::*;
+
+fn approx_eq(a: [f64; 3], b: [f64; 3]) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-6)
+}
+
+#[test]
+pub fn weave_sine_tool_plane() {
+    // tool pointing down, the tool z axis is the base -z
+    let path = [
+        Transform::from_rx(180.0).set_vector([0.0, 0.0, 100.0]),
+        Transform::from_rx(180.0).set_vector([0.0, 40.0, 100.0]),
+    ];
+    let waypoints = Weave::new(5.0, 0.05, WeavePlane::Tool)
+        .set_shape(WeaveShape::Sine)
+        .set_samples_per_cycle(4)
+        .waypoints(&path);
+
+    assert_eq!(waypoints.len(), 9);
+    assert!(approx_eq(waypoints[0].get_vector(), [0.0, 0.0, 100.0]));
+    // left of the path looking along -z, the base +x
+    assert!(approx_eq(waypoints[1].get_vector(), [5.0, 5.0, 100.0]));
+    assert!(approx_eq(waypoints[3].get_vector(), [-5.0, 15.0, 100.0]));
+    assert!(approx_eq(waypoints[8].get_vector(), [0.0, 40.0, 100.0]));
+    assert!(waypoints
+        .iter()
+        .all(|t| t.rotation_distance_deg(&path[0]) < 1e-6));
+}
+
+#[test]
+pub fn weave_corner() {
+    let up = WeavePlane::Normal([0.0, 0.0, 1.0].into());
+    let path = [
+        Transform::identity(),
+        Transform::from_x(10.0),
+        Transform::from_vector([10.0, 10.0, 0.0]),
+    ];
+    let bead = weave(&path, 1.0, 0.1, up);
+    let targets: Vec<[f64; 3]> = bead
+        .iter()
+        .map(|command| match command {
+            RobotCommand::Motion {
+                motion_mode: MotionMode::Linear,
+                target: MotionTarget::Transform(target),
+                ..
+            } => target.get_vector(),
+            command => panic!("unexpected command: {:?}", command),
+        })
+        .collect();
+
+    // the corner is kept, and the weave turns with the path
+    assert_eq!(targets.len(), 7);
+    assert!(approx_eq(targets[1], [2.5, 1.0, 0.0]));
+    assert!(approx_eq(targets[2], [7.5, -1.0, 0.0]));
+    assert!(approx_eq(targets[3], [10.0, 0.0, 0.0]));
+    assert!(approx_eq(targets[4], [9.0, 2.5, 0.0]));
+    assert!(approx_eq(targets[6], [10.0, 10.0, 0.0]));
+
+    // nothing to weave
+    assert_eq!(weave(&path[..1], 1.0, 0.1, up).len(), 1);
+    assert_eq!(Weave::new(1.0, 0.0, up).waypoints(&path), path);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "python")]
+
+use pyo3::ffi::c_str;
+use pyo3::prelude::*;
+
+#[test]
+fn python_bindings() -> PyResult<()> {
+    inovo_rs::python::append_to_inittab();
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+        py.run(
+            c_str!(
+                r#"
+from inovo_rs import CommandSequence, JointCoord, MotionParam, RobotError, Transform
+
+t = Transform(100.0, rz=90.0) * Transform(x=10.0)
+assert t.approx_eq(Transform(100.0, 10.0, rz=90.0), 1e-9, 1e-9), t
+assert str(Transform(z=1.5)) == "x=0 y=0 z=1.5 | rx=0 ry=0 rz=0"
+assert (t / t).approx_eq(Transform(), 1e-9, 1e-9)
+
+j = JointCoord(j6=10.0) + JointCoord(1.0)
+assert j.to_list() == [1.0, 0.0, 0.0, 0.0, 0.0, 10.0]
+assert j[5] == 10.0 and len(j) == 6
+
+seq = CommandSequence().then_set_param(MotionParam(speed=50.0)).then_joint(j).then_linear(t).then_sleep(1.0)
+assert len(seq) == 4
+assert CommandSequence.from_json(seq.to_json()) == seq
+
+assert issubclass(RobotError, RuntimeError)
+"#
+            ),
+            None,
+            None,
+        )
+    })
+}
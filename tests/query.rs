@@ -0,0 +1,66 @@
+mod common;
+
+use common::{fake_psu, FakePsu};
+use inovo_rs::geometry::Transform;
+use inovo_rs::iva::{GetTarget, IOTarget, Instruction, RobotCommand};
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn query_channel() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let query = QueryChannel::new(fake_psu(records.clone())?);
+
+    let hmi = query.clone();
+    let transform = std::thread::spawn(move || hmi.get_current_transform())
+        .join()
+        .unwrap()?;
+    assert_eq!(
+        transform,
+        Transform::new(100.0, 200.0, 300.0, 0.0, 0.0, 0.0)
+    );
+    assert!(query.io_get(IOTarget::Wrist, 1)?);
+
+    // nothing but a query is sent
+    let motion = Instruction::exec(RobotCommand::linear(Transform::identity()));
+    assert!(matches!(
+        query.query::<String>(motion),
+        Err(RobotError::InvalidArgument(_))
+    ));
+    assert_eq!(records.lock().unwrap().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn instruction_is_query() {
+    assert!(Instruction::io_get(IOTarget::Beckhoff, 0).is_query());
+    assert!(!Instruction::io_set(IOTarget::Beckhoff, 0, true).is_query());
+    assert!(!Instruction::pop().is_query());
+}
+
+#[test]
+fn data_batch_transform() -> Result<(), Box<dyn std::error::Error>> {
+    // a pose stored in the data dict, answered as a json object in a batch
+    let psu = FakePsu::with_responder(|inst| match inst {
+        Instruction::Get(GetTarget::Data { .. }) => {
+            "{rx: 0, ry: 0.5, rz: 0, x: 0.1, y: 0.2, z: 0.3}".to_string()
+        }
+        Instruction::Get(GetTarget::DataBatch { .. }) => serde_json::json!({
+            "pose": {"rx": 0, "ry": 0.5, "rz": 0, "x": 0.1, "y": 0.2, "z": 0.3},
+            "offsets": [1.5, -2],
+            "label": "tray",
+        })
+        .to_string(),
+        inst => FakePsu::default_response(inst),
+    })?;
+    let mut bot = psu.connect()?;
+
+    let data = bot.get_data_batch(&["pose", "offsets", "label"])?;
+    let pose = Transform::from_robot(data["pose"].clone())?;
+    assert_eq!(pose, bot.get_data::<Transform>("pose")?);
+    assert_eq!(pose.get_vector(), [100.0, 200.0, 300.0]);
+    assert!((pose.get_ry() - 0.5f64.to_degrees()).abs() < 1e-9);
+    assert_eq!(data["offsets"], "[1.5, -2]");
+    assert_eq!(data["label"], "tray");
+    Ok(())
+}
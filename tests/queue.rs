@@ -0,0 +1,46 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::iva::{Instruction, RobotCommand};
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn queue_introspection() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut robot = fake_psu(records.clone())?;
+
+    assert_eq!(
+        robot.queued()?,
+        [RobotCommand::sleep(1.5), RobotCommand::Synchronize]
+    );
+    robot.clear_queue()?;
+    assert_eq!(
+        records.lock().unwrap().last().unwrap(),
+        "{\"op_code\":\"clear_queue\"}"
+    );
+    assert_eq!(Instruction::clear_queue().op_code(), "clear_queue");
+    Ok(())
+}
+
+#[test]
+fn queue_dequeue_n() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut robot = fake_psu(records.clone())?;
+
+    robot.enqueue(RobotCommand::sleep(1.0))?;
+    robot.enqueue(RobotCommand::sleep(2.0))?;
+    robot.dequeue_n(1)?;
+    assert_eq!(
+        records.lock().unwrap().last().unwrap(),
+        "{\"op_code\":\"dequeue\",\"enter_context\":0.0,\"count\":1}"
+    );
+
+    // the count is omitted for a full dequeue
+    robot.dequeue()?;
+    assert_eq!(
+        records.lock().unwrap().last().unwrap(),
+        "{\"op_code\":\"dequeue\",\"enter_context\":0.0}"
+    );
+    Ok(())
+}
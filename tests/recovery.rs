@@ -0,0 +1,103 @@
+mod common;
+
+use common::FakePsu;
+use inovo_rs::iva::{GetTarget, Instruction, RobotCommand};
+use inovo_rs::logger::Logger;
+use inovo_rs::robot::*;
+use inovo_rs::socket::Stream;
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+/// a fake psu answering the context depth with a settable value
+fn fake_psu(depth: Arc<AtomicU32>) -> Result<FakePsu, std::io::Error> {
+    FakePsu::with_responder(move |inst| match inst {
+        Instruction::Get(GetTarget::ContextDepth) => depth.load(Ordering::Relaxed).to_string(),
+        inst => FakePsu::default_response(inst),
+    })
+}
+
+fn stream(psu: &FakePsu) -> Result<Stream, std::io::Error> {
+    Stream::new(TcpStream::connect(psu.addr())?, Logger::empty())
+}
+
+#[test]
+fn recovery_pop_all() -> Result<(), Box<dyn std::error::Error>> {
+    let depth = Arc::new(AtomicU32::new(0));
+    let psu = fake_psu(depth.clone())?;
+    let mut bot = psu.connect()?;
+
+    let mut outer = bot.with_sleep(1.0)?;
+    assert_eq!(outer.tracked_context_depth(), 1);
+
+    // the robot program restarted, with a context of its own
+    depth.store(2, Ordering::Relaxed);
+    outer.reconnect(stream(&psu)?)?;
+    assert_eq!(outer.tracked_context_depth(), 0);
+    assert_eq!(psu.instructions().last(), Some(&Instruction::PopAll));
+
+    // a context entered after the recovery is popped, the orphaned one is not
+    outer.with_sleep(2.0)?.finish()?;
+    outer.finish()?;
+    let instructions = psu.instructions();
+    let pops = instructions
+        .iter()
+        .filter(|inst| **inst == Instruction::Pop)
+        .count();
+    assert_eq!(pops, 1);
+    Ok(())
+}
+
+#[test]
+fn recovery_replay() -> Result<(), Box<dyn std::error::Error>> {
+    let depth = Arc::new(AtomicU32::new(0));
+    let psu = fake_psu(depth.clone())?;
+    let mut bot = psu.connect()?;
+    bot.set_context_recovery(ContextRecovery::Replay);
+
+    let mut outer = bot.with_sleep(1.0)?;
+    let seq = CommandSequence::new().then_sleep(0.5).then_sleep(0.5);
+    let mut inner = outer.with_sequence(seq)?;
+
+    // the robot kept the outer context only
+    depth.store(1, Ordering::Relaxed);
+    let before = psu.instructions().len();
+    inner.reconnect(stream(&psu)?)?;
+    let replayed = psu.instructions()[before + 1..].to_vec();
+    assert_eq!(
+        replayed,
+        [
+            Instruction::enqueue(RobotCommand::Sleep { second: 0.5 }),
+            Instruction::enqueue(RobotCommand::Sleep { second: 0.5 }),
+            Instruction::dequeue_push(),
+        ]
+    );
+    assert_eq!(inner.tracked_context_depth(), 2);
+
+    // the robot has an extra context
+    depth.store(3, Ordering::Relaxed);
+    inner.reconnect(stream(&psu)?)?;
+    assert_eq!(psu.instructions().last(), Some(&Instruction::Pop));
+    inner.finish()?;
+    outer.finish()?;
+    assert_eq!(bot.tracked_context_depth(), 0);
+    Ok(())
+}
+
+#[test]
+fn recovery_verify() -> Result<(), Box<dyn std::error::Error>> {
+    let depth = Arc::new(AtomicU32::new(2));
+    let psu = fake_psu(depth)?;
+    let mut bot = psu.connect()?;
+    bot.set_context_recovery(ContextRecovery::Verify);
+
+    let res = bot.reconnect(stream(&psu)?);
+    assert!(matches!(
+        res,
+        Err(RobotError::ContextMismatch {
+            depth: 2,
+            tracked: 0
+        })
+    ));
+    Ok(())
+}
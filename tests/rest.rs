@@ -0,0 +1,67 @@
+#![cfg(feature = "rest")]
+
+mod common;
+
+use common::fake_psu;
+use inovo_rs::rest;
+use inovo_rs::robot::*;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::{Arc, Mutex};
+
+/// send a http request, returning the status and the body
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let status = response[9..12].parse().unwrap();
+    let body = response.split_once("\r\n\r\n").unwrap().1.to_string();
+    (status, body)
+}
+
+#[test]
+fn rest_server() -> Result<(), std::io::Error> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let handle = RobotHandle::new(fake_psu(records.clone())?);
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let listener = runtime.block_on(tokio::net::TcpListener::bind("127.0.0.1:0"))?;
+    let addr = listener.local_addr()?;
+    runtime.spawn(rest::serve(listener, handle));
+
+    assert_eq!(
+        request(addr, "PUT", "/io/wrist/3", "true"),
+        (204, String::new())
+    );
+    assert!(records.lock().unwrap()[0].contains("\"target\":\"wrist\""));
+
+    assert_eq!(
+        request(addr, "GET", "/io/beckhoff/1", ""),
+        (200, "true".to_string())
+    );
+
+    // the psu rejects the linear motion
+    let (status, body) = request(
+        addr,
+        "POST",
+        "/linear",
+        r#"{"x":0,"y":0,"z":100,"rx":0,"ry":0,"rz":0}"#,
+    );
+    assert_eq!(status, 409);
+    assert!(body.contains("\"category\":\"response\""));
+
+    // invalid requests never reach the robot
+    assert_eq!(request(addr, "GET", "/io/elbow/1", "").0, 400);
+    assert_eq!(request(addr, "POST", "/param", "{").0, 400);
+    assert_eq!(records.lock().unwrap().len(), 3);
+    Ok(())
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use common::FakePsu;
+use inovo_rs::geometry::Transform;
+use inovo_rs::iva::{GetTarget, Instruction, RobotCommand};
+use inovo_rs::robot::*;
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use websocket::sync::Server;
+use websocket::{Message, OwnedMessage};
+
+/// a ros bridge with a stopped runtime, the fake psu connecting back to `port` on every start
+fn fake_ros_bridge(psu: FakePsu, port: u16) -> Result<(), std::io::Error> {
+    let mut server = Server::bind("127.0.0.1:9090")?;
+    thread::spawn(move || {
+        while let Ok(upgrade) = server.accept() {
+            let mut client = upgrade.accept().unwrap();
+            while let Ok(OwnedMessage::Text(text)) = client.recv_message() {
+                let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+                let response = match json["op"].as_str() {
+                    Some("subscribe") => serde_json::json!({
+                        "op": "publish",
+                        "topic": json["topic"],
+                        "msg": { "state": 0 },
+                    }),
+                    Some("call_service") => {
+                        let ip = local_ip_address::local_ip().unwrap();
+                        psu.dial((ip, port).into()).unwrap();
+                        serde_json::json!({
+                            "op": "service_response",
+                            "service": json["service"],
+                            "values": { "success": true },
+                            "result": true,
+                        })
+                    }
+                    _ => continue,
+                };
+                client
+                    .send_message(&Message::text(response.to_string()))
+                    .unwrap();
+            }
+        }
+    });
+    Ok(())
+}
+
+#[test]
+fn auto_restart_retries_queries_only() -> Result<(), Box<dyn std::error::Error>> {
+    // the connection drops on the first motion and the first transform query
+    let drops = Arc::new(AtomicUsize::new(0));
+    let psu = FakePsu::with_dropping_responder(move |inst| match inst {
+        Instruction::Execute {
+            robot_command: RobotCommand::Motion { .. },
+            ..
+        }
+        | Instruction::Get(GetTarget::Transform)
+            if drops.fetch_add(1, Ordering::Relaxed) < 2 =>
+        {
+            None
+        }
+        inst => Some(FakePsu::default_response(inst)),
+    })?;
+    let port = TcpListener::bind("127.0.0.1:0")?.local_addr()?.port();
+    fake_ros_bridge(psu.clone(), port)?;
+
+    let mut bot = psu.connect()?;
+    bot.set_auto_restart(Some(AutoRestart::new(port, "127.0.0.1")?));
+
+    // the motion may have run before the connection dropped, it is not sent again
+    let res = bot.linear_relative(Transform::from_z(-10.0));
+    assert!(matches!(res, Err(RobotError::SocketError(_))));
+    assert_eq!(bot.auto_restart().unwrap().restarts(), 1);
+    let motions = |psu: &FakePsu| {
+        psu.instructions()
+            .iter()
+            .filter(|inst| {
+                matches!(
+                    inst,
+                    Instruction::Execute {
+                        robot_command: RobotCommand::Motion { .. },
+                        ..
+                    }
+                )
+            })
+            .count()
+    };
+    assert_eq!(motions(&psu), 1);
+
+    // a query is sent again on the new connection
+    bot.get_current_transform()?;
+    assert_eq!(bot.auto_restart().unwrap().restarts(), 2);
+    let queries = psu
+        .instructions()
+        .iter()
+        .filter(|inst| **inst == Instruction::Get(GetTarget::Transform))
+        .count();
+    assert_eq!(queries, 2);
+
+    // the restarted connection serves the next motion
+    bot.linear_relative(Transform::from_z(10.0))?;
+    assert_eq!(motions(&psu), 2);
+    Ok(())
+}
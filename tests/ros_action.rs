@@ -0,0 +1,112 @@
+use inovo_rs::ros_bridge::*;
+use std::sync::mpsc;
+use std::thread;
+use websocket::sync::Server;
+use websocket::{Message, OwnedMessage};
+
+/// a message of the action server, published on a topic for a goal
+fn publish(
+    topic: &str,
+    id: &serde_json::Value,
+    status: u8,
+    field: &str,
+    value: f64,
+) -> Message<'static> {
+    let json = serde_json::json!({
+        "op": "publish",
+        "topic": topic,
+        "msg": {
+            "status": { "goal_id": { "id": id }, "status": status },
+            field: value,
+        },
+    });
+    Message::text(json.to_string())
+}
+
+#[test]
+fn ros_bridge_action_goal() -> Result<(), Box<dyn std::error::Error>> {
+    let mut server = Server::bind("127.0.0.1:9090")?;
+    let (sender, received) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut client = server.accept().ok().unwrap().accept().unwrap();
+        loop {
+            let text = match client.recv_message() {
+                Ok(OwnedMessage::Text(text)) => text,
+                _ => break,
+            };
+            let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+            if json["op"] == "publish" && json["topic"] == "/calibration/goal" {
+                let id = &json["msg"]["goal_id"]["id"];
+                // the feedback of another goal is skipped
+                let other = serde_json::json!("another goal");
+                for message in [
+                    publish("/calibration/feedback", &other, 1, "feedback", 0.9),
+                    publish("/calibration/feedback", id, 1, "feedback", 0.5),
+                ] {
+                    client.send_message(&message).unwrap();
+                }
+            }
+            if json["op"] == "publish" && json["topic"] == "/calibration/cancel" {
+                let id = &json["msg"]["id"];
+                let message = publish("/calibration/result", id, 2, "result", 10.0);
+                client.send_message(&message).unwrap();
+            }
+            sender.send(json).unwrap();
+        }
+    });
+
+    let mut ros_bridge = RosBridge::new("127.0.0.1", 100);
+    let goal = serde_json::json!({ "samples": 20 });
+    let mut calibration =
+        ros_bridge.send_action_goal("/calibration", "calibration_msgs/Calibrate", goal)?;
+    assert_eq!(calibration.status(), GoalStatus::Pending);
+
+    assert_eq!(calibration.next_event()?, ActionEvent::Feedback(0.5.into()));
+    assert_eq!(calibration.status(), GoalStatus::Active);
+
+    calibration.cancel()?;
+    let (status, result) = calibration.wait_result()?;
+    assert_eq!(status, GoalStatus::Preempted);
+    assert!(status.is_terminal());
+    assert_eq!(result, 10.0);
+
+    let goal_id = calibration.goal_id().to_string();
+    drop(calibration);
+
+    let messages: Vec<serde_json::Value> = received.iter().take(10).collect();
+    let ops: Vec<String> = messages
+        .iter()
+        .map(|m| {
+            format!(
+                "{} {}",
+                m["op"].as_str().unwrap(),
+                m["topic"].as_str().unwrap()
+            )
+        })
+        .collect();
+    assert_eq!(
+        ops,
+        [
+            "advertise /calibration/goal",
+            "advertise /calibration/cancel",
+            "subscribe /calibration/feedback",
+            "subscribe /calibration/result",
+            "publish /calibration/goal",
+            "publish /calibration/cancel",
+            "unadvertise /calibration/goal",
+            "unadvertise /calibration/cancel",
+            "unsubscribe /calibration/feedback",
+            "unsubscribe /calibration/result",
+        ]
+    );
+    assert_eq!(messages[0]["type"], "calibration_msgs/CalibrateActionGoal");
+    assert_eq!(
+        messages[3]["type"],
+        "calibration_msgs/CalibrateActionResult"
+    );
+    assert_eq!(messages[4]["msg"]["goal"]["samples"], 20);
+    assert_eq!(messages[4]["msg"]["goal_id"]["id"], goal_id.as_str());
+    assert_eq!(messages[5]["msg"]["id"], goal_id.as_str());
+    Ok(())
+}
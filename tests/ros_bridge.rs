@@ -0,0 +1,95 @@
+use inovo_rs::ros_bridge::*;
+use std::sync::mpsc;
+use std::thread;
+use websocket::sync::Server;
+use websocket::{Message, OwnedMessage};
+
+/// split a message in fragments of at most `size` bytes, sent in reverse order
+fn fragment(text: &str, size: Option<u64>) -> Vec<String> {
+    let Some(size) = size else {
+        return vec![text.to_string()];
+    };
+    let chunks: Vec<String> = text
+        .as_bytes()
+        .chunks(size as usize)
+        .map(|chunk| String::from_utf8(chunk.to_vec()).unwrap())
+        .collect();
+    let total = chunks.len();
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(num, data)| {
+            serde_json::json!({
+                "op": "fragment",
+                "id": "publish:1",
+                "data": data,
+                "num": num,
+                "total": total,
+            })
+            .to_string()
+        })
+        .rev()
+        .collect()
+}
+
+#[test]
+fn ros_bridge_subscription() -> Result<(), Box<dyn std::error::Error>> {
+    let mut server = Server::bind("127.0.0.1:9090")?;
+    let (sender, received) = mpsc::channel();
+
+    thread::spawn(move || {
+        // a runtime state published, for each connection
+        for _ in 0..3 {
+            let mut client = server.accept().ok().unwrap().accept().unwrap();
+            loop {
+                let text = match client.recv_message() {
+                    Ok(OwnedMessage::Text(text)) => text,
+                    _ => break,
+                };
+                let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+                if json["op"] == "subscribe" {
+                    let publish = serde_json::json!({
+                        "op": "publish",
+                        "topic": json["topic"],
+                        "msg": { "state": 2 },
+                    })
+                    .to_string();
+                    for text in fragment(&publish, json["fragment_size"].as_u64()) {
+                        client.send_message(&Message::text(text)).unwrap();
+                    }
+                }
+                sender.send(json).unwrap();
+            }
+        }
+    });
+
+    let mut ros_bridge = RosBridge::new("127.0.0.1", 100);
+    assert_eq!(ros_bridge.get_runtime_state()?, RuntimeState::Pause);
+    assert_eq!(ros_bridge.get_runtime_state()?, RuntimeState::Pause);
+
+    // every subscription is unsubscribed, with its own id
+    let messages: Vec<serde_json::Value> = received.iter().take(4).collect();
+    let ops: Vec<&str> = messages.iter().map(|m| m["op"].as_str().unwrap()).collect();
+    assert_eq!(
+        ops,
+        ["subscribe", "unsubscribe", "subscribe", "unsubscribe"]
+    );
+    assert_eq!(messages[0]["id"], messages[1]["id"]);
+    assert_ne!(messages[0]["id"], messages[2]["id"]);
+    // the options not set are left out
+    assert!(messages[0].get("throttle_rate").is_none());
+
+    let options = SubscribeOptions::new()
+        .set_throttle_rate_ms(100)
+        .set_queue_length(1)
+        .set_fragment_size(16);
+    let mut subscription =
+        ros_bridge.subscribe_with("/joint_states", "sensor_msgs/JointState", options)?;
+    // reassembled from the fragments
+    assert_eq!(subscription.next_message()?["state"], 2);
+    let json = received.recv()?;
+    assert_eq!(json["throttle_rate"], 100);
+    assert_eq!(json["queue_length"], 1);
+    assert_eq!(json["fragment_size"], 16);
+    Ok(())
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "procedures")]
+
+use inovo_rs::ros_bridge::*;
+use std::collections::BTreeMap;
+use std::thread;
+use websocket::sync::Server;
+use websocket::{Message, OwnedMessage};
+
+/// answer a service call of the sequencer, storing the procedures as json strings
+fn sequencer(
+    procedures: &mut BTreeMap<String, String>,
+    json: &serde_json::Value,
+) -> serde_json::Value {
+    let args = &json["args"];
+    let name = args["procedure_name"].as_str().unwrap_or_default();
+    match json["service"].as_str().unwrap() {
+        "/sequence/list_procedures" => {
+            serde_json::json!({ "success": true, "procedure_names": procedures.keys().collect::<Vec<_>>() })
+        }
+        "/sequence/get_procedure" => match procedures.get(name) {
+            Some(definition) => serde_json::json!({ "success": true, "definition": definition }),
+            None => serde_json::json!({ "success": false, "definition": "" }),
+        },
+        "/sequence/set_procedure" => {
+            let definition = args["definition"].as_str().unwrap().to_string();
+            procedures.insert(name.to_string(), definition);
+            serde_json::json!({ "success": true })
+        }
+        _ => serde_json::json!({ "success": false }),
+    }
+}
+
+#[test]
+fn ros_bridge_project() -> Result<(), Box<dyn std::error::Error>> {
+    let mut server = Server::bind("127.0.0.1:9090")?;
+
+    thread::spawn(move || {
+        let mut procedures = BTreeMap::new();
+        procedures.insert(
+            "iva".to_string(),
+            r#"{"steps": [{"type": "tcp_server", "port": 50003}]}"#.to_string(),
+        );
+        // a connection for each service call
+        while let Ok(upgrade) = server.accept() {
+            let mut client = upgrade.accept().unwrap();
+            let Ok(OwnedMessage::Text(text)) = client.recv_message() else {
+                continue;
+            };
+            let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+            let response = serde_json::json!({
+                "op": "service_response",
+                "service": json["service"],
+                "values": sequencer(&mut procedures, &json),
+                "result": true,
+            });
+            client
+                .send_message(&Message::text(response.to_string()))
+                .unwrap();
+            let _ = client.recv_message();
+        }
+    });
+
+    let mut ros_bridge = RosBridge::new("127.0.0.1", 100);
+    assert_eq!(ros_bridge.list_procedures()?, ["iva"]);
+    assert!(ros_bridge.download_procedure("missing").is_err());
+
+    let mut project = ros_bridge.download_project()?;
+    assert_eq!(project.len(), 1);
+    assert_eq!(project.get("iva").unwrap()["steps"][0]["port"], 50003);
+
+    project.insert("home", serde_json::json!({ "steps": [{ "type": "home" }] }));
+    let path = std::env::temp_dir().join("inovo_rs_ros_program.json");
+    project.save(&path)?;
+    let loaded = Project::load(&path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(loaded, project);
+
+    ros_bridge.upload_project(&loaded)?;
+    assert_eq!(ros_bridge.list_procedures()?, ["home", "iva"]);
+    assert_eq!(ros_bridge.download_project()?, project);
+    Ok(())
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::context::ContextGuard;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+
+fn sent_speeds(records: &Arc<Mutex<Vec<String>>>) -> Vec<String> {
+    records
+        .lock()
+        .unwrap()
+        .drain(..)
+        .filter_map(|r| {
+            let start = r.find("\"speed\":")? + 8;
+            let end = start + r[start..].find(',')?;
+            Some(r[start..end].to_string())
+        })
+        .collect()
+}
+
+#[test]
+pub fn safe_mode_caps_speed() -> Result<(), RobotError> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut bot = fake_psu(records.clone())?;
+    bot.set_param(MotionParam::new().set_speed(80.0))?;
+    assert_eq!(sent_speeds(&records), ["0.8"]);
+
+    {
+        let mut setup = bot.safe_mode(10.0)?;
+        assert_eq!(setup.speed_cap(), Some(10.0));
+        setup.set_param(MotionParam::new().set_speed(5.0))?;
+        setup.set_param(MotionParam::new())?;
+        setup
+            .sequence(CommandSequence::new().then_set_param(MotionParam::new().set_speed(90.0)))?;
+        setup.set_param(MotionParam::new().set_speed(50.0))?;
+        {
+            // nested, the lower cap wins
+            let nested = setup.safe_mode(20.0)?;
+            assert_eq!(nested.speed_cap(), Some(10.0));
+        }
+        assert_eq!(setup.speed_cap(), Some(10.0));
+    }
+    assert_eq!(
+        sent_speeds(&records),
+        // entering, the params and the sequence, entering and exiting nested, exiting
+        ["0.1", "0.05", "0.1", "0.1", "0.1", "0.1", "0.1", "0.5"]
+    );
+    assert_eq!(bot.speed_cap(), None);
+    assert_eq!(bot.motion_param().unwrap().get_speed(), 50.0);
+
+    assert!(matches!(
+        bot.safe_mode(0.0),
+        Err(RobotError::InvalidArgument(_))
+    ));
+    let guard: ContextGuard<'_, Robot, SafeModeContext> = bot.safe_mode(25.0)?;
+    guard.finish()?;
+    assert_eq!(sent_speeds(&records), ["0.25", "0.5"]);
+    Ok(())
+}
+
+#[test]
+pub fn with_param_restores_known_param() -> Result<(), RobotError> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut bot = fake_psu(records.clone())?;
+
+    // the param of the robot is unknown, it is not restored
+    bot.with_param(MotionParam::new().set_speed(10.0))?
+        .finish()?;
+    assert_eq!(sent_speeds(&records), ["0.1"]);
+
+    bot.set_param(MotionParam::new().set_speed(80.0))?;
+    bot.with_param(MotionParam::new().set_speed(10.0))?
+        .finish()?;
+    assert_eq!(sent_speeds(&records), ["0.8", "0.1", "0.8"]);
+    assert_eq!(bot.motion_param().unwrap().get_speed(), 80.0);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "script")]
+
+use inovo_rs::geometry::*;
+use inovo_rs::robot::*;
+use inovo_rs::script::*;
+
+#[test]
+fn script_geometry() {
+    let engine = ScriptEngine::new();
+    let pose = engine
+        .eval("(from_x(100.0) * from_rz(90.0)).then_relative(from_x(10.0))")
+        .unwrap()
+        .cast::<Transform>();
+    assert!(pose.approx_eq(
+        &(Transform::from_x(100.0) * Transform::from_rz(90.0)).then_relative_x(10.0),
+        1e-9,
+        1e-9
+    ));
+
+    let j6 = engine
+        .eval("(joint_coord(0.0, 0.0, 0.0, 0.0, 0.0, 10.0) + joint_coord(0.0, 0.0, 0.0, 0.0, 0.0, 5.0)).j6")
+        .unwrap()
+        .cast::<f64>();
+    assert_eq!(j6, 15.0);
+
+    assert!(engine.eval("from_x(100)").is_err());
+    assert!(engine.run("bot.linear(identity())").is_err());
+    assert!(engine.into_robot().is_none());
+}
+
+#[test]
+fn script_sequence() {
+    let engine = ScriptEngine::new();
+    let sequence = engine
+        .eval(
+            r#"
+            let seq = sequence().then_set_param(motion_param().set_speed(20.0));
+            for z in [100.0, 50.0] {
+                seq = seq.then_linear(from_z(z));
+            }
+            seq.then_sleep(1.0)
+            "#,
+        )
+        .unwrap()
+        .cast::<CommandSequence>();
+    let expected = CommandSequence::new()
+        .then_set_param(MotionParam::new().set_speed(20.0))
+        .then_linear(Transform::from_z(100.0))
+        .then_linear(Transform::from_z(50.0))
+        .then_sleep(1.0);
+    assert_eq!(sequence, expected);
+}
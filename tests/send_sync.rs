@@ -0,0 +1,63 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::logger::Logger;
+use inovo_rs::robot::*;
+use inovo_rs::socket::Stream;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+fn assert_send<T: Send>() {}
+fn assert_sync<T: Sync>() {}
+
+#[test]
+fn thread_safe_types() {
+    assert_send::<Robot>();
+    assert_sync::<Robot>();
+    assert_send::<Logger>();
+    assert_sync::<Logger>();
+    assert_send::<Stream>();
+    assert_send::<RobotHandle>();
+    assert_sync::<RobotHandle>();
+    assert_send::<StateStream>();
+    assert_send::<RobotError>();
+}
+
+#[test]
+fn robot_across_threads() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut bot = fake_psu(records.clone())?;
+
+    // move the robot to a worker thread and back
+    bot = thread::spawn(move || -> Result<Robot, RobotError> {
+        bot.sleep(0.0)?;
+        Ok(bot)
+    })
+    .join()
+    .unwrap()?;
+    bot.sleep(0.0)?;
+
+    // share it between threads
+    let handle = RobotHandle::new(bot);
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let handle = handle.clone();
+            thread::spawn(move || handle.with(|bot| bot.sleep(0.0).map(|_| ())))
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap()?;
+    }
+
+    // and log from every thread with a shared logger
+    let logger = Logger::empty();
+    thread::scope(|s| {
+        for i in 0..4 {
+            let logger = &logger;
+            s.spawn(move || logger.info(format!("thread {}", i)));
+        }
+    });
+
+    assert_eq!(records.lock().unwrap().len(), 6);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use common::FakePsu;
+use inovo_rs::iva::{GetTarget, Instruction};
+use inovo_rs::robot::*;
+use std::time::{Duration, Instant};
+
+#[test]
+fn wait_until_settled() -> Result<(), Box<dyn std::error::Error>> {
+    let psu = FakePsu::new()?;
+    let mut bot = psu.connect()?;
+    let tolerance = SettleTolerance::new()
+        .set_window(Duration::from_millis(30))
+        .set_interval(Duration::from_millis(5));
+
+    let start = Instant::now();
+    let pose = bot.wait_until_settled(tolerance, Duration::from_secs(5))?;
+    assert!(start.elapsed() >= Duration::from_millis(30));
+    assert_eq!(pose.get_vector(), [0.0, 0.0, 0.0]);
+    Ok(())
+}
+
+#[test]
+fn wait_until_settled_timeout() -> Result<(), Box<dyn std::error::Error>> {
+    // moving at 100 mm/s
+    let psu = FakePsu::with_responder(|inst| match inst {
+        Instruction::Get(GetTarget::TcpVelocity) => "[0.1, 0]".to_string(),
+        inst => FakePsu::default_response(inst),
+    })?;
+    let mut bot = psu.connect()?;
+    let tolerance = SettleTolerance::new().set_interval(Duration::from_millis(5));
+
+    let res = bot.wait_until_settled(tolerance, Duration::from_millis(50));
+    assert!(matches!(res, Err(RobotError::Timeout(_))));
+    Ok(())
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "shell")]
+
+use crossterm::event::KeyCode;
+use inovo_rs::cli::*;
+use inovo_rs::geometry::*;
+use inovo_rs::robot::{JogAxis, JogFrame, WaypointLibrary};
+use inovo_rs::shell::*;
+
+#[test]
+fn shell_parse() {
+    assert_eq!(ShellCommand::parse("jog").unwrap(), ShellCommand::Jog);
+    assert_eq!(
+        ShellCommand::parse("step 0.5 1").unwrap(),
+        ShellCommand::Step {
+            mm: 0.5,
+            deg: Some(1.0)
+        }
+    );
+    assert_eq!(
+        ShellCommand::parse("play pick place").unwrap(),
+        ShellCommand::Play(vec!["pick".to_string(), "place".to_string()])
+    );
+    // the cli commands are available in the shell
+    assert_eq!(
+        ShellCommand::parse("io get 1 --wrist").unwrap(),
+        ShellCommand::Cli(CliCommand::IoGet {
+            target: inovo_rs::iva::IOTarget::Wrist,
+            port: 1
+        })
+    );
+    assert!(ShellCommand::parse("play").is_err());
+    assert!(ShellCommand::parse("step far").is_err());
+
+    assert_eq!(
+        JogAction::from_key(KeyCode::Up),
+        Some(JogAction::Jog(JogAxis::X, true))
+    );
+    assert_eq!(JogAction::from_key(KeyCode::Esc), Some(JogAction::Exit));
+    assert_eq!(JogAction::from_key(KeyCode::Char('x')), None);
+}
+
+#[test]
+fn jog_frame() {
+    let current = Transform::from_vector([100.0, 0.0, 0.0]).set_rz(90.0);
+    let base = JogFrame::Base.jog(&current, Transform::from_x(10.0));
+    let tool = JogFrame::Tool.jog(&current, Transform::from_x(10.0));
+    assert_eq!(
+        format!("{:.3}", base.vector_only()),
+        "x=110.000 y=0.000 z=0.000 | rx=0.000 ry=0.000 rz=0.000"
+    );
+    assert_eq!(
+        format!("{:.3}", tool.vector_only()),
+        "x=100.000 y=10.000 z=0.000 | rx=0.000 ry=0.000 rz=0.000"
+    );
+}
+
+#[test]
+fn waypoint_library() -> Result<(), std::io::Error> {
+    let path = std::env::temp_dir().join(format!("inovo-waypoints-{}.json", std::process::id()));
+    let mut library = WaypointLibrary::load(&path)?;
+    assert!(library.is_empty());
+
+    library.insert("home", Transform::from_z(300.0));
+    library.save(&path)?;
+    let loaded = WaypointLibrary::load(&path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(loaded.get("home").unwrap().get_z(), 300.0);
+    assert_eq!(loaded.sequence(["home", "missing"]).unwrap_err(), "missing");
+    Ok(())
+}
@@ -6,8 +6,8 @@ use std::thread;
 const MSG_COUNT: u16 = 100;
 const SERVER_PORT: u16 = 50003;
 
-fn client(port: u16, addr: SocketAddr) -> Result<(), std::io::Error> {
-    let mut client = Stream::connect(port, addr, None)?;
+fn client(port: u16, addr: SocketAddr, logger: Logger) -> Result<(), std::io::Error> {
+    let mut client = Stream::connect(port, addr, Some(logger))?;
     for i in 0..MSG_COUNT {
         client.write(format!("{} send {}", port, i))?;
         let _ = client.read()?;
@@ -25,7 +25,8 @@ fn handle(mut stream: Stream) -> Result<(), std::io::Error> {
 
 #[test]
 fn socket_test() -> Result<(), std::io::Error> {
-    let mut logger = Logger::default_target("SOCKET TEST");
+    // a single logger shared by every thread
+    let logger = Logger::default_target("SOCKET TEST");
     let mut listener = Listener::new(SERVER_PORT, None)?;
 
     let addr = listener.addr()?;
@@ -35,13 +36,14 @@ fn socket_test() -> Result<(), std::io::Error> {
     let mut handles = vec![];
 
     for port in ports.clone() {
-        handles.push(thread::spawn(move || client(port, addr)));
+        let logger = logger.clone();
+        handles.push(thread::spawn(move || client(port, addr, logger)));
     }
 
     logger.info("Spawned all client threads.");
 
     for _ in ports {
-        let stream = listener.accept(None).unwrap();
+        let stream = listener.accept(Some(logger.clone())).unwrap();
         handles.push(thread::spawn(move || handle(stream)));
     }
 
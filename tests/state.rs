@@ -0,0 +1,173 @@
+mod common;
+
+use common::{fake_psu, FakePsu};
+use inovo_rs::geometry::{JointCoord, Transform};
+use inovo_rs::iva::IOTarget;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[test]
+fn state_streamer() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let robot = fake_psu(records.clone())?;
+
+    let stream = StateStreamer::new().set_rate_hz(100.0).spawn(robot)?;
+    let state = stream.recv_timeout(Duration::from_secs(5)).unwrap()?;
+    assert_eq!(
+        state.transform,
+        Transform::new(100.0, 200.0, 300.0, 0.0, 0.0, 0.0)
+    );
+    assert_eq!(state.joints, Default::default());
+
+    let next = stream.recv_timeout(Duration::from_secs(5)).unwrap()?;
+    assert!(next.timestamp > state.timestamp);
+
+    let mut robot = stream.stop().unwrap();
+    robot.sleep(0.0)?;
+    assert!(records.lock().unwrap().len() >= 5);
+    Ok(())
+}
+
+#[test]
+fn state_streamer_stops_within_period() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let stream = StateStreamer::new()
+        .set_rate_hz(0.1)
+        .spawn(fake_psu(records)?)?;
+    stream.recv_timeout(Duration::from_secs(5)).unwrap()?;
+
+    // the poll is not waited for the end of its 10 s period
+    let start = Instant::now();
+    assert!(stream.stop().is_some());
+    assert!(start.elapsed() < Duration::from_secs(1));
+    Ok(())
+}
+
+#[test]
+fn state_streamer_error_when_full() -> Result<(), Box<dyn std::error::Error>> {
+    // the connection drops after a few queries, while nobody receives
+    let queries = Arc::new(Mutex::new(0));
+    let psu = FakePsu::with_dropping_responder(move |inst| {
+        let mut queries = queries.lock().unwrap();
+        *queries += 1;
+        (*queries <= 4).then(|| FakePsu::default_response(inst))
+    })?;
+    let stream = StateStreamer::new()
+        .set_rate_hz(100.0)
+        .set_capacity(1)
+        .spawn(psu.connect()?)?;
+    std::thread::sleep(Duration::from_millis(200));
+
+    let states = stream.iter().collect::<Vec<_>>();
+    assert_eq!(states.len(), 2);
+    assert!(states[0].is_ok());
+    assert!(matches!(states[1], Err(RobotError::SocketError(_))));
+    Ok(())
+}
+
+#[test]
+fn state_streamer_shared() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let handle = RobotHandle::new(fake_psu(records)?);
+
+    let stream = StateStreamer::new()
+        .set_rate_hz(100.0)
+        .spawn_shared(handle.clone())?;
+    handle.with(|bot| bot.sleep(0.0).map(|_| ()))?;
+    assert!(stream.recv_timeout(Duration::from_secs(5)).unwrap().is_ok());
+    assert!(stream.stop().is_none());
+
+    assert!(StateStreamer::new()
+        .set_rate_hz(0.0)
+        .spawn_shared(handle)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn robot_snapshot() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut robot = fake_psu(records.clone())?;
+    robot.set_param(MotionParam::new().set_speed(25.0))?;
+
+    let snapshot = robot.snapshot()?;
+    assert_eq!(
+        snapshot.transform,
+        Transform::new(100.0, 200.0, 300.0, 0.0, 0.0, 0.0)
+    );
+    assert_eq!(snapshot.io[&IOTarget::Beckhoff], 0b1001);
+    assert_eq!(snapshot.io[&IOTarget::Wrist], 0);
+    assert_eq!(snapshot.gripper, None);
+    assert_eq!(snapshot.params.as_ref(), robot.motion_param());
+
+    // one exchange for the whole snapshot
+    assert_eq!(records.lock().unwrap().len(), 2);
+    let state = RobotState::from(snapshot.clone());
+    assert_eq!(state.timestamp, snapshot.timestamp);
+    Ok(())
+}
+
+#[test]
+fn state_diff() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut robot = fake_psu(records)?;
+    let actual = robot.snapshot()?;
+
+    let mut golden = actual.clone();
+    golden.transform = Transform::new(100.0, 200.0, 310.0, 0.0, 0.0, 0.0);
+    golden.joints = JointCoord::from_j2(5.0);
+    golden.io.insert(IOTarget::Wrist, 0b10);
+    golden.gripper = Some(40.0);
+
+    let diff = golden.diff(&actual);
+    assert!((diff.translation_mm - 10.0).abs() < 1e-9);
+    assert_eq!(diff.rotation_deg, 0.0);
+    assert_eq!(diff.joints, JointCoord::from_j2(-5.0));
+    assert_eq!(
+        diff.io,
+        [IOChange {
+            target: IOTarget::Wrist,
+            port: 1,
+            state: false
+        }]
+    );
+    assert_eq!(diff.gripper, Some((Some(40.0), None)));
+    assert_eq!(
+        diff.to_string(),
+        "moved 10.000 mm, joints moved up to 5.000 deg, wrist 1 off, gripper 40.000 -> none"
+    );
+    assert!(!diff.is_within(20.0, 10.0));
+
+    assert!(actual.diff(&actual).is_empty());
+    assert_eq!(actual.diff(&actual).to_string(), "no change");
+    Ok(())
+}
+
+#[test]
+fn runtime_state_without_ros_bridge() -> Result<(), Box<dyn std::error::Error>> {
+    let mut robot = fake_psu(Arc::new(Mutex::new(vec![])))?;
+    assert!(matches!(
+        robot.runtime_state(),
+        Err(RobotError::InvalidArgument(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn robot_info() -> Result<(), Box<dyn std::error::Error>> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut robot = fake_psu(records.clone())?;
+
+    let info = robot.info()?;
+    assert_eq!(info.model, "IVA-5");
+    assert_eq!(info.serial_number, "IV5-0042");
+    assert_eq!(info.software_version, "2.4.1");
+    assert_eq!(info.tool.as_deref(), Some("Robotiq 2F-85"));
+    assert_eq!(
+        info.to_string(),
+        "IVA-5 IV5-0042 (software 2.4.1) with Robotiq 2F-85"
+    );
+    assert!(records.lock().unwrap()[0].contains("\"target\":\"info\""));
+    Ok(())
+}
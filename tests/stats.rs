@@ -0,0 +1,31 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::geometry::Transform;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn robot_stats() -> Result<(), Box<dyn std::error::Error>> {
+    let mut bot = fake_psu(Arc::new(Mutex::new(vec![])))?;
+
+    bot.sleep(0.0)?.sleep(0.0)?;
+    bot.joint_relative(Transform::from_z(10.0))?;
+    assert!(bot.linear(Transform::from_x(10.0)).is_err());
+    bot.get_current_transform()?;
+    assert_eq!(bot.get_joint_torques()?, [1.5, -2.0, 0.0, 0.25, 0.0, 0.0]);
+
+    let stats = bot.stats();
+    let execute = &stats.instructions["execute"];
+    assert_eq!(execute.count, 4);
+    assert!(execute.p50 <= execute.p90 && execute.p90 <= execute.p99 && execute.p99 <= execute.max);
+    assert_eq!(stats.instructions["get"].count, 2);
+    assert_eq!(stats.motions, 2);
+    assert_eq!(stats.errors.get("response"), Some(&1));
+
+    bot.reset_stats();
+    let stats = bot.stats();
+    assert!(stats.instructions.is_empty());
+    assert_eq!(stats.motions, 0);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::FakePsu;
+use inovo_rs::iva::{GetTarget, Instruction};
+use inovo_rs::robot::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn supervisor_health() -> Result<(), Box<dyn std::error::Error>> {
+    let failing = Arc::new(AtomicBool::new(false));
+    let responder_failing = failing.clone();
+    let psu = FakePsu::with_responder(move |inst| match inst {
+        Instruction::Get(GetTarget::ContextDepth) if responder_failing.load(Ordering::Relaxed) => {
+            "Error".to_string()
+        }
+        inst => FakePsu::default_response(inst),
+    })?;
+    let handle = RobotHandle::new(psu.connect()?);
+
+    let supervisor = Supervisor::new()
+        .set_interval(Duration::from_millis(10))
+        .set_max_failures(2)
+        .spawn(handle.clone())?;
+    std::thread::sleep(Duration::from_millis(50));
+    assert!(supervisor.is_healthy());
+
+    failing.store(true, Ordering::Relaxed);
+    let timeout = Duration::from_secs(5);
+    assert!(matches!(
+        supervisor.recv_timeout(timeout),
+        Some(Health::Degraded { .. })
+    ));
+    assert_eq!(supervisor.recv_timeout(timeout), Some(Health::Down));
+    assert_eq!(supervisor.health(), Health::Down);
+
+    failing.store(false, Ordering::Relaxed);
+    assert_eq!(supervisor.recv_timeout(timeout), Some(Health::Healthy));
+
+    // the application shares the robot with the heartbeat
+    handle.with(|bot| bot.sleep(0.0).map(|_| ()))?;
+    supervisor.stop();
+    Ok(())
+}
+
+#[test]
+fn supervisor_classifier() {
+    assert_eq!(
+        classify_error(&RobotError::SocketError(
+            std::io::ErrorKind::BrokenPipe.into()
+        )),
+        ErrorClass::Fatal
+    );
+    assert_eq!(classify_error(&RobotError::Cancelled), ErrorClass::Ignore);
+    assert_eq!(
+        classify_error(&RobotError::ResponseError("Error".to_string())),
+        ErrorClass::Degraded
+    );
+}
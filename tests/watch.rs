@@ -0,0 +1,47 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[test]
+pub fn watch_data_changes() -> Result<(), RobotError> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let monitor = fake_psu(records.clone())?;
+
+    // the fake psu counts up every third instruction
+    let watch = monitor.watch_data::<i64>("counter", Duration::from_millis(1))?;
+    let changes: Vec<DataChange<i64>> = watch.iter().take(3).collect::<Result<_, _>>()?;
+    assert_eq!(changes[0].previous, None);
+    assert_eq!(
+        changes
+            .iter()
+            .map(|c| (c.previous, c.value))
+            .collect::<Vec<_>>(),
+        [(None, 0), (Some(0), 1), (Some(1), 2)]
+    );
+    assert!(watch.stop().is_some());
+    assert!(records.lock().unwrap().len() >= 7);
+    Ok(())
+}
+
+#[test]
+pub fn watch_data_error() -> Result<(), RobotError> {
+    let handle = RobotHandle::new(fake_psu(Arc::new(Mutex::new(vec![])))?);
+
+    // the fake psu responds "OK", not a number
+    let watch = handle.watch_data::<f64>("missing", Duration::from_millis(1))?;
+    assert!(matches!(
+        watch.recv_timeout(Duration::from_secs(5)),
+        Some(Err(RobotError::ResponseError(_)))
+    ));
+    assert!(watch.recv().is_none());
+    assert!(watch.stop().is_none());
+
+    assert!(matches!(
+        handle.watch_data::<f64>("missing", Duration::ZERO),
+        Err(RobotError::InvalidArgument(_))
+    ));
+    Ok(())
+}
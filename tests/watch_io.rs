@@ -0,0 +1,61 @@
+mod common;
+
+use common::FakePsu;
+use inovo_rs::iva::{IOCommand, IOTarget, Instruction};
+use inovo_rs::robot::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[test]
+fn watch_io_edges() -> Result<(), Box<dyn std::error::Error>> {
+    let polls = AtomicUsize::new(0);
+    let psu = FakePsu::with_responder(move |inst| match inst {
+        Instruction::IO {
+            target: IOTarget::Beckhoff,
+            io_command: IOCommand::GetBank { .. },
+            ..
+        } => {
+            // a glitch of port 1, then port 3 held high until it falls
+            let mask = match polls.fetch_add(1, Ordering::Relaxed) {
+                2 => 0b0010,
+                3..=20 => 0b1000,
+                _ => 0b0000,
+            };
+            mask.to_string()
+        }
+        Instruction::IO {
+            target: IOTarget::Wrist,
+            io_command: IOCommand::GetBank { .. },
+            ..
+        } => "1".to_string(),
+        inst => FakePsu::default_response(inst),
+    })?;
+    let watcher = IOWatcher::new(Duration::from_millis(2))
+        .watch(IOTarget::Beckhoff, 0..4)
+        .watch(IOTarget::Wrist, 0..2)
+        .set_debounce(Duration::from_millis(10));
+    let watch = psu.connect()?.watch_io(watcher)?;
+
+    let edges: Vec<IOEdge> = watch.iter().take(2).collect::<Result<_, _>>()?;
+    assert_eq!(edges[0].to_string(), "beckhoff port 3: false -> true");
+    assert!(edges[0].is_rising());
+    assert_eq!((edges[1].port, edges[1].state), (3, false));
+    assert!(edges[0].timestamp < edges[1].timestamp);
+    assert!(watch.recv_timeout(Duration::from_millis(50)).is_none());
+    assert!(watch.stop().is_some());
+    Ok(())
+}
+
+#[test]
+fn watch_io_invalid() -> Result<(), Box<dyn std::error::Error>> {
+    let psu = FakePsu::new()?;
+    let handle = RobotHandle::new(psu.connect()?);
+    let interval = Duration::from_millis(10);
+
+    assert!(handle.watch_io(IOWatcher::new(interval)).is_err());
+    let watcher = IOWatcher::new(interval).watch(IOTarget::Beckhoff, 0..33);
+    assert!(handle.watch_io(watcher).is_err());
+    let watcher = IOWatcher::new(Duration::ZERO).watch(IOTarget::Beckhoff, 0..8);
+    assert!(handle.watch_io(watcher).is_err());
+    Ok(())
+}
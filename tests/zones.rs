@@ -0,0 +1,97 @@
+mod common;
+
+use common::fake_psu;
+use inovo_rs::geometry::*;
+use inovo_rs::iva::*;
+use inovo_rs::robot::*;
+use std::sync::{Arc, Mutex};
+
+fn operator_side() -> SpeedZones {
+    SpeedZones::new()
+        .add_zone([-1000.0, 300.0, -500.0], [1000.0, 1000.0, 1500.0], 20.0)
+        .add_zone([-1000.0, 600.0, -500.0], [1000.0, 1000.0, 1500.0], 5.0)
+}
+
+#[test]
+pub fn zones_speed_along() {
+    let zones = operator_side();
+    let origin = Transform::from_vector([0.0, 0.0, 200.0]);
+
+    assert_eq!(zones.speed_at(&origin), None);
+    assert_eq!(
+        zones.speed_at(&Transform::from_vector([0.0, 400.0, 0.0])),
+        Some(20.0)
+    );
+    assert_eq!(
+        zones.speed_at(&Transform::from_vector([0.0, 700.0, 0.0])),
+        Some(5.0)
+    );
+    assert_eq!(zones.slowest_speed(), Some(5.0));
+
+    // passing through the zone, both ends outside
+    let through = Transform::from_vector([0.0, 1200.0, 200.0]);
+    assert_eq!(zones.speed_along(&origin, &through), Some(5.0));
+    let along = Transform::from_vector([500.0, 0.0, 200.0]);
+    assert_eq!(zones.speed_along(&origin, &along), None);
+    // the corners are swapped when added
+    let swapped = SpeedZones::new().add_zone([10.0, 10.0, 10.0], [-10.0, -10.0, -10.0], 30.0);
+    assert_eq!(swapped.speed_at(&Transform::identity()), Some(30.0));
+}
+
+#[test]
+pub fn zones_apply() {
+    let zones = operator_side();
+    let base = MotionParam::new().set_speed(80.0).set_accel(50.0);
+    let slow = MotionParam::new().set_speed(10.0);
+
+    let path = CommandSequence::new()
+        .then_linear(Transform::from_vector([0.0, 400.0, 200.0]))
+        .then_set_param(slow.clone())
+        .then_linear_relative(Transform::from_y(-400.0))
+        .then_joint(JointCoord::identity())
+        .then_sleep(1.0);
+    let zoned = zones.apply(path, Some(Transform::from_z(200.0)), &base);
+
+    assert_eq!(
+        zoned.to_vec(),
+        vec![
+            RobotCommand::SetParameter(base.clone().set_speed(20.0)),
+            RobotCommand::linear(Transform::from_vector([0.0, 400.0, 200.0])),
+            // slower than the zone, kept
+            RobotCommand::SetParameter(slow.clone()),
+            RobotCommand::linear_relative(Transform::from_y(-400.0)),
+            // unknown path
+            RobotCommand::SetParameter(slow.clone().set_speed(5.0)),
+            RobotCommand::joint(JointCoord::identity()),
+            RobotCommand::Sleep { second: 1.0 },
+            RobotCommand::SetParameter(slow),
+        ]
+    );
+
+    // nothing inserted out of the zones
+    let path = CommandSequence::new().then_linear(Transform::from_x(100.0));
+    assert_eq!(zones.apply(path.clone(), None, &base), path);
+}
+
+#[test]
+pub fn zones_robot_sequence() -> Result<(), RobotError> {
+    let records = Arc::new(Mutex::new(vec![]));
+    let mut bot = fake_psu(records.clone())?;
+    bot.set_param(MotionParam::new().set_speed(80.0))?;
+    bot.set_speed_zones(operator_side());
+    records.lock().unwrap().clear();
+
+    // the fake psu is at (100, 200, 300) mm
+    bot.sequence(CommandSequence::new().then_linear_relative(Transform::from_y(200.0)))?;
+    let records = records.lock().unwrap().clone();
+    assert_eq!(records[0], r#"{"op_code":"get","target":"transform"}"#);
+    assert!(records[1].contains(r#""speed":0.2,"#));
+    assert!(records[2].contains("linear_relative"));
+    assert!(records[3].contains(r#""speed":0.8,"#));
+    assert!(records[4].contains("dequeue"));
+    assert_eq!(records.len(), 5);
+
+    bot.clear_speed_zones();
+    assert!(bot.speed_zones().is_none());
+    Ok(())
+}